@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+# ------------------------------------------------------------------------------
+#
+# Convert the data file of the NRLMSISE-00 reference C implementation 
+# (nrlmsise-00_data.c) into the plain coefficient file read by the simulation 
+# (nrlmsise-00_coefficients.txt).
+#
+# Each array is written as a header line "<name> <rows> <cols>" followed by 
+# its values in row-major order, one row per line.
+#
+# Usage: python3 convert_nrlmsise00.py nrlmsise-00_data.c nrlmsise-00_coefficients.txt
+#
+# ------------------------------------------------------------------------------
+import re
+import sys
+
+# Coefficient arrays used by the model and their shape (rows, cols)
+ARRAYS = [("pt", 1, 150), ("pd", 9, 150), ("ps", 1, 150), ("pdl", 2, 25),
+          ("ptl", 4, 100), ("pma", 10, 100), ("ptm", 1, 10), ("pdm", 8, 10),
+          ("pavgm", 1, 10)]
+
+NUMBER_PATTERN = re.compile(r"[-+]?(?:\d+\.\d*|\.\d+|\d+)(?:[eE][-+]?\d+)?")
+
+
+def strip_c_comments(content):
+    content = re.sub(r"/\*.*?\*/", " ", content, flags=re.DOTALL)
+    return re.sub(r"//[^\n]*", " ", content)
+
+
+def read_c_array(content, name, rows, cols):
+    match = re.search(r"double\s+" + name + r"\s*(?:\[[^\]]*\]\s*)+=\s*\{", content)
+    if match is None:
+        sys.exit("Array %s not found" % name)
+    depth, end = 1, match.end()
+    while depth > 0:
+        depth += {"{": 1, "}": -1}.get(content[end], 0)
+        end += 1
+    values = NUMBER_PATTERN.findall(content[match.end():end - 1])
+    if len(values) != rows * cols:
+        sys.exit("Array %s: expected %d values, found %d" % (name, rows * cols, len(values)))
+    return [values[row * cols:(row + 1) * cols] for row in range(rows)]
+
+
+def main(source_path, target_path):
+    with open(source_path) as source_file:
+        content = strip_c_comments(source_file.read())
+    with open(target_path, "w") as target_file:
+        target_file.write("# NRLMSISE-00 model coefficients converted from %s\n" % source_path)
+        target_file.write("# <name> <rows> <cols> followed by the values in row-major order\n")
+        for name, rows, cols in ARRAYS:
+            target_file.write("%s %d %d\n" % (name, rows, cols))
+            for row in read_c_array(content, name, rows, cols):
+                target_file.write(" ".join(row) + "\n")
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 3:
+        sys.exit("Usage: convert_nrlmsise00.py <nrlmsise-00_data.c> <output file>")
+    main(sys.argv[1], sys.argv[2])
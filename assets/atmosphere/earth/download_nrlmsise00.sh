@@ -0,0 +1,29 @@
+#!/bin/sh
+# ------------------------------------------------------------------------------
+#
+# Download the coefficients of the NRLMSISE-00 atmosphere model (see 
+# DATA_README.txt) and convert them into this folder:
+#
+#   nrlmsise-00_coefficients.txt - Plain coefficient file converted from the 
+#                                  data file nrlmsise-00_data.c of the 
+#                                  reference C implementation by Dominik 
+#                                  Brodowski (public domain)
+#
+# The data file is taken from the release archive of the reference 
+# implementation and converted with convert_nrlmsise00.py.
+# Set NRLMSISE00_ARCHIVE_URL to use another release or mirror.
+#
+# Usage: sh assets/atmosphere/earth/download_nrlmsise00.sh
+#
+# ------------------------------------------------------------------------------
+set -e
+cd "$(dirname "$0")"
+
+ARCHIVE_URL=${NRLMSISE00_ARCHIVE_URL:-https://www.brodo.de/space/nrlmsise/nrlmsise-00.tar.gz}
+TMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+curl -fL -o "$TMP_DIR/nrlmsise-00.tar.gz" "$ARCHIVE_URL"
+tar -xzf "$TMP_DIR/nrlmsise-00.tar.gz" -C "$TMP_DIR"
+python3 convert_nrlmsise00.py "$(find "$TMP_DIR" -name nrlmsise-00_data.c | head -n 1)" \
+  nrlmsise-00_coefficients.txt
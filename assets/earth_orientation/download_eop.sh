@@ -0,0 +1,25 @@
+#!/bin/sh
+# ------------------------------------------------------------------------------
+#
+# Download the IERS Earth orientation data of the iau2006 rotation model 
+# (see DATA_README.txt) into this folder:
+#
+#   finals2000A.all - Earth Orientation Parameters (IERS Rapid Service)
+#   tab5.2a.txt     - IAU 2006/2000A series of the CIP coordinate X
+#   tab5.2b.txt     - IAU 2006/2000A series of the CIP coordinate Y
+#   tab5.2d.txt     - IAU 2006/2000A series of s + XY/2
+#
+# Usage: sh assets/earth_orientation/download_eop.sh
+#
+# ------------------------------------------------------------------------------
+set -e
+cd "$(dirname "$0")"
+
+curl -fL -o finals2000A.all \
+  https://datacenter.iers.org/products/eop/rapid/standard/finals2000A.all
+
+for table in tab5.2a.txt tab5.2b.txt tab5.2d.txt
+do
+  curl -fL -o "$table" \
+    "https://iers-conventions.obspm.fr/content/chapter5/additional_info/$table"
+done
@@ -0,0 +1,16 @@
+#!/bin/sh
+# ------------------------------------------------------------------------------
+#
+# Download the IGRF-13 coefficient table of the igrf magnetic field model 
+# (see DATA_README.txt) into this folder:
+#
+#   igrf13coeffs.txt - Schmidt semi-normalized coefficients (1900 - 2025)
+#
+# Usage: sh assets/magnetic_field/download_igrf.sh
+#
+# ------------------------------------------------------------------------------
+set -e
+cd "$(dirname "$0")"
+
+curl -fL -o igrf13coeffs.txt \
+  https://www.ncei.noaa.gov/IAGA/vmod/coeffs/igrf13coeffs.txt
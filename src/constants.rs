@@ -6,4 +6,13 @@ pub mod filepaths;
 pub mod time; 
 pub mod general;
 pub mod atmosphere;
-pub mod spacecraft;
\ No newline at end of file
+pub mod spacecraft;
+pub mod celestial;
+
+pub mod orbit;
+
+pub mod earth_orientation;
+
+pub mod ephemeris;
+
+pub mod magnetic_field;
@@ -62,4 +62,37 @@ pub const ATMOS_SPEED_OF_SOUND_TABLE_INDX_SPEED_OF_SOUND: usize = 0;
   * @brief: Path at which the mean free path table data can be loaded
   *  
   */
-  pub const ATMOS_SPEED_OF_SOUND_TABLE_PATH: &str = "assets/atmosphere/earth/speed_of_sound.csv";
\ No newline at end of file
+  pub const ATMOS_SPEED_OF_SOUND_TABLE_PATH: &str = "assets/atmosphere/earth/speed_of_sound.csv";
+
+ /*
+  * @brief: NRLMSISE-00 output indices of the density array. Number densities 
+  *         are given in 1/m3, the total mass density in kg/m3.
+  *  
+  */
+pub const NRLMSISE_INDX_HE: usize          = 0;
+pub const NRLMSISE_INDX_O: usize           = 1;
+pub const NRLMSISE_INDX_N2: usize          = 2;
+pub const NRLMSISE_INDX_O2: usize          = 3;
+pub const NRLMSISE_INDX_AR: usize          = 4;
+pub const NRLMSISE_INDX_TOTAL_MASS: usize  = 5;
+pub const NRLMSISE_INDX_H: usize           = 6;
+pub const NRLMSISE_INDX_N: usize           = 7;
+pub const NRLMSISE_INDX_ANOMALOUS_O: usize = 8;
+
+ /*
+  * @brief: NRLMSISE-00 output indices of the species number densities in the 
+  *         order He, O, N2, O2, Ar, H, N, anomalous O (without the total mass 
+  *         density).
+  *  
+  */
+pub const NRLMSISE_SPECIES_INDICES: [usize; 8] = [NRLMSISE_INDX_HE, NRLMSISE_INDX_O, NRLMSISE_INDX_N2,
+                                                  NRLMSISE_INDX_O2, NRLMSISE_INDX_AR, NRLMSISE_INDX_H,
+                                                  NRLMSISE_INDX_N, NRLMSISE_INDX_ANOMALOUS_O];
+
+ /*
+  * @brief: NRLMSISE-00 output indices of the temperature array (exospheric 
+  *         temperature and temperature at altitude) in K.
+  *  
+  */
+pub const NRLMSISE_INDX_TEMP_EXOSPHERE: usize = 0;
+pub const NRLMSISE_INDX_TEMP_ALTITUDE: usize  = 1;
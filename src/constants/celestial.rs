@@ -0,0 +1,64 @@
+/*----------------------------------------------------------------------------*/
+/*
+ *                  [Celestial body constants]
+ * 
+ */
+/*----------------------------------------------------------------------------*/
+/* 
+ * @brief: Gravitational constant of the Sun (GM)
+ *  
+ * Source: IERS Conventions (2010), Table 1.1
+ * 
+ * @unit:  m3/s2
+ * @frame: N/A
+ */
+pub const SUN_GRAVITATIONAL_CONSTANT: f64 = 1.32712442099e20;
+
+/* 
+ * @brief: Gravitational constant of the Moon (GM)
+ *  
+ * Source: DE430 (GM_Earth / Earth-Moon mass ratio 81.30056907419062)
+ * 
+ * @unit:  m3/s2
+ * @frame: N/A
+ */
+pub const MOON_GRAVITATIONAL_CONSTANT: f64 = 4.902800066e12;
+
+/* 
+ * @brief: Astronomical unit
+ *  
+ * Source: IAU 2012 Resolution B2
+ * 
+ * @unit:  m
+ * @frame: N/A
+ */
+pub const ASTRONOMICAL_UNIT_M: f64 = 149597870700.0;
+
+/* 
+ * @brief: Obliquity of the ecliptic at J2000 epoch
+ *  
+ * @description: Angle between the mean equator and the ecliptic used to 
+ *               convert ecliptic to equatorial coordinates
+ * 
+ * @unit:  degree
+ * @frame: N/A
+ */
+pub const OBLIQUITY_J2000_DEG: f64 = 23.43929111;
+
+/* 
+ * @brief: Equatorial radius of the Sun
+ *  
+ * Source: IAU 2015 Resolution B3 (nominal solar radius)
+ * 
+ * @unit:  m
+ * @frame: N/A
+ */
+pub const SUN_RADIUS_M: f64 = 6.957e8;
+
+/* 
+ * @brief: Speed of light in vacuum
+ *  
+ * @unit:  m/s
+ * @frame: N/A
+ */
+pub const SPEED_OF_LIGHT_MS: f64 = 299792458.0;
@@ -0,0 +1,247 @@
+/*----------------------------------------------------------------------------*/
+/*
+ *                  [Earth orientation constants]
+ *
+ */
+/*----------------------------------------------------------------------------*/
+/*
+ * @brief: Conversion factors from arcseconds, milliarcseconds and
+ *         microarcseconds to radians
+ *
+ * @unit:  rad
+ * @frame: N/A
+ */
+pub const ARCSEC_TO_RAD: f64 = std::f64::consts::PI / (180.0 * 3600.0);
+pub const MILLIARCSEC_TO_RAD: f64 = ARCSEC_TO_RAD * 1.0e-3;
+pub const MICROARCSEC_TO_RAD: f64 = ARCSEC_TO_RAD * 1.0e-6;
+
+/*
+ * @brief: Earth rotation angle (ERA) at J2000.0 UT1 and its rate
+ *
+ * @description: ERA = 2pi * (ERA_AT_J2000 + ERA_RATE * (JD_UT1 - 2451545.0))
+ *
+ * Source: IERS Conventions (2010), Eq. (5.15)
+ *
+ * @unit:  revolutions, revolutions per UT1 day
+ * @frame: N/A
+ */
+pub const ERA_AT_J2000_REV: f64 = 0.7790572732640;
+pub const ERA_RATE_REV_PER_DAY: f64 = 1.0027378119113546;
+
+/*
+ * @brief: Rate of the TIO locator s'
+ *
+ * Source: IERS Conventions (2010), Eq. (5.13)
+ *
+ * @unit:  microarcseconds per Julian century TT
+ * @frame: N/A
+ */
+pub const TIO_LOCATOR_RATE_UAS: f64 = -47.0;
+
+/*
+ * @brief: Polynomial part of the CIP coordinates X, Y and of the CIO locator
+ *         s + XY/2 (IAU 2006/2000A), coefficients of t^0 .. t^5 with t in
+ *         Julian centuries TT since J2000.0
+ *
+ * Source: IERS Conventions (2010), Eq. (5.16) and Table 5.2d
+ *
+ * @unit:  microarcseconds
+ * @frame: GCRS
+ */
+pub const CIP_X_POLYNOMIAL_UAS: [f64; 6] = [-16617.0, 2004191898.0, -429782.9, -198618.34, 7.578, 5.9285];
+pub const CIP_Y_POLYNOMIAL_UAS: [f64; 6] = [-6951.0, -25896.0, -22407274.7, 1900.59, 1112.526, 0.1358];
+pub const CIO_S_POLYNOMIAL_UAS: [f64; 6] = [94.0, 3808.65, -122.68, -72574.11, 27.98, 15.62];
+
+/*
+ * @brief: Largest periodic terms of s + XY/2 used if the full series is not
+ *         loaded. Columns: multipliers of (l, l', F, D, Om), sine amplitude.
+ *
+ * Source: IERS Conventions (2010), Table 5.2d
+ *
+ * @unit:  N/A, microarcseconds
+ * @frame: N/A
+ */
+pub const CIO_S_LEADING_TERMS: [[f64; 6]; 4] = [
+  [0.0, 0.0, 0.0,  0.0, 1.0, -2640.73],
+  [0.0, 0.0, 0.0,  0.0, 2.0,   -63.53],
+  [0.0, 0.0, 2.0, -2.0, 3.0,   -11.75],
+  [0.0, 0.0, 2.0, -2.0, 1.0,   -11.21],
+];
+
+/*
+ * @brief: Fukushima-Williams precession angles gamma_bar, phi_bar, psi_bar
+ *         and mean obliquity of the ecliptic epsilon_A (IAU 2006),
+ *         coefficients of t^0 .. t^5 with t in Julian centuries TT
+ *
+ * Source: IERS Conventions (2010), Eq. (5.39) and (5.40)
+ *
+ * @unit:  arcseconds
+ * @frame: N/A
+ */
+pub const PRECESSION_FW_GAMMA_ARCSEC: [f64; 6] = [-0.052928, 10.556378, 0.4932044, -0.00031238, -0.000002788, 0.0000000260];
+pub const PRECESSION_FW_PHI_ARCSEC: [f64; 6] = [84381.412819, -46.811016, 0.0511268, 0.00053289, -0.000000440, -0.0000000176];
+pub const PRECESSION_FW_PSI_ARCSEC: [f64; 6] = [-0.041775, 5038.481484, 1.5584175, -0.00018522, -0.000026452, -0.0000000148];
+pub const MEAN_OBLIQUITY_ARCSEC: [f64; 6] = [84381.406, -46.836769, -0.0001831, 0.00200340, -0.000000576, -0.0000000434];
+
+/*
+ * @brief: Delaunay arguments of the lunisolar nutation (l, l', F, D, Om),
+ *         coefficients of t^0 .. t^4 with t in Julian centuries TT
+ *
+ * Source: IERS Conventions (2010), Eq. (5.43)
+ *
+ * @unit:  arcseconds
+ * @frame: N/A
+ */
+pub const DELAUNAY_ARGUMENTS_ARCSEC: [[f64; 5]; 5] = [
+  [ 485868.249036, 1717915923.2178,  31.8792,  0.051635, -0.00024470],
+  [1287104.79305,   129596581.0481,  -0.5532,  0.000136, -0.00001149],
+  [ 335779.526232, 1739527262.8478, -12.7512, -0.001037,  0.00000417],
+  [1072260.70369,  1602961601.2090,  -6.3706,  0.006593, -0.00003169],
+  [ 450160.398036,   -6962890.5431,   7.4722,  0.007702, -0.00005939],
+];
+
+/*
+ * @brief: Mean longitudes of the planets Mercury to Neptune (constant and
+ *         linear term) and general accumulated precession in longitude p_A
+ *         (linear and quadratic term)
+ *
+ * Source: IERS Conventions (2010), Eq. (5.44)
+ *
+ * @unit:  rad
+ * @frame: N/A
+ */
+pub const PLANETARY_ARGUMENTS_RAD: [[f64; 2]; 8] = [
+  [4.402608842, 2608.7903141574],
+  [3.176146697, 1021.3285546211],
+  [1.753470314,  628.3075849991],
+  [6.203480913,  334.0612426700],
+  [0.599546497,   52.9690962641],
+  [0.874016757,   21.3299104960],
+  [5.481293872,    7.4781598567],
+  [5.311886287,    3.8133035638],
+];
+pub const GENERAL_PRECESSION_RAD: [f64; 2] = [0.02438175, 0.00000538691];
+
+/*
+ * @brief: Number of fundamental arguments (5 Delaunay arguments, 8 planetary
+ *         longitudes, general precession)
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const NUM_FUNDAMENTAL_ARGUMENTS: usize = 14;
+
+/*
+ * @brief: Luni-solar nutation series IAU 2000B (77 terms). Columns:
+ *         multipliers of (l, l', F, D, Om), longitude sin, sin*t, cos and
+ *         obliquity cos, cos*t, sin coefficients.
+ *
+ * Source: McCarthy, Luzum - An abridged model of the precession-nutation of
+ *         the celestial pole, Celest. Mech. Dyn. Astron. 85, 2003
+ *
+ * @unit:  N/A, 0.1 microarcseconds
+ * @frame: N/A
+ */
+pub const NUTATION_IAU2000B_TERMS: [[f64; 11]; 77] = [
+  [ 0.0, 0.0, 0.0, 0.0, 1.0, -172064161.0, -174666.0,  33386.0, 92052331.0,  9086.0,  15377.0],
+  [ 0.0, 0.0, 2.0,-2.0, 2.0,  -13170906.0,   -1675.0, -13696.0,  5730336.0, -3015.0,  -4587.0],
+  [ 0.0, 0.0, 2.0, 0.0, 2.0,   -2276413.0,    -234.0,   2796.0,   978459.0,  -485.0,   1374.0],
+  [ 0.0, 0.0, 0.0, 0.0, 2.0,    2074554.0,     207.0,   -698.0,  -897492.0,   470.0,   -291.0],
+  [ 0.0, 1.0, 0.0, 0.0, 0.0,    1475877.0,   -3633.0,  11817.0,    73871.0,  -184.0,  -1924.0],
+  [ 0.0, 1.0, 2.0,-2.0, 2.0,    -516821.0,    1226.0,   -524.0,   224386.0,  -677.0,   -174.0],
+  [ 1.0, 0.0, 0.0, 0.0, 0.0,     711159.0,      73.0,   -872.0,    -6750.0,     0.0,    358.0],
+  [ 0.0, 0.0, 2.0, 0.0, 1.0,    -387298.0,    -367.0,    380.0,   200728.0,    18.0,    318.0],
+  [ 1.0, 0.0, 2.0, 0.0, 2.0,    -301461.0,     -36.0,    816.0,   129025.0,   -63.0,    367.0],
+  [ 0.0,-1.0, 2.0,-2.0, 2.0,     215829.0,    -494.0,    111.0,   -95929.0,   299.0,    132.0],
+  [ 0.0, 0.0, 2.0,-2.0, 1.0,     128227.0,     137.0,    181.0,   -68982.0,    -9.0,     39.0],
+  [-1.0, 0.0, 2.0, 0.0, 2.0,     123457.0,      11.0,     19.0,   -53311.0,    32.0,     -4.0],
+  [-1.0, 0.0, 0.0, 2.0, 0.0,     156994.0,      10.0,   -168.0,    -1235.0,     0.0,     82.0],
+  [ 1.0, 0.0, 0.0, 0.0, 1.0,      63110.0,      63.0,     27.0,   -33228.0,     0.0,     -9.0],
+  [-1.0, 0.0, 0.0, 0.0, 1.0,     -57976.0,     -63.0,   -189.0,    31429.0,     0.0,    -75.0],
+  [-1.0, 0.0, 2.0, 2.0, 2.0,     -59641.0,     -11.0,    149.0,    25543.0,   -11.0,     66.0],
+  [ 1.0, 0.0, 2.0, 0.0, 1.0,     -51613.0,     -42.0,    129.0,    26366.0,     0.0,     78.0],
+  [-2.0, 0.0, 2.0, 0.0, 1.0,      45893.0,      50.0,     31.0,   -24236.0,   -10.0,     20.0],
+  [ 0.0, 0.0, 0.0, 2.0, 0.0,      63384.0,      11.0,   -150.0,    -1220.0,     0.0,     29.0],
+  [ 0.0, 0.0, 2.0, 2.0, 2.0,     -38571.0,      -1.0,    158.0,    16452.0,   -11.0,     68.0],
+  [ 0.0,-2.0, 2.0,-2.0, 2.0,      32481.0,       0.0,      0.0,   -13870.0,     0.0,      0.0],
+  [-2.0, 0.0, 0.0, 2.0, 0.0,     -47722.0,       0.0,    -18.0,      477.0,     0.0,    -25.0],
+  [ 2.0, 0.0, 2.0, 0.0, 2.0,     -31046.0,      -1.0,    131.0,    13238.0,   -11.0,     59.0],
+  [ 1.0, 0.0, 2.0,-2.0, 2.0,      28593.0,       0.0,     -1.0,   -12338.0,    10.0,     -3.0],
+  [-1.0, 0.0, 2.0, 0.0, 1.0,      20441.0,      21.0,     10.0,   -10758.0,     0.0,     -3.0],
+  [ 2.0, 0.0, 0.0, 0.0, 0.0,      29243.0,       0.0,    -74.0,     -609.0,     0.0,     13.0],
+  [ 0.0, 0.0, 2.0, 0.0, 0.0,      25887.0,       0.0,    -66.0,     -550.0,     0.0,     11.0],
+  [ 0.0, 1.0, 0.0, 0.0, 1.0,     -14053.0,     -25.0,     79.0,     8551.0,    -2.0,    -45.0],
+  [-1.0, 0.0, 0.0, 2.0, 1.0,      15164.0,      10.0,     11.0,    -8001.0,     0.0,     -1.0],
+  [ 0.0, 2.0, 2.0,-2.0, 2.0,     -15794.0,      72.0,    -16.0,     6850.0,   -42.0,     -5.0],
+  [ 0.0, 0.0,-2.0, 2.0, 0.0,      21783.0,       0.0,     13.0,     -167.0,     0.0,     13.0],
+  [ 1.0, 0.0, 0.0,-2.0, 1.0,     -12873.0,     -10.0,    -37.0,     6953.0,     0.0,    -14.0],
+  [ 0.0,-1.0, 0.0, 0.0, 1.0,     -12654.0,      11.0,     63.0,     6415.0,     0.0,     26.0],
+  [-1.0, 0.0, 2.0, 2.0, 1.0,     -10204.0,       0.0,     25.0,     5222.0,     0.0,     15.0],
+  [ 0.0, 2.0, 0.0, 0.0, 0.0,      16707.0,     -85.0,    -10.0,      168.0,    -1.0,     10.0],
+  [ 1.0, 0.0, 2.0, 2.0, 2.0,      -7691.0,       0.0,     44.0,     3268.0,     0.0,     19.0],
+  [-2.0, 0.0, 2.0, 0.0, 0.0,     -11024.0,       0.0,    -14.0,      104.0,     0.0,      2.0],
+  [ 0.0, 1.0, 2.0, 0.0, 2.0,       7566.0,     -21.0,    -11.0,    -3250.0,     0.0,     -5.0],
+  [ 0.0, 0.0, 2.0, 2.0, 1.0,      -6637.0,     -11.0,     25.0,     3353.0,     0.0,     14.0],
+  [ 0.0,-1.0, 2.0, 0.0, 2.0,      -7141.0,      21.0,      8.0,     3070.0,     0.0,      4.0],
+  [ 0.0, 0.0, 0.0, 2.0, 1.0,      -6302.0,     -11.0,      2.0,     3272.0,     0.0,      4.0],
+  [ 1.0, 0.0, 2.0,-2.0, 1.0,       5800.0,      10.0,      2.0,    -3045.0,     0.0,     -1.0],
+  [ 2.0, 0.0, 2.0,-2.0, 2.0,       6443.0,       0.0,     -7.0,    -2768.0,     0.0,     -4.0],
+  [-2.0, 0.0, 0.0, 2.0, 1.0,      -5774.0,     -11.0,    -15.0,     3041.0,     0.0,     -5.0],
+  [ 2.0, 0.0, 2.0, 0.0, 1.0,      -5350.0,       0.0,     21.0,     2695.0,     0.0,     12.0],
+  [ 0.0,-1.0, 2.0,-2.0, 1.0,      -4752.0,     -11.0,     -3.0,     2719.0,     0.0,     -3.0],
+  [ 0.0, 0.0, 0.0,-2.0, 1.0,      -4940.0,     -11.0,    -21.0,     2720.0,     0.0,     -9.0],
+  [-1.0,-1.0, 0.0, 2.0, 0.0,       7350.0,       0.0,     -8.0,      -51.0,     0.0,      4.0],
+  [ 2.0, 0.0, 0.0,-2.0, 1.0,       4065.0,       0.0,      6.0,    -2206.0,     0.0,      1.0],
+  [ 1.0, 0.0, 0.0, 2.0, 0.0,       6579.0,       0.0,    -24.0,     -199.0,     0.0,      2.0],
+  [ 0.0, 1.0, 2.0,-2.0, 1.0,       3579.0,       0.0,      5.0,    -1900.0,     0.0,      1.0],
+  [ 1.0,-1.0, 0.0, 0.0, 0.0,       4725.0,       0.0,     -6.0,      -41.0,     0.0,      3.0],
+  [-2.0, 0.0, 2.0, 0.0, 2.0,      -3075.0,       0.0,     -2.0,     1313.0,     0.0,     -1.0],
+  [ 3.0, 0.0, 2.0, 0.0, 2.0,      -2904.0,       0.0,     15.0,     1233.0,     0.0,      7.0],
+  [ 0.0,-1.0, 0.0, 2.0, 0.0,       4348.0,       0.0,    -10.0,      -81.0,     0.0,      2.0],
+  [ 1.0,-1.0, 2.0, 0.0, 2.0,      -2878.0,       0.0,      8.0,     1232.0,     0.0,      4.0],
+  [ 0.0, 0.0, 0.0, 1.0, 0.0,      -4230.0,       0.0,      5.0,      -20.0,     0.0,     -2.0],
+  [-1.0,-1.0, 2.0, 2.0, 2.0,      -2819.0,       0.0,      7.0,     1207.0,     0.0,      3.0],
+  [-1.0, 0.0, 2.0, 0.0, 0.0,      -4056.0,       0.0,      5.0,       40.0,     0.0,     -2.0],
+  [ 0.0,-1.0, 2.0, 2.0, 2.0,      -2647.0,       0.0,     11.0,     1129.0,     0.0,      5.0],
+  [-2.0, 0.0, 0.0, 0.0, 1.0,      -2294.0,       0.0,    -10.0,     1266.0,     0.0,     -4.0],
+  [ 1.0, 1.0, 2.0, 0.0, 2.0,       2481.0,       0.0,     -7.0,    -1062.0,     0.0,     -3.0],
+  [ 2.0, 0.0, 0.0, 0.0, 1.0,       2179.0,       0.0,     -2.0,    -1129.0,     0.0,     -2.0],
+  [-1.0, 1.0, 0.0, 1.0, 0.0,       3276.0,       0.0,      1.0,       -9.0,     0.0,      0.0],
+  [ 1.0, 1.0, 0.0, 0.0, 0.0,      -3389.0,       0.0,      5.0,       35.0,     0.0,     -2.0],
+  [ 1.0, 0.0, 2.0, 0.0, 0.0,       3339.0,       0.0,    -13.0,     -107.0,     0.0,      1.0],
+  [-1.0, 0.0, 2.0,-2.0, 1.0,      -1987.0,       0.0,     -6.0,     1073.0,     0.0,     -2.0],
+  [ 1.0, 0.0, 0.0, 0.0, 2.0,      -1981.0,       0.0,      0.0,      854.0,     0.0,      0.0],
+  [-1.0, 0.0, 0.0, 1.0, 0.0,       4026.0,       0.0,   -353.0,     -553.0,     0.0,   -139.0],
+  [ 0.0, 0.0, 2.0, 1.0, 2.0,       1660.0,       0.0,     -5.0,     -710.0,     0.0,     -2.0],
+  [-1.0, 0.0, 2.0, 4.0, 2.0,      -1521.0,       0.0,      9.0,      647.0,     0.0,      4.0],
+  [-1.0, 1.0, 0.0, 1.0, 1.0,       1314.0,       0.0,      0.0,     -700.0,     0.0,      0.0],
+  [ 0.0,-2.0, 2.0,-2.0, 1.0,      -1283.0,       0.0,      0.0,      672.0,     0.0,      0.0],
+  [ 1.0, 0.0, 2.0, 2.0, 1.0,      -1331.0,       0.0,      8.0,      663.0,     0.0,      4.0],
+  [-2.0, 0.0, 2.0, 2.0, 2.0,       1383.0,       0.0,     -2.0,     -594.0,     0.0,     -2.0],
+  [-1.0, 0.0, 0.0, 0.0, 2.0,       1405.0,       0.0,      4.0,     -610.0,     0.0,      2.0],
+  [ 1.0, 1.0, 2.0,-2.0, 2.0,       1290.0,       0.0,      0.0,     -556.0,     0.0,      0.0],
+];
+
+/*
+ * @brief: Fixed offsets replacing the planetary nutation terms in IAU 2000B
+ *
+ * Source: McCarthy, Luzum (2003)
+ *
+ * @unit:  milliarcseconds
+ * @frame: N/A
+ */
+pub const NUTATION_IAU2000B_PLANETARY_PSI_MAS: f64 = -0.135;
+pub const NUTATION_IAU2000B_PLANETARY_EPS_MAS: f64 = 0.388;
+
+/*
+ * @brief: Interval to update the precession-nutation matrix and the Earth
+ *         orientation parameters
+ *
+ * @description: Precession and nutation change the orientation of the CIP by
+ *               less than 1e-4 arcseconds within this interval. The Earth
+ *               rotation angle is computed at every call.
+ *
+ * @unit:  seconds
+ * @frame: N/A
+ */
+pub const EOP_UPDATE_INTERVAL_S: f64 = 60.0;
@@ -0,0 +1,293 @@
+/*----------------------------------------------------------------------------*/
+/*
+ *                  [Ephemeris constants]
+ *
+ */
+/*----------------------------------------------------------------------------*/
+/*
+ * @brief: Polynomial coefficients (t^0 .. t^4, t in Julian centuries TT) of
+ *         the fundamental arguments of the lunar theory ELP-2000/82
+ *
+ * @description: L' - mean longitude of the Moon (mean equinox of date)
+ *               D  - mean elongation of the Moon
+ *               M  - mean anomaly of the Sun
+ *               M' - mean anomaly of the Moon
+ *               F  - argument of latitude of the Moon
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Eq. (47.1) - (47.5)
+ *
+ * @unit:  degree
+ * @frame: N/A
+ */
+pub const MOON_MEAN_LONGITUDE_DEG: [f64; 5] = [218.3164477, 481267.88123421, -0.0015786,
+                                               1.0 / 538841.0, -1.0 / 65194000.0];
+pub const MOON_MEAN_ELONGATION_DEG: [f64; 5] = [297.8501921, 445267.1114034, -0.0018819,
+                                                1.0 / 545868.0, -1.0 / 113065000.0];
+pub const SUN_MEAN_ANOMALY_DEG: [f64; 4] = [357.5291092, 35999.0502909, -0.0001536,
+                                            1.0 / 24490000.0];
+pub const MOON_MEAN_ANOMALY_DEG: [f64; 5] = [134.9633964, 477198.8675055, 0.0087414,
+                                             1.0 / 69699.0, -1.0 / 14712000.0];
+pub const MOON_ARGUMENT_OF_LATITUDE_DEG: [f64; 5] = [93.2720950, 483202.0175233, -0.0036539,
+                                                     -1.0 / 3526000.0, 1.0 / 863310000.0];
+
+/*
+ * @brief: Additional arguments A1 (action of Venus), A2 (action of Jupiter)
+ *         and A3 of the lunar theory (t^0, t^1)
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Chapter 47
+ *
+ * @unit:  degree
+ * @frame: N/A
+ */
+pub const MOON_ARGUMENT_A1_DEG: [f64; 2] = [119.75, 131.849];
+pub const MOON_ARGUMENT_A2_DEG: [f64; 2] = [53.09, 479264.290];
+pub const MOON_ARGUMENT_A3_DEG: [f64; 2] = [313.45, 481266.484];
+
+/*
+ * @brief: Eccentricity factor of the Earth's orbit (t^0 .. t^2) applied to
+ *         the terms containing the Sun's mean anomaly M
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Eq. (47.6)
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const MOON_ECCENTRICITY_FACTOR: [f64; 3] = [1.0, -0.002516, -0.0000074];
+
+/*
+ * @brief: Mean distance of the Moon from the Earth used with the distance
+ *         series
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Chapter 47
+ *
+ * @unit:  m
+ * @frame: N/A
+ */
+pub const MOON_MEAN_DISTANCE_M: f64 = 385000.56e3;
+
+/*
+ * @brief: Periodic terms for the longitude and distance of the Moon
+ *
+ * @description: Each row contains the multiples of D, M, M', F followed by
+ *               the sine coefficient of the longitude [1e-6 degree] and the
+ *               cosine coefficient of the distance [m]
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Table 47.A
+ *
+ * @unit:  N/A, 1e-6 degree, m
+ * @frame: Mean ecliptic and equinox of date
+ */
+pub const MOON_LONGITUDE_DISTANCE_TERMS: [[f64; 6]; 60] = [
+  [0.0,  0.0,  1.0,  0.0,  6288774.0, -20905355.0],
+  [2.0,  0.0, -1.0,  0.0,  1274027.0,  -3699111.0],
+  [2.0,  0.0,  0.0,  0.0,   658314.0,  -2955968.0],
+  [0.0,  0.0,  2.0,  0.0,   213618.0,   -569925.0],
+  [0.0,  1.0,  0.0,  0.0,  -185116.0,     48888.0],
+  [0.0,  0.0,  0.0,  2.0,  -114332.0,     -3149.0],
+  [2.0,  0.0, -2.0,  0.0,    58793.0,    246158.0],
+  [2.0, -1.0, -1.0,  0.0,    57066.0,   -152138.0],
+  [2.0,  0.0,  1.0,  0.0,    53322.0,   -170733.0],
+  [2.0, -1.0,  0.0,  0.0,    45758.0,   -204586.0],
+  [0.0,  1.0, -1.0,  0.0,   -40923.0,   -129620.0],
+  [1.0,  0.0,  0.0,  0.0,   -34720.0,    108743.0],
+  [0.0,  1.0,  1.0,  0.0,   -30383.0,    104755.0],
+  [2.0,  0.0,  0.0, -2.0,    15327.0,     10321.0],
+  [0.0,  0.0,  1.0,  2.0,   -12528.0,         0.0],
+  [0.0,  0.0,  1.0, -2.0,    10980.0,     79661.0],
+  [4.0,  0.0, -1.0,  0.0,    10675.0,    -34782.0],
+  [0.0,  0.0,  3.0,  0.0,    10034.0,    -23210.0],
+  [4.0,  0.0, -2.0,  0.0,     8548.0,    -21636.0],
+  [2.0,  1.0, -1.0,  0.0,    -7888.0,     24208.0],
+  [2.0,  1.0,  0.0,  0.0,    -6766.0,     30824.0],
+  [1.0,  0.0, -1.0,  0.0,    -5163.0,     -8379.0],
+  [1.0,  1.0,  0.0,  0.0,     4987.0,    -16675.0],
+  [2.0, -1.0,  1.0,  0.0,     4036.0,    -12831.0],
+  [2.0,  0.0,  2.0,  0.0,     3994.0,    -10445.0],
+  [4.0,  0.0,  0.0,  0.0,     3861.0,    -11650.0],
+  [2.0,  0.0, -3.0,  0.0,     3665.0,     14403.0],
+  [0.0,  1.0, -2.0,  0.0,    -2689.0,     -7003.0],
+  [2.0,  0.0, -1.0,  2.0,    -2602.0,         0.0],
+  [2.0, -1.0, -2.0,  0.0,     2390.0,     10056.0],
+  [1.0,  0.0,  1.0,  0.0,    -2348.0,      6322.0],
+  [2.0, -2.0,  0.0,  0.0,     2236.0,     -9884.0],
+  [0.0,  1.0,  2.0,  0.0,    -2120.0,      5751.0],
+  [0.0,  2.0,  0.0,  0.0,    -2069.0,         0.0],
+  [2.0, -2.0, -1.0,  0.0,     2048.0,     -4950.0],
+  [2.0,  0.0,  1.0, -2.0,    -1773.0,      4130.0],
+  [2.0,  0.0,  0.0,  2.0,    -1595.0,         0.0],
+  [4.0, -1.0, -1.0,  0.0,     1215.0,     -3958.0],
+  [0.0,  0.0,  2.0,  2.0,    -1110.0,         0.0],
+  [3.0,  0.0, -1.0,  0.0,     -892.0,      3258.0],
+  [2.0,  1.0,  1.0,  0.0,     -810.0,      2616.0],
+  [4.0, -1.0, -2.0,  0.0,      759.0,     -1897.0],
+  [0.0,  2.0, -1.0,  0.0,     -713.0,     -2117.0],
+  [2.0,  2.0, -1.0,  0.0,     -700.0,      2354.0],
+  [2.0,  1.0, -2.0,  0.0,      691.0,         0.0],
+  [2.0, -1.0,  0.0, -2.0,      596.0,         0.0],
+  [4.0,  0.0,  1.0,  0.0,      549.0,     -1423.0],
+  [0.0,  0.0,  4.0,  0.0,      537.0,     -1117.0],
+  [4.0, -1.0,  0.0,  0.0,      520.0,     -1571.0],
+  [1.0,  0.0, -2.0,  0.0,     -487.0,     -1739.0],
+  [2.0,  1.0,  0.0, -2.0,     -399.0,         0.0],
+  [0.0,  0.0,  2.0, -2.0,     -381.0,     -4421.0],
+  [1.0,  1.0,  1.0,  0.0,      351.0,         0.0],
+  [3.0,  0.0, -2.0,  0.0,     -340.0,         0.0],
+  [4.0,  0.0, -3.0,  0.0,      330.0,         0.0],
+  [2.0, -1.0,  2.0,  0.0,      327.0,         0.0],
+  [0.0,  2.0,  1.0,  0.0,     -323.0,      1165.0],
+  [1.0,  1.0, -1.0,  0.0,      299.0,         0.0],
+  [2.0,  0.0,  3.0,  0.0,      294.0,         0.0],
+  [2.0,  0.0, -1.0, -2.0,        0.0,      8752.0],
+];
+
+/*
+ * @brief: Periodic terms for the latitude of the Moon
+ *
+ * @description: Each row contains the multiples of D, M, M', F followed by
+ *               the sine coefficient of the latitude [1e-6 degree]
+ *
+ * Source: Meeus - Astronomical Algorithms, 2nd edition, Table 47.B
+ *
+ * @unit:  N/A, 1e-6 degree
+ * @frame: Mean ecliptic and equinox of date
+ */
+pub const MOON_LATITUDE_TERMS: [[f64; 5]; 60] = [
+  [0.0,  0.0,  0.0,  1.0,  5128122.0],
+  [0.0,  0.0,  1.0,  1.0,   280602.0],
+  [0.0,  0.0,  1.0, -1.0,   277693.0],
+  [2.0,  0.0,  0.0, -1.0,   173237.0],
+  [2.0,  0.0, -1.0,  1.0,    55413.0],
+  [2.0,  0.0, -1.0, -1.0,    46271.0],
+  [2.0,  0.0,  0.0,  1.0,    32573.0],
+  [0.0,  0.0,  2.0,  1.0,    17198.0],
+  [2.0,  0.0,  1.0, -1.0,     9266.0],
+  [0.0,  0.0,  2.0, -1.0,     8822.0],
+  [2.0, -1.0,  0.0, -1.0,     8216.0],
+  [2.0,  0.0, -2.0, -1.0,     4324.0],
+  [2.0,  0.0,  1.0,  1.0,     4200.0],
+  [2.0,  1.0,  0.0, -1.0,    -3359.0],
+  [2.0, -1.0, -1.0,  1.0,     2463.0],
+  [2.0, -1.0,  0.0,  1.0,     2211.0],
+  [2.0, -1.0, -1.0, -1.0,     2065.0],
+  [0.0,  1.0, -1.0, -1.0,    -1870.0],
+  [4.0,  0.0, -1.0, -1.0,     1828.0],
+  [0.0,  1.0,  0.0,  1.0,    -1794.0],
+  [0.0,  0.0,  0.0,  3.0,    -1749.0],
+  [0.0,  1.0, -1.0,  1.0,    -1565.0],
+  [1.0,  0.0,  0.0,  1.0,    -1491.0],
+  [0.0,  1.0,  1.0,  1.0,    -1475.0],
+  [0.0,  1.0,  1.0, -1.0,    -1410.0],
+  [0.0,  1.0,  0.0, -1.0,    -1344.0],
+  [1.0,  0.0,  0.0, -1.0,    -1335.0],
+  [0.0,  0.0,  3.0,  1.0,     1107.0],
+  [4.0,  0.0,  0.0, -1.0,     1021.0],
+  [4.0,  0.0, -1.0,  1.0,      833.0],
+  [0.0,  0.0,  1.0, -3.0,      777.0],
+  [4.0,  0.0, -2.0,  1.0,      671.0],
+  [2.0,  0.0,  0.0, -3.0,      607.0],
+  [2.0,  0.0,  2.0, -1.0,      596.0],
+  [2.0, -1.0,  1.0, -1.0,      491.0],
+  [2.0,  0.0, -2.0,  1.0,     -451.0],
+  [0.0,  0.0,  3.0, -1.0,      439.0],
+  [2.0,  0.0,  2.0,  1.0,      422.0],
+  [2.0,  0.0, -3.0, -1.0,      421.0],
+  [2.0,  1.0, -1.0,  1.0,     -366.0],
+  [2.0,  1.0,  0.0,  1.0,     -351.0],
+  [4.0,  0.0,  0.0,  1.0,      331.0],
+  [2.0, -1.0,  1.0,  1.0,      315.0],
+  [2.0, -2.0,  0.0, -1.0,      302.0],
+  [0.0,  0.0,  1.0,  3.0,     -283.0],
+  [2.0,  1.0,  1.0, -1.0,     -229.0],
+  [1.0,  1.0,  0.0, -1.0,      223.0],
+  [1.0,  1.0,  0.0,  1.0,      223.0],
+  [0.0,  1.0, -2.0, -1.0,     -220.0],
+  [2.0,  1.0, -1.0, -1.0,     -220.0],
+  [1.0,  0.0,  1.0,  1.0,     -185.0],
+  [2.0, -1.0, -2.0, -1.0,      181.0],
+  [0.0,  1.0,  2.0,  1.0,     -177.0],
+  [4.0,  0.0, -2.0, -1.0,      176.0],
+  [4.0, -1.0, -1.0, -1.0,      166.0],
+  [1.0,  0.0,  1.0, -1.0,     -164.0],
+  [4.0,  0.0,  1.0, -1.0,      132.0],
+  [1.0,  0.0, -1.0, -1.0,     -119.0],
+  [4.0, -1.0,  0.0, -1.0,      115.0],
+  [2.0, -2.0,  0.0,  1.0,      107.0],
+];
+
+/*
+ * @brief: Mean Keplerian elements of the Earth-Moon barycentre (heliocentric)
+ *         and their rates
+ *
+ * @description: Each row contains the value at J2000 and the rate per Julian
+ *               century TT of: semi-major axis [AU], eccentricity,
+ *               inclination [deg], mean longitude [deg], longitude of
+ *               perihelion [deg], longitude of the ascending node [deg].
+ *               Valid from 1800 to 2050.
+ *
+ * Source: Standish - Keplerian Elements for Approximate Positions of the Major
+ *         Planets (JPL), Table 1
+ *
+ * @unit:  AU, N/A, degree
+ * @frame: Mean ecliptic and equinox of J2000
+ */
+pub const EMB_SMA_AU: [f64; 2] = [1.00000261, 0.00000562];
+pub const EMB_ECC: [f64; 2] = [0.01671123, -0.00004392];
+pub const EMB_INC_DEG: [f64; 2] = [-0.00001531, -0.01294668];
+pub const EMB_MEAN_LONGITUDE_DEG: [f64; 2] = [100.46457166, 35999.37244981];
+pub const EMB_LONGITUDE_PERIHELION_DEG: [f64; 2] = [102.93768193, 0.32327364];
+pub const EMB_LONGITUDE_NODE_DEG: [f64; 2] = [0.0, 0.0];
+
+/*
+ * @brief: Earth-Moon mass ratio
+ *
+ * Source: DE430
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const EARTH_MOON_MASS_RATIO: f64 = 81.30056907419062;
+
+/*
+ * @brief: Size of a record of a Double precision Array File (DAF)
+ *
+ * Source: NAIF - DAF Required Reading
+ *
+ * @unit:  bytes
+ * @frame: N/A
+ */
+pub const DAF_RECORD_SIZE_BYTES: usize = 1024;
+
+/*
+ * @brief: Number of double precision components of an SPK segment summary
+ *         (start and end epoch)
+ *
+ * Source: NAIF - SPK Required Reading
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const SPK_SUMMARY_NUM_DOUBLES: usize = 2;
+
+/*
+ * @brief: NAIF frame ID of the J2000 (ICRF) frame
+ *
+ * Source: NAIF - Frames Required Reading
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const SPK_FRAME_J2000: i32 = 1;
+
+/*
+ * @brief: NAIF integer IDs of the bodies used by the simulation
+ *
+ * Source: NAIF - NAIF IDs Required Reading
+ *
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const NAIF_ID_SOLAR_SYSTEM_BARYCENTER: i32 = 0;
+pub const NAIF_ID_EARTH_MOON_BARYCENTER: i32 = 3;
+pub const NAIF_ID_SUN: i32 = 10;
+pub const NAIF_ID_MOON: i32 = 301;
+pub const NAIF_ID_EARTH: i32 = 399;
@@ -23,4 +23,58 @@
   *         planet atmosphere specific simulation parameters .
   *  
   */
-  pub const ATMOSPHERE_PARAMETER_FILE_PATH: &str = "parameters/atmosphere.ini";
\ No newline at end of file
+  pub const ATMOSPHERE_PARAMETER_FILE_PATH: &str = "parameters/atmosphere.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         gravity model specific simulation parameters .
+  *  
+  */
+  pub const GRAVITY_PARAMETER_FILE_PATH: &str = "parameters/gravity.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         solar radiation pressure specific simulation parameters .
+  *  
+  */
+  pub const SRP_PARAMETER_FILE_PATH: &str = "parameters/srp.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         Sun and Moon ephemeris specific simulation parameters .
+  *  
+  */
+  pub const EPHEMERIS_PARAMETER_FILE_PATH: &str = "parameters/ephemeris.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         attitude guidance and control specific simulation parameters .
+  *  
+  */
+  pub const ATTITUDE_CONTROL_PARAMETER_FILE_PATH: &str = "parameters/attitude_control.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         the reaction wheel assembly and momentum dumping parameters.
+  *  
+  */
+  pub const REACTION_WHEELS_PARAMETER_FILE_PATH: &str = "parameters/reaction_wheels.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         the geomagnetic field model parameters.
+  *  
+  */
+  pub const MAGNETIC_FIELD_PARAMETER_FILE_PATH: &str = "parameters/magnetic_field.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         the magnetorquer, magnetometer and B-dot controller parameters.
+  *  
+  */
+  pub const MAGNETORQUERS_PARAMETER_FILE_PATH: &str = "parameters/magnetorquers.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         the thruster, propellant and burn parameters.
+  *  
+  */
+  pub const PROPULSION_PARAMETER_FILE_PATH: &str = "parameters/propulsion.ini";
+  /*
+  * @brief: Relative path from the executable to the paramater file containing 
+  *         the impulsive maneuver parameters and the path of the maneuver plan.
+  *  
+  */
+  pub const MANEUVERS_PARAMETER_FILE_PATH: &str = "parameters/maneuvers.ini";
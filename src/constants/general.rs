@@ -11,4 +11,19 @@
  */
 pub const VEC_X: usize           =  0;
 pub const VEC_Y: usize           =  1;
-pub const VEC_Z: usize           =  2;
\ No newline at end of file
+pub const VEC_Z: usize           =  2;
+/* 
+ * @brief: Conversion factor from revolutions per minute to radians per second 
+ *  
+ * @unit:  (rad/s) / rpm
+ * @frame: N/A
+ */
+pub const RPM_TO_RADS: f64 = 2.0 * std::f64::consts::PI / 60.0;
+/* 
+ * @brief: Standard gravity used to convert the specific impulse to the 
+ *         effective exhaust velocity
+ *  
+ * @unit:  m/s/s
+ * @frame: N/A
+ */
+pub const STANDARD_GRAVITY_MSS: f64 = 9.80665;
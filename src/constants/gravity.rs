@@ -6,5 +6,12 @@
  * 
  */
 /*----------------------------------------------------------------------------*/
-/* None */
+/* 
+ * @brief: Highest degree of the analytic zonal harmonics (J2 to J6) supported by 
+ *         the point_mass_zonal gravity model
+ *  
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const GRAV_ZONAL_MAX_DEGREE: usize = 6;
  /*----------------------------------------------------------------------------*/
\ No newline at end of file
@@ -0,0 +1,49 @@
+/* 
+ * @brief: Reference radius of the geomagnetic field model (IGRF)
+ *  
+ * Source: IGRF-13, Alken et al. (2021), Earth, Planets and Space 73:49
+ * 
+ * @unit:  m
+ * @frame: N/A
+ */
+pub const EARTH_MAGNETIC_REFERENCE_RADIUS_M: f64 = 6371200.0;
+
+/* 
+ * @brief: Epoch of the dipole coefficients of the tilted dipole model
+ *  
+ * @unit:  years
+ * @frame: N/A
+ */
+pub const IGRF_DIPOLE_EPOCH_YR: f64 = 2020.0;
+
+/* 
+ * @brief: Schmidt semi-normalized dipole coefficients [g10, g11, h11] and 
+ *         their secular variation of the tilted dipole model
+ *  
+ * Source: IGRF-13 main field (2020.0) and predictive secular variation 
+ *         (2020 - 2025)
+ * 
+ * @unit:  nT, nT/year
+ * @frame: PCPF
+ */
+pub const IGRF_DIPOLE_COEFF_NT: [f64; 3] = [-29404.8, -1450.9, 4652.5];
+pub const IGRF_DIPOLE_SV_NTPY: [f64; 3] = [5.7, 7.4, -25.9];
+
+/* 
+ * @brief: Conversion factor from nanotesla to tesla
+ *  
+ * @unit:  T/nT
+ * @frame: N/A
+ */
+pub const NANOTESLA_TO_TESLA: f64 = 1.0e-9;
+
+/* 
+ * @brief: Interval after which the interpolated IGRF coefficients are updated
+ *
+ * @description: The secular variation changes the field by less than 0.5 nT 
+ *               within this interval.
+ *  
+ * @unit:  days
+ * @frame: N/A
+ */
+pub const IGRF_UPDATE_INTERVAL_DAYS: f64 = 1.0;
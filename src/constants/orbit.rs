@@ -0,0 +1,50 @@
+
+/* 
+ * @brief: Indices of the classical Keplerian elements in an element vector
+ *  
+ * @description: Element vectors are used by the conversions between Keplerian
+ *               elements and Cartesian state vectors in orbit_math
+ * 
+ *               [0] semi-major axis                  [m]
+ *               [1] eccentricity                     [-]
+ *               [2] inclination                      [rad]
+ *               [3] right ascension of ascending node [rad]
+ *               [4] argument of periapsis            [rad]
+ *               [5] true anomaly                     [rad]
+ * 
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const KEPLER_INDX_SMA: usize          = 0;
+pub const KEPLER_INDX_ECC: usize          = 1;
+pub const KEPLER_INDX_INC: usize          = 2;
+pub const KEPLER_INDX_RAAN: usize         = 3;
+pub const KEPLER_INDX_ARGP: usize         = 4;
+pub const KEPLER_INDX_TRUE_ANOMALY: usize = 5;
+
+pub const KEPLER_NUM_ELEMENTS: usize      = 6;
+
+/* 
+ * @brief: Tolerance to identify circular (eccentricity) and equatorial 
+ *         (normalized node vector length) orbits
+ *  
+ * @description: Below this tolerance the argument of periapsis and/or the 
+ *               right ascension of the ascending node are undefined and set 
+ *               to zero. The true anomaly is then replaced by the argument of
+ *               latitude, the true longitude or the longitude of periapsis is
+ *               used as argument of periapsis (see orbit_math).
+ * 
+ * @unit:  N/A
+ * @frame: N/A
+ */
+pub const KEPLER_SINGULARITY_TOLERANCE: f64 = 1.0e-11;
+
+/* 
+ * @brief: Convergence tolerance and maximum number of iterations to solve 
+ *         Kepler's equation M = E - e sin(E) for the eccentric anomaly
+ *  
+ * @unit:  rad
+ * @frame: N/A
+ */
+pub const KEPLER_EQUATION_TOLERANCE_RAD: f64 = 1.0e-14;
+pub const KEPLER_EQUATION_MAX_ITERATIONS: usize = 50;
@@ -18,4 +18,17 @@
     * @brief: Path at which the mean free path table data can be loaded
     *  
     */
-    pub const SC_DRAG_COEFF_TABLE_PATH: &str = "assets/spacecraft/cd_over_mach_saturnv.csv";
\ No newline at end of file
+    pub const SC_DRAG_COEFF_TABLE_PATH: &str = "assets/spacecraft/cd_over_mach_saturnv.csv";
+   /*
+    * @brief: Maximum number of reaction wheels of the reaction wheel assembly.
+    *         The state vector holds the speed and torque of this number of 
+    *         wheels.
+    *  
+    */
+    pub const SC_RWA_MAX_NUM_WHEELS: usize = 6;
+   /*
+    * @brief: Propellant mass below which the propellant is considered 
+    *         depleted and the thrusters stop firing
+    *  
+    */
+    pub const SC_PROPELLANT_TOLERANCE_KG: f64 = 1.0e-9;
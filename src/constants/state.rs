@@ -16,7 +16,7 @@
  * @unit:  N/A
  * @frame: N/A
  */
-pub const STATE_VEC_NUM_ELEMENTS: usize = 36;
+pub const STATE_VEC_NUM_ELEMENTS: usize = 134;
 
 /* 
  * @brief: State vector indices
@@ -61,4 +61,112 @@ pub const STATE_VEC_INDX_AERO_FORCE_Z: usize       = 31;
 pub const STATE_VEC_INDX_DRAG_COEFF: usize         = 32;
 pub const STATE_VEC_INDX_BALLISTIC_COEFF: usize    = 33;
 pub const STATE_VEC_INDX_MACH_NUMBER: usize        = 34;
-pub const STATE_VEC_INDX_KNUDSEN_NUMBER: usize     = 35;
\ No newline at end of file
+pub const STATE_VEC_INDX_KNUDSEN_NUMBER: usize     = 35;
+pub const STATE_VEC_INDX_SUN_FORCE_X: usize        = 36;
+pub const STATE_VEC_INDX_SUN_FORCE_Y: usize        = 37;
+pub const STATE_VEC_INDX_SUN_FORCE_Z: usize        = 38;
+pub const STATE_VEC_INDX_MOON_FORCE_X: usize       = 39;
+pub const STATE_VEC_INDX_MOON_FORCE_Y: usize       = 40;
+pub const STATE_VEC_INDX_MOON_FORCE_Z: usize       = 41;
+pub const STATE_VEC_INDX_ILLUMINATION_FRACTION: usize = 42;
+pub const STATE_VEC_INDX_SRP_FORCE_X: usize        = 43;
+pub const STATE_VEC_INDX_SRP_FORCE_Y: usize        = 44;
+pub const STATE_VEC_INDX_SRP_FORCE_Z: usize        = 45;
+pub const STATE_VEC_INDX_AIRSPEED_MS: usize        = 46;
+/* Osculating orbital elements and two-body diagnostics (switchable output) */
+pub const STATE_VEC_INDX_ORBIT_SMA_M: usize        = 47;
+pub const STATE_VEC_INDX_ORBIT_ECC: usize          = 48;
+pub const STATE_VEC_INDX_ORBIT_INC_DEG: usize      = 49;
+pub const STATE_VEC_INDX_ORBIT_RAAN_DEG: usize     = 50;
+pub const STATE_VEC_INDX_ORBIT_ARGP_DEG: usize     = 51;
+pub const STATE_VEC_INDX_ORBIT_TRUE_ANOM_DEG: usize = 52;
+pub const STATE_VEC_INDX_ORBIT_ENERGY_JKG: usize   = 53;
+pub const STATE_VEC_INDX_ORBIT_ANG_MOM_X: usize    = 54;
+pub const STATE_VEC_INDX_ORBIT_ANG_MOM_Y: usize    = 55;
+pub const STATE_VEC_INDX_ORBIT_ANG_MOM_Z: usize    = 56;
+pub const STATE_VEC_INDX_ORBIT_ANG_MOM_MAGN: usize = 57;
+pub const STATE_VEC_INDX_ORBIT_PERI_ALT_M: usize   = 58;
+pub const STATE_VEC_INDX_ORBIT_APO_ALT_M: usize    = 59;
+pub const STATE_VEC_INDX_ORBIT_PERIOD_S: usize     = 60;
+/* Position and velocity in the planet fixed frame */
+pub const STATE_VEC_INDX_POS_PCPF_X: usize         = 61;
+pub const STATE_VEC_INDX_POS_PCPF_Y: usize         = 62;
+pub const STATE_VEC_INDX_POS_PCPF_Z: usize         = 63;
+pub const STATE_VEC_INDX_VEL_PCPF_X: usize         = 64;
+pub const STATE_VEC_INDX_VEL_PCPF_Y: usize         = 65;
+pub const STATE_VEC_INDX_VEL_PCPF_Z: usize         = 66;
+/* Two-part epoch: whole days since J2000 (constant during a run) and seconds
+ * since the start of that day */
+pub const STATE_VEC_INDX_EPOCH_DAYS: usize         = 67;
+pub const STATE_VEC_INDX_EPOCH_SECONDS: usize      = 68;
+/* Unit vectors from the spacecraft to the Sun and the Moon in PCI 
+ * (switchable output) */
+pub const STATE_VEC_INDX_SUN_DIR_X: usize          = 69;
+pub const STATE_VEC_INDX_SUN_DIR_Y: usize          = 70;
+pub const STATE_VEC_INDX_SUN_DIR_Z: usize          = 71;
+pub const STATE_VEC_INDX_MOON_DIR_X: usize         = 72;
+pub const STATE_VEC_INDX_MOON_DIR_Y: usize         = 73;
+pub const STATE_VEC_INDX_MOON_DIR_Z: usize         = 74;
+/* Disturbance torques acting on the spacecraft in the body frame */
+pub const STATE_VEC_INDX_GRAV_GRAD_TORQUE_X: usize = 75;
+pub const STATE_VEC_INDX_GRAV_GRAD_TORQUE_Y: usize = 76;
+pub const STATE_VEC_INDX_GRAV_GRAD_TORQUE_Z: usize = 77;
+pub const STATE_VEC_INDX_AERO_TORQUE_X: usize      = 78;
+pub const STATE_VEC_INDX_AERO_TORQUE_Y: usize      = 79;
+pub const STATE_VEC_INDX_AERO_TORQUE_Z: usize      = 80;
+pub const STATE_VEC_INDX_SRP_TORQUE_X: usize       = 81;
+pub const STATE_VEC_INDX_SRP_TORQUE_Y: usize       = 82;
+pub const STATE_VEC_INDX_SRP_TORQUE_Z: usize       = 83;
+/* Attitude control error angle and commanded torque in the body frame */
+pub const STATE_VEC_INDX_ATT_ERROR_ANGLE_DEG: usize = 84;
+pub const STATE_VEC_INDX_CMD_TORQUE_X: usize       = 85;
+pub const STATE_VEC_INDX_CMD_TORQUE_Y: usize       = 86;
+pub const STATE_VEC_INDX_CMD_TORQUE_Z: usize       = 87;
+/* Reaction wheel speeds relative to the body (integrated) and net wheel 
+ * torques of up to SC_RWA_MAX_NUM_WHEELS wheels, wheel angular momentum and 
+ * momentum dumping torque in the body frame and total angular momentum of the
+ * spacecraft including the wheels in PCI (switchable output) */
+pub const STATE_VEC_INDX_RW_SPEED_1: usize         = 88;
+pub const STATE_VEC_INDX_RW_SPEED_6: usize         = 93;
+pub const STATE_VEC_INDX_RW_TORQUE_1: usize        = 94;
+pub const STATE_VEC_INDX_RW_TORQUE_6: usize        = 99;
+pub const STATE_VEC_INDX_RW_ANG_MOM_X: usize       = 100;
+pub const STATE_VEC_INDX_RW_ANG_MOM_Y: usize       = 101;
+pub const STATE_VEC_INDX_RW_ANG_MOM_Z: usize       = 102;
+pub const STATE_VEC_INDX_DUMP_TORQUE_X: usize      = 103;
+pub const STATE_VEC_INDX_DUMP_TORQUE_Y: usize      = 104;
+pub const STATE_VEC_INDX_DUMP_TORQUE_Z: usize      = 105;
+pub const STATE_VEC_INDX_SYS_ANG_MOM_X: usize      = 106;
+pub const STATE_VEC_INDX_SYS_ANG_MOM_Y: usize      = 107;
+pub const STATE_VEC_INDX_SYS_ANG_MOM_Z: usize      = 108;
+/* Magnetic field vector in the planet fixed, north-east-down and body frame
+ * (switchable output) */
+pub const STATE_VEC_INDX_MAG_FIELD_PCPF_X: usize   = 109;
+pub const STATE_VEC_INDX_MAG_FIELD_PCPF_Y: usize   = 110;
+pub const STATE_VEC_INDX_MAG_FIELD_PCPF_Z: usize   = 111;
+pub const STATE_VEC_INDX_MAG_FIELD_NED_N: usize    = 112;
+pub const STATE_VEC_INDX_MAG_FIELD_NED_E: usize    = 113;
+pub const STATE_VEC_INDX_MAG_FIELD_NED_D: usize    = 114;
+pub const STATE_VEC_INDX_MAG_FIELD_B_X: usize      = 115;
+pub const STATE_VEC_INDX_MAG_FIELD_B_Y: usize      = 116;
+pub const STATE_VEC_INDX_MAG_FIELD_B_Z: usize      = 117;
+/* Magnetorquer dipole and torque, magnetometer measurement in the body frame
+ * and norm of the angular rate (switchable output) */
+pub const STATE_VEC_INDX_MTQ_DIPOLE_X: usize       = 118;
+pub const STATE_VEC_INDX_MTQ_DIPOLE_Y: usize       = 119;
+pub const STATE_VEC_INDX_MTQ_DIPOLE_Z: usize       = 120;
+pub const STATE_VEC_INDX_MTQ_TORQUE_X: usize       = 121;
+pub const STATE_VEC_INDX_MTQ_TORQUE_Y: usize       = 122;
+pub const STATE_VEC_INDX_MTQ_TORQUE_Z: usize       = 123;
+pub const STATE_VEC_INDX_MAGNETOMETER_B_X: usize   = 124;
+pub const STATE_VEC_INDX_MAGNETOMETER_B_Y: usize   = 125;
+pub const STATE_VEC_INDX_MAGNETOMETER_B_Z: usize   = 126;
+pub const STATE_VEC_INDX_ANG_RATE_MAGN_DEGS: usize = 127;
+/* Propellant mass and delta-v of the thrusters (integrated), thrust force in 
+ * PCI and propellant mass flow (switchable output) */
+pub const STATE_VEC_INDX_PROPELLANT_MASS: usize    = 128;
+pub const STATE_VEC_INDX_THRUST_DELTA_V_MS: usize  = 129;
+pub const STATE_VEC_INDX_THRUST_FORCE_X: usize     = 130;
+pub const STATE_VEC_INDX_THRUST_FORCE_Y: usize     = 131;
+pub const STATE_VEC_INDX_THRUST_FORCE_Z: usize     = 132;
+pub const STATE_VEC_INDX_MASS_FLOW_KGPS: usize     = 133;
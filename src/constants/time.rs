@@ -2,12 +2,12 @@
 /* 
  * @brief: (Chrono) DateTime format string  
  *  
- * @description: Format %Y-%m-%d %H:%M:%S %z
+ * @description: Format %Y-%m-%d %H:%M:%S %z with optional fractional seconds
  * 
  * @unit:  N/A
  * @frame: N/A
  */
-pub const DATETIME_FORMAT: &str = " %Y-%m-%d %H:%M:%S %z" ;
+pub const DATETIME_FORMAT: &str = " %Y-%m-%d %H:%M:%S%.f %z" ;
 
 /* 
  * @brief: J2000 Epoch in UTC and DateTime format 
@@ -78,4 +78,104 @@ pub const SECONDS_OF_EARTH_DAY: f64 = 86400.0;
  * @unit:  days
  * @frame: N/A
  */
-pub const MEAN_DAYS_IN_EARTH_YEAR: f64 = 365.25;
\ No newline at end of file
+pub const MEAN_DAYS_IN_EARTH_YEAR: f64 = 365.25;
+/* 
+ * @brief: Number of days in a Julian century
+ *  
+ * @description: Used to express epochs as Julian centuries since J2000 for 
+ *               the evaluation of ephemeris and Earth orientation series
+ * 
+ * @unit:  days
+ * @frame: N/A
+ */
+pub const DAYS_OF_JULIAN_CENTURY: f64 = 36525.0;
+
+/* 
+ * @brief: Tolerance to compare simulation times
+ *  
+ * @description: Used by the simulation loop to decide if the end time or an 
+ *               archive epoch has been reached despite floating point round 
+ *               off of the accumulated step sizes.
+ * 
+ * @unit:  seconds
+ * @frame: N/A
+ */
+pub const SIM_TIME_TOLERANCE_S: f64 = 1.0e-9;
+
+/* 
+ * @brief: Difference between Terrestrial Time (TT) and International Atomic 
+ *         Time (TAI)
+ *  
+ * @unit:  seconds
+ * @frame: N/A
+ */
+pub const TT_MINUS_TAI_S: f64 = 32.184;
+
+/* 
+ * @brief: Leap second table, difference between International Atomic Time 
+ *         (TAI) and UTC since the given date
+ *  
+ * @description: Columns: MJD (UTC) from which the value is valid, TAI-UTC. 
+ *               Epochs before 1972-01-01 use the first entry. Must be 
+ *               extended when the IERS announces a new leap second.
+ * 
+ *               Source: https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat
+ * 
+ * @unit:  days, seconds
+ * @frame: N/A
+ */
+pub const LEAP_SECONDS_TAI_MINUS_UTC: [[f64; 2]; 28] = [
+  [41317.0, 10.0],
+  [41499.0, 11.0],
+  [41683.0, 12.0],
+  [42048.0, 13.0],
+  [42413.0, 14.0],
+  [42778.0, 15.0],
+  [43144.0, 16.0],
+  [43509.0, 17.0],
+  [43874.0, 18.0],
+  [44239.0, 19.0],
+  [44786.0, 20.0],
+  [45151.0, 21.0],
+  [45516.0, 22.0],
+  [46247.0, 23.0],
+  [47161.0, 24.0],
+  [47892.0, 25.0],
+  [48257.0, 26.0],
+  [48804.0, 27.0],
+  [49169.0, 28.0],
+  [49534.0, 29.0],
+  [50083.0, 30.0],
+  [50630.0, 31.0],
+  [51179.0, 32.0],
+  [53736.0, 33.0],
+  [54832.0, 34.0],
+  [56109.0, 35.0],
+  [57204.0, 36.0],
+  [57754.0, 37.0],
+];
+
+/* 
+ * @brief: Difference between International Atomic Time (TAI) and GPS time 
+ *         (GPS time was aligned with UTC at 1980-01-06)
+ *  
+ * @unit:  seconds
+ * @frame: N/A
+ */
+pub const TAI_MINUS_GPS_S: f64 = 19.0;
+
+/* 
+ * @brief: Coefficients of the periodic difference between Barycentric 
+ *         Dynamical Time (TDB) and TT
+ *  
+ * @description: TDB - TT = A1 sin(g) + A2 sin(2g) with the mean anomaly of the
+ *               Earth g = G0 + G1 * d (d: days TT since J2000). Accurate to 
+ *               ~30 microseconds.
+ * 
+ *               Ref: USNO Circular 179, Eq. 2.6
+ * 
+ * @unit:  seconds, degree, degree per day
+ * @frame: N/A
+ */
+pub const TDB_MINUS_TT_AMPLITUDES_S: [f64; 2] = [0.001657, 0.000014];
+pub const TDB_MEAN_ANOMALY_EARTH_DEG: [f64; 2] = [357.53, 0.98560028];
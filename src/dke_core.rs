@@ -7,4 +7,8 @@ pub mod eom;
 
 pub mod state_augmentation;
 
-pub mod dke_core_load_param;
\ No newline at end of file
+pub mod dke_core_load_param;
+
+pub mod events;
+
+pub mod maneuvers;
\ No newline at end of file
@@ -15,15 +15,22 @@
 
 /* Include external crates */
 use std::time::Instant;
-use ndarray::Array1;
+use ndarray::{Array1, s};
 use tqdm::tqdm;
 
 /* Import (local) structs */
 use crate::dke_core::state::State;
+use crate::dke_core::events::{EventDetection, EventType};
+use crate::dke_core::maneuvers::ManeuverPlan;
+use crate::math::epoch::Epoch;
+use crate::math::vec_math::l2_norm_array1;
 
 use crate::environment::environment::Environment;
+use crate::environment::spacecraft::magnetorquers::update_bdot_controller;
+use crate::environment::attitude_control::attitude_controller::update_attitude_controller;
+use crate::environment::spacecraft::propulsion::Propulsion;
 /* Include local crates */
-use crate::solver::rk4::step;
+use crate::solver::integrator::{Integrator, SolverType, StepSizeControl, create_integrator};
 use crate::dke_core::eom::dxdt;
 use crate::io::write_csv::{*, self};
 use crate::dke_core::state_augmentation::{augment_state_solve,
@@ -34,6 +41,8 @@ use crate::util::plot::*;
 
 /* Import constants */
 use crate::constants::state::*;
+use crate::constants::time::SIM_TIME_TOLERANCE_S;
+use crate::constants::spacecraft::SC_RWA_MAX_NUM_WHEELS;
 
 pub struct DKE {
   /* [start time] 
@@ -61,6 +70,41 @@ pub struct DKE {
    * 
    * */
    dt_s: f64,
+  /* [solver type] 
+   * @description : Integrator used to solve the equations of motion
+   * @unit        : N/A
+   * 
+   * */
+   solver_type: SolverType,
+  /* [relative tolerance] 
+   * @description : Relative tolerance of the local truncation error used by 
+   *                the adaptive step size solvers
+   * @unit        : N/A
+   * 
+   * */
+   solver_rel_tol: f64,
+  /* [absolute tolerance] 
+   * @description : Absolute tolerance of the local truncation error used by 
+   *                the adaptive step size solvers
+   * @unit        : N/A
+   * 
+   * */
+   solver_abs_tol: f64,
+  /* [minimum step size] 
+   * @description : Lower bound of the step size of the adaptive step size 
+   *                solvers. Steps of this size are accepted even if the error 
+   *                tolerances are not met.
+   * @unit        : seconds
+   * 
+   * */
+   solver_dt_min_s: f64,
+  /* [maximum step size] 
+   * @description : Upper bound of the step size of the adaptive step size 
+   *                solvers
+   * @unit        : seconds
+   * 
+   * */
+   solver_dt_max_s: f64,
   /* [Print Interval] 
    * @description : Time interval print outs while the simulation is running are 
    *                called
@@ -83,6 +127,21 @@ pub struct DKE {
    * 
    * */
    param_sim_archive_flush_interval_s: f64,
+  /* [Write orbital elements flag] 
+   * @description : If true the osculating orbital elements, energy, angular
+   *                momentum, periapsis/apoapsis altitudes and orbital period 
+   *                are written to the results file.
+   * @unit        : N/A
+   * 
+   * */
+   param_flag_write_orbit_elements: bool,
+  /* [Write Sun and Moon direction flag] 
+   * @description : If true the unit vectors from the spacecraft to the Sun 
+   *                and to the Moon are written to the results file.
+   * @unit        : N/A
+   * 
+   * */
+   param_flag_write_sun_moon_direction: bool,
   /* [State struct] 
    * @description : Full state struct, currently used to input the start state
    *                when initialising the simulation.
@@ -90,6 +149,18 @@ pub struct DKE {
    * 
    * */
    state: State,
+  /* [Event detection] 
+   * @description : Events detected on the integrated trajectory
+   * @unit        : N/A
+   * 
+   * */
+   event_detection: EventDetection,
+  /* [Maneuver plan] 
+   * @description : Impulsive maneuvers applied during the simulation
+   * @unit        : N/A
+   * 
+   * */
+   maneuver_plan: ManeuverPlan,
   /* [Environment struct] 
    * @description : Full environment struct
    * @unit        : N/A
@@ -109,10 +180,19 @@ impl DKE {
       sim_end_time_s: 0.0,
       sim_current_time_s: 0.0,
       dt_s: 0.0,
+      solver_type: SolverType::Rk4,
+      solver_rel_tol: 0.0,
+      solver_abs_tol: 0.0,
+      solver_dt_min_s: 0.0,
+      solver_dt_max_s: 0.0,
       param_sim_print_interval_s: 0.0,
       param_sim_archive_interval_s: 0.0,
       param_sim_archive_flush_interval_s: 0.0,
+      param_flag_write_orbit_elements: true,
+      param_flag_write_sun_moon_direction: false,
       state: State::new(),
+      event_detection: EventDetection::new(),
+      maneuver_plan: ManeuverPlan::new(),
       environment: Environment::new()
     }
   }
@@ -132,6 +212,21 @@ impl DKE {
   pub fn set_step_size(&mut self, dt_s_in: &f64) {
     self.dt_s = *dt_s_in;
   }
+  pub fn set_solver_type(&mut self, solver_type_in: &SolverType) {
+    self.solver_type = *solver_type_in;
+  }
+  pub fn set_solver_rel_tol(&mut self, solver_rel_tol_in: &f64) {
+    self.solver_rel_tol = *solver_rel_tol_in;
+  }
+  pub fn set_solver_abs_tol(&mut self, solver_abs_tol_in: &f64) {
+    self.solver_abs_tol = *solver_abs_tol_in;
+  }
+  pub fn set_solver_dt_min_s(&mut self, solver_dt_min_s_in: &f64) {
+    self.solver_dt_min_s = *solver_dt_min_s_in;
+  }
+  pub fn set_solver_dt_max_s(&mut self, solver_dt_max_s_in: &f64) {
+    self.solver_dt_max_s = *solver_dt_max_s_in;
+  }
   pub fn set_start_state(&mut self, start_state: State) {
     self.state = start_state.clone();
   }
@@ -144,6 +239,12 @@ impl DKE {
   pub fn set_param_sim_archive_flush_interval_s(&mut self, param_sim_archive_flush_interval_s_in: &f64) {
     self.param_sim_archive_flush_interval_s = *param_sim_archive_flush_interval_s_in;
   }
+  pub fn set_param_flag_write_orbit_elements(&mut self, param_flag_write_orbit_elements_in: &bool) {
+    self.param_flag_write_orbit_elements = *param_flag_write_orbit_elements_in;
+  }
+  pub fn set_param_flag_write_sun_moon_direction(&mut self, param_flag_write_sun_moon_direction_in: &bool) {
+    self.param_flag_write_sun_moon_direction = *param_flag_write_sun_moon_direction_in;
+  }
 }
 
 /* -----------------------------------------------------------------------------
@@ -156,6 +257,8 @@ impl DKE {
   pub fn get_sim_time_s(&self) -> f64 {self.sim_current_time_s}
 
   pub fn get_mut_environment(&mut self) -> &mut Environment {&mut self.environment}
+  pub fn get_mut_event_detection(&mut self) -> &mut EventDetection {&mut self.event_detection}
+  pub fn get_mut_maneuver_plan(&mut self) -> &mut ManeuverPlan {&mut self.maneuver_plan}
 }
 
 
@@ -189,20 +292,97 @@ impl DKE {
     /* Make sure the state vectors time value matches the start time selected 
      * for this simulation. */
     state_vec[STATE_VEC_INDX_SIM_TIME] = self.sim_start_time_s;
+    /* Set the reaction wheel speeds at simulation start > reaction_wheels.ini < */
+    self.environment.get_spacecraft().get_reaction_wheel_assembly()
+      .write_start_speeds_to_state_vec(&mut state_vec);
+    /* Set the propellant mass at simulation start > propulsion.ini < */
+    let is_propulsion_enabled: bool = *self.environment.get_spacecraft().get_propulsion()
+                                                       .is_propulsion_enabled();
+    if is_propulsion_enabled
+    {
+      let sim_start_time_s: f64 = self.sim_start_time_s;
+      self.environment.get_mut_spacecraft().get_mut_propulsion().set_sim_start_time_s(&sim_start_time_s);
+      self.environment.get_spacecraft().get_propulsion().write_propellant_mass_to_state_vec(&mut state_vec);
+    }
     /* Create a clone of the start state to keep track of the previous state
      * Note: This is used for post-solving state augmentation */
     let mut state_vec_n0: Array1<f64> = state_vec.clone();
 
-    /* Calculate total number of simulation steps */
-    let num_steps: i64 = ((self.sim_end_time_s - self.sim_start_time_s) 
-                              / self.dt_s) as i64 + 1;
+    /* Calculate number of archive intervals to track the simulation progress */
+    let num_write_steps: i64 = ((self.sim_end_time_s - self.sim_start_time_s) 
+                              / self.param_sim_archive_interval_s).ceil() as i64;
     
     /* Initialize variable to track simulation time */
     self.sim_current_time_s = self.sim_start_time_s;
+    /* Start epoch of the simulation. The epoch of each step is computed from 
+     * the start epoch plus n * dt for steps of nominal size, instead of adding
+     * up the step sizes, to avoid accumulating roundoff over long runs */
+    let start_epoch: Epoch = Epoch::from_state_vec(state_vec.view());
+    let mut num_nominal_steps: i64 = 0;
+    /* Start epoch shifted by all steps of non-nominal size (adaptive steps, 
+     * steps truncated at the end time or at terminal events) */
+    let mut epoch_other_steps: Epoch = start_epoch;
+
+    /* Load the maneuver plan > maneuvers.ini < */
+    let is_maneuvers_enabled: bool = *self.maneuver_plan.is_maneuvers_enabled();
+    if is_maneuvers_enabled
+    {
+      self.maneuver_plan.load(&start_epoch, self.sim_start_time_s);
+    }
+
+    /* Select the columns written to file */
+    let mut column_mask: Vec<bool> = write_csv::create_csv_column_mask();
+    if !self.param_flag_write_orbit_elements
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_ORBIT_SMA_M, 
+                                     STATE_VEC_INDX_ORBIT_PERIOD_S);
+    }
+    if !self.param_flag_write_sun_moon_direction
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_SUN_DIR_X, 
+                                     STATE_VEC_INDX_MOON_DIR_Z);
+    }
+    if !*self.environment.get_magnetic_field().is_magnetic_field_output_enabled()
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_MAG_FIELD_PCPF_X, 
+                                     STATE_VEC_INDX_MAG_FIELD_B_Z);
+    }
+    if !*self.environment.get_spacecraft().get_magnetorquers().is_magnetorquers_enabled()
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_MTQ_DIPOLE_X, 
+                                     STATE_VEC_INDX_ANG_RATE_MAGN_DEGS);
+    }
+    if !is_propulsion_enabled
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_PROPELLANT_MASS, 
+                                     STATE_VEC_INDX_MASS_FLOW_KGPS);
+    }
+    let num_wheels: usize = self.environment.get_spacecraft().get_reaction_wheel_assembly().get_num_wheels();
+    if !*self.environment.get_spacecraft().get_reaction_wheel_assembly().is_reaction_wheels_enabled()
+    {
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_RW_SPEED_1, 
+                                     STATE_VEC_INDX_SYS_ANG_MOM_Z);
+    }
+    else if num_wheels < SC_RWA_MAX_NUM_WHEELS
+    {
+      /* Only write the columns of the wheels in use */
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_RW_SPEED_1 + num_wheels, 
+                                     STATE_VEC_INDX_RW_SPEED_6);
+      write_csv::disable_csv_columns(&mut column_mask, 
+                                     STATE_VEC_INDX_RW_TORQUE_1 + num_wheels, 
+                                     STATE_VEC_INDX_RW_TORQUE_6);
+    }
 
     /* Create file writer */
     let mut results_writer = write_csv::create_csv(
-      "./data_out/out.csv".to_string());
+      "./data_out/out.csv".to_string(), &column_mask);
 
     log.log_msg("---------------------------------------------------------------");
     log.log_msg("              [SIMULATION START]");
@@ -214,64 +394,205 @@ impl DKE {
 
     /* Set counter for print outs while the simulation is running */
     let mut print_out_counter: f64 = 0.0;
-    let mut write_out_counter: f64 = 0.0;
     let mut write_flush_counter: f64 = 0.0;
+    /* Epoch of the next write to file. 
+     * Note: Adaptive step size solvers shorten their steps to hit this epoch 
+     *       exactly */
+    let mut next_write_time_s: f64 = self.sim_start_time_s + self.param_sim_archive_interval_s;
+    /* Create the solver selected in sim.ini */
+    let mut integrator: Box<dyn Integrator> = create_integrator(
+      self.solver_type,
+      self.dt_s,
+      &StepSizeControl::new(self.solver_rel_tol, 
+                            self.solver_abs_tol, 
+                            self.solver_dt_min_s, 
+                            self.solver_dt_max_s));
+    /* Counter for the summary */
+    let mut num_steps: i64 = 0;
+    /* Tolerance to decide if the end time or a write epoch is reached. Fixed 
+     * step size solvers write at the step closest to the write epoch */
+    let time_tolerance_s: f64 = if integrator.is_adaptive() {SIM_TIME_TOLERANCE_S}
+                                else {0.5 * self.dt_s};
+    /* Progress bar advancing with each write to file */
+    let mut progress_bar = tqdm(0..num_write_steps).style(tqdm::Style::Block);
+
+    /* Update the planet's orientation and the magnetic field model for the 
+     * start epoch */
+    self.get_mut_environment().get_mut_planet().get_mut_earth_orientation()
+      .update(&start_epoch);
+    self.get_mut_environment().get_mut_magnetic_field().update(&start_epoch);
+
+    /* Take the first magnetometer sample of the B-dot controller at the start
+     * epoch > magnetorquers.ini < */
+    let is_magnetorquers_enabled: bool = *self.environment.get_spacecraft().get_magnetorquers()
+                                                          .is_magnetorquers_enabled();
+    if is_magnetorquers_enabled
+    {
+      self.environment.get_mut_spacecraft().get_mut_magnetorquers()
+        .set_next_sample_time_s(&self.sim_start_time_s);
+      update_bdot_controller(&state_vec, self.sim_start_time_s, time_tolerance_s, &mut self.environment);
+    }
+
+    /* Apply the maneuvers planned at the start epoch */
+    if is_maneuvers_enabled
+    {
+      self.maneuver_plan.apply_due_maneuvers(&mut state_vec, self.sim_start_time_s, is_propulsion_enabled);
+    }
+
+    /* Evaluate the attitude controller for the initial state 
+     * > attitude_control.ini < */
+    let is_attitude_control_enabled: bool = *self.environment.get_attitude_control()
+                                                             .is_attitude_control_enabled();
+    if is_attitude_control_enabled
+    {
+      update_attitude_controller(&state_vec, &mut self.environment);
+    }
 
     /* Write initial state to csv */
     state_vec  = augment_state_write(&self.get_mut_environment(), &mut state_vec, &state_vec_n0 );
-    write_csv::append_to_csv(&mut results_writer, &state_vec).unwrap();
+    write_csv::append_to_csv(&mut results_writer, &state_vec, &column_mask).unwrap();
+
+    /* Initialise event functions with the start state */
+    self.event_detection.init(&state_vec, &self.environment);
 
     /* ---------------------------------------------------------------------- */
     /* [!] -----> Simulation main loop                                        */
-    for sim_step in tqdm(0..num_steps).style(tqdm::Style::Block) {
+    while self.sim_end_time_s - self.sim_current_time_s > time_tolerance_s {
 
       /* Write Simulation status to console  */
       if print_out_counter >= self.param_sim_print_interval_s 
-        || sim_step == 0
-        || sim_step == num_steps - 1
+        || num_steps == 0
       {
         log.log_dbg(&format!("SimTime [s] {:.3?} ( {:.2?}  {:.2?}  {:.2?} ) ->> Altitude [m] {:.2?}", 
         self.sim_current_time_s, state_vec[STATE_VEC_INDX_POS_X], state_vec[STATE_VEC_INDX_POS_Y], 
         state_vec[STATE_VEC_INDX_POS_Z], self.state.get_altitude(&state_vec)));
         print_out_counter = 0.0;
       }
-      print_out_counter += self.dt_s;
+
+      /* Select the maximum size of this step, the simulation end time is 
+       * never stepped over */
+      let mut dt_max_s: f64 = self.sim_end_time_s - self.sim_current_time_s;
+      if integrator.is_adaptive()
+      {
+        dt_max_s = dt_max_s.min(next_write_time_s - self.sim_current_time_s);
+        /* Do not step over the next sample of the B-dot controller */
+        if is_magnetorquers_enabled
+        {
+          dt_max_s = dt_max_s.min(*self.environment.get_spacecraft().get_magnetorquers().get_next_sample_time_s()
+                                  - self.sim_current_time_s);
+        }
+      }
+
+      /* Update the firing state of the thrusters for this step, the step ends
+       * at the next start or end of a burn or at propellant depletion */
+      if is_propulsion_enabled
+      {
+        let propulsion: &mut Propulsion = self.environment.get_mut_spacecraft().get_mut_propulsion();
+        let propellant_mass_kg: f64 = state_vec[STATE_VEC_INDX_PROPELLANT_MASS];
+        if propulsion.update_firing(self.sim_current_time_s, propellant_mass_kg)
+        {
+          integrator.reset();
+        }
+        dt_max_s = dt_max_s.min(propulsion.calc_max_step_s(self.sim_current_time_s, propellant_mass_kg));
+      }
+
+      /* Stop the integration at the epoch of the next maneuver */
+      if let Some(next_maneuver_time_s) = self.maneuver_plan.get_next_maneuver_time_s()
+      {
+        dt_max_s = dt_max_s.min(next_maneuver_time_s - self.sim_current_time_s);
+      }
 
       /* -------------------------------------------------------------------- */
-      /* !! ---> Perform integration step with step size dt_s <--- !!         */
+      /* !! ---> Perform integration step with the selected solver <--- !!    */
       /* +_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_ */
-      state_vec = step( &state_vec, 
-                          &dxdt, 
-                          self.dt_s, 
-                          &mut self.environment);
+      let (state_vec_step, dt_integrated_s) = integrator.step(&state_vec, 
+                                                              &dxdt, 
+                                                              dt_max_s, 
+                                                              &mut self.environment);
       /* +_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_+_ */
       /* -------------------------------------------------------------------- */
 
-      /* Update current simulation time for the current result step */
-      self.sim_current_time_s += self.dt_s;
+      /* Detect events within the step, the step is truncated at terminal 
+       * events */
+      let (state_vec_step, dt_step_s, is_terminal_event) = self.event_detection.detect(&state_vec_n0, 
+                                                                                       state_vec_step, 
+                                                                                       self.sim_current_time_s, 
+                                                                                       dt_integrated_s, 
+                                                                                       &self.environment);
+      state_vec = state_vec_step;
+      num_steps += 1;
+
+      /* Normalize the attitude quaternion to remove the drift of its norm 
+       * introduced by the integration */
+      let quat_norm: f64 = l2_norm_array1(state_vec.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+      if quat_norm == 0.0 || !quat_norm.is_finite()
+      {
+        panic!("! [ERROR] ! > Attitude quaternion norm {} at t = {} s, cannot normalize! <",
+               quat_norm, self.sim_current_time_s);
+      }
+      state_vec.slice_mut(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)])
+        .mapv_inplace(|q: f64| q / quat_norm);
+      print_out_counter += dt_step_s;
+
+      /* Update current epoch and simulation time for the current result step */
+      if !integrator.is_adaptive() && dt_step_s == self.dt_s
+      {
+        num_nominal_steps += 1;
+      }
+      else
+      {
+        epoch_other_steps = epoch_other_steps.add_seconds(dt_step_s);
+      }
+      let current_epoch: Epoch = epoch_other_steps.add_seconds(num_nominal_steps as f64 * self.dt_s);
+      current_epoch.write_to_state_vec(&mut state_vec);
+      self.sim_current_time_s = self.sim_start_time_s + current_epoch.diff_s(&start_epoch);
       
       /* Update environment with simtime information */
-      let dts: f64 = self.dt_s;
       let simtime: f64 = self.sim_current_time_s;
-      self.get_mut_environment().set_simtimes(&dts, &simtime);
+      self.get_mut_environment().set_simtimes(&dt_step_s, &simtime);
+
+      /* Apply the impulsive maneuvers due at this epoch. The integrator history
+       * and the event functions are restarted after the velocity jump */
+      if is_maneuvers_enabled && !is_terminal_event
+        && self.maneuver_plan.apply_due_maneuvers(&mut state_vec, simtime, is_propulsion_enabled)
+      {
+        integrator.reset();
+        self.event_detection.init(&state_vec, &self.environment);
+      }
+
+      /* Sample the magnetometer and update the dipole of the B-dot controller.
+       * The integrator history is restarted after the torque jump */
+      if is_magnetorquers_enabled
+        && update_bdot_controller(&state_vec, simtime, time_tolerance_s, &mut self.environment)
+      {
+        integrator.reset();
+      }
+
+      /* Update the attitude error and the commanded torque of the controller 
+       * and check the guidance for degeneracy once per step */
+      if is_attitude_control_enabled
+      {
+        update_attitude_controller(&state_vec, &mut self.environment);
+      }
 
       /* Post-process elements that are not filled in by the solver at solving 
        * frequency
        * */
       state_vec  = augment_state_solve(&self.get_mut_environment(),&mut state_vec, &state_vec_n0 );
 
-      /* Increment counter to trigger [write results to file] */
-      write_out_counter += self.dt_s;
       /* Write state udpates to file */
-      if write_out_counter >= self.param_sim_archive_interval_s 
-        || sim_step == num_steps - 1 
+      if self.sim_current_time_s >= next_write_time_s - time_tolerance_s
+        || self.sim_end_time_s - self.sim_current_time_s <= time_tolerance_s
+        || is_terminal_event
       { 
         /* Augment state at writing frequency */
         state_vec  = augment_state_write(&self.get_mut_environment(), &mut state_vec, &state_vec_n0 );
         /* Write state to csv */
         write_csv::append_to_csv(&mut results_writer, 
-                                 &state_vec).unwrap();
-        write_out_counter = 0.0;
+                                 &state_vec,
+                                 &column_mask).unwrap();
+        next_write_time_s += self.param_sim_archive_interval_s;
+        progress_bar.next();
       }
 
       /* Flush csv writer */
@@ -280,11 +601,21 @@ impl DKE {
         flush_csv_writer(&mut results_writer).unwrap();
         write_flush_counter = 0.0;
       }
-      write_flush_counter += self.dt_s;
+      write_flush_counter += dt_step_s;
 
       /* Update vector to keep last timesteps state */
       state_vec_n0 = state_vec.clone();
 
+      /* Check if a terminal event occurred */
+      if is_terminal_event
+      {
+        log.log_wrn("!! [ Exit Simulation ] !!");
+        log.log_wrn(&format!("Terminal event: [{}] at SimTime [s] {:.6}", 
+          self.event_detection.get_detected_events().last().unwrap().get_event_type().get_name(),
+          self.sim_current_time_s));
+        break;
+      }
+
       /* Check if early exit condition is met */
       if self.is_exit_conditions(&state_vec) == true
       {
@@ -292,13 +623,39 @@ impl DKE {
         log.log_wrn("Early exit condition: [altitude below zero]");
         break;
       }
-    } /* for(sim_step */
+    } /* while(sim_current_time_s */
     /* ---------------------------------------------------------------------- */
 
     /* One extra flush to make sure everything is written   to the file
        before exiting */
     flush_csv_writer(&mut results_writer).unwrap();
 
+    /* Write detected events to file */
+    let mut events_writer = write_csv::create_event_csv(
+      "./data_out/events.csv".to_string());
+    for event in self.event_detection.get_detected_events()
+    {
+      write_csv::append_event_to_csv(&mut events_writer, 
+                                     event.get_event_type().get_name(), 
+                                     *event.get_sim_time_s(), 
+                                     event.get_state()).unwrap();
+    }
+    flush_csv_writer(&mut events_writer).unwrap();
+
+    /* Write executed maneuvers to file */
+    if is_maneuvers_enabled
+    {
+      let mut maneuvers_writer = write_csv::create_maneuver_csv(
+        "./data_out/maneuvers.csv".to_string());
+      for (maneuver_num, executed_maneuver) in self.maneuver_plan.get_executed_maneuvers().iter().enumerate()
+      {
+        write_csv::append_maneuver_to_csv(&mut maneuvers_writer, 
+                                          maneuver_num + 1, 
+                                          executed_maneuver).unwrap();
+      }
+      flush_csv_writer(&mut maneuvers_writer).unwrap();
+    }
+
     /* Print summary on completed simulation */
     log.log_msg("");
     log.log_msg("---------------------------------------------------------------");
@@ -308,10 +665,53 @@ impl DKE {
       state_vec[STATE_VEC_INDX_SIM_TIME] ));
     log.log_msg(&format!("Runtime                             [s] : {:.3?}", 
     (simulation_timer.elapsed().as_millis() as f64) / 1000.0) );
+    log.log_msg(&format!("Solver                                  : {:?}", 
+      self.solver_type));
     log.log_msg(&format!("Number of integration steps             : {:?}", 
       num_steps));
-    log.log_msg(&format!("Step size                          [ms] : {:?}", 
-      self.dt_s*1000.0));
+    if integrator.is_adaptive()
+    {
+      log.log_msg(&format!("Number of rejected steps                : {:?}", 
+        integrator.get_num_rejected_steps()));
+    }
+    for event_type in self.event_detection.get_events()
+    {
+      let num_events: usize = self.event_detection.get_detected_events()
+                                                  .iter()
+                                                  .filter(|event| event.get_event_type() == event_type)
+                                                  .count();
+      log.log_msg(&format!("Number of events {:<22} : {:?}", 
+        format!("[{}]", event_type.get_name()), num_events));
+    }
+    if let Some(detumble_event) = self.event_detection.get_detected_events()
+                                                     .iter()
+                                                     .find(|event| *event.get_event_type() == EventType::Detumbled)
+    {
+      log.log_msg(&format!("Detumble time                       [s] : {:.1}", 
+        detumble_event.get_sim_time_s() - self.sim_start_time_s));
+    }
+    if is_propulsion_enabled
+    {
+      log.log_msg(&format!("Propellant used                    [kg] : {:.3}", 
+        self.environment.get_spacecraft().get_propulsion().get_propellant_mass_start_kg() 
+        - state_vec[STATE_VEC_INDX_PROPELLANT_MASS]));
+      log.log_msg(&format!("Propellant remaining               [kg] : {:.3}", 
+        state_vec[STATE_VEC_INDX_PROPELLANT_MASS].max(0.0)));
+      log.log_msg(&format!("Thruster delta-v                  [m/s] : {:.3}", 
+        state_vec[STATE_VEC_INDX_THRUST_DELTA_V_MS]));
+    }
+    if is_maneuvers_enabled
+    {
+      let executed_maneuvers = self.maneuver_plan.get_executed_maneuvers();
+      log.log_msg(&format!("Maneuvers executed / planned            : {} / {}", 
+        executed_maneuvers.len(), self.maneuver_plan.get_maneuvers().len()));
+      log.log_msg(&format!("Maneuver delta-v                  [m/s] : {:.3}", 
+        executed_maneuvers.iter().fold(0.0, |sum, maneuver| sum + maneuver.get_delta_v_magn_ms())));
+      log.log_msg(&format!("Maneuver propellant used           [kg] : {:.3}", 
+        executed_maneuvers.iter().fold(0.0, |sum, maneuver| sum + maneuver.get_propellant_used_kg())));
+    }
+    log.log_msg(&format!("Mean step size                     [ms] : {:.3?}", 
+      (self.sim_current_time_s - self.sim_start_time_s) / num_steps as f64 * 1000.0));
     log.log_msg(&format!("Simulation time                    [ms] : {:.3?}", 
       simulation_timer.elapsed().as_millis()) );
     log.log_msg(&format!("Exec time per step                 [ms] : {:.6?}", 
@@ -321,6 +721,24 @@ impl DKE {
                                                         .elapsed()
                                                         .as_millis() as f64) * 1000.0));
     log.log_msg("---------------------------------------------------------------");
+    /* Print the summary table of the executed maneuvers */
+    if is_maneuvers_enabled && !self.maneuver_plan.get_executed_maneuvers().is_empty()
+    {
+      log.log_msg("              [MANEUVERS]");
+      log.log_msg("---------------------------------------------------------------");
+      log.log_msg("  #   SimTime [s]  Frame   dv [m/s]  Mass [kg]  Prop. [kg]");
+      for (maneuver_num, executed_maneuver) in self.maneuver_plan.get_executed_maneuvers().iter().enumerate()
+      {
+        log.log_msg(&format!("{:>3} {:>13.3}  {:<5} {:>10.3} {:>10.3} {:>11.3}", 
+          maneuver_num + 1,
+          executed_maneuver.get_maneuver().get_sim_time_s(),
+          executed_maneuver.get_maneuver().get_frame().get_name(),
+          executed_maneuver.get_delta_v_magn_ms(),
+          executed_maneuver.get_mass_after_kg(),
+          executed_maneuver.get_propellant_used_kg()));
+      }
+      log.log_msg("---------------------------------------------------------------");
+    }
     /* Call Plotting functions on results */
     // TODO add enabler flags for postprocessing charts
     log.log_msg("[Save Plot] -> S/C ground track");
@@ -1,15 +1,31 @@
 
 /* Include external crates */
 use ini::Ini;
+use ndarray::{Array1, arr2};
 
 /* Import (local) structs */
 /* None */
 
 /* Include local crates */
 use crate::dke_core::dke_core::DKE;
+use crate::solver::integrator::SolverType;
+use crate::dke_core::events::EventType;
+use crate::environment::gravity::gravity_model::GravityModelType;
+use crate::environment::planet::atmosphere::AtmosphereModelType;
+use crate::environment::planet::earth_orientation::EarthRotationModelType;
+use crate::environment::planet::eop::EopFileFormat;
+use crate::environment::ephemeris::ephemeris_model::EphemerisModelType;
+use crate::environment::attitude_control::attitude_controller::AttitudeGuidanceMode;
+use crate::environment::attitude_control::guidance::SecondaryTargetType;
+use crate::environment::spacecraft::reaction_wheels::{ReactionWheel, MomentumDumpingMode};
+use crate::environment::spacecraft::propulsion::{Thruster, ThrusterBurn};
+use crate::environment::magnetic_field::magnetic_field_model::MagneticFieldModelType;
 
 /* Import constants */
 use crate::constants::filepaths::*;
+use crate::constants::gravity::*;
+use crate::constants::general::RPM_TO_RADS;
+use crate::constants::magnetic_field::NANOTESLA_TO_TESLA;
 
 /*
  * @brief: This function is to load all required parameters from configuration 
@@ -42,6 +58,77 @@ pub fn load_dke_core_parameters(dke: &mut DKE)
     .section(Some("write_setting")).unwrap()
     .get("sim_archive_flush_interval_s").unwrap())
     .parse::<f64>().unwrap()));
+
+  dke.set_param_flag_write_orbit_elements(&((sim_conf
+    .section(Some("write_setting")).unwrap()
+    .get("flag_write_orbit_elements").unwrap())
+    .parse::<bool>().unwrap()));
+
+  dke.set_param_flag_write_sun_moon_direction(&((sim_conf
+    .section(Some("write_setting")).unwrap()
+    .get("flag_write_sun_moon_direction").unwrap())
+    .parse::<bool>().unwrap()));
+  /* -------------------------------------------------------------------------
+  * SOLVER
+  * 
+  * -----------------------------------------------------------------------*/
+  dke.set_solver_type(&SolverType::from_param_str(sim_conf
+    .section(Some("solver")).unwrap()
+    .get("solver").expect("! [ERROR] ! > solver not found in sim.ini! <")));
+
+  dke.set_solver_rel_tol(&((sim_conf
+    .section(Some("solver")).unwrap()
+    .get("rel_tol").unwrap())
+    .parse::<f64>().unwrap()));
+
+  dke.set_solver_abs_tol(&((sim_conf
+    .section(Some("solver")).unwrap()
+    .get("abs_tol").unwrap())
+    .parse::<f64>().unwrap()));
+
+  dke.set_solver_dt_min_s(&((sim_conf
+    .section(Some("solver")).unwrap()
+    .get("dt_min_s").unwrap())
+    .parse::<f64>().unwrap()));
+
+  dke.set_solver_dt_max_s(&((sim_conf
+    .section(Some("solver")).unwrap()
+    .get("dt_max_s").unwrap())
+    .parse::<f64>().unwrap()));
+  /* -------------------------------------------------------------------------
+  * EVENTS
+  * 
+  * -----------------------------------------------------------------------*/
+  if sim_conf.section(Some("events")).unwrap()
+    .get("flag_enable_impact_event").unwrap()
+    .parse::<bool>().unwrap()
+  {
+    dke.get_mut_event_detection().add_event(&EventType::GroundImpact);
+  }
+
+  if sim_conf.section(Some("events")).unwrap()
+    .get("flag_enable_apsis_events").unwrap()
+    .parse::<bool>().unwrap()
+  {
+    dke.get_mut_event_detection().add_event(&EventType::Periapsis);
+    dke.get_mut_event_detection().add_event(&EventType::Apoapsis);
+  }
+
+  if sim_conf.section(Some("events")).unwrap()
+    .get("flag_enable_node_events").unwrap()
+    .parse::<bool>().unwrap()
+  {
+    dke.get_mut_event_detection().add_event(&EventType::AscendingNode);
+    dke.get_mut_event_detection().add_event(&EventType::DescendingNode);
+  }
+
+  if sim_conf.section(Some("events")).unwrap()
+    .get("flag_enable_eclipse_events").unwrap()
+    .parse::<bool>().unwrap()
+  {
+    dke.get_mut_event_detection().add_event(&EventType::EclipseEntry);
+    dke.get_mut_event_detection().add_event(&EventType::EclipseExit);
+  }
   /* -------------------------------------------------------------------------
   *      [PLANET]
   * 
@@ -74,6 +161,36 @@ pub fn load_dke_core_parameters(dke: &mut DKE)
     .get("planet_omega_rads").unwrap())
     .parse::<f64>().unwrap() );
 
+ /* -------------------------------------------------------------------------
+  *      [PLANET / EARTH ORIENTATION]
+  * 
+  * -----------------------------------------------------------------------*/
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_rotation_model(
+    &EarthRotationModelType::from_param_str(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("rotation_model").unwrap()) );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_eop_file_path(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("eop_file_path").unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_eop_file_format(
+    &EopFileFormat::from_param_str(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("eop_file_format").unwrap()) );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_cip_series_x_file_path(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("cip_series_x_file_path").unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_cip_series_y_file_path(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("cip_series_y_file_path").unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_earth_orientation().set_cip_series_s_file_path(planet_conf
+      .section(Some("earth_orientation")).unwrap()
+      .get("cip_series_s_file_path").unwrap() );
+
  /* -------------------------------------------------------------------------
   *      [PLANET / ATMOSPHERE]
   * 
@@ -86,6 +203,20 @@ pub fn load_dke_core_parameters(dke: &mut DKE)
       .get("flag_enable_atmosphere_modelling").unwrap())
       .parse::<bool>().unwrap() );
 
+  dke.get_mut_environment().get_mut_planet().get_mut_atmosphere().set_model_type(
+    &AtmosphereModelType::from_param_str(atmosphere_conf
+      .section(Some("general")).unwrap()
+      .get("atmosphere_model").unwrap()) );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_atmosphere().set_nrlmsise00_coefficient_file_path(atmosphere_conf
+      .section(Some("nrlmsise00")).unwrap()
+      .get("coefficient_file_path").unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_atmosphere().set_radio_10_cm_flux_81_day_avg(&(atmosphere_conf
+      .section(Some("general")).unwrap()
+      .get("radio_10_cm_flux_81_day_avg").unwrap())
+      .parse::<f64>().unwrap() );
+
   dke.get_mut_environment().get_mut_planet().get_mut_atmosphere().set_radio_10_cm_flux(&(atmosphere_conf
       .section(Some("general")).unwrap()
       .get("radio_10_cm_flux").unwrap())
@@ -96,6 +227,51 @@ pub fn load_dke_core_parameters(dke: &mut DKE)
         .get("geomagnetic_ap_index").unwrap())
         .parse::<f64>().unwrap() );
 
+  /* -------------------------------------------------------------------------
+  *      [PLANET / GRAVITY]
+  * 
+  * -----------------------------------------------------------------------*/
+  let gravity_conf: Ini = Ini::load_from_file(GRAVITY_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > gravity.ini not found! <");
+
+  dke.get_mut_environment().get_mut_planet().get_mut_gravity_model().set_model_type(
+    &GravityModelType::from_param_str(gravity_conf
+      .section(Some("general")).unwrap()
+      .get("gravity_model").unwrap()) );
+
+  for degree in 2..=GRAV_ZONAL_MAX_DEGREE
+  {
+    dke.get_mut_environment().get_mut_planet().get_mut_gravity_model().set_zonal_coefficient(degree, 
+      &(gravity_conf
+        .section(Some("zonal")).unwrap()
+        .get(format!("j{}", degree)).unwrap())
+        .parse::<f64>().unwrap() );
+  }
+
+  dke.get_mut_environment().get_mut_planet().get_mut_gravity_model().set_coefficient_file_path(gravity_conf
+      .section(Some("spherical_harmonics")).unwrap()
+      .get("coefficient_file_path").unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_gravity_model().set_max_degree(&(gravity_conf
+      .section(Some("spherical_harmonics")).unwrap()
+      .get("max_degree").unwrap())
+      .parse::<usize>().unwrap() );
+
+  dke.get_mut_environment().get_mut_planet().get_mut_gravity_model().set_max_order(&(gravity_conf
+      .section(Some("spherical_harmonics")).unwrap()
+      .get("max_order").unwrap())
+      .parse::<usize>().unwrap() );
+
+  dke.get_mut_environment().get_mut_third_body().set_enable_sun_perturbation(&(gravity_conf
+      .section(Some("third_body")).unwrap()
+      .get("flag_enable_sun_perturbation").unwrap())
+      .parse::<bool>().unwrap() );
+
+  dke.get_mut_environment().get_mut_third_body().set_enable_moon_perturbation(&(gravity_conf
+      .section(Some("third_body")).unwrap()
+      .get("flag_enable_moon_perturbation").unwrap())
+      .parse::<bool>().unwrap() );
+
   /*
    * @brief: After all parameters have been loaded -> initialize planet and sub-structs
    * 
@@ -113,20 +289,362 @@ pub fn load_dke_core_parameters(dke: &mut DKE)
     .get("sc_eff_aero_area_mm").unwrap())
     .parse::<f64>().unwrap() );
 
-  dke.get_mut_environment().get_mut_spacecraft().set_sc_mass_kg(&(sim_conf
-    .section(Some("start_state")).unwrap()
-    .get("sc_mass_start_kg").unwrap())
-    .parse::<f64>().unwrap() );
-
   dke.get_mut_environment().get_mut_spacecraft().set_sc_charact_length_m(&(sim_conf
       .section(Some("start_state")).unwrap()
       .get("sc_charact_length_m").unwrap())
       .parse::<f64>().unwrap() );
 
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_srp_area_mm(&(sim_conf
+      .section(Some("start_state")).unwrap()
+      .get("sc_srp_area_mm").unwrap())
+      .parse::<f64>().unwrap() );
+
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_reflectivity_coeff(&(sim_conf
+      .section(Some("start_state")).unwrap()
+      .get("sc_reflectivity_coeff").unwrap())
+      .parse::<f64>().unwrap() );
+
+  /* Inertia tensor from its moments and products of inertia */
+  let read_spacecraft_param = |key: &str| -> f64 {
+    sim_conf.section(Some("start_state")).unwrap()
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in sim.ini! <", key))
+      .parse::<f64>().unwrap()
+  };
+  let inertia_xx: f64 = read_spacecraft_param("sc_inertia_xx_kgmm");
+  let inertia_yy: f64 = read_spacecraft_param("sc_inertia_yy_kgmm");
+  let inertia_zz: f64 = read_spacecraft_param("sc_inertia_zz_kgmm");
+  let inertia_xy: f64 = read_spacecraft_param("sc_inertia_xy_kgmm");
+  let inertia_xz: f64 = read_spacecraft_param("sc_inertia_xz_kgmm");
+  let inertia_yz: f64 = read_spacecraft_param("sc_inertia_yz_kgmm");
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_inertia_tensor_kgmm(&arr2(&[
+    [inertia_xx, inertia_xy, inertia_xz],
+    [inertia_xy, inertia_yy, inertia_yz],
+    [inertia_xz, inertia_yz, inertia_zz]]));
+
+  /* Centre of pressure offsets from the centre of mass for the disturbance 
+   * torques */
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_aero_cop_offset_b_m_x(&read_spacecraft_param("sc_aero_cop_offset_x_m"));
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_aero_cop_offset_b_m_y(&read_spacecraft_param("sc_aero_cop_offset_y_m"));
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_aero_cop_offset_b_m_z(&read_spacecraft_param("sc_aero_cop_offset_z_m"));
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_srp_cop_offset_b_m_x(&read_spacecraft_param("sc_srp_cop_offset_x_m"));
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_srp_cop_offset_b_m_y(&read_spacecraft_param("sc_srp_cop_offset_y_m"));
+  dke.get_mut_environment().get_mut_spacecraft().set_sc_srp_cop_offset_b_m_z(&read_spacecraft_param("sc_srp_cop_offset_z_m"));
+
   /*
    * @brief: After all parameters have been loaded -> initialize spacecraft and sub-structs
    * 
    */
   dke.get_mut_environment().get_mut_spacecraft().init();
 
+ /* -------------------------------------------------------------------------
+  *      [SOLAR RADIATION PRESSURE]
+  * 
+  * -----------------------------------------------------------------------*/
+  let srp_conf: Ini = Ini::load_from_file(SRP_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > srp.ini not found! <");
+
+  dke.get_mut_environment().get_mut_srp().set_enable_srp_modelling(&(srp_conf
+      .section(Some("general")).unwrap()
+      .get("flag_enable_srp_modelling").unwrap())
+      .parse::<bool>().unwrap() );
+
+  dke.get_mut_environment().get_mut_srp().set_solar_flux_1au_wmm(&(srp_conf
+      .section(Some("general")).unwrap()
+      .get("solar_flux_1au_wmm").unwrap())
+      .parse::<f64>().unwrap() );
+
+ /* -------------------------------------------------------------------------
+  *      [EPHEMERIS]
+  * 
+  * -----------------------------------------------------------------------*/
+  let ephemeris_conf: Ini = Ini::load_from_file(EPHEMERIS_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > ephemeris.ini not found! <");
+
+  dke.get_mut_environment().get_mut_ephemeris().set_model_type(&EphemerisModelType::from_param_str(ephemeris_conf
+      .section(Some("general")).unwrap()
+      .get("ephemeris_model").expect("! [ERROR] ! > ephemeris_model not found in ephemeris.ini! <")));
+
+  dke.get_mut_environment().get_mut_ephemeris().set_spk_file_path(ephemeris_conf
+      .section(Some("general")).unwrap()
+      .get("spk_file_path").unwrap_or(""));
+
+  dke.get_mut_environment().get_mut_ephemeris().init();
+
+ /* -------------------------------------------------------------------------
+  *      [MAGNETIC FIELD]
+  * 
+  * -----------------------------------------------------------------------*/
+  let magnetic_field_conf: Ini = Ini::load_from_file(MAGNETIC_FIELD_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > magnetic_field.ini not found! <");
+
+  dke.get_mut_environment().get_mut_magnetic_field().set_enable_magnetic_field_output(&(magnetic_field_conf
+      .section(Some("general")).unwrap()
+      .get("flag_write_magnetic_field").expect("! [ERROR] ! > flag_write_magnetic_field not found in magnetic_field.ini! <"))
+      .parse::<bool>().unwrap() );
+
+  dke.get_mut_environment().get_mut_magnetic_field().set_model_type(&MagneticFieldModelType::from_param_str(magnetic_field_conf
+      .section(Some("general")).unwrap()
+      .get("magnetic_field_model").expect("! [ERROR] ! > magnetic_field_model not found in magnetic_field.ini! <")));
+
+  dke.get_mut_environment().get_mut_magnetic_field().set_igrf_file_path(magnetic_field_conf
+      .section(Some("general")).unwrap()
+      .get("igrf_file_path").unwrap_or(""));
+
+  dke.get_mut_environment().get_mut_magnetic_field().init();
+
+ /* -------------------------------------------------------------------------
+  *      [ATTITUDE CONTROL]
+  * 
+  * -----------------------------------------------------------------------*/
+  let attitude_control_conf: Ini = Ini::load_from_file(ATTITUDE_CONTROL_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > attitude_control.ini not found! <");
+  let read_attitude_control_param = |section: &str, key: &str| -> String {
+    attitude_control_conf.section(Some(section)).unwrap()
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in attitude_control.ini! <", key))
+      .to_string()
+  };
+  let read_attitude_control_vec = |section: &str, keys: &[&str]| -> Array1<f64> {
+    Array1::from_iter(keys.iter().map(|key| read_attitude_control_param(section, key)
+                                              .parse::<f64>().unwrap()))
+  };
+
+  dke.get_mut_environment().get_mut_attitude_control().set_enable_attitude_control(
+    &read_attitude_control_param("general", "flag_enable_attitude_control").parse::<bool>().unwrap());
+
+  dke.get_mut_environment().get_mut_attitude_control().set_guidance_mode(&AttitudeGuidanceMode::from_param_str(
+    &read_attitude_control_param("general", "guidance_mode")));
+
+  dke.get_mut_environment().get_mut_attitude_control().set_proportional_gain_nm(
+    &read_attitude_control_param("controller", "proportional_gain_nm").parse::<f64>().unwrap());
+
+  dke.get_mut_environment().get_mut_attitude_control().set_derivative_gain_nms(
+    &read_attitude_control_param("controller", "derivative_gain_nms").parse::<f64>().unwrap());
+
+  dke.get_mut_environment().get_mut_attitude_control().set_max_control_torque_nm(
+    &read_attitude_control_param("controller", "max_control_torque_nm").parse::<f64>().unwrap());
+
+  dke.get_mut_environment().get_mut_attitude_control().set_inertial_quat_pci_to_b(&read_attitude_control_vec(
+    "inertial", &["quat_pci_to_b_x", "quat_pci_to_b_y", "quat_pci_to_b_z", "quat_pci_to_b_w"]));
+
+  dke.get_mut_environment().get_mut_attitude_control().set_primary_axis_b(&read_attitude_control_vec(
+    "pointing", &["primary_axis_b_x", "primary_axis_b_y", "primary_axis_b_z"]));
+
+  dke.get_mut_environment().get_mut_attitude_control().set_secondary_axis_b(&read_attitude_control_vec(
+    "pointing", &["secondary_axis_b_x", "secondary_axis_b_y", "secondary_axis_b_z"]));
+
+  dke.get_mut_environment().get_mut_attitude_control().set_secondary_target_type(&SecondaryTargetType::from_param_str(
+    &read_attitude_control_param("pointing", "secondary_target")));
+
+  let target_pos_lla: Array1<f64> = read_attitude_control_vec("pointing", &["target_lat_deg", "target_lon_deg", "target_alt_m"]);
+  dke.get_mut_environment().get_mut_attitude_control().set_target_pos_lla(&Array1::from(vec![
+    target_pos_lla[0].to_radians(), target_pos_lla[1].to_radians(), target_pos_lla[2]]));
+
+ /* -------------------------------------------------------------------------
+  *      [REACTION WHEELS]
+  * 
+  * -----------------------------------------------------------------------*/
+  let reaction_wheels_conf: Ini = Ini::load_from_file(REACTION_WHEELS_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > reaction_wheels.ini not found! <");
+  let read_reaction_wheels_param = |section: &str, key: &str| -> String {
+    reaction_wheels_conf.section(Some(section))
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Section [{}] not found in reaction_wheels.ini! <", section))
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in reaction_wheels.ini! <", key))
+      .to_string()
+  };
+  let read_reaction_wheels_f64 = |section: &str, key: &str| -> f64 {
+    read_reaction_wheels_param(section, key).parse::<f64>().unwrap()
+  };
+
+  let enable_reaction_wheels: bool = read_reaction_wheels_param("general", "flag_enable_reaction_wheels")
+    .parse::<bool>().unwrap();
+  dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly()
+    .set_enable_reaction_wheels(&enable_reaction_wheels);
+
+  if enable_reaction_wheels
+  {
+    let num_wheels: usize = read_reaction_wheels_param("general", "num_wheels").parse::<usize>().unwrap();
+    for wheel_num in 1..=num_wheels
+    {
+      let section: String = format!("wheel_{}", wheel_num);
+      let wheel: ReactionWheel = ReactionWheel::new(
+        &Array1::from(vec![read_reaction_wheels_f64(&section, "spin_axis_b_x"),
+                           read_reaction_wheels_f64(&section, "spin_axis_b_y"),
+                           read_reaction_wheels_f64(&section, "spin_axis_b_z")]),
+        read_reaction_wheels_f64(&section, "rotor_inertia_kgmm"),
+        read_reaction_wheels_f64(&section, "max_torque_nm"),
+        read_reaction_wheels_f64(&section, "max_speed_rpm") * RPM_TO_RADS,
+        read_reaction_wheels_f64(&section, "viscous_friction_nms"),
+        read_reaction_wheels_f64(&section, "coulomb_friction_nm"),
+        read_reaction_wheels_f64(&section, "start_speed_rpm") * RPM_TO_RADS);
+      dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().add_wheel(wheel);
+    }
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().set_dumping_mode(
+      &MomentumDumpingMode::from_param_str(&read_reaction_wheels_param("momentum_dumping", "dumping_mode")));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().set_dumping_gain_1ps(
+      &read_reaction_wheels_f64("momentum_dumping", "dumping_gain_1ps"));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().set_max_dumping_torque_nm(
+      &read_reaction_wheels_f64("momentum_dumping", "max_dumping_torque_nm"));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().set_max_dumping_dipole_amm(
+      &read_reaction_wheels_f64("momentum_dumping", "max_dumping_dipole_amm"));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_reaction_wheel_assembly().init();
+  }
+
+ /* -------------------------------------------------------------------------
+  *      [MAGNETORQUERS]
+  * 
+  * -----------------------------------------------------------------------*/
+  let magnetorquers_conf: Ini = Ini::load_from_file(MAGNETORQUERS_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > magnetorquers.ini not found! <");
+  let read_magnetorquers_param = |section: &str, key: &str| -> String {
+    magnetorquers_conf.section(Some(section))
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Section [{}] not found in magnetorquers.ini! <", section))
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in magnetorquers.ini! <", key))
+      .to_string()
+  };
+  let read_magnetorquers_vec = |section: &str, keys: &[&str]| -> Array1<f64> {
+    Array1::from_iter(keys.iter().map(|key| read_magnetorquers_param(section, key)
+                                              .parse::<f64>().unwrap()))
+  };
+
+  let enable_magnetorquers: bool = read_magnetorquers_param("general", "flag_enable_magnetorquers")
+    .parse::<bool>().unwrap();
+  dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers()
+    .set_enable_magnetorquers(&enable_magnetorquers);
+
+  if enable_magnetorquers
+  {
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_max_dipole_amm(&read_magnetorquers_vec(
+      "rods", &["max_dipole_x_amm", "max_dipole_y_amm", "max_dipole_z_amm"]));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_dipole_quantization_amm(&read_magnetorquers_vec(
+      "rods", &["dipole_quantization_x_amm", "dipole_quantization_y_amm", "dipole_quantization_z_amm"]));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_magnetometer_bias_t(&(NANOTESLA_TO_TESLA
+      * read_magnetorquers_vec("magnetometer", &["bias_x_nt", "bias_y_nt", "bias_z_nt"])));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_magnetometer_noise_std_t(&(NANOTESLA_TO_TESLA
+      * read_magnetorquers_param("magnetometer", "noise_std_nt").parse::<f64>().unwrap()));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_magnetometer_resolution_t(&(NANOTESLA_TO_TESLA
+      * read_magnetorquers_param("magnetometer", "resolution_nt").parse::<f64>().unwrap()));
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_noise_seed(
+      &read_magnetorquers_param("magnetometer", "noise_seed").parse::<u64>().unwrap());
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_bdot_gain_nms(
+      &read_magnetorquers_param("bdot", "bdot_gain_nms").parse::<f64>().unwrap());
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_sample_interval_s(
+      &read_magnetorquers_param("bdot", "sample_interval_s").parse::<f64>().unwrap());
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_magnetorquers().set_detumble_rate_threshold_rads(
+      &read_magnetorquers_param("bdot", "detumble_rate_threshold_degs").parse::<f64>().unwrap().to_radians());
+
+    /* Detect the time at which the angular rate drops below the threshold */
+    dke.get_mut_event_detection().add_event(&EventType::Detumbled);
+  }
+
+ /* -------------------------------------------------------------------------
+  *      [PROPULSION]
+  * 
+  * -----------------------------------------------------------------------*/
+  let propulsion_conf: Ini = Ini::load_from_file(PROPULSION_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > propulsion.ini not found! <");
+  let read_propulsion_param = |section: &str, key: &str| -> String {
+    propulsion_conf.section(Some(section))
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Section [{}] not found in propulsion.ini! <", section))
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in propulsion.ini! <", key))
+      .to_string()
+  };
+  let read_propulsion_f64 = |section: &str, key: &str| -> f64 {
+    read_propulsion_param(section, key).parse::<f64>().unwrap()
+  };
+
+  let enable_propulsion: bool = read_propulsion_param("general", "flag_enable_propulsion")
+    .parse::<bool>().unwrap();
+  dke.get_mut_environment().get_mut_spacecraft().get_mut_propulsion()
+    .set_enable_propulsion(&enable_propulsion);
+
+  if enable_propulsion
+  {
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_propulsion().set_propellant_mass_start_kg(
+      &read_propulsion_f64("general", "propellant_mass_kg"));
+
+    let num_thrusters: usize = read_propulsion_param("general", "num_thrusters").parse::<usize>().unwrap();
+    for thruster_num in 1..=num_thrusters
+    {
+      let section: String = format!("thruster_{}", thruster_num);
+      for key in ["thrust_n", "isp_s"]
+      {
+        let value: f64 = read_propulsion_f64(&section, key);
+        if value <= 0.0 || !value.is_finite()
+        {
+          panic!("! [ERROR] ! > {} = {} in [{}] of propulsion.ini must be positive! <", key, value, section);
+        }
+      }
+      let thruster: Thruster = Thruster::new(
+        &Array1::from(vec![read_propulsion_f64(&section, "thrust_direction_b_x"),
+                           read_propulsion_f64(&section, "thrust_direction_b_y"),
+                           read_propulsion_f64(&section, "thrust_direction_b_z")]),
+        read_propulsion_f64(&section, "thrust_n"),
+        read_propulsion_f64(&section, "isp_s"),
+        read_propulsion_f64(&section, "min_on_time_s"),
+        read_propulsion_f64(&section, "max_on_time_s"));
+      dke.get_mut_environment().get_mut_spacecraft().get_mut_propulsion().add_thruster(thruster);
+    }
+
+    let num_burns: usize = read_propulsion_param("general", "num_burns").parse::<usize>().unwrap();
+    for burn_num in 1..=num_burns
+    {
+      let section: String = format!("burn_{}", burn_num);
+      let thruster_num: usize = read_propulsion_param(&section, "thruster").parse::<usize>().unwrap();
+      if thruster_num == 0
+      {
+        panic!("! [ERROR] ! > Thruster numbers start at 1 in [{}] of propulsion.ini! <", section);
+      }
+      let burn: ThrusterBurn = ThrusterBurn::new(
+        thruster_num - 1,
+        read_propulsion_f64(&section, "start_time_s"),
+        read_propulsion_f64(&section, "duration_s"));
+      dke.get_mut_environment().get_mut_spacecraft().get_mut_propulsion().add_burn(burn);
+    }
+
+    dke.get_mut_environment().get_mut_spacecraft().get_mut_propulsion().init();
+  }
+
+ /* -------------------------------------------------------------------------
+  *      [MANEUVERS]
+  * 
+  * -----------------------------------------------------------------------*/
+  let maneuvers_conf: Ini = Ini::load_from_file(MANEUVERS_PARAMETER_FILE_PATH)
+    .expect("! [ERROR] ! > maneuvers.ini not found! <");
+  let read_maneuvers_param = |section: &str, key: &str| -> String {
+    maneuvers_conf.section(Some(section))
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Section [{}] not found in maneuvers.ini! <", section))
+      .get(key).unwrap_or_else(|| panic!("! [ERROR] ! > {} not found in maneuvers.ini! <", key))
+      .to_string()
+  };
+
+  let enable_maneuvers: bool = read_maneuvers_param("general", "flag_enable_maneuvers")
+    .parse::<bool>().unwrap();
+  dke.get_mut_maneuver_plan().set_enable_maneuvers(&enable_maneuvers);
+
+  if enable_maneuvers
+  {
+    dke.get_mut_maneuver_plan().set_plan_file_path(
+      read_maneuvers_param("general", "maneuver_plan_file_path").trim_matches('"'));
+
+    let isp_s: f64 = read_maneuvers_param("general", "isp_s").parse::<f64>().unwrap();
+    if isp_s <= 0.0 || !isp_s.is_finite()
+    {
+      panic!("! [ERROR] ! > isp_s = {} in maneuvers.ini must be positive! <", isp_s);
+    }
+    dke.get_mut_maneuver_plan().set_isp_s(&isp_s);
+  }
+
 }
@@ -1,10 +1,19 @@
 /* Include external crates */
-use ndarray::Array1;
+use ndarray::{Array1, s};
 
 /* Include local carates */
 use crate::environment::environment::Environment;
 use crate::environment::gravity::*;
 use crate::environment::aerodynamic::*;
+use crate::environment::third_body::*;
+use crate::environment::srp::*;
+use crate::environment::attitude_control::*;
+use crate::environment::spacecraft::reaction_wheels;
+use crate::environment::spacecraft::magnetorquers;
+use crate::environment::spacecraft::propulsion;
+use crate::math::epoch::Epoch;
+use crate::math::attitude_math::{calc_quat_derivative, calc_ang_acc_b};
+use crate::math::vec_math::{cross_product_array1, l2_norm_array1};
 
 /* Include constants */
 use crate::constants::state::*;
@@ -18,6 +27,13 @@ pub fn dxdt(
   let mut dxdt_out = Array1
                                                     ::<f64>
                                                     ::zeros(STATE_VEC_NUM_ELEMENTS);
+  /* Update the planet's orientation (precession-nutation and polar motion) 
+   * if the epoch moved by more than the update interval */
+  environment.get_mut_planet().get_mut_earth_orientation().update(&Epoch::from_state_vec(x_in.view()));
+  /* Update the interpolated IGRF coefficients if the epoch moved by more 
+   * than the update interval */
+  environment.get_mut_magnetic_field().update(&Epoch::from_state_vec(x_in.view()));
+
   /* Get sum of all forces acting on the S/C */
   let sum_of_forces_pci: Array1<f64> = get_sum_of_force_vecs_pci(x_in, environment) ;
 
@@ -49,8 +65,61 @@ pub fn dxdt(
   dxdt_out[STATE_VEC_INDX_VEL_Y] = ay;
   dxdt_out[STATE_VEC_INDX_VEL_Z] = az;
 
+  /* [MASS DERIVATIVE] */
+  /* dm/dt = - F / (Isp * g0) of the firing thrusters, the delta-v of the 
+   * thrusters is integrated alongside
+   * Note: Requires the thrust force of this state from 
+   *       get_sum_of_force_vecs_pci() */
+  if *environment.get_spacecraft().get_propulsion().is_propulsion_enabled()
+  {
+    let propulsion = environment.get_spacecraft().get_propulsion();
+    dxdt_out[STATE_VEC_INDX_MASS] = -propulsion.get_mass_flow_kgps();
+    dxdt_out[STATE_VEC_INDX_PROPELLANT_MASS] = -propulsion.get_mass_flow_kgps();
+    dxdt_out[STATE_VEC_INDX_THRUST_DELTA_V_MS] = l2_norm_array1(propulsion.get_thrust_force_pci_n().view()) 
+                                                 / mass_kg;
+  }
+
+  /* [EPOCH] */
+  /* The epoch is integrated alongside the state so that the intermediate 
+   * stages of the solvers evaluate time dependent models (e.g. planet 
+   * rotation, Sun and Moon positions) at the correct epoch. Only the seconds
+   * of the two-part epoch advance, the seconds since J2000 are kept in sync 
+   * for output */
+  dxdt_out[STATE_VEC_INDX_EPOCH_SECONDS] = 1.0;
+  dxdt_out[STATE_VEC_INDX_J2000_S] = 1.0;
+
   /* [ATTITUDE] */
-  // TODO
+  /* Quaternion kinematics dq/dt = 0.5 * q * [w, 0] */
+  let quat_pci_to_b = x_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]);
+  let ang_rate_b = x_in.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]);
+  dxdt_out.slice_mut(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)])
+    .assign(&calc_quat_derivative(quat_pci_to_b, ang_rate_b));
+
+  /* [ANGULAR RATE DERIVATIVE] */
+  /* Euler's equations dw/dt = I^-1 * (T - w x (I * w + h_w)) with the angular
+   * momentum of the reaction wheels h_w */
+  let mut sum_of_torques_b: Array1<f64> = get_sum_of_torque_vecs_b(x_in, environment);
+  let is_reaction_wheels_enabled: bool = *environment.get_spacecraft().get_reaction_wheel_assembly()
+                                                      .is_reaction_wheels_enabled();
+  if is_reaction_wheels_enabled
+  {
+    let wheel_ang_mom_b: Array1<f64> = environment.get_spacecraft().get_reaction_wheel_assembly()
+                                                  .calc_wheel_ang_mom_b(x_in.view());
+    sum_of_torques_b -= &cross_product_array1(ang_rate_b, wheel_ang_mom_b.view());
+  }
+  let ang_acc_b: Array1<f64> = calc_ang_acc_b(environment.get_spacecraft().get_sc_inertia_tensor_kgmm().view(), 
+                                              environment.get_spacecraft().get_sc_inertia_tensor_inv().view(), 
+                                              ang_rate_b, 
+                                              sum_of_torques_b.view());
+  dxdt_out.slice_mut(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]).assign(&ang_acc_b);
+
+  /* [REACTION WHEEL SPEED DERIVATIVE] */
+  if is_reaction_wheels_enabled
+  {
+    let reaction_wheels = environment.get_spacecraft().get_reaction_wheel_assembly();
+    dxdt_out.slice_mut(s![STATE_VEC_INDX_RW_SPEED_1..(STATE_VEC_INDX_RW_SPEED_1 + reaction_wheels.get_num_wheels())])
+      .assign(&reaction_wheels.calc_wheel_acc_radss(ang_acc_b.view()));
+  }
 
   dxdt_out
 }
@@ -76,5 +145,84 @@ pub fn get_sum_of_force_vecs_pci(x_n1: &Array1<f64>, environment: &mut Environme
     sum_of_forces_vec_pci_n += &aerodynamic::get_force_vec_pci(x_n1.view(), environment);
   }
 
+  /* [THIRD BODY FORCES] */
+  if environment.get_third_body().is_any_perturbation_modelled()
+  {
+    sum_of_forces_vec_pci_n += &third_body_model::get_force_vec_pci(x_n1, environment);
+  }
+
+  /* [SOLAR RADIATION PRESSURE] */
+  if *environment.get_srp().is_srp_modelled()
+  {
+    sum_of_forces_vec_pci_n += &srp_model::get_force_vec_pci(x_n1, environment);
+  }
+
+  /* [THRUST FORCE] */
+  if *environment.get_spacecraft().get_propulsion().is_propulsion_enabled()
+  {
+    sum_of_forces_vec_pci_n += &propulsion::get_force_vec_pci(x_n1, environment);
+  }
+
   sum_of_forces_vec_pci_n
 }
+
+/*
+ * @brief: Function to gather the sum of all external torques on the vehicle 
+ *         about its centre of mass
+ * 
+ * NOTE: Add all torques acting on the spacecraft here
+ * 
+ * @unit: Nm
+ * @frame: Body
+ */
+pub fn get_sum_of_torque_vecs_b(x_n1: &Array1<f64>, environment: &mut Environment) -> Array1<f64>
+{
+  let mut sum_of_torques_vec_b_nm: Array1<f64> = Array1::zeros(3);
+
+  /* [GRAVITY GRADIENT TORQUE] */
+  sum_of_torques_vec_b_nm += &gravity::get_gravity_gradient_torque_vec_b(x_n1, environment);
+
+  /* [AERODYNAMIC TORQUE] 
+   * Note: Requires the aerodynamic force of this state from 
+   *       get_sum_of_force_vecs_pci() */
+  if *environment.get_planet().get_atmosphere().is_atmoshpere_modelled()
+  {
+    sum_of_torques_vec_b_nm += &aerodynamic::get_torque_vec_b(x_n1.view(), environment);
+  }
+
+  /* [SOLAR RADIATION PRESSURE TORQUE] 
+   * Note: Requires the SRP force of this state from 
+   *       get_sum_of_force_vecs_pci() */
+  if *environment.get_srp().is_srp_modelled()
+  {
+    sum_of_torques_vec_b_nm += &srp_model::get_torque_vec_b(x_n1, environment);
+  }
+
+  /* [MAGNETORQUER TORQUE] 
+   * Note: The dipole is held between the samples of the B-dot controller */
+  if *environment.get_spacecraft().get_magnetorquers().is_magnetorquers_enabled()
+  {
+    sum_of_torques_vec_b_nm += &magnetorquers::get_torque_vec_b(x_n1, environment);
+  }
+
+  /* [CONTROL TORQUE] */
+  let mut cmd_torque_vec_b_nm: Array1<f64> = Array1::zeros(3);
+  if *environment.get_attitude_control().is_attitude_control_enabled()
+  {
+    cmd_torque_vec_b_nm = attitude_controller::get_torque_vec_b(x_n1, environment);
+  }
+
+  /* [REACTION WHEEL TORQUE] 
+   * Note: The commanded torque is applied as ideal control torque, if no 
+   *       reaction wheels are modelled */
+  if *environment.get_spacecraft().get_reaction_wheel_assembly().is_reaction_wheels_enabled()
+  {
+    sum_of_torques_vec_b_nm += &reaction_wheels::get_torque_vec_b(x_n1, cmd_torque_vec_b_nm.view(), environment);
+  }
+  else
+  {
+    sum_of_torques_vec_b_nm += &cmd_torque_vec_b_nm;
+  }
+
+  sum_of_torques_vec_b_nm
+}
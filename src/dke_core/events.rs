@@ -0,0 +1,448 @@
+/*
+ * @brief: Event detection on the integrated trajectory.
+ *
+ * @description: Each event is described by a continuous scalar function
+ *               g(x) of the state (event function) which changes its sign
+ *               when the event occurs. After each integration step the event
+ *               functions of the previous and the new state are compared. If
+ *               a sign change in the direction of the event is bracketed, the
+ *               exact event epoch is refined with Brent's method on states
+ *               interpolated between both steps (cubic Hermite interpolation
+ *               of position and velocity).
+ *
+ *               Built-in events:
+ *
+ *               | Event            | g(x)                              | Direction  |
+ *               |------------------|-----------------------------------|------------|
+ *               | Ground impact    | altitude                          | decreasing |
+ *               | Periapsis        | r . v                             | increasing |
+ *               | Apoapsis         | r . v                             | decreasing |
+ *               | Ascending node   | z (PCI)                           | increasing |
+ *               | Descending node  | z (PCI)                           | decreasing |
+ *               | Eclipse entry    | separation - (sun + body radius)  | decreasing |
+ *               | Eclipse exit     | separation - (sun + body radius)  | increasing |
+ *               | Detumbled        | |w| - detumble rate threshold     | decreasing |
+ *
+ *               The eclipse events use the apparent radii and separation of
+ *               the Sun and the central body as seen from the spacecraft and
+ *               mark the entry into and exit from the penumbra.
+ *
+ *               The detumbled event marks the angular rate dropping below the
+ *               threshold of the B-dot controller (magnetorquers.ini).
+ *
+ *               Ground impact is a terminal event: the simulation is stopped
+ *               at the exact impact epoch.
+ *
+ */
+
+/* Include external crates */
+use ndarray::{Array1, ArrayView1, s};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::epoch::Epoch;
+use crate::math::root_math::find_root_brent;
+use crate::math::vec_math::l2_norm_array1;
+
+/* Include constants */
+use crate::constants::celestial::SUN_RADIUS_M;
+use crate::constants::state::*;
+
+/* Absolute tolerance of the event epoch */
+const EVENT_TIME_TOLERANCE_S: f64 = 1.0e-6;
+/* Maximum number of iterations of the root finding */
+const EVENT_MAX_ITERATIONS: usize = 100;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum EventType {
+  GroundImpact,
+  Periapsis,
+  Apoapsis,
+  AscendingNode,
+  DescendingNode,
+  EclipseEntry,
+  EclipseExit,
+  Detumbled
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum EventDirection {
+  Increasing,
+  Decreasing
+}
+
+impl EventType {
+ /*
+  * @brief: Name of the event used for logging and the event file
+  */
+  pub fn get_name(&self) -> &str
+  {
+    match self
+    {
+      EventType::GroundImpact => "ground_impact",
+      EventType::Periapsis => "periapsis",
+      EventType::Apoapsis => "apoapsis",
+      EventType::AscendingNode => "ascending_node",
+      EventType::DescendingNode => "descending_node",
+      EventType::EclipseEntry => "eclipse_entry",
+      EventType::EclipseExit => "eclipse_exit",
+      EventType::Detumbled => "detumbled"
+    }
+  }
+
+ /*
+  * @brief: Direction of the sign change of the event function
+  */
+  pub fn get_direction(&self) -> EventDirection
+  {
+    match self
+    {
+      EventType::GroundImpact
+      | EventType::Apoapsis
+      | EventType::DescendingNode
+      | EventType::EclipseEntry
+      | EventType::Detumbled => EventDirection::Decreasing,
+      EventType::Periapsis
+      | EventType::AscendingNode
+      | EventType::EclipseExit => EventDirection::Increasing
+    }
+  }
+
+ /*
+  * @brief: True if the simulation shall be stopped at the event
+  */
+  pub fn is_terminal(&self) -> bool
+  {
+    *self == EventType::GroundImpact
+  }
+
+ /*
+  * @brief: Function to evaluate the event function g(x) for a state
+  */
+  pub fn eval(&self, state_in: &Array1<f64>, environment: &Environment) -> f64
+  {
+    let pos_pci_m: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+    let vel_pci_ms: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]);
+    let epoch: Epoch = Epoch::from_state_vec(state_in.view());
+
+    match self
+    {
+      EventType::GroundImpact => calc_geodetic_altitude(pos_pci_m,
+                                                        &epoch,
+                                                        environment),
+      EventType::Periapsis
+      | EventType::Apoapsis => pos_pci_m.dot(&vel_pci_ms),
+      EventType::AscendingNode
+      | EventType::DescendingNode => pos_pci_m[2],
+      EventType::EclipseEntry
+      | EventType::EclipseExit => calc_penumbra_function(pos_pci_m,
+                                                         &epoch,
+                                                         environment),
+      EventType::Detumbled => l2_norm_array1(state_in.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]))
+                              - environment.get_spacecraft().get_magnetorquers().get_detumble_rate_threshold_rads()
+    }
+  }
+}
+
+/*
+ * @brief: Event detected by the event detection
+ */
+#[derive(Clone)]
+
+pub struct DetectedEvent {
+  /*
+   * @description : Type of the detected event
+   * @unit        : N/A
+   * */
+  event_type: EventType,
+  /*
+   * @description : Simulation time of the event
+   * @unit        : seconds
+   * */
+  sim_time_s: f64,
+  /*
+   * @description : Full state vector (interpolated) at the event epoch
+   * @unit        : N/A
+   * */
+  state: Array1<f64>,
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl DetectedEvent {
+  pub fn get_event_type(&self) -> &EventType {&self.event_type}
+  pub fn get_sim_time_s(&self) -> &f64 {&self.sim_time_s}
+  pub fn get_state(&self) -> &Array1<f64> {&self.state}
+}
+
+#[derive(Clone)]
+
+pub struct EventDetection {
+  /*
+   * @description : Events enabled in sim.ini
+   * @unit        : N/A
+   * */
+  events: Vec<EventType>,
+  /*
+   * @description : Event function values at the previous step
+   * @unit        : N/A
+   * */
+  g_prev: Vec<f64>,
+  /*
+   * @description : Events detected during the simulation in chronological
+   *                order
+   * @unit        : N/A
+   * */
+  detected_events: Vec<DetectedEvent>,
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl EventDetection {
+  pub fn new() -> EventDetection {
+    EventDetection {
+      events: Vec::new(),
+      g_prev: Vec::new(),
+      detected_events: Vec::new(),
+    }
+  }
+}
+
+impl Default for EventDetection {
+  fn default() -> EventDetection {
+    EventDetection::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl EventDetection {
+  pub fn add_event(&mut self, event_in: &EventType)
+  {
+    if !self.events.contains(event_in)
+    {
+      self.events.push(*event_in);
+    }
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl EventDetection {
+  pub fn get_events(&self) -> &Vec<EventType> {&self.events}
+  pub fn get_detected_events(&self) -> &Vec<DetectedEvent> {&self.detected_events}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl EventDetection {
+ /*
+  * @brief: Function to initialise the event functions with the start state
+  */
+  pub fn init(&mut self, state_in: &Array1<f64>, environment: &Environment)
+  {
+    self.g_prev = self.events.iter()
+                             .map(|event| event.eval(state_in, environment))
+                             .collect();
+  }
+
+ /*
+  * @brief: Function to detect events between the previous and the new state.
+  *
+  * @description: All events within the step are located and stored in
+  *               chronological order. If a terminal event occurred, the new
+  *               state is replaced by the state at the (first) terminal event
+  *               and the step size is shortened accordingly.
+  *
+  * @param[in] x0_in       - State at the beginning of the step
+  * @param[in] x1_in       - State at the end of the step
+  * @param[in] t0_s        - Simulation time at the beginning of the step
+  * @param[in] dt_s        - Step size
+  * @param[in] environment - Environment
+  *
+  * @returns: (state at the end of the (shortened) step,
+  *            (shortened) step size,
+  *            true if a terminal event occurred)
+  */
+  pub fn detect(&mut self,
+                x0_in: &Array1<f64>,
+                x1_in: Array1<f64>,
+                t0_s: f64,
+                dt_s: f64,
+                environment: &Environment)
+  -> (Array1<f64>, f64, bool)
+  {
+    let mut step_events: Vec<DetectedEvent> = Vec::new();
+
+    for (event, g_prev) in self.events.iter().zip(self.g_prev.iter_mut())
+    {
+      let g_0: f64 = *g_prev;
+      let g_1: f64 = event.eval(&x1_in, environment);
+      *g_prev = g_1;
+
+      let is_sign_change: bool = match event.get_direction()
+      {
+        EventDirection::Increasing => g_0 < 0.0 && g_1 >= 0.0,
+        EventDirection::Decreasing => g_0 > 0.0 && g_1 <= 0.0
+      };
+      if !is_sign_change || dt_s <= 0.0
+      {
+        continue;
+      }
+
+      /* Refine the event epoch on the interpolated trajectory. The
+       * interpolation parameter tau runs from 0 (x0) to 1 (x1). */
+      let g_tau = |tau: f64| event.eval(&interpolate_state(x0_in, &x1_in, dt_s, tau), environment);
+      let tau: f64 = find_root_brent(&g_tau,
+                                     0.0,
+                                     1.0,
+                                     g_0,
+                                     g_1,
+                                     EVENT_TIME_TOLERANCE_S / dt_s,
+                                     EVENT_MAX_ITERATIONS);
+
+      step_events.push(DetectedEvent {
+        event_type: *event,
+        sim_time_s: t0_s + tau * dt_s,
+        state: interpolate_state(x0_in, &x1_in, dt_s, tau),
+      });
+    }
+
+    step_events.sort_by(|a, b| a.sim_time_s.total_cmp(&b.sim_time_s));
+
+    /* Truncate the step at the first terminal event */
+    let mut x_out: Array1<f64> = x1_in;
+    let mut dt_out_s: f64 = dt_s;
+    let mut is_terminal: bool = false;
+
+    for event in step_events
+    {
+      if is_terminal
+      {
+        break;
+      }
+      if event.event_type.is_terminal()
+      {
+        is_terminal = true;
+        x_out = event.state.clone();
+        dt_out_s = event.sim_time_s - t0_s;
+      }
+      self.detected_events.push(event);
+    }
+
+    (x_out, dt_out_s, is_terminal)
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to interpolate the state within a step.
+ *
+ * @description: Position is interpolated with a cubic Hermite polynomial
+ *               using the velocities at both ends as derivatives. The
+ *               velocity is the derivative of that polynomial. All other
+ *               state elements are interpolated linearly.
+ *
+ * @param[in] x0_in - State at the beginning of the step
+ * @param[in] x1_in - State at the end of the step
+ * @param[in] dt_s  - Step size
+ * @param[in] tau   - Interpolation parameter [0, 1]
+ */
+fn interpolate_state(x0_in: &Array1<f64>, x1_in: &Array1<f64>, dt_s: f64, tau: f64)
+-> Array1<f64>
+{
+  let mut x_out: Array1<f64> = x0_in + &((x1_in - x0_in) * tau);
+
+  /* Hermite basis functions and their derivatives w.r.t. tau */
+  let tau2: f64 = tau * tau;
+  let tau3: f64 = tau2 * tau;
+  let h00: f64 = 2.0 * tau3 - 3.0 * tau2 + 1.0;
+  let h10: f64 = tau3 - 2.0 * tau2 + tau;
+  let h01: f64 = -2.0 * tau3 + 3.0 * tau2;
+  let h11: f64 = tau3 - tau2;
+  let dh00: f64 = 6.0 * tau2 - 6.0 * tau;
+  let dh10: f64 = 3.0 * tau2 - 4.0 * tau + 1.0;
+  let dh01: f64 = -6.0 * tau2 + 6.0 * tau;
+  let dh11: f64 = 3.0 * tau2 - 2.0 * tau;
+
+  for axis in 0..3
+  {
+    let p0: f64 = x0_in[STATE_VEC_INDX_POS_X + axis];
+    let p1: f64 = x1_in[STATE_VEC_INDX_POS_X + axis];
+    let v0: f64 = x0_in[STATE_VEC_INDX_VEL_X + axis];
+    let v1: f64 = x1_in[STATE_VEC_INDX_VEL_X + axis];
+
+    x_out[STATE_VEC_INDX_POS_X + axis] = h00 * p0 + h10 * dt_s * v0 + h01 * p1 + h11 * dt_s * v1;
+    x_out[STATE_VEC_INDX_VEL_X + axis] = (dh00 * p0 + dh01 * p1) / dt_s + dh10 * v0 + dh11 * v1;
+  }
+  x_out
+}
+
+/*
+ * @brief: Function to compute the penumbra event function.
+ *
+ * @description: Apparent separation of the centres of the Sun and the central
+ *               body minus the sum of their apparent radii as seen from the
+ *               spacecraft. Positive in sunlight, negative in (partial)
+ *               shadow.
+ *
+ * @unit: rad
+ */
+fn calc_penumbra_function(pos_sc_pci_m: ArrayView1<f64>, epoch: &Epoch, environment: &Environment)
+-> f64
+{
+  let body_radius_m: f64 = *environment.get_planet().get_semi_major_axis();
+  let pos_sun_rel_m: Array1<f64> = environment.get_ephemeris().calc_sun_pos_pci_m(epoch) - pos_sc_pci_m;
+  let dist_body_m: f64 = l2_norm_array1(pos_sc_pci_m);
+  let dist_sun_m: f64 = l2_norm_array1(pos_sun_rel_m.view());
+
+  /* Apparent radius of the Sun */
+  let a: f64 = (SUN_RADIUS_M / dist_sun_m).asin();
+  /* Apparent radius of the central body */
+  let b: f64 = (body_radius_m / dist_body_m).min(1.0).asin();
+  /* Apparent separation of the centres of both bodies */
+  let c: f64 = (-pos_sc_pci_m.dot(&pos_sun_rel_m) / (dist_body_m * dist_sun_m))
+                .clamp(-1.0, 1.0)
+                .acos();
+  c - (a + b)
+}
+
+/*
+ * @brief: Function to compute the geodetic altitude above the reference 
+ *         ellipsoid of the central body.
+ *
+ * @unit: m
+ */
+fn calc_geodetic_altitude(pos_sc_pci_m: ArrayView1<f64>, epoch: &Epoch, environment: &Environment)
+-> f64
+{
+  let pos_sc_pcpf_m: Array1<f64> = environment.get_planet()
+                                               .get_earth_orientation()
+                                               .convert_pci_to_pcpf(pos_sc_pci_m, epoch);
+
+  environment.get_planet().calc_geodetic_pos(pos_sc_pcpf_m.view())[2]
+}
@@ -0,0 +1,455 @@
+/*
+ * @brief: Impulsive orbit maneuvers from a maneuver plan
+ *
+ * @description: The maneuver plan (csv file set in maneuvers.ini) lists the
+ *               epochs and delta-v vectors of impulsive maneuvers. The
+ *               delta-v of each maneuver is given in one of the frames
+ *
+ *               | Frame | Axis 1                 | Axis 2               | Axis 3             |
+ *               |-------|------------------------|----------------------|--------------------|
+ *               | PCI   | x                      | y                    | z                  |
+ *               | RTN   | radial                 | transverse (N x R)   | orbit normal       |
+ *               | LVLH  | x (= T)                | y (= -N)             | z (nadir, = -R)    |
+ *               | VNB   | velocity               | orbit normal         | binormal (V x N)   |
+ *
+ *               and rotated to PCI with the position and velocity right
+ *               before the maneuver.
+ *
+ *               The simulation loop truncates the integration step at each
+ *               maneuver epoch and applies the impulse exactly at the epoch:
+ *
+ *               v+ = v- + dv_pci
+ *
+ *               The spacecraft mass decreases according to the rocket
+ *               equation with the specific impulse set in maneuvers.ini:
+ *
+ *               m+ = m- * exp( - |dv| / (Isp * g0) )
+ *
+ *               If the propulsion subsystem is enabled (propulsion.ini), the
+ *               propellant mass decreases by the same amount and a maneuver
+ *               exceeding the remaining propellant is scaled down to the
+ *               achievable delta-v.
+ *
+ *               Ref: Vallado - Fundamentals of Astrodynamics and Applications,
+ *                    Chapter 6
+ */
+
+/* Include external crates */
+use std::fs;
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2, s};
+
+/* Include local crates */
+use crate::math::epoch::Epoch;
+use crate::math::vec_math::l2_norm_array1;
+use crate::math::frame_math::{calc_dcm_rtn_to_pci, calc_dcm_lvlh_to_pci, calc_dcm_vnb_to_pci};
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::general::STANDARD_GRAVITY_MSS;
+use crate::constants::spacecraft::SC_PROPELLANT_TOLERANCE_KG;
+use crate::constants::time::{DATETIME_FORMAT, SIM_TIME_TOLERANCE_S};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum ManeuverFrame {
+  Pci,
+  Rtn,
+  Lvlh,
+  Vnb
+}
+
+impl ManeuverFrame {
+ /*
+  * @brief: Function to select the frame of the delta-v from the maneuver plan
+  */
+  pub fn from_param_str(frame_in: &str) -> ManeuverFrame
+  {
+    match frame_in.trim().trim_matches('"').to_lowercase().as_str()
+    {
+      "pci"  => ManeuverFrame::Pci,
+      "rtn"  => ManeuverFrame::Rtn,
+      "lvlh" => ManeuverFrame::Lvlh,
+      "vnb"  => ManeuverFrame::Vnb,
+      _ => panic!("! [ERROR] ! > Unknown maneuver frame {} in the maneuver plan! <", frame_in)
+    }
+  }
+
+ /*
+  * @brief: Name of the frame used for logging and the maneuver file
+  */
+  pub fn get_name(&self) -> &str
+  {
+    match self
+    {
+      ManeuverFrame::Pci  => "PCI",
+      ManeuverFrame::Rtn  => "RTN",
+      ManeuverFrame::Lvlh => "LVLH",
+      ManeuverFrame::Vnb  => "VNB"
+    }
+  }
+
+ /*
+  * @brief: Function to compute the direction cosine matrix from the frame to
+  *         PCI for the given position and velocity in PCI
+  */
+  pub fn calc_dcm_to_pci(&self, state_in: &Array1<f64>) -> Array2<f64>
+  {
+    let pos_pci_m = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+    let vel_pci_ms = state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]);
+
+    match self
+    {
+      ManeuverFrame::Pci  => Array2::eye(3),
+      ManeuverFrame::Rtn  => calc_dcm_rtn_to_pci(pos_pci_m, vel_pci_ms),
+      ManeuverFrame::Lvlh => calc_dcm_lvlh_to_pci(pos_pci_m, vel_pci_ms),
+      ManeuverFrame::Vnb  => calc_dcm_vnb_to_pci(pos_pci_m, vel_pci_ms)
+    }
+  }
+}
+
+/*
+ * @brief: Planned impulsive maneuver
+ */
+#[derive(Clone)]
+
+pub struct Maneuver {
+  /*
+   * @description : Simulation time of the maneuver
+   * @unit        : seconds
+   * */
+  sim_time_s: f64,
+  /*
+   * @description : Frame of the delta-v vector
+   * @unit        : N/A
+   * */
+  frame: ManeuverFrame,
+  /*
+   * @description : Delta-v vector in the maneuver frame
+   * @unit        : m/s
+   * */
+  delta_v_ms: Array1<f64>,
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Maneuver {
+  pub fn new(sim_time_s: f64, frame: ManeuverFrame, delta_v_ms: &Array1<f64>) -> Maneuver {
+    Maneuver {
+      sim_time_s,
+      frame,
+      delta_v_ms: delta_v_ms.clone(),
+    }
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Maneuver {
+  pub fn get_sim_time_s(&self) -> &f64 {&self.sim_time_s}
+  pub fn get_frame(&self) -> &ManeuverFrame {&self.frame}
+  pub fn get_delta_v_ms(&self) -> &Array1<f64> {&self.delta_v_ms}
+}
+
+/*
+ * @brief: Impulsive maneuver applied during the simulation
+ */
+#[derive(Clone)]
+
+pub struct ExecutedManeuver {
+  /*
+   * @description : Planned maneuver
+   * @unit        : N/A
+   * */
+  maneuver: Maneuver,
+  /*
+   * @description : Applied delta-v vector (scaled down if the propellant was
+   *                insufficient)
+   * @unit        : m/s
+   * @frame       : PCI
+   * */
+  delta_v_pci_ms: Array1<f64>,
+  /*
+   * @description : Spacecraft mass before the maneuver
+   * @unit        : kg
+   * */
+  mass_before_kg: f64,
+  /*
+   * @description : Spacecraft mass after the maneuver
+   * @unit        : kg
+   * */
+  mass_after_kg: f64,
+  /*
+   * @description : Full state vector after the maneuver
+   * @unit        : N/A
+   * */
+  state: Array1<f64>,
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl ExecutedManeuver {
+  pub fn get_maneuver(&self) -> &Maneuver {&self.maneuver}
+  pub fn get_delta_v_pci_ms(&self) -> &Array1<f64> {&self.delta_v_pci_ms}
+  pub fn get_delta_v_magn_ms(&self) -> f64 {l2_norm_array1(self.delta_v_pci_ms.view())}
+  pub fn get_mass_before_kg(&self) -> &f64 {&self.mass_before_kg}
+  pub fn get_mass_after_kg(&self) -> &f64 {&self.mass_after_kg}
+  pub fn get_propellant_used_kg(&self) -> f64 {self.mass_before_kg - self.mass_after_kg}
+  pub fn get_state(&self) -> &Array1<f64> {&self.state}
+}
+
+#[derive(Clone)]
+
+pub struct ManeuverPlan {
+  /*
+   * @description : Flag, if true the maneuver plan is executed
+   * @unit        : N/A
+   * */
+  enable_maneuvers: bool,
+  /*
+   * @description : Specific impulse of the impulsive maneuvers
+   * @unit        : seconds
+   * */
+  isp_s: f64,
+  /*
+   * @description : Path of the maneuver plan file
+   * @unit        : N/A
+   * */
+  plan_file_path: String,
+  /*
+   * @description : Planned maneuvers in chronological order
+   * @unit        : N/A
+   * */
+  maneuvers: Vec<Maneuver>,
+  /*
+   * @description : Index of the next maneuver to apply
+   * @unit        : N/A
+   * */
+  next_maneuver_indx: usize,
+  /*
+   * @description : Maneuvers applied during the simulation in chronological
+   *                order
+   * @unit        : N/A
+   * */
+  executed_maneuvers: Vec<ExecutedManeuver>,
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl ManeuverPlan {
+  pub fn new() -> ManeuverPlan {
+    ManeuverPlan {
+      enable_maneuvers: false,
+      isp_s: 0.0,
+      plan_file_path: String::new(),
+      maneuvers: Vec::new(),
+      next_maneuver_indx: 0,
+      executed_maneuvers: Vec::new(),
+    }
+  }
+}
+
+impl Default for ManeuverPlan {
+  fn default() -> ManeuverPlan {
+    ManeuverPlan::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl ManeuverPlan {
+  pub fn set_enable_maneuvers(&mut self, val_in: &bool) {self.enable_maneuvers = *val_in;}
+  pub fn set_isp_s(&mut self, val_in: &f64) {self.isp_s = *val_in;}
+  pub fn set_plan_file_path(&mut self, val_in: &str) {self.plan_file_path = val_in.to_string();}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl ManeuverPlan {
+  pub fn is_maneuvers_enabled(&self) -> &bool {&self.enable_maneuvers}
+  pub fn get_isp_s(&self) -> &f64 {&self.isp_s}
+  pub fn get_plan_file_path(&self) -> &String {&self.plan_file_path}
+  pub fn get_maneuvers(&self) -> &Vec<Maneuver> {&self.maneuvers}
+  pub fn get_executed_maneuvers(&self) -> &Vec<ExecutedManeuver> {&self.executed_maneuvers}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl ManeuverPlan {
+ /*
+  * @brief: Function to load the maneuvers from the maneuver plan file
+  *
+  * @description: Each line of the plan contains
+  *
+  *               epoch, frame, dv_1_ms, dv_2_ms, dv_3_ms
+  *
+  *               The epoch is either the time in seconds relative to the
+  *               simulation start or a date in the format of the start date
+  *               in sim.ini. Lines starting with # and the header line are
+  *               ignored. Maneuvers before the simulation start are skipped.
+  *
+  * @param[in] start_epoch_in      - Epoch of the simulation start
+  * @param[in] sim_start_time_s_in - Simulation start time
+  */
+  pub fn load(&mut self, start_epoch_in: &Epoch, sim_start_time_s_in: f64)
+  {
+    let file_content: String = fs::read_to_string(&self.plan_file_path)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > Maneuver plan {} not found! <", self.plan_file_path));
+
+    for (line_num, line) in file_content.lines().enumerate()
+    {
+      let line: &str = line.trim();
+      if line.is_empty() || line.starts_with('#') || line.starts_with("epoch")
+      {
+        continue;
+      }
+
+      let fields: Vec<&str> = line.split(',').map(|field| field.trim().trim_matches('"')).collect();
+      if fields.len() != 5
+      {
+        panic!("! [ERROR] ! > Invalid maneuver in line {} of {}! <", line_num + 1, self.plan_file_path);
+      }
+
+      let sim_time_s: f64 = match fields[0].parse::<f64>()
+      {
+        Ok(time_s) => sim_start_time_s_in + time_s,
+        Err(_) =>
+        {
+          let date_time_utc: DateTime<Utc> = DateTime::parse_from_str(fields[0], DATETIME_FORMAT)
+            .unwrap_or_else(|_| panic!("! [ERROR] ! > Invalid maneuver epoch {} in line {} of {}! <",
+                                       fields[0], line_num + 1, self.plan_file_path))
+            .with_timezone(&Utc);
+          sim_start_time_s_in + Epoch::from_datetime(&date_time_utc).diff_s(start_epoch_in)
+        }
+      };
+
+      let delta_v_ms: Array1<f64> = fields[2..].iter()
+        .map(|field| field.parse::<f64>()
+          .unwrap_or_else(|_| panic!("! [ERROR] ! > Invalid delta-v {} in line {} of {}! <",
+                                     field, line_num + 1, self.plan_file_path)))
+        .collect();
+
+      if sim_time_s < sim_start_time_s_in - SIM_TIME_TOLERANCE_S
+      {
+        println!("[WRN] Maneuver in line {} of {} is before the simulation start and skipped",
+                 line_num + 1, self.plan_file_path);
+        continue;
+      }
+
+      self.maneuvers.push(Maneuver::new(sim_time_s, ManeuverFrame::from_param_str(fields[1]), &delta_v_ms));
+    }
+    self.maneuvers.sort_by(|a, b| a.sim_time_s.total_cmp(&b.sim_time_s));
+    self.next_maneuver_indx = 0;
+    self.executed_maneuvers.clear();
+
+    println!("[x] Loaded {} maneuvers from {}", self.maneuvers.len(), self.plan_file_path);
+  }
+
+ /*
+  * @brief: Function to get the simulation time of the next maneuver, the
+  *         integration steps are truncated at this time
+  *
+  * @returns: Simulation time of the next maneuver, None if all maneuvers are
+  *           applied
+  */
+  pub fn get_next_maneuver_time_s(&self) -> Option<f64>
+  {
+    self.maneuvers.get(self.next_maneuver_indx).map(|maneuver| maneuver.sim_time_s)
+  }
+
+ /*
+  * @brief: Function to apply all maneuvers due at the current simulation time
+  *
+  * @description: The delta-v is rotated to PCI with the state before the
+  *               maneuver and added to the velocity. The mass (and the
+  *               propellant mass if tracked) decreases according to the
+  *               rocket equation.
+  *
+  * @param[inout] state_inout           - Full state vector
+  * @param[in]    sim_time_s_in         - Current simulation time
+  * @param[in]    is_propellant_tracked - True if the propellant mass is part
+  *                                       of the state (propulsion enabled)
+  *
+  * @returns: true if at least one maneuver was applied
+  */
+  pub fn apply_due_maneuvers(&mut self,
+                             state_inout: &mut Array1<f64>,
+                             sim_time_s_in: f64,
+                             is_propellant_tracked: bool)
+  -> bool
+  {
+    let mut is_applied: bool = false;
+    let exhaust_velocity_ms: f64 = self.isp_s * STANDARD_GRAVITY_MSS;
+
+    while let Some(maneuver) = self.maneuvers.get(self.next_maneuver_indx)
+    {
+      if maneuver.sim_time_s > sim_time_s_in + SIM_TIME_TOLERANCE_S
+      {
+        break;
+      }
+
+      let mut delta_v_pci_ms: Array1<f64> = maneuver.frame.calc_dcm_to_pci(state_inout)
+                                                          .dot(&maneuver.delta_v_ms);
+      let delta_v_magn_ms: f64 = l2_norm_array1(delta_v_pci_ms.view());
+      let mass_before_kg: f64 = state_inout[STATE_VEC_INDX_MASS];
+      let mut mass_after_kg: f64 = mass_before_kg * (-delta_v_magn_ms / exhaust_velocity_ms).exp();
+
+      /* Scale the maneuver down to the delta-v achievable with the remaining
+       * propellant */
+      if is_propellant_tracked
+      {
+        let propellant_mass_kg: f64 = state_inout[STATE_VEC_INDX_PROPELLANT_MASS].max(0.0);
+        if mass_before_kg - mass_after_kg > propellant_mass_kg + SC_PROPELLANT_TOLERANCE_KG
+        {
+          let delta_v_achievable_ms: f64 = exhaust_velocity_ms
+                                           * (mass_before_kg / (mass_before_kg - propellant_mass_kg)).ln();
+          println!("[WRN] Insufficient propellant for the maneuver at SimTime [s] {:.3}, delta-v reduced from {:.3} to {:.3} m/s",
+                   maneuver.sim_time_s, delta_v_magn_ms, delta_v_achievable_ms);
+          delta_v_pci_ms.mapv_inplace(|dv: f64| dv * delta_v_achievable_ms / delta_v_magn_ms);
+          mass_after_kg = mass_before_kg - propellant_mass_kg;
+        }
+        state_inout[STATE_VEC_INDX_PROPELLANT_MASS] -= mass_before_kg - mass_after_kg;
+      }
+
+      let mut vel_pci_ms = state_inout.slice_mut(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]);
+      vel_pci_ms += &delta_v_pci_ms;
+      state_inout[STATE_VEC_INDX_MASS] = mass_after_kg;
+
+      self.executed_maneuvers.push(ExecutedManeuver {
+        maneuver: maneuver.clone(),
+        delta_v_pci_ms,
+        mass_before_kg,
+        mass_after_kg,
+        state: state_inout.clone(),
+      });
+      self.next_maneuver_indx += 1;
+      is_applied = true;
+    }
+
+    is_applied
+  }
+}
@@ -12,6 +12,7 @@
 use crate::math::vec3::Vec3;
 use crate::math::vec9::Vec9;
 use crate::math::quat::Quat;
+use crate::math::epoch::Epoch;
 
 /* Import externals crates */
 use ndarray::Array1;
@@ -72,11 +73,11 @@ pub struct State {
    * */
   mass_kg: f64,
   /* [state epoch ] 
-   * @description : State epoch in seconds since J2000 epoch
-   * @unit        : seconds
+   * @description : State epoch as whole days and seconds since J2000 epoch
+   * @unit        : days / seconds
    * 
    * */
-  state_since_epoch_j2000_s: f64,
+  state_epoch: Epoch,
   /* [state gast ] 
    * @description : Greenwich aparent sidrereal time
    * @unit        : degree
@@ -123,7 +124,7 @@ impl State {
       angular_acc_xyz_radss: Vec3::new(),
       inertia_matrix_kgmm: Vec9::new(),
       mass_kg: 1.0,
-      state_since_epoch_j2000_s: 0.0,
+      state_epoch: Epoch::new(0, 0.0),
       state_gast_deg: 0.0,
       vel_magn_pci_ms: 0.0,
       aero_drag_coeff: 0.0,
@@ -196,8 +197,8 @@ impl State {
 }
 
 impl State {
-  pub fn get_state_epoch(&mut self) -> f64 {
-    self.state_since_epoch_j2000_s
+  pub fn get_state_epoch(&mut self) -> Epoch {
+    self.state_epoch
   }
 }
 
@@ -243,11 +244,14 @@ impl State {
     /* [Vehicle mass] */
     vec_out[STATE_VEC_INDX_MASS] = self.mass_kg;
 
-    /* [State absolute time as] */
-    vec_out[STATE_VEC_INDX_J2000_S] = self.state_since_epoch_j2000_s + self.time_s;
+    /* [State absolute time as two-part epoch and seconds since J2000] */
+    let epoch: Epoch = self.state_epoch.add_seconds(self.time_s);
+    vec_out[STATE_VEC_INDX_EPOCH_DAYS] = *epoch.get_days() as f64;
+    vec_out[STATE_VEC_INDX_EPOCH_SECONDS] = *epoch.get_seconds();
+    vec_out[STATE_VEC_INDX_J2000_S] = epoch.to_j2000_s();
 
     /* [State greenwich aparent sidereal time] */
-    vec_out[STATE_VEC_INDX_GAST_DEG] = self.state_since_epoch_j2000_s + self.time_s;
+    vec_out[STATE_VEC_INDX_GAST_DEG] = self.state_gast_deg;
 
     /* [State velocity mangitude in inertial frame] */
     vec_out[STATE_VEC_INDX_VEL_MAGN_PCI_MS] = self.vel_magn_pci_ms;
@@ -303,6 +307,26 @@ impl State {
   {self.velocity_xyz_ms.set_z(new_vel_z);}
 }
 
+impl State {
+  pub fn set_att(&mut self, new_x: &f64, new_y: &f64, new_z: &f64, new_w: &f64) 
+  {self.attitude_quat.set(new_x, new_y, new_z, new_w);}
+}
+
+impl State {
+  pub fn set_ang_rate_x(&mut self, new_ang_rate_x: &f64) 
+  {self.angular_rate_xyz_rads.set_x(new_ang_rate_x);}
+}
+
+impl State {
+  pub fn set_ang_rate_y(&mut self, new_ang_rate_y: &f64) 
+  {self.angular_rate_xyz_rads.set_y(new_ang_rate_y);}
+}
+
+impl State {
+  pub fn set_ang_rate_z(&mut self, new_ang_rate_z: &f64) 
+  {self.angular_rate_xyz_rads.set_z(new_ang_rate_z);}
+}
+
 impl State {
   pub fn set_mass_kg(&mut self, new_mass_kg_in: &f64) 
   {self.mass_kg = *new_mass_kg_in;}
@@ -316,10 +340,9 @@ impl State {
         .unwrap()
         .with_timezone(&Utc);
 
-    /* Set difference as seconds since epoch */
-    self.state_since_epoch_j2000_s = (date_time_utc.timestamp() 
-                                      - UNIX_SECONDS_AT_J2000_EPOCH) as f64;
-    println!("[x] Initialise J2000 time [s]: {:?}",  self.state_since_epoch_j2000_s)
+    /* Set epoch as whole days and seconds since J2000 (UTC, sub-second precision) */
+    self.state_epoch = Epoch::from_datetime(&date_time_utc);
+    println!("[x] Initialise J2000 time [s]: {:?}",  self.state_epoch.to_j2000_s())
   }
 }
 
@@ -347,11 +370,13 @@ impl State
     self.acceleration_xyz_mss.set_y(&state_vec_in[STATE_VEC_INDX_ACC_Y]) ;
     self.acceleration_xyz_mss.set_z(&state_vec_in[STATE_VEC_INDX_ACC_Z]) ;
 
-    /* [Attitude Quaternion] */
-    self.attitude_quat.set_x(&state_vec_in[STATE_VEC_INDX_ATTQ_X]) ;
-    self.attitude_quat.set_y(&state_vec_in[STATE_VEC_INDX_ATTQ_Y]) ;
-    self.attitude_quat.set_z(&state_vec_in[STATE_VEC_INDX_ATTQ_Z]) ;
-    self.attitude_quat.set_w(&state_vec_in[STATE_VEC_INDX_ATTQ_W]) ;
+    /* [Attitude Quaternion] 
+     * Note: All components are set at once, the quaternion is normalized on
+     *       each set */
+    self.attitude_quat.set(&state_vec_in[STATE_VEC_INDX_ATTQ_X],
+                           &state_vec_in[STATE_VEC_INDX_ATTQ_Y],
+                           &state_vec_in[STATE_VEC_INDX_ATTQ_Z],
+                           &state_vec_in[STATE_VEC_INDX_ATTQ_W]) ;
 
     /* [Angular Rate] */
     self.angular_rate_xyz_rads.set_x(&state_vec_in[STATE_VEC_INDX_ATTRATE_X]) ;
@@ -365,7 +390,7 @@ impl State
 
     self.mass_kg = state_vec_in[STATE_VEC_INDX_MASS] ;
 
-    self.state_since_epoch_j2000_s = state_vec_in[STATE_VEC_INDX_J2000_S] ;
+    self.state_epoch = Epoch::from_state_vec(state_vec_in.view()).add_seconds(-self.time_s) ;
 
     self.state_gast_deg = state_vec_in[STATE_VEC_INDX_GAST_DEG] ;
 
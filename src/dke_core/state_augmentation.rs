@@ -2,23 +2,24 @@
 use std::env;
 
 /* Include external crates */
-use ndarray::{Array1, ArrayView1, s};
-use chrono::*;
+use ndarray::{Array1, Array2, ArrayView1, s};
 
 /* Import (local) structs */
-/* None */
+use crate::environment::spacecraft::reaction_wheels::ReactionWheelAssembly;
+use crate::environment::spacecraft::magnetorquers::Magnetorquers;
 
 /* Include local crates */
 use crate::environment::environment::Environment;
 use crate::environment::gravity::gravity::get_grav_acc;
-use crate::math::frame_math::{convert_eci_to_ecef,
-                              convert_ecef_to_llr};
-use crate::math::time_math::calc_earth_gast_deg;
-use crate::math::vec_math::l2_norm_array1;
+use crate::environment::aerodynamic::aerodynamic::get_air_rel_vel_vec_pci;
+use crate::math::vec_math::{l2_norm_array1, cross_product_array1, normalize_array1};
+use crate::math::orbit_math::convert_cartesian_to_kepler;
+use crate::math::epoch::Epoch;
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
 
 /* Import constants */
 use crate::constants::state::*;
-use crate::constants::time::*;
+use crate::constants::orbit::*;
 
 /*
  * @brief: This function is to fill fields of the full state vector that are not 
@@ -39,6 +40,7 @@ use crate::constants::time::*;
  * 
  *           Post solving computations include: 
  *           * Acceleration
+ *           * Angular acceleration
  * 
  * @param[in] x1_in - Full state vector for current solving step n
  * 
@@ -55,9 +57,6 @@ pub fn augment_state_solve(environment: &Environment, x1_inout: &Array1<f64>, x0
 
   /* Assign simulation time to current state */
   state_vec_out[STATE_VEC_INDX_SIM_TIME] = environment.get_sim_time_s();
-  /* Update state epoch */
-  state_vec_out[STATE_VEC_INDX_J2000_S] += environment.get_dt_s();
-
   /* Compute linear acceleration from incremental velocity change */
   state_vec_out[STATE_VEC_INDX_ACC_X] = (state_vec_out[STATE_VEC_INDX_VEL_X] 
     - x0_in[STATE_VEC_INDX_VEL_X]) / environment.get_dt_s();
@@ -65,15 +64,23 @@ pub fn augment_state_solve(environment: &Environment, x1_inout: &Array1<f64>, x0
     - x0_in[STATE_VEC_INDX_VEL_Y]) / environment.get_dt_s();
   state_vec_out[STATE_VEC_INDX_ACC_Z] = (state_vec_out[STATE_VEC_INDX_VEL_Z] 
     - x0_in[STATE_VEC_INDX_VEL_Z]) / environment.get_dt_s();
+  /* Compute angular acceleration from incremental angular rate change */
+  state_vec_out[STATE_VEC_INDX_ATTACC_X] = (state_vec_out[STATE_VEC_INDX_ATTRATE_X] 
+    - x0_in[STATE_VEC_INDX_ATTRATE_X]) / environment.get_dt_s();
+  state_vec_out[STATE_VEC_INDX_ATTACC_Y] = (state_vec_out[STATE_VEC_INDX_ATTRATE_Y] 
+    - x0_in[STATE_VEC_INDX_ATTRATE_Y]) / environment.get_dt_s();
+  state_vec_out[STATE_VEC_INDX_ATTACC_Z] = (state_vec_out[STATE_VEC_INDX_ATTRATE_Z] 
+    - x0_in[STATE_VEC_INDX_ATTRATE_Z]) / environment.get_dt_s();
 
-  /* The following computes a first approximation of the S/C altitude above ground
-     This will be overwritten for the result output by the augment_state_write() function*/
-  let mut pos_eci_m: Array1<f64> = Array1::zeros(3);
-  pos_eci_m.assign(&state_vec_out.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]));
+  /* The following computes the geodetic S/C altitude above the reference 
+     ellipsoid at solving frequency, e.g. for the exit conditions */
+  let pos_ecef_m: Array1<f64> = environment.get_planet()
+    .get_earth_orientation()
+    .convert_pci_to_pcpf(state_vec_out.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]),
+                         &Epoch::from_state_vec(state_vec_out.view()));
 
-  state_vec_out[STATE_VEC_INDX_ALTITUDE_PCPF_M] = l2_norm_array1(pos_eci_m.view())
-    - (environment.get_planet().get_semi_major_axis()
-       + environment.get_planet().get_semi_minor_axis()) * 0.5;
+  state_vec_out[STATE_VEC_INDX_ALTITUDE_PCPF_M] = environment.get_planet()
+    .calc_geodetic_pos(pos_ecef_m.view())[2];
 
   state_vec_out
 }
@@ -117,37 +124,43 @@ pub fn augment_state_write(environment: &Environment, x1_inout: &Array1<f64>, x0
   let mut pos_eci_m: Array1<f64> = Array1::zeros(3);
   pos_eci_m.assign(&state_vec_out.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]));
 
-  /* Get position in PCPF frame from eci position and current time */
-  let unix_time_ms: i64 = ((x1_inout[STATE_VEC_INDX_J2000_S] 
-    + (UNIX_SECONDS_AT_J2000_EPOCH as f64)) * 1000.0) as i64;
+  /* Get velocity in PCI frame from state vector */
+  let mut vel_eci_ms: Array1<f64> = Array1::zeros(3);
+  vel_eci_ms.assign(&state_vec_out.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]));
 
-  /* Create DateTime from unix timestamp */
-  let native_datetime: NaiveDateTime = NaiveDateTime::from_timestamp_millis(unix_time_ms).unwrap();
-  let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(native_datetime, Utc);
-  let gast_deg: f64 = calc_earth_gast_deg(datetime);
-  
   /* Update Greenwich aparent sidreal time in degree */
-  state_vec_out[STATE_VEC_INDX_GAST_DEG] = gast_deg;
+  let epoch: Epoch = Epoch::from_state_vec(x1_inout.view());
+  state_vec_out[STATE_VEC_INDX_GAST_DEG] = environment.get_planet().get_earth_orientation().calc_gast_deg(&epoch);
+
+  /* Get position and velocity in PCPF frame from eci state and current time */
+  let (pos_ecef_m, vel_ecef_ms): (Array1<f64>, Array1<f64>) = environment.get_planet()
+    .get_earth_orientation()
+    .convert_pci_to_pcpf_state(pos_eci_m.view(), 
+                               vel_eci_ms.view(), 
+                               &epoch, 
+                               *environment.get_planet().get_omega());
+  state_vec_out.slice_mut(s![STATE_VEC_INDX_POS_PCPF_X..(STATE_VEC_INDX_POS_PCPF_Z+1)]).assign(&pos_ecef_m);
+  state_vec_out.slice_mut(s![STATE_VEC_INDX_VEL_PCPF_X..(STATE_VEC_INDX_VEL_PCPF_Z+1)]).assign(&vel_ecef_ms);
 
-  let pos_ecef_m: Array1<f64> = convert_eci_to_ecef(&pos_eci_m, gast_deg);
-  let pos_ecef_llr: Array1<f64> = convert_ecef_to_llr(pos_ecef_m.view());
+  let pos_ecef_lla: Array1<f64> = environment.get_planet().calc_geodetic_pos(pos_ecef_m.view());
 
-  /* Update Latitude / Longitude in ECEF  */
-  state_vec_out[STATE_VEC_INDX_POS_PCPF_LAT_DEG] = (pos_ecef_llr[0]).to_degrees();
-  state_vec_out[STATE_VEC_INDX_POS_PCPF_LONG_DEG] = (pos_ecef_llr[1]).to_degrees();
+  /* Update geodetic Latitude / Longitude in ECEF  */
+  state_vec_out[STATE_VEC_INDX_POS_PCPF_LAT_DEG] = (pos_ecef_lla[0]).to_degrees();
+  state_vec_out[STATE_VEC_INDX_POS_PCPF_LONG_DEG] = (pos_ecef_lla[1]).to_degrees();
 
-  state_vec_out[STATE_VEC_INDX_ALTITUDE_PCPF_M] = pos_ecef_llr[2]
-    - (environment.get_planet().get_semi_major_axis()
-       + environment.get_planet().get_semi_minor_axis()) * 0.5;
+  /* Update geodetic altitude above the reference ellipsoid */
+  state_vec_out[STATE_VEC_INDX_ALTITUDE_PCPF_M] = pos_ecef_lla[2];
 
   /* Get local magnitude of the gravitational acceleration */
   state_vec_out[STATE_VEC_INDX_GRAV_ACC_MSS] = get_grav_acc(&x1_inout, &environment);
 
   /* Compute the magnitude of the velocity vector in PCI frame */
-  let mut vel_eci_ms: Array1<f64> = Array1::zeros(3);
-  vel_eci_ms.assign(&state_vec_out.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]));
   state_vec_out[STATE_VEC_INDX_VEL_MAGN_PCI_MS] = l2_norm_array1(vel_eci_ms.view()); 
 
+  /* Compute the speed relative to the co-rotating atmosphere */
+  state_vec_out[STATE_VEC_INDX_AIRSPEED_MS] = l2_norm_array1(get_air_rel_vel_vec_pci(state_vec_out.view(), 
+                                                                                     environment).view());
+
   /* Update atmospheric density from Spacecraft struct */
   state_vec_out[STATE_VEC_INDX_ATMOS_DENSITY] = *environment.get_planet().get_atmosphere().get_density_kgmmm();
 
@@ -162,17 +175,183 @@ pub fn augment_state_write(environment: &Environment, x1_inout: &Array1<f64>, x0
  
   let aero_force_magn_n: f64 = l2_norm_array1(aero_force_vec.view());
 
-  /* Compute drag coefficient from drag froce and effective surface area */
+  /* Compute drag coefficient from drag froce, airspeed and effective surface area */
   state_vec_out[STATE_VEC_INDX_DRAG_COEFF] = 2.0 * aero_force_magn_n 
         / (state_vec_out[STATE_VEC_INDX_ATMOS_DENSITY] 
-          * state_vec_out[STATE_VEC_INDX_VEL_MAGN_PCI_MS] * state_vec_out[STATE_VEC_INDX_VEL_MAGN_PCI_MS] 
+          * state_vec_out[STATE_VEC_INDX_AIRSPEED_MS] * state_vec_out[STATE_VEC_INDX_AIRSPEED_MS] 
           * *environment.get_spacecraft().get_sc_aero_eff_area_mm());
 
-  state_vec_out[STATE_VEC_INDX_BALLISTIC_COEFF] =  environment.get_spacecraft().get_sc_mass_kg() 
+  state_vec_out[STATE_VEC_INDX_BALLISTIC_COEFF] =  state_vec_out[STATE_VEC_INDX_MASS] 
     / (state_vec_out[STATE_VEC_INDX_DRAG_COEFF] * environment.get_spacecraft().get_sc_aero_eff_area_mm())  ;
 
   state_vec_out[STATE_VEC_INDX_MACH_NUMBER] = *environment.get_spacecraft().get_sc_mach_number();
   state_vec_out[STATE_VEC_INDX_KNUDSEN_NUMBER] = *environment.get_planet().get_atmosphere().get_knudsen_number();
 
+  /* Update third body forces on the spacecraft from the spacecraft struct */
+  state_vec_out[STATE_VEC_INDX_SUN_FORCE_X] = *environment.get_spacecraft().get_sun_force_pci_n_x();
+  state_vec_out[STATE_VEC_INDX_SUN_FORCE_Y] = *environment.get_spacecraft().get_sun_force_pci_n_y();
+  state_vec_out[STATE_VEC_INDX_SUN_FORCE_Z] = *environment.get_spacecraft().get_sun_force_pci_n_z();
+  state_vec_out[STATE_VEC_INDX_MOON_FORCE_X] = *environment.get_spacecraft().get_moon_force_pci_n_x();
+  state_vec_out[STATE_VEC_INDX_MOON_FORCE_Y] = *environment.get_spacecraft().get_moon_force_pci_n_y();
+  state_vec_out[STATE_VEC_INDX_MOON_FORCE_Z] = *environment.get_spacecraft().get_moon_force_pci_n_z();
+
+  /* Update solar radiation pressure force and illumination from the SRP and spacecraft struct */
+  state_vec_out[STATE_VEC_INDX_ILLUMINATION_FRACTION] = *environment.get_srp().get_illumination_fraction();
+  state_vec_out[STATE_VEC_INDX_SRP_FORCE_X] = *environment.get_spacecraft().get_srp_force_pci_n_x();
+  state_vec_out[STATE_VEC_INDX_SRP_FORCE_Y] = *environment.get_spacecraft().get_srp_force_pci_n_y();
+  state_vec_out[STATE_VEC_INDX_SRP_FORCE_Z] = *environment.get_spacecraft().get_srp_force_pci_n_z();
+
+  /* Update disturbance torques on the spacecraft from the spacecraft struct */
+  state_vec_out[STATE_VEC_INDX_GRAV_GRAD_TORQUE_X] = *environment.get_spacecraft().get_grav_grad_torque_b_nm_x();
+  state_vec_out[STATE_VEC_INDX_GRAV_GRAD_TORQUE_Y] = *environment.get_spacecraft().get_grav_grad_torque_b_nm_y();
+  state_vec_out[STATE_VEC_INDX_GRAV_GRAD_TORQUE_Z] = *environment.get_spacecraft().get_grav_grad_torque_b_nm_z();
+  state_vec_out[STATE_VEC_INDX_AERO_TORQUE_X] = *environment.get_spacecraft().get_aero_torque_b_nm_x();
+  state_vec_out[STATE_VEC_INDX_AERO_TORQUE_Y] = *environment.get_spacecraft().get_aero_torque_b_nm_y();
+  state_vec_out[STATE_VEC_INDX_AERO_TORQUE_Z] = *environment.get_spacecraft().get_aero_torque_b_nm_z();
+  state_vec_out[STATE_VEC_INDX_SRP_TORQUE_X] = *environment.get_spacecraft().get_srp_torque_b_nm_x();
+  state_vec_out[STATE_VEC_INDX_SRP_TORQUE_Y] = *environment.get_spacecraft().get_srp_torque_b_nm_y();
+  state_vec_out[STATE_VEC_INDX_SRP_TORQUE_Z] = *environment.get_spacecraft().get_srp_torque_b_nm_z();
+
+  /* Update attitude error and commanded torque from the attitude control struct */
+  state_vec_out[STATE_VEC_INDX_ATT_ERROR_ANGLE_DEG] = *environment.get_attitude_control().get_att_error_angle_deg();
+  state_vec_out.slice_mut(s![STATE_VEC_INDX_CMD_TORQUE_X..(STATE_VEC_INDX_CMD_TORQUE_Z+1)])
+    .assign(environment.get_attitude_control().get_cmd_torque_b_nm());
+
+  /* Update reaction wheel torques, wheel angular momentum, dumping torque and 
+   * total angular momentum of the spacecraft including the wheels */
+  let reaction_wheels: &ReactionWheelAssembly = environment.get_spacecraft().get_reaction_wheel_assembly();
+  if *reaction_wheels.is_reaction_wheels_enabled()
+  {
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_RW_TORQUE_1..(STATE_VEC_INDX_RW_TORQUE_1 + reaction_wheels.get_num_wheels())])
+      .assign(reaction_wheels.get_wheel_torque_nm());
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_DUMP_TORQUE_X..(STATE_VEC_INDX_DUMP_TORQUE_Z+1)])
+      .assign(reaction_wheels.get_dumping_torque_b_nm());
+
+    let wheel_ang_mom_b_nms: Array1<f64> = reaction_wheels.calc_wheel_ang_mom_b(x1_inout.view());
+    let body_ang_mom_b_nms: Array1<f64> = environment.get_spacecraft().get_sc_inertia_tensor_kgmm()
+      .dot(&x1_inout.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]));
+    let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(
+      x1_inout.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_RW_ANG_MOM_X..(STATE_VEC_INDX_RW_ANG_MOM_Z+1)])
+      .assign(&wheel_ang_mom_b_nms);
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_SYS_ANG_MOM_X..(STATE_VEC_INDX_SYS_ANG_MOM_Z+1)])
+      .assign(&dcm_pci_to_b.t().dot(&(&body_ang_mom_b_nms + &wheel_ang_mom_b_nms)));
+  }
+
+  /* Update magnetic field vector */
+  if *environment.get_magnetic_field().is_magnetic_field_output_enabled()
+  {
+    let (field_pcpf_t, field_ned_t, field_b_t): (Array1<f64>, Array1<f64>, Array1<f64>) = environment
+      .get_magnetic_field().calc_field_vecs(x1_inout.view(), environment.get_planet());
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_MAG_FIELD_PCPF_X..(STATE_VEC_INDX_MAG_FIELD_PCPF_Z+1)])
+      .assign(&field_pcpf_t);
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_MAG_FIELD_NED_N..(STATE_VEC_INDX_MAG_FIELD_NED_D+1)])
+      .assign(&field_ned_t);
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_MAG_FIELD_B_X..(STATE_VEC_INDX_MAG_FIELD_B_Z+1)])
+      .assign(&field_b_t);
+  }
+
+  /* Update magnetorquer dipole and torque, last magnetometer measurement and
+   * norm of the angular rate */
+  let magnetorquers: &Magnetorquers = environment.get_spacecraft().get_magnetorquers();
+  if *magnetorquers.is_magnetorquers_enabled()
+  {
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_MTQ_DIPOLE_X..(STATE_VEC_INDX_MTQ_DIPOLE_Z+1)])
+      .assign(magnetorquers.get_dipole_b_amm());
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_MTQ_TORQUE_X..(STATE_VEC_INDX_MTQ_TORQUE_Z+1)])
+      .assign(magnetorquers.get_torque_b_nm());
+    if let Some(measurement_b_t) = magnetorquers.get_last_measurement_b_t()
+    {
+      state_vec_out.slice_mut(s![STATE_VEC_INDX_MAGNETOMETER_B_X..(STATE_VEC_INDX_MAGNETOMETER_B_Z+1)])
+        .assign(measurement_b_t);
+    }
+    state_vec_out[STATE_VEC_INDX_ANG_RATE_MAGN_DEGS] = l2_norm_array1(
+      x1_inout.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)])).to_degrees();
+  }
+
+  /* Update thrust force and propellant mass flow */
+  if *environment.get_spacecraft().get_propulsion().is_propulsion_enabled()
+  {
+    state_vec_out.slice_mut(s![STATE_VEC_INDX_THRUST_FORCE_X..(STATE_VEC_INDX_THRUST_FORCE_Z+1)])
+      .assign(environment.get_spacecraft().get_propulsion().get_thrust_force_pci_n());
+    state_vec_out[STATE_VEC_INDX_MASS_FLOW_KGPS] = *environment.get_spacecraft().get_propulsion().get_mass_flow_kgps();
+  }
+
+  /* Update osculating orbital elements and two-body diagnostics */
+  augment_state_orbit(environment, &mut state_vec_out, pos_eci_m.view(), vel_eci_ms.view());
+
+  /* Update directions from the spacecraft to the Sun and the Moon */
+  let sun_dir_pci: Array1<f64> = normalize_array1(environment.get_ephemeris().calc_sun_pos_pci_m(&epoch) - &pos_eci_m);
+  let moon_dir_pci: Array1<f64> = normalize_array1(environment.get_ephemeris().calc_moon_pos_pci_m(&epoch) - &pos_eci_m);
+  state_vec_out.slice_mut(s![STATE_VEC_INDX_SUN_DIR_X..(STATE_VEC_INDX_SUN_DIR_Z+1)]).assign(&sun_dir_pci);
+  state_vec_out.slice_mut(s![STATE_VEC_INDX_MOON_DIR_X..(STATE_VEC_INDX_MOON_DIR_Z+1)]).assign(&moon_dir_pci);
+
   state_vec_out
+}
+
+/*
+ * @brief: Function to fill the osculating orbital elements, the specific 
+ *         orbital energy, the specific angular momentum vector, the periapsis
+ *         and apoapsis altitudes and the orbital period of the state vector.
+ * 
+ * @details: All quantities are computed from the two-body problem with the 
+ *           gravitational constant of the central body. Energy and angular 
+ *           momentum are therefore only conserved for a pure point mass 
+ *           gravity field; their drift is a measure of the integration error
+ *           in that case. Periapsis and apoapsis altitudes are given above the
+ *           equatorial radius. Apoapsis altitude and period are NaN for 
+ *           hyperbolic orbits.
+ * 
+ * @param[in] pos_pci_m_in  - Position vector [m]
+ * @param[in] vel_pci_ms_in - Velocity vector [m/s]
+ * 
+ */
+fn augment_state_orbit(environment: &Environment,
+                       state_vec_inout: &mut Array1<f64>,
+                       pos_pci_m_in: ArrayView1<f64>,
+                       vel_pci_ms_in: ArrayView1<f64>)
+{
+  let gravitational_constant: f64 = *environment.get_planet().get_gravitational_constant();
+  let equatorial_radius_m: f64 = *environment.get_planet().get_semi_major_axis();
+
+  let elements: Array1<f64> = convert_cartesian_to_kepler(pos_pci_m_in, 
+                                                          vel_pci_ms_in, 
+                                                          gravitational_constant);
+  let sma_m: f64 = elements[KEPLER_INDX_SMA];
+  let ecc: f64 = elements[KEPLER_INDX_ECC];
+
+  state_vec_inout[STATE_VEC_INDX_ORBIT_SMA_M] = sma_m;
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ECC] = ecc;
+  state_vec_inout[STATE_VEC_INDX_ORBIT_INC_DEG] = elements[KEPLER_INDX_INC].to_degrees();
+  state_vec_inout[STATE_VEC_INDX_ORBIT_RAAN_DEG] = elements[KEPLER_INDX_RAAN].to_degrees();
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ARGP_DEG] = elements[KEPLER_INDX_ARGP].to_degrees();
+  state_vec_inout[STATE_VEC_INDX_ORBIT_TRUE_ANOM_DEG] = elements[KEPLER_INDX_TRUE_ANOMALY].to_degrees();
+
+  /* Specific orbital energy */
+  let speed_ms: f64 = l2_norm_array1(vel_pci_ms_in);
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ENERGY_JKG] = 0.5 * speed_ms * speed_ms 
+    - gravitational_constant / l2_norm_array1(pos_pci_m_in);
+
+  /* Specific angular momentum */
+  let ang_mom_vec: Array1<f64> = cross_product_array1(pos_pci_m_in, vel_pci_ms_in);
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ANG_MOM_X] = ang_mom_vec[0];
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ANG_MOM_Y] = ang_mom_vec[1];
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ANG_MOM_Z] = ang_mom_vec[2];
+  state_vec_inout[STATE_VEC_INDX_ORBIT_ANG_MOM_MAGN] = l2_norm_array1(ang_mom_vec.view());
+
+  /* Periapsis radius from the semi-latus rectum (also valid for e >= 1) */
+  let p_m: f64 = ang_mom_vec.dot(&ang_mom_vec) / gravitational_constant;
+  state_vec_inout[STATE_VEC_INDX_ORBIT_PERI_ALT_M] = p_m / (1.0 + ecc) - equatorial_radius_m;
+
+  if ecc < 1.0
+  {
+    state_vec_inout[STATE_VEC_INDX_ORBIT_APO_ALT_M] = sma_m * (1.0 + ecc) - equatorial_radius_m;
+    state_vec_inout[STATE_VEC_INDX_ORBIT_PERIOD_S] = 2.0 * std::f64::consts::PI 
+      * (sma_m * sma_m * sma_m / gravitational_constant).sqrt();
+  }
+  else
+  {
+    state_vec_inout[STATE_VEC_INDX_ORBIT_APO_ALT_M] = f64::NAN;
+    state_vec_inout[STATE_VEC_INDX_ORBIT_PERIOD_S] = f64::NAN;
+  }
 }
\ No newline at end of file
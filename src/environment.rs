@@ -6,6 +6,16 @@ pub mod planet;
 
 pub mod aerodynamic;
 
+pub mod third_body;
+
+pub mod srp;
+
 pub mod spacecraft;
 
-pub mod environment;
\ No newline at end of file
+pub mod environment;
+
+pub mod ephemeris;
+
+pub mod attitude_control;
+
+pub mod magnetic_field;
@@ -1,9 +1,12 @@
-use ndarray::{Array1, ArrayView1, s};
+use ndarray::{Array1, Array2, ArrayView1, s};
 
 /* Include local crates */
 use crate::environment::environment::Environment;
+use crate::math::epoch::Epoch;
 use crate::math::vec_math::{l2_norm_array1,
-                            normalize_array1};
+                            normalize_array1,
+                            cross_product_array1};
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
 
 /* Import constants */
 use crate::constants::state::*;
@@ -23,27 +26,47 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
 {
   let mut sum_of_forces_vec_pci_n: Array1<f64> = Array1::zeros(3);
   let charct_lenth_m: f64 = *environment.get_spacecraft().get_sc_charact_length_m();
+  let epoch: Epoch = Epoch::from_state_vec(state_in);
+
+  /* Get latitude and longitude of the current S/C position in PCPF */
+  let pos_pcpf_m: Array1<f64> = environment.get_planet()
+    .get_earth_orientation()
+    .convert_pci_to_pcpf(state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]),
+                         &epoch);
+  let pos_pcpf_lla: Array1<f64> = environment.get_planet().calc_geodetic_pos(pos_pcpf_m.view());
+
+  /* Geodetic altitude above the reference ellipsoid from the position of the 
+   * evaluated state. 
+   * Note: The altitude field of the state vector is only updated after each 
+   *       completed step and would be outdated at the intermediate stages of 
+   *       the solver */
+  let altitude_m: f64 = pos_pcpf_lla[2];
 
   /* Get local atmospheric density from atmosphere model */
   environment.get_mut_planet().get_mut_atmosphere()
-    .update_density(state_in[STATE_VEC_INDX_ALTITUDE_PCPF_M]);
+    .update_density(altitude_m,
+                    pos_pcpf_lla[0].to_degrees(),
+                    pos_pcpf_lla[1].to_degrees(),
+                    &epoch);
 
   /* Update free mean path and Knudsen number */
   environment.get_mut_planet()
              .get_mut_atmosphere()
-             .update_mean_free_path_and_kn(state_in[STATE_VEC_INDX_ALTITUDE_PCPF_M], 
+             .update_mean_free_path_and_kn(altitude_m, 
                               charct_lenth_m );
 
   /* Update Speed of sound */
   environment.get_mut_planet()
              .get_mut_atmosphere()
-             .update_speed_of_sound(state_in[STATE_VEC_INDX_ALTITUDE_PCPF_M]);
+             .update_speed_of_sound(altitude_m);
+
+  /* Get velocity relative to the co-rotating atmosphere */
+  let vel_air_rel_pci_ms: Array1<f64> = get_air_rel_vel_vec_pci(state_in, environment);
 
   /* Update Mach number */
-  // TODO we need the velocity magnitude corrected wrt the atmsophere not purely in PCI 
   let speed_of_sound_ms: f64 = *environment.get_planet().get_atmosphere().get_speed_of_sound_ms();
   environment.get_mut_spacecraft()
-             .update_mach_number(state_in[STATE_VEC_INDX_VEL_MAGN_PCI_MS], 
+             .update_mach_number(l2_norm_array1(vel_air_rel_pci_ms.view()), 
               speed_of_sound_ms);
 
   /* Get Knudsen number for current S/C position from atmosphere model */
@@ -54,7 +77,7 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
   {
     /* Update Mach dependent drag coefficient */
     environment.get_mut_spacecraft().update_drag_coeff();
-    sum_of_forces_vec_pci_n = get_continous_flow_force_vec(state_in, environment);
+    sum_of_forces_vec_pci_n = get_continous_flow_force_vec(&vel_air_rel_pci_ms, environment);
   }
   /* Transitional flow */
   else if Kn < 10.0
@@ -62,8 +85,8 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
     /* Update Mach dependent drag coefficient */
     environment.get_mut_spacecraft().update_drag_coeff();
     /* Bridge for transitional flow regime from planetary entry, descent and landing course */
-    let newt_flow_force_vec_n: Array1<f64> = get_newtonian_flow_force_vec(state_in, environment);
-    let cont_flow_force_vec_n: Array1<f64> = get_continous_flow_force_vec(state_in, environment);
+    let newt_flow_force_vec_n: Array1<f64> = get_newtonian_flow_force_vec(&vel_air_rel_pci_ms, environment);
+    let cont_flow_force_vec_n: Array1<f64> = get_continous_flow_force_vec(&vel_air_rel_pci_ms, environment);
     let phi: f64 = std::f64::consts::PI * (3./8. + 1./8. * Kn.log10());
     let pb: f64 = (phi.sin()).powf(2.);
     sum_of_forces_vec_pci_n = pb * newt_flow_force_vec_n + (1. - pb) * cont_flow_force_vec_n;
@@ -71,7 +94,7 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
   /* Free molecular flow */
   else
   {
-    sum_of_forces_vec_pci_n = get_newtonian_flow_force_vec(state_in, environment);
+    sum_of_forces_vec_pci_n = get_newtonian_flow_force_vec(&vel_air_rel_pci_ms, environment);
   }
 
   environment.get_mut_spacecraft().set_aero_force_pci_n_x(&sum_of_forces_vec_pci_n[VEC_X]);
@@ -81,6 +104,63 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
   sum_of_forces_vec_pci_n
 }
 
+/*
+ * @brief: Function to compute the aerodynamic torque acting on the spacecraft 
+ *         from the aerodynamic force applied at the centre of pressure
+ * 
+ *         T = r_cp x F_aero_b
+ * 
+ * Note: Uses the aerodynamic force of the last call of get_force_vec_pci(), 
+ *       which needs to be called with the same state before.
+ * 
+ * @unit: Nm
+ * @frame: Body
+ * 
+ */
+pub fn get_torque_vec_b(state_in: ArrayView1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let spacecraft = environment.get_spacecraft();
+  let aero_force_pci_n: Array1<f64> = Array1::from(vec![*spacecraft.get_aero_force_pci_n_x(),
+                                                        *spacecraft.get_aero_force_pci_n_y(),
+                                                        *spacecraft.get_aero_force_pci_n_z()]);
+  let cop_offset_b_m: Array1<f64> = Array1::from(vec![*spacecraft.get_sc_aero_cop_offset_b_m_x(),
+                                                      *spacecraft.get_sc_aero_cop_offset_b_m_y(),
+                                                      *spacecraft.get_sc_aero_cop_offset_b_m_z()]);
+
+  let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+  let aero_force_b_n: Array1<f64> = dcm_pci_to_b.dot(&aero_force_pci_n);
+  let aero_torque_b_nm: Array1<f64> = cross_product_array1(cop_offset_b_m.view(), aero_force_b_n.view());
+
+  environment.get_mut_spacecraft().set_aero_torque_b_nm_x(&aero_torque_b_nm[VEC_X]);
+  environment.get_mut_spacecraft().set_aero_torque_b_nm_y(&aero_torque_b_nm[VEC_Y]);
+  environment.get_mut_spacecraft().set_aero_torque_b_nm_z(&aero_torque_b_nm[VEC_Z]);
+
+  aero_torque_b_nm
+}
+
+/*
+ * @brief: Function to compute the velocity of the spacecraft relative to the 
+ *         atmosphere. The atmosphere is assumed to co-rotate with the planet:
+ * 
+ *         v_rel = v_pci - omega x r_pci
+ * 
+ * @unit: m/s
+ * @frame: PCI
+ * 
+ */
+pub fn get_air_rel_vel_vec_pci(state_in: ArrayView1<f64>, environment: &Environment)
+-> Array1<f64>
+{
+  let pos_pci_m: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+  let vel_pci_ms: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]);
+
+  /* Planet's angular velocity vector (rotation about the PCI z-axis) */
+  let omega_pci_rads: Array1<f64> = Array1::from(vec![0.0, 0.0, *environment.get_planet().get_omega()]);
+
+  &vel_pci_ms - &cross_product_array1(omega_pci_rads.view(), pos_pci_m)
+}
+
 /* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
  *
  *                                    [PRIVATE FUNCTIONS]
@@ -101,17 +181,16 @@ pub fn get_force_vec_pci(state_in: ArrayView1<f64>, environment: &mut Environmen
  * @frame: PCI
  * 
  */
-fn get_continous_flow_force_vec(state_in: ArrayView1<f64>, environment: &mut Environment)
+fn get_continous_flow_force_vec(vel_air_rel_pci_ms: &Array1<f64>, environment: &mut Environment)
 -> Array1<f64>
 {
 
   // TODO implement continuum flow aerodynamic model 
 
-  /* Get velocity vector in PCI frame from state vector */
-  let mut velocity_vec: Array1<f64> = Array1::zeros(3);
-  velocity_vec.assign(&state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]));
+  /* Get velocity vector relative to the atmosphere in PCI frame */
+  let velocity_vec: Array1<f64> = vel_air_rel_pci_ms.clone();
  
-  /* Compute Vinfinity as the length of the velocity vector in PCI frame */
+  /* Compute Vinfinity as the length of the air relative velocity vector */
   let v_infinity: f64 = l2_norm_array1(velocity_vec.view());
   /* Compute squared velocity */
   let v_squared: f64 = v_infinity * v_infinity;
@@ -143,15 +222,14 @@ fn get_continous_flow_force_vec(state_in: ArrayView1<f64>, environment: &mut Env
  * @frame: PCI
  * 
  */
-fn get_newtonian_flow_force_vec(state_in: ArrayView1<f64>, environment: &mut Environment)
+fn get_newtonian_flow_force_vec(vel_air_rel_pci_ms: &Array1<f64>, environment: &mut Environment)
 -> Array1<f64>
 {
 
-  /* Get velocity vector in PCI frame from state vector */
-  let mut velocity_vec: Array1<f64> = Array1::zeros(3);
-  velocity_vec.assign(&state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]));
+  /* Get velocity vector relative to the atmosphere in PCI frame */
+  let velocity_vec: Array1<f64> = vel_air_rel_pci_ms.clone();
  
-  /* Compute Vinfinity as the length of the velocity vector in PCI frame */
+  /* Compute Vinfinity as the length of the air relative velocity vector */
   let v_infinity: f64 = l2_norm_array1(velocity_vec.view());
   /* Compute squared velocity */
   let v_squared: f64 = v_infinity * v_infinity;
@@ -0,0 +1,3 @@
+pub mod attitude_controller;
+
+pub mod guidance;
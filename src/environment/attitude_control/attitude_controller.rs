@@ -0,0 +1,343 @@
+/*
+ * @brief: Attitude guidance and control
+ *
+ * @description: The reference attitude of the selected guidance mode (see
+ *               guidance.rs) is tracked with a quaternion feedback PD
+ *               controller:
+ *
+ *               T_cmd = - Kp * sign(q_err_w) * q_err_xyz - Kd * (w - w_ref)
+ *
+ *               with the error quaternion q_err = q_ref^-1 * q and the
+ *               reference rate w_ref in the body frame. Each component of the
+ *               commanded torque is limited to the maximum control torque.
+ *               The controller is evaluated continuously with the equations of
+ *               motion and the commanded torque is applied as ideal control
+ *               torque.
+ *
+ *               Ref: Wie, Weiss, Arapostathis - Quaternion feedback regulator
+ *                    for spacecraft eigenaxis rotations, J. Guidance (1989)
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, ArrayView1, s};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::environment::attitude_control::guidance::*;
+use crate::math::epoch::Epoch;
+use crate::math::frame_math::convert_geodetic_to_ecef;
+use crate::math::attitude_math::{convert_quat_to_dcm_pci_to_b, calc_quat_error};
+
+/* Include constants */
+use crate::constants::state::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum AttitudeGuidanceMode {
+  Nadir,
+  Inertial,
+  SunPointing,
+  TargetTracking
+}
+
+impl AttitudeGuidanceMode {
+  pub fn from_param_str(mode_str: &str) -> AttitudeGuidanceMode
+  {
+    match mode_str.trim().trim_matches('"')
+    {
+      "nadir" => AttitudeGuidanceMode::Nadir,
+      "inertial" => AttitudeGuidanceMode::Inertial,
+      "sun_pointing" => AttitudeGuidanceMode::SunPointing,
+      "target_tracking" => AttitudeGuidanceMode::TargetTracking,
+      _ => panic!("! [ERROR] ! > Unknown guidance_mode {} in attitude_control.ini! <", mode_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct AttitudeControl {
+  /*
+   * @description : Flag, if true the attitude is controlled
+   * @unit        : N/A
+   *
+   * */
+  enable_attitude_control: bool,
+  /*
+   * @description : Guidance mode providing the reference attitude
+   * @unit        : N/A
+   *
+   * */
+  guidance_mode: AttitudeGuidanceMode,
+  /*
+   * @description : Proportional and derivative gain of the PD controller
+   * @unit        : Nm, Nms
+   *
+   * */
+  proportional_gain_nm: f64,
+  derivative_gain_nms: f64,
+  /*
+   * @description : Maximum control torque per body axis
+   * @unit        : Nm
+   *
+   * */
+  max_control_torque_nm: f64,
+  /*
+   * @description : Reference quaternion PCI to body of the inertial mode
+   * @unit        : N/A
+   *
+   * */
+  inertial_quat_pci_to_b: Array1<f64>,
+  /*
+   * @description : Primary and secondary body axis and the secondary
+   *                direction of the sun_pointing and target_tracking modes
+   * @unit        : N/A
+   *
+   * */
+  primary_axis_b: Array1<f64>,
+  secondary_axis_b: Array1<f64>,
+  secondary_target_type: SecondaryTargetType,
+  /*
+   * @description : Geodetic latitude, longitude and height of the ground
+   *                target of the target_tracking mode
+   * @unit        : rad, rad, m
+   *
+   * */
+  target_pos_lla: Array1<f64>,
+  /*
+   * @description : Attitude error angle and commanded torque. Updated with
+   *                every call of get_torque_vec_b.
+   * @unit        : degree, Nm
+   *
+   * */
+  att_error_angle_deg: f64,
+  cmd_torque_b_nm: Array1<f64>,
+  /*
+   * @description : Flag, true while the secondary direction is parallel to
+   *                the line of sight and the fallback direction is used. A
+   *                warning is printed each time this condition is entered.
+   * @unit        : N/A
+   *
+   * */
+  is_secondary_dir_degenerate: bool
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl AttitudeControl {
+  pub fn new() -> AttitudeControl {
+    AttitudeControl {
+      enable_attitude_control: false,
+      guidance_mode: AttitudeGuidanceMode::Nadir,
+      proportional_gain_nm: 0.0,
+      derivative_gain_nms: 0.0,
+      max_control_torque_nm: 0.0,
+      inertial_quat_pci_to_b: Array1::from(vec![0.0, 0.0, 0.0, 1.0]),
+      primary_axis_b: Array1::from(vec![0.0, 0.0, 1.0]),
+      secondary_axis_b: Array1::from(vec![1.0, 0.0, 0.0]),
+      secondary_target_type: SecondaryTargetType::OrbitNormal,
+      target_pos_lla: Array1::zeros(3),
+      att_error_angle_deg: 0.0,
+      cmd_torque_b_nm: Array1::zeros(3),
+      is_secondary_dir_degenerate: false
+    }
+  }
+}
+
+impl Default for AttitudeControl {
+  fn default() -> AttitudeControl {
+    AttitudeControl::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl AttitudeControl {
+  pub fn set_enable_attitude_control(&mut self, val_in: &bool) {self.enable_attitude_control = *val_in;}
+  pub fn set_guidance_mode(&mut self, val_in: &AttitudeGuidanceMode) {self.guidance_mode = *val_in;}
+  pub fn set_proportional_gain_nm(&mut self, val_in: &f64) {self.proportional_gain_nm = *val_in;}
+  pub fn set_derivative_gain_nms(&mut self, val_in: &f64) {self.derivative_gain_nms = *val_in;}
+  pub fn set_max_control_torque_nm(&mut self, val_in: &f64) {self.max_control_torque_nm = *val_in;}
+  pub fn set_inertial_quat_pci_to_b(&mut self, val_in: &Array1<f64>)
+  {
+    self.inertial_quat_pci_to_b = val_in / val_in.dot(val_in).sqrt();
+  }
+  pub fn set_primary_axis_b(&mut self, val_in: &Array1<f64>) {self.primary_axis_b = val_in.clone();}
+  pub fn set_secondary_axis_b(&mut self, val_in: &Array1<f64>) {self.secondary_axis_b = val_in.clone();}
+  pub fn set_secondary_target_type(&mut self, val_in: &SecondaryTargetType) {self.secondary_target_type = *val_in;}
+  pub fn set_target_pos_lla(&mut self, val_in: &Array1<f64>) {self.target_pos_lla = val_in.clone();}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl AttitudeControl {
+  pub fn is_attitude_control_enabled(&self) -> &bool {&self.enable_attitude_control}
+  pub fn get_guidance_mode(&self) -> &AttitudeGuidanceMode {&self.guidance_mode}
+  pub fn get_proportional_gain_nm(&self) -> &f64 {&self.proportional_gain_nm}
+  pub fn get_derivative_gain_nms(&self) -> &f64 {&self.derivative_gain_nms}
+  pub fn get_max_control_torque_nm(&self) -> &f64 {&self.max_control_torque_nm}
+  pub fn get_att_error_angle_deg(&self) -> &f64 {&self.att_error_angle_deg}
+  pub fn get_cmd_torque_b_nm(&self) -> &Array1<f64> {&self.cmd_torque_b_nm}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl AttitudeControl {
+ /*
+  * @brief: Function to compute the torque commanded by the PD controller to
+  *         track a reference attitude
+  *
+  * @param[in] quat_in          - Attitude quaternion PCI to body
+  * @param[in] ang_rate_b_in    - Angular rate of the body [rad/s]
+  * @param[in] quat_ref_in      - Reference quaternion PCI to body
+  * @param[in] ang_rate_ref_pci - Reference angular rate in PCI [rad/s]
+  *
+  * @returns: (attitude error angle [deg], commanded torque [Nm] in body frame)
+  */
+  pub fn calc_cmd_torque_b(&self,
+                           quat_in: ArrayView1<f64>,
+                           ang_rate_b_in: ArrayView1<f64>,
+                           quat_ref_in: ArrayView1<f64>,
+                           ang_rate_ref_pci: ArrayView1<f64>)
+  -> (f64, Array1<f64>)
+  {
+    let quat_error: Array1<f64> = calc_quat_error(quat_in, quat_ref_in);
+    /* Rotate along the shorter path */
+    let sign: f64 = if quat_error[3] < 0.0 {-1.0} else {1.0};
+    let att_error_angle_deg: f64 = (2.0 * quat_error[3].abs().min(1.0).acos()).to_degrees();
+
+    let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(quat_in);
+    let ang_rate_error_b: Array1<f64> = &ang_rate_b_in - &dcm_pci_to_b.dot(&ang_rate_ref_pci);
+
+    let cmd_torque_b_nm: Array1<f64> = - self.proportional_gain_nm * sign * &quat_error.slice(s![0..3])
+                                       - self.derivative_gain_nms * &ang_rate_error_b;
+
+    (att_error_angle_deg, cmd_torque_b_nm.mapv(|torque: f64| torque.clamp(-self.max_control_torque_nm,
+                                                                          self.max_control_torque_nm)))
+  }
+}
+
+/*
+ * @brief: Function to compute the control torque commanded by the attitude
+ *         controller for the guidance mode selected in attitude_control.ini
+ *
+ * Note: Called from the equations of motion, the controller state is only
+ *       updated by update_attitude_controller after each integration step.
+ *
+ * @unit: Nm
+ * @frame: Body
+ *
+ */
+pub fn get_torque_vec_b(state_in: &Array1<f64>, environment: &Environment)
+-> Array1<f64>
+{
+  let (_, cmd_torque_b_nm, _): (f64, Array1<f64>, bool) = calc_controller_output(state_in, environment);
+  cmd_torque_b_nm
+}
+
+/*
+ * @brief: Function to update the attitude error and the commanded torque of
+ *         the controller for the output and to warn once if the secondary
+ *         direction of the line of sight guidance becomes degenerate.
+ *
+ * Note: This function shall be called once after each integration step and
+ *       not from the equations of motion.
+ *
+ * @param[in] state_in - Full state vector at the end of the step
+ *
+ */
+pub fn update_attitude_controller(state_in: &Array1<f64>, environment: &mut Environment)
+{
+  let (att_error_angle_deg, cmd_torque_b_nm, is_secondary_dir_degenerate): (f64, Array1<f64>, bool) =
+    calc_controller_output(state_in, environment);
+
+  let attitude_control: &mut AttitudeControl = environment.get_mut_attitude_control();
+  if is_secondary_dir_degenerate && !attitude_control.is_secondary_dir_degenerate
+  {
+    println!("[WRN] Secondary direction parallel to the line of sight at SimTime [s] {:.3}. Rotation about the primary axis is undefined, using the PCI axis least aligned with the line of sight as secondary direction!",
+             state_in[STATE_VEC_INDX_SIM_TIME]);
+  }
+  attitude_control.is_secondary_dir_degenerate = is_secondary_dir_degenerate;
+  attitude_control.att_error_angle_deg = att_error_angle_deg;
+  attitude_control.cmd_torque_b_nm = cmd_torque_b_nm;
+}
+
+/* - - - [PRIVATE FUNCTIONS] - - - */
+/*
+ * @brief: Compute the reference attitude of the selected guidance mode and the
+ *         resulting PD controller output
+ *
+ * @returns: (attitude error angle [deg], commanded torque [Nm] in body frame,
+ *            true if the secondary direction is degenerate)
+ */
+fn calc_controller_output(state_in: &Array1<f64>, environment: &Environment)
+-> (f64, Array1<f64>, bool)
+{
+  let pos_pci_m: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+  let vel_pci_ms: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_VEL_X..(STATE_VEC_INDX_VEL_Z+1)]);
+  let attitude_control: &AttitudeControl = environment.get_attitude_control();
+
+  let (quat_ref, ang_rate_ref_pci, is_secondary_dir_degenerate): (Array1<f64>, Array1<f64>, bool) = 
+    match attitude_control.guidance_mode
+  {
+    AttitudeGuidanceMode::Nadir =>
+    {
+      let (quat_ref, ang_rate_ref_pci): (Array1<f64>, Array1<f64>) = calc_nadir_reference(pos_pci_m, vel_pci_ms);
+      (quat_ref, ang_rate_ref_pci, false)
+    }
+    AttitudeGuidanceMode::Inertial => (attitude_control.inertial_quat_pci_to_b.clone(), Array1::zeros(3), false),
+    AttitudeGuidanceMode::SunPointing =>
+    {
+      /* The motion of the Sun is neglected */
+      let pos_sun_pci_m: Array1<f64> = environment.get_ephemeris()
+        .calc_sun_pos_pci_m(&Epoch::from_state_vec(state_in.view()));
+      calc_line_of_sight_reference((&pos_sun_pci_m - &pos_pci_m).view(),
+                                   (-&vel_pci_ms).view(),
+                                   pos_pci_m,
+                                   vel_pci_ms,
+                                   attitude_control.primary_axis_b.view(),
+                                   attitude_control.secondary_axis_b.view(),
+                                   attitude_control.secondary_target_type)
+    }
+    AttitudeGuidanceMode::TargetTracking =>
+    {
+      let planet = environment.get_planet();
+      let target_pos_pcpf_m: Array1<f64> = convert_geodetic_to_ecef(attitude_control.target_pos_lla.view(),
+                                                                    *planet.get_semi_major_axis(),
+                                                                    *planet.get_flattening_factor());
+      let (target_pos_pci_m, target_vel_pci_ms): (Array1<f64>, Array1<f64>) = planet.get_earth_orientation()
+        .convert_pcpf_to_pci_state(target_pos_pcpf_m.view(),
+                                   Array1::zeros(3).view(),
+                                   &Epoch::from_state_vec(state_in.view()),
+                                   *planet.get_omega());
+      calc_line_of_sight_reference((&target_pos_pci_m - &pos_pci_m).view(),
+                                   (&target_vel_pci_ms - &vel_pci_ms).view(),
+                                   pos_pci_m,
+                                   vel_pci_ms,
+                                   attitude_control.primary_axis_b.view(),
+                                   attitude_control.secondary_axis_b.view(),
+                                   attitude_control.secondary_target_type)
+    }
+  };
+
+  let (att_error_angle_deg, cmd_torque_b_nm): (f64, Array1<f64>) = attitude_control.calc_cmd_torque_b(
+    state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]),
+    state_in.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]),
+    quat_ref.view(),
+    ang_rate_ref_pci.view());
+
+  (att_error_angle_deg, cmd_torque_b_nm, is_secondary_dir_degenerate)
+}
@@ -0,0 +1,141 @@
+/*
+ * @brief: Reference attitude generators (attitude guidance)
+ *
+ * @description: Each generator returns the reference attitude quaternion PCI
+ *               to body and the reference angular rate in PCI:
+ *
+ *               * nadir            - Body axes aligned with the local vertical
+ *                                    local horizontal (LVLH) frame: z towards
+ *                                    nadir, y against the orbit normal, x
+ *                                    completes the triad (along the velocity
+ *                                    for circular orbits)
+ *               * inertial         - Fixed quaternion
+ *               * sun_pointing     - Primary body axis towards the Sun
+ *               * target_tracking  - Primary body axis towards a target on
+ *                                    the planet's surface
+ *
+ *               For sun_pointing and target_tracking the rotation about the
+ *               primary axis is fixed by aligning a secondary body axis as
+ *               close as possible with a secondary direction (orbit normal,
+ *               nadir or velocity). The reference rate of these modes is the
+ *               rotation rate of the line of sight, the rotation about the
+ *               primary axis is neglected. If the secondary direction is
+ *               parallel to the line of sight (e.g. nadir during an overhead
+ *               pass of the ground target or the orbit normal at a beta angle
+ *               of 90 deg), the rotation about the primary axis is undefined
+ *               and the PCI axis least aligned with the line of sight is used
+ *               as secondary direction instead.
+ *
+ *               Ref: Wertz - Spacecraft Attitude Determination and Control
+ *                    Markley, Crassidis - Fundamentals of Spacecraft Attitude
+ *                    Determination and Control, Chapter 5.1 (TRIAD)
+ *
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, ArrayView1};
+
+/* Include local crates */
+use crate::math::attitude_math::{convert_dcm_to_quat_pci_to_b, calc_triad_dcm_pci_to_b, is_triad_degenerate};
+use crate::math::vec_math::{l2_norm_array1, normalize_array1, cross_product_array1};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum SecondaryTargetType {
+  OrbitNormal,
+  Nadir,
+  Velocity
+}
+
+impl SecondaryTargetType {
+  pub fn from_param_str(target_str: &str) -> SecondaryTargetType
+  {
+    match target_str.trim().trim_matches('"')
+    {
+      "orbit_normal" => SecondaryTargetType::OrbitNormal,
+      "nadir" => SecondaryTargetType::Nadir,
+      "velocity" => SecondaryTargetType::Velocity,
+      _ => panic!("! [ERROR] ! > Unknown secondary_target {} in attitude_control.ini! <", target_str)
+    }
+  }
+}
+
+/*
+ * @brief: Function to compute the nadir pointing (LVLH) reference attitude
+ *
+ * @param[in] pos_pci_m_in  - Spacecraft position [m]
+ * @param[in] vel_pci_ms_in - Spacecraft velocity [m/s]
+ *
+ * @returns: (reference quaternion PCI to body, reference rate [rad/s] in PCI)
+ */
+pub fn calc_nadir_reference(pos_pci_m_in: ArrayView1<f64>, vel_pci_ms_in: ArrayView1<f64>)
+-> (Array1<f64>, Array1<f64>)
+{
+  let ang_mom_vec: Array1<f64> = cross_product_array1(pos_pci_m_in, vel_pci_ms_in);
+
+  let z_axis_pci: Array1<f64> = -normalize_array1(pos_pci_m_in.to_owned());
+  let y_axis_pci: Array1<f64> = -normalize_array1(ang_mom_vec.clone());
+  let x_axis_pci: Array1<f64> = cross_product_array1(y_axis_pci.view(), z_axis_pci.view());
+
+  /* Rows of the direction cosine matrix are the body axes in PCI */
+  let mut dcm_pci_to_b: Array2<f64> = Array2::zeros((3, 3));
+  dcm_pci_to_b.row_mut(0).assign(&x_axis_pci);
+  dcm_pci_to_b.row_mut(1).assign(&y_axis_pci);
+  dcm_pci_to_b.row_mut(2).assign(&z_axis_pci);
+
+  /* The LVLH frame rotates with the orbit rate about the orbit normal */
+  let radius_m: f64 = l2_norm_array1(pos_pci_m_in);
+  let ang_rate_ref_pci: Array1<f64> = ang_mom_vec / (radius_m * radius_m);
+
+  (convert_dcm_to_quat_pci_to_b(dcm_pci_to_b.view()), ang_rate_ref_pci)
+}
+
+/*
+ * @brief: Function to compute the reference attitude that points a primary
+ *         body axis along the line of sight to a target (Sun or ground
+ *         target) and a secondary body axis towards a secondary direction
+ *
+ * @param[in] rel_pos_pci_m_in      - Position of the target relative to the
+ *                                    spacecraft [m]
+ * @param[in] rel_vel_pci_ms_in     - Velocity of the target relative to the
+ *                                    spacecraft [m/s]
+ * @param[in] pos_pci_m_in          - Spacecraft position [m]
+ * @param[in] vel_pci_ms_in         - Spacecraft velocity [m/s]
+ * @param[in] primary_axis_b_in     - Primary body axis
+ * @param[in] secondary_axis_b_in   - Secondary body axis
+ * @param[in] secondary_target_type - Secondary direction
+ *
+ * @returns: (reference quaternion PCI to body, reference rate [rad/s] in PCI,
+ *            true if the secondary direction is parallel to the line of sight
+ *            and replaced by the fallback direction)
+ */
+pub fn calc_line_of_sight_reference(rel_pos_pci_m_in: ArrayView1<f64>,
+                                    rel_vel_pci_ms_in: ArrayView1<f64>,
+                                    pos_pci_m_in: ArrayView1<f64>,
+                                    vel_pci_ms_in: ArrayView1<f64>,
+                                    primary_axis_b_in: ArrayView1<f64>,
+                                    secondary_axis_b_in: ArrayView1<f64>,
+                                    secondary_target_type: SecondaryTargetType)
+-> (Array1<f64>, Array1<f64>, bool)
+{
+  let secondary_dir_pci: Array1<f64> = match secondary_target_type
+  {
+    SecondaryTargetType::OrbitNormal => cross_product_array1(pos_pci_m_in, vel_pci_ms_in),
+    SecondaryTargetType::Nadir => -pos_pci_m_in.to_owned(),
+    SecondaryTargetType::Velocity => vel_pci_ms_in.to_owned()
+  };
+
+  let dcm_pci_to_b: Array2<f64> = calc_triad_dcm_pci_to_b(primary_axis_b_in,
+                                                          rel_pos_pci_m_in,
+                                                          secondary_axis_b_in,
+                                                          secondary_dir_pci.view());
+
+  /* Rotation rate of the line of sight */
+  let distance_m: f64 = l2_norm_array1(rel_pos_pci_m_in);
+  let ang_rate_ref_pci: Array1<f64> = cross_product_array1(rel_pos_pci_m_in, rel_vel_pci_ms_in)
+                                      / (distance_m * distance_m);
+
+  (convert_dcm_to_quat_pci_to_b(dcm_pci_to_b.view()), 
+   ang_rate_ref_pci, 
+   is_triad_degenerate(rel_pos_pci_m_in, secondary_dir_pci.view()))
+}
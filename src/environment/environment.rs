@@ -6,6 +6,11 @@ use ndarray::{Array1, ArrayView1, s};
 /* Import (local) structs */
 use crate::environment::planet::planet::Planet;
 use crate::environment::spacecraft::spacecraft::Spacecraft;
+use crate::environment::third_body::third_body_model::ThirdBody;
+use crate::environment::srp::srp_model::SRP;
+use crate::environment::ephemeris::ephemeris_model::Ephemeris;
+use crate::environment::attitude_control::attitude_controller::AttitudeControl;
+use crate::environment::magnetic_field::magnetic_field_model::MagneticField;
 
 /* Include local crates */
 use crate::dke_core::dke_core::DKE;
@@ -30,7 +35,38 @@ pub struct Environment {
    * @unit        : N/A
    * 
    * */
-   spacecraft: Spacecraft
+   spacecraft: Spacecraft,
+  /* [ThirdBody struct] 
+   * @description : Data struct containing the third body perturbation settings
+   * @unit        : N/A
+   * 
+   * */
+   third_body: ThirdBody,
+  /* [SRP struct] 
+   * @description : Data struct containing the solar radiation pressure settings
+   * @unit        : N/A
+   * 
+   * */
+   srp: SRP,
+  /* [Ephemeris struct] 
+   * @description : Data struct providing the Sun and Moon positions
+   * @unit        : N/A
+   * 
+   * */
+   ephemeris: Ephemeris,
+  /* [AttitudeControl struct] 
+   * @description : Data struct containing the attitude guidance and control 
+   *                settings
+   * @unit        : N/A
+   * 
+   * */
+   attitude_control: AttitudeControl,
+  /* [MagneticField struct] 
+   * @description : Data struct containing the geomagnetic field model
+   * @unit        : N/A
+   * 
+   * */
+   magnetic_field: MagneticField
 }
 
 
@@ -47,6 +83,11 @@ impl Environment {
       dt_s: 0.0,
       planet: Planet::new(),
       spacecraft: Spacecraft::new(),
+      third_body: ThirdBody::new(),
+      srp: SRP::new(),
+      ephemeris: Ephemeris::new(),
+      attitude_control: AttitudeControl::new(),
+      magnetic_field: MagneticField::new(),
 
     }
   }
@@ -88,6 +129,21 @@ impl Environment {
   pub fn get_spacecraft(&self) -> &Spacecraft {&self.spacecraft}
   pub fn get_mut_spacecraft(&mut self) -> &mut Spacecraft {&mut self.spacecraft}
 
+  pub fn get_third_body(&self) -> &ThirdBody {&self.third_body}
+  pub fn get_mut_third_body(&mut self) -> &mut ThirdBody {&mut self.third_body}
+
+  pub fn get_srp(&self) -> &SRP {&self.srp}
+  pub fn get_mut_srp(&mut self) -> &mut SRP {&mut self.srp}
+
+  pub fn get_ephemeris(&self) -> &Ephemeris {&self.ephemeris}
+  pub fn get_mut_ephemeris(&mut self) -> &mut Ephemeris {&mut self.ephemeris}
+
+  pub fn get_attitude_control(&self) -> &AttitudeControl {&self.attitude_control}
+  pub fn get_mut_attitude_control(&mut self) -> &mut AttitudeControl {&mut self.attitude_control}
+
+  pub fn get_magnetic_field(&self) -> &MagneticField {&self.magnetic_field}
+  pub fn get_mut_magnetic_field(&mut self) -> &mut MagneticField {&mut self.magnetic_field}
+
   pub fn get_dt_s(&self) -> f64 {self.dt_s}
   pub fn get_sim_time_s(&self) -> f64 {self.sim_current_time_s}
 
@@ -0,0 +1,3 @@
+pub mod ephemeris_model;
+
+pub mod spk;
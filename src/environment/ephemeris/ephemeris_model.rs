@@ -0,0 +1,203 @@
+/*
+ * @brief: Ephemerides of the Sun and the Moon
+ *
+ * @description: Provides the Sun and Moon positions with respect to the
+ *               central body for an epoch (UTC) to all environment models
+ *               (third body perturbations, SRP, eclipse events, output).
+ *               The epoch is converted to TT internally.
+ *
+ *               Models (ephemeris_model in ephemeris.ini):
+ *               * low_precision  - Low precision analytic series, about
+ *                                  1 arcmin (Sun) and several arcmin (Moon)
+ *                                  Ref: Montenbruck, Gill - Satellite Orbits,
+ *                                       Section 3.3.2
+ *               * high_precision - Mean elements of the Earth-Moon barycentre
+ *                                  and the ELP-2000/82 main terms, about
+ *                                  20 arcsec (Sun) and 10 arcsec (Moon)
+ *                                  Ref: Meeus - Astronomical Algorithms,
+ *                                       Chapter 47
+ *               * spk            - JPL planetary ephemeris from a binary SPK
+ *                                  kernel (e.g. de440s.bsp), evaluated at TDB.
+ *                                  The J2000 (ICRF) frame of the kernel is
+ *                                  used as PCI.
+ *
+ * Note: The analytic series are only valid if Earth is the central body!
+ */
+
+/* Include external crates */
+use ndarray::Array1;
+
+/* Include local crates */
+use crate::environment::ephemeris::spk::SpkKernel;
+use crate::math::ephemeris_math::*;
+use crate::math::epoch::Epoch;
+use crate::math::time_scale_math::TimeScale;
+
+/* Import constants */
+use crate::constants::ephemeris::{NAIF_ID_EARTH, NAIF_ID_MOON, NAIF_ID_SUN};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum EphemerisModelType {
+  LowPrecision,
+  HighPrecision,
+  Spk
+}
+
+impl EphemerisModelType {
+ /*
+  * @brief: Function to map the ephemeris_model parameter string to the model
+  *         type.
+  */
+  pub fn from_param_str(model_str: &str) -> EphemerisModelType
+  {
+    match model_str.trim().trim_matches('"')
+    {
+      "low_precision" => EphemerisModelType::LowPrecision,
+      "high_precision" => EphemerisModelType::HighPrecision,
+      "spk" => EphemerisModelType::Spk,
+      _ => panic!("! [ERROR] ! > Unknown ephemeris model {} in ephemeris.ini! <", model_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct Ephemeris {
+  /* [model type]
+   * @description : Series used for the Sun and Moon positions
+   * @unit        : N/A
+   * */
+  model_type: EphemerisModelType,
+  /* [SPK kernel]
+   * @description : Path to and content of the binary SPK kernel (spk model)
+   * @unit        : N/A
+   * */
+  spk_file_path: String,
+  spk_kernel: SpkKernel
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Ephemeris {
+  pub fn new() -> Ephemeris {
+    Ephemeris {
+      model_type: EphemerisModelType::LowPrecision,
+      spk_file_path: String::new(),
+      spk_kernel: SpkKernel::new()
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. This function usually contains file loaders.
+   */
+  pub fn init(&mut self)
+  {
+    println!("[x] Initialize ephemeris model: {:?}", self.model_type);
+    if self.model_type == EphemerisModelType::Spk
+    {
+      let spk_file_path: String = self.spk_file_path.clone();
+      self.spk_kernel.load(&spk_file_path);
+    }
+  }
+}
+
+impl Default for Ephemeris {
+  fn default() -> Ephemeris {
+    Ephemeris::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl Ephemeris {
+  pub fn set_model_type(&mut self, val_in: &EphemerisModelType) {self.model_type = *val_in;}
+  pub fn set_spk_file_path(&mut self, val_in: &str) {self.spk_file_path = val_in.trim().trim_matches('"').to_string();}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Ephemeris {
+  pub fn get_model_type(&self) -> &EphemerisModelType {&self.model_type}
+  pub fn get_spk_kernel(&self) -> &SpkKernel {&self.spk_kernel}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl Ephemeris {
+ /*
+  * @brief: Function to compute the position of the Sun with respect to the
+  *         central body
+  *
+  * @param[in] epoch - Epoch (UTC)
+  *
+  * @unit: m
+  * @frame: PCI
+  */
+  pub fn calc_sun_pos_pci_m(&self, epoch: &Epoch)
+  -> Array1<f64>
+  {
+    match self.model_type
+    {
+      EphemerisModelType::LowPrecision => calc_sun_pos_pci_m(epoch.convert_utc_to_tt().to_j2000_s()),
+      EphemerisModelType::HighPrecision => calc_sun_pos_pci_m_high_precision(epoch.convert_utc_to_tt().to_j2000_s()),
+      EphemerisModelType::Spk => self.calc_body_pos_pci_m(NAIF_ID_SUN, NAIF_ID_EARTH, epoch)
+    }
+  }
+
+ /*
+  * @brief: Function to compute the position of the Moon with respect to the
+  *         central body
+  *
+  * @param[in] epoch - Epoch (UTC)
+  *
+  * @unit: m
+  * @frame: PCI
+  */
+  pub fn calc_moon_pos_pci_m(&self, epoch: &Epoch)
+  -> Array1<f64>
+  {
+    match self.model_type
+    {
+      EphemerisModelType::LowPrecision => calc_moon_pos_pci_m(epoch.convert_utc_to_tt().to_j2000_s()),
+      EphemerisModelType::HighPrecision => calc_moon_pos_pci_m_high_precision(epoch.convert_utc_to_tt().to_j2000_s()),
+      EphemerisModelType::Spk => self.calc_body_pos_pci_m(NAIF_ID_MOON, NAIF_ID_EARTH, epoch)
+    }
+  }
+
+ /*
+  * @brief: Function to compute the position of any body of the SPK kernel
+  *         relative to any other body (spk model only)
+  *
+  * @param[in] target   - NAIF ID of the target body (e.g. 4 Mars barycentre)
+  * @param[in] observer - NAIF ID of the observing body (e.g. 399 Earth)
+  * @param[in] epoch    - Epoch (UTC)
+  *
+  * @unit: m
+  * @frame: PCI
+  */
+  pub fn calc_body_pos_pci_m(&self, target: i32, observer: i32, epoch: &Epoch)
+  -> Array1<f64>
+  {
+    if !self.spk_kernel.is_loaded()
+    {
+      panic!("! [ERROR] ! > Body positions from SPK kernels require ephemeris_model = spk in ephemeris.ini! <");
+    }
+    let et_s: f64 = epoch.convert_time_scale(TimeScale::Utc, TimeScale::Tdb, 0.0).to_j2000_s();
+    self.spk_kernel.calc_pos_m(target, observer, et_s)
+  }
+}
@@ -0,0 +1,521 @@
+/*
+ * @brief: This file contains the reader of JPL binary SPK ephemeris kernels
+ *         (e.g. de440s.bsp).
+ *
+ * @description: SPK kernels are stored in the Double precision Array File
+ *               (DAF) format. The file is organised in records of 1024 bytes:
+ *
+ *               * File record     - identification "DAF/SPK", number of
+ *                                   double (ND = 2) and integer (NI = 6)
+ *                                   components of a summary, first summary
+ *                                   record (FWARD)
+ *               * Summary records - linked list of segment summaries:
+ *                                   [start ET, end ET] and [target, center,
+ *                                   frame, data type, start address, end
+ *                                   address]
+ *               * Name records    - segment names (not used here)
+ *               * Element records - segment data
+ *
+ *               Supported are segments of type 2 (Chebyshev, position) and
+ *               type 3 (Chebyshev, position and velocity) in the J2000
+ *               (ICRF) frame, which contain all bodies of the DE4xx planetary
+ *               ephemerides. Each segment ends with the directory
+ *               [INIT, INTLEN, RSIZE, N] followed by N records of equal
+ *               length INTLEN, each [MID, RADIUS, X coefficients,
+ *               Y coefficients, Z coefficients, ...].
+ *
+ *               Positions of a body relative to another body are computed by
+ *               chaining the segments of both bodies to the solar system
+ *               barycentre (NAIF ID 0), e.g. Moon (301) -> Earth-Moon
+ *               barycentre (3) -> SSB (0). Where segments overlap the last
+ *               segment in the file takes precedence.
+ *
+ *               The byte order (little or big endian) is detected from the
+ *               file record.
+ *
+ *               Ref: NAIF - DAF Required Reading, SPK Required Reading
+ *
+ */
+use std::fs;
+use ndarray::Array1;
+
+/* Import constants */
+use crate::constants::ephemeris::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub struct SpkSegment {
+  /* [body]
+   * @description : NAIF IDs of the target and the center body of the segment
+   * @unit        : N/A
+   * */
+  pub target: i32,
+  pub center: i32,
+  /* [coverage]
+   * @description : Time span covered by the segment (TDB seconds since J2000)
+   * @unit        : seconds
+   * */
+  pub start_et_s: f64,
+  pub end_et_s: f64,
+  /* [data type]
+   * @description : SPK data type (2 or 3)
+   * @unit        : N/A
+   * */
+  pub data_type: i32,
+  /* [directory]
+   * @description : Start epoch and length of the Chebyshev intervals, size of
+   *                a record in doubles and the number of records
+   * @unit        : seconds, seconds, N/A, N/A
+   * */
+  pub init_et_s: f64,
+  pub interval_length_s: f64,
+  pub record_size: usize,
+  pub num_records: usize,
+  /* [address]
+   * @description : Index of the first double of the segment in the file
+   *                (0-based, DAF addresses are 1-based)
+   * @unit        : N/A
+   * */
+  pub start_index: usize,
+}
+
+#[derive(Clone)]
+
+pub struct SpkKernel {
+  /* [file content]
+   * @description : Raw content of the kernel file
+   * @unit        : N/A
+   * */
+  data: Vec<u8>,
+  /* [byte order]
+   * @description : True if the file is stored in little endian byte order
+   * @unit        : N/A
+   * */
+  is_little_endian: bool,
+  /* [segments]
+   * @description : Supported segments in file order
+   * @unit        : N/A
+   * */
+  segments: Vec<SpkSegment>,
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl SpkKernel {
+  pub fn new() -> SpkKernel
+  {
+    SpkKernel {
+      data: Vec::new(),
+      is_little_endian: true,
+      segments: Vec::new(),
+    }
+  }
+}
+
+impl Default for SpkKernel {
+  fn default() -> SpkKernel {
+    SpkKernel::new()
+  }
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl SpkKernel {
+  pub fn get_segments(&self) -> &Vec<SpkSegment> {&self.segments}
+  pub fn is_loaded(&self) -> bool {!self.segments.is_empty()}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl SpkKernel {
+ /*
+  * @brief: Function to load a binary SPK kernel and read the segment
+  *         summaries
+  */
+  pub fn load(&mut self, filepath: &str)
+  {
+    self.data = fs::read(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > SPK file {} not found! <", filepath));
+
+    if self.data.len() < DAF_RECORD_SIZE_BYTES || &self.data[0..7] != b"DAF/SPK"
+    {
+      panic!("! [ERROR] ! > {} is not a binary SPK kernel! <", filepath);
+    }
+    /* The number of double components of a summary is 2 for SPK */
+    self.is_little_endian = i32::from_le_bytes(self.data[8..12].try_into().unwrap()) == SPK_SUMMARY_NUM_DOUBLES as i32;
+
+    let num_doubles: usize = self.read_i32(8) as usize;
+    let num_integers: usize = self.read_i32(12) as usize;
+    let summary_size: usize = num_doubles + num_integers.div_ceil(2);
+
+    /* Walk through the linked list of summary records */
+    self.segments.clear();
+    let mut num_skipped_segments: usize = 0;
+    let mut record_number: usize = self.read_i32(76) as usize;
+    while record_number > 0
+    {
+      let record_index: usize = (record_number - 1) * DAF_RECORD_SIZE_BYTES / 8;
+      let num_summaries: usize = self.read_f64(record_index + 2) as usize;
+
+      for n in 0..num_summaries
+      {
+        let summary_index: usize = record_index + 3 + n * summary_size;
+        let integer_offset: usize = (summary_index + num_doubles) * 8;
+        let integers: Vec<i32> = (0..num_integers).map(|m| self.read_i32(integer_offset + 4 * m))
+                                                  .collect();
+        let data_type: i32 = integers[3];
+
+        if integers[2] != SPK_FRAME_J2000 || (data_type != 2 && data_type != 3)
+        {
+          num_skipped_segments += 1;
+          continue;
+        }
+
+        /* Segment directory at the end of the segment */
+        let end_index: usize = integers[5] as usize - 1;
+        self.segments.push(SpkSegment {
+          target: integers[0],
+          center: integers[1],
+          start_et_s: self.read_f64(summary_index),
+          end_et_s: self.read_f64(summary_index + 1),
+          data_type,
+          init_et_s: self.read_f64(end_index - 3),
+          interval_length_s: self.read_f64(end_index - 2),
+          record_size: self.read_f64(end_index - 1) as usize,
+          num_records: self.read_f64(end_index) as usize,
+          start_index: integers[4] as usize - 1,
+        });
+      }
+      record_number = self.read_f64(record_index) as usize;
+    }
+
+    if self.segments.is_empty()
+    {
+      panic!("! [ERROR] ! > No supported segments (type 2/3, J2000 frame) found in {}! <", filepath);
+    }
+    if num_skipped_segments > 0
+    {
+      println!("[WRN] Skipped {} unsupported SPK segments in {}", num_skipped_segments, filepath);
+    }
+    println!("[x] Loaded {} SPK segments from {}", self.segments.len(), filepath);
+  }
+
+ /*
+  * @brief: Function to compute the position of a body relative to another
+  *         body
+  *
+  * @param[in] target   - NAIF ID of the target body
+  * @param[in] observer - NAIF ID of the observing body (origin)
+  * @param[in] et_s     - Ephemeris time, TDB seconds since J2000
+  *
+  * @returns: Position vector of the target relative to the observer
+  * @unit: m
+  * @frame: J2000 (ICRF)
+  */
+  pub fn calc_pos_m(&self, target: i32, observer: i32, et_s: f64)
+  -> Array1<f64>
+  {
+    (self.calc_pos_wrt_ssb_km(target, et_s) - self.calc_pos_wrt_ssb_km(observer, et_s)) * 1000.0
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+impl SpkKernel {
+ /*
+  * @brief: Function to compute the position of a body relative to the solar
+  *         system barycentre by chaining the segments of the body
+  *
+  * @unit: km
+  */
+  fn calc_pos_wrt_ssb_km(&self, body: i32, et_s: f64)
+  -> Array1<f64>
+  {
+    let mut pos_km: Array1<f64> = Array1::zeros(3);
+    let mut current_body: i32 = body;
+    while current_body != NAIF_ID_SOLAR_SYSTEM_BARYCENTER
+    {
+      let segment: &SpkSegment = self.find_segment(current_body, et_s);
+      pos_km = pos_km + self.eval_segment(segment, et_s);
+      current_body = segment.center;
+    }
+    pos_km
+  }
+
+ /*
+  * @brief: Function to find the segment of a body covering the epoch. The
+  *         last segment in the file takes precedence.
+  */
+  fn find_segment(&self, body: i32, et_s: f64)
+  -> &SpkSegment
+  {
+    self.segments.iter()
+                 .rev()
+                 .find(|segment| segment.target == body
+                                 && et_s >= segment.start_et_s
+                                 && et_s <= segment.end_et_s)
+                 .unwrap_or_else(|| panic!("! [ERROR] ! > No SPK segment for body {} at ET {} s! <", body, et_s))
+  }
+
+ /*
+  * @brief: Function to evaluate the Chebyshev polynomials of a segment
+  *
+  * @unit: km
+  */
+  fn eval_segment(&self, segment: &SpkSegment, et_s: f64)
+  -> Array1<f64>
+  {
+    let num_components: usize = if segment.data_type == 2 {3} else {6};
+    let num_coefficients: usize = (segment.record_size - 2) / num_components;
+
+    let record: usize = (((et_s - segment.init_et_s) / segment.interval_length_s).floor().max(0.0) as usize)
+                        .min(segment.num_records - 1);
+    let record_index: usize = segment.start_index + record * segment.record_size;
+
+    /* Normalized time within the record, [-1, 1] */
+    let tau: f64 = (et_s - self.read_f64(record_index)) / self.read_f64(record_index + 1);
+
+    let mut chebyshev: Vec<f64> = vec![1.0; num_coefficients];
+    if num_coefficients > 1
+    {
+      chebyshev[1] = tau;
+    }
+    for n in 2..num_coefficients
+    {
+      chebyshev[n] = 2.0 * tau * chebyshev[n - 1] - chebyshev[n - 2];
+    }
+
+    Array1::from_iter((0..3).map(|component| {
+      let coefficient_index: usize = record_index + 2 + component * num_coefficients;
+      chebyshev.iter()
+               .enumerate()
+               .map(|(n, t_n)| self.read_f64(coefficient_index + n) * t_n)
+               .sum::<f64>()
+    }))
+  }
+
+ /*
+  * @brief: Function to read the double at the given index (in doubles) of the
+  *         file
+  */
+  fn read_f64(&self, index: usize) -> f64
+  {
+    let bytes: [u8; 8] = self.data[8 * index..8 * index + 8].try_into().unwrap();
+    if self.is_little_endian {f64::from_le_bytes(bytes)} else {f64::from_be_bytes(bytes)}
+  }
+
+ /*
+  * @brief: Function to read the integer at the given byte offset of the file
+  */
+  fn read_i32(&self, offset: usize) -> i32
+  {
+    let bytes: [u8; 4] = self.data[offset..offset + 4].try_into().unwrap();
+    if self.is_little_endian {i32::from_le_bytes(bytes)} else {i32::from_be_bytes(bytes)}
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::env;
+
+  /* Segment directory of the synthetic type 2 segments: start epoch and
+   * length of the two Chebyshev intervals, three coefficients per component */
+  const INIT_ET_S: f64 = -86400.0;
+  const INTERVAL_LENGTH_S: f64 = 86400.0;
+  const NUM_COEFFICIENTS: usize = 3;
+  const NUM_RECORDS: usize = 2;
+  const RECORD_SIZE: usize = 2 + 3 * NUM_COEFFICIENTS;
+
+  /*
+   * @brief: Chebyshev coefficients [km] of a record of a body, component
+   *         major
+   */
+  fn calc_coefficients(target: i32, record: usize) -> [f64; 3 * NUM_COEFFICIENTS]
+  {
+    let scale: f64 = target as f64 + record as f64;
+    [1.0e5 * scale, 2.0e3, -5.0e2,
+     -3.0e4 * scale, 7.5e2, 1.25e2,
+     4.0e2 * scale, -6.0e1, 3.0e1]
+  }
+
+  /*
+   * @brief: Reference position [km] and velocity [km/s] of a body from its
+   *         Chebyshev expansion T0 = 1, T1 = tau, T2 = 2 tau^2 - 1
+   */
+  fn calc_reference_state(target: i32, et_s: f64) -> ([f64; 3], [f64; 3])
+  {
+    let record: usize = (((et_s - INIT_ET_S) / INTERVAL_LENGTH_S).floor() as usize).min(NUM_RECORDS - 1);
+    let radius_s: f64 = 0.5 * INTERVAL_LENGTH_S;
+    let tau: f64 = (et_s - (INIT_ET_S + (record as f64 + 0.5) * INTERVAL_LENGTH_S)) / radius_s;
+    let coefficients: [f64; 3 * NUM_COEFFICIENTS] = calc_coefficients(target, record);
+
+    let mut pos_km: [f64; 3] = [0.0; 3];
+    let mut vel_kmps: [f64; 3] = [0.0; 3];
+    for component in 0..3
+    {
+      let c: &[f64] = &coefficients[component * NUM_COEFFICIENTS..(component + 1) * NUM_COEFFICIENTS];
+      pos_km[component] = c[0] + c[1] * tau + c[2] * (2.0 * tau * tau - 1.0);
+      vel_kmps[component] = (c[1] + 4.0 * c[2] * tau) / radius_s;
+    }
+    (pos_km, vel_kmps)
+  }
+
+  /*
+   * @brief: Build a DAF/SPK file with the segments EMB -> SSB and Earth -> EMB
+   *         (type 2, J2000) and an unsupported segment (type 2, other frame)
+   *
+   * @description: Record 1 - file record, record 2 - summary record,
+   *               record 3 - name record, records 4ff - segment data
+   */
+  fn build_spk_file(is_little_endian: bool) -> Vec<u8>
+  {
+    let write_f64 = |data: &mut Vec<u8>, index: usize, value: f64| {
+      let bytes: [u8; 8] = if is_little_endian {value.to_le_bytes()} else {value.to_be_bytes()};
+      data[8 * index..8 * index + 8].copy_from_slice(&bytes);
+    };
+    let write_i32 = |data: &mut Vec<u8>, offset: usize, value: i32| {
+      let bytes: [u8; 4] = if is_little_endian {value.to_le_bytes()} else {value.to_be_bytes()};
+      data[offset..offset + 4].copy_from_slice(&bytes);
+    };
+
+    let segments: [(i32, i32, i32); 3] = [(NAIF_ID_EARTH_MOON_BARYCENTER, NAIF_ID_SOLAR_SYSTEM_BARYCENTER, SPK_FRAME_J2000),
+                                          (NAIF_ID_EARTH, NAIF_ID_EARTH_MOON_BARYCENTER, SPK_FRAME_J2000),
+                                          (NAIF_ID_MOON, NAIF_ID_EARTH_MOON_BARYCENTER, 17)];
+    let segment_size: usize = NUM_RECORDS * RECORD_SIZE + 4;
+    let mut data: Vec<u8> = vec![0; 3 * DAF_RECORD_SIZE_BYTES + segments.len() * segment_size * 8];
+
+    /* File record */
+    data[0..8].copy_from_slice(b"DAF/SPK ");
+    write_i32(&mut data, 8, SPK_SUMMARY_NUM_DOUBLES as i32);
+    write_i32(&mut data, 12, 6);
+    write_i32(&mut data, 76, 2);
+    write_i32(&mut data, 80, 2);
+
+    /* Summary record: next, previous, number of summaries */
+    let summary_record_index: usize = DAF_RECORD_SIZE_BYTES / 8;
+    write_f64(&mut data, summary_record_index + 2, segments.len() as f64);
+
+    for (n, (target, center, frame)) in segments.iter().enumerate()
+    {
+      let start_index: usize = 3 * DAF_RECORD_SIZE_BYTES / 8 + n * segment_size;
+      let summary_index: usize = summary_record_index + 3 + n * 5;
+      write_f64(&mut data, summary_index, INIT_ET_S);
+      write_f64(&mut data, summary_index + 1, INIT_ET_S + NUM_RECORDS as f64 * INTERVAL_LENGTH_S);
+      for (m, value) in [*target, *center, *frame, 2, start_index as i32 + 1, (start_index + segment_size) as i32]
+                          .iter().enumerate()
+      {
+        write_i32(&mut data, (summary_index + 2) * 8 + 4 * m, *value);
+      }
+
+      for record in 0..NUM_RECORDS
+      {
+        let record_index: usize = start_index + record * RECORD_SIZE;
+        write_f64(&mut data, record_index, INIT_ET_S + (record as f64 + 0.5) * INTERVAL_LENGTH_S);
+        write_f64(&mut data, record_index + 1, 0.5 * INTERVAL_LENGTH_S);
+        for (k, coefficient) in calc_coefficients(*target, record).iter().enumerate()
+        {
+          write_f64(&mut data, record_index + 2 + k, *coefficient);
+        }
+      }
+      let directory_index: usize = start_index + NUM_RECORDS * RECORD_SIZE;
+      for (k, value) in [INIT_ET_S, INTERVAL_LENGTH_S, RECORD_SIZE as f64, NUM_RECORDS as f64].iter().enumerate()
+      {
+        write_f64(&mut data, directory_index + k, *value);
+      }
+    }
+    data
+  }
+
+  /*
+   * @brief: Write the synthetic kernel to a temporary file and load it
+   */
+  fn load_spk_kernel(is_little_endian: bool) -> SpkKernel
+  {
+    let filepath = env::temp_dir().join(format!("rdke_test_{}_{}.bsp",
+                                                if is_little_endian {"le"} else {"be"},
+                                                std::process::id()));
+    fs::write(&filepath, build_spk_file(is_little_endian)).unwrap();
+    let mut kernel: SpkKernel = SpkKernel::new();
+    kernel.load(filepath.to_str().unwrap());
+    fs::remove_file(&filepath).unwrap();
+    kernel
+  }
+
+  #[test]
+  fn daf_summaries()
+  {
+    for is_little_endian in [true, false]
+    {
+      let kernel: SpkKernel = load_spk_kernel(is_little_endian);
+      assert_eq!(kernel.is_little_endian, is_little_endian);
+
+      /* The segment in the unsupported frame is skipped */
+      let segments: &Vec<SpkSegment> = kernel.get_segments();
+      assert_eq!(segments.len(), 2);
+      assert_eq!((segments[0].target, segments[0].center), (NAIF_ID_EARTH_MOON_BARYCENTER, NAIF_ID_SOLAR_SYSTEM_BARYCENTER));
+      assert_eq!((segments[1].target, segments[1].center), (NAIF_ID_EARTH, NAIF_ID_EARTH_MOON_BARYCENTER));
+      for segment in segments
+      {
+        assert_eq!(segment.data_type, 2);
+        assert_eq!(segment.start_et_s, INIT_ET_S);
+        assert_eq!(segment.end_et_s, INIT_ET_S + NUM_RECORDS as f64 * INTERVAL_LENGTH_S);
+        assert_eq!(segment.init_et_s, INIT_ET_S);
+        assert_eq!(segment.interval_length_s, INTERVAL_LENGTH_S);
+        assert_eq!(segment.record_size, RECORD_SIZE);
+        assert_eq!(segment.num_records, NUM_RECORDS);
+      }
+      assert_eq!(segments[1].start_index, segments[0].start_index + NUM_RECORDS * RECORD_SIZE + 4);
+    }
+  }
+
+  #[test]
+  fn chebyshev_position_and_derivative()
+  {
+    let kernel_le: SpkKernel = load_spk_kernel(true);
+    let kernel_be: SpkKernel = load_spk_kernel(false);
+    let dt_s: f64 = 10.0;
+
+    for et_s in [-80000.0, -1000.0, 1000.0, 43200.0, 86000.0]
+    {
+      let (pos_emb_km, vel_emb_kmps) = calc_reference_state(NAIF_ID_EARTH_MOON_BARYCENTER, et_s);
+      let (pos_earth_km, vel_earth_kmps) = calc_reference_state(NAIF_ID_EARTH, et_s);
+
+      let pos_m: Array1<f64> = kernel_le.calc_pos_m(NAIF_ID_EARTH, NAIF_ID_SOLAR_SYSTEM_BARYCENTER, et_s);
+      assert_eq!(pos_m, kernel_be.calc_pos_m(NAIF_ID_EARTH, NAIF_ID_SOLAR_SYSTEM_BARYCENTER, et_s));
+
+      /* Central difference of the quadratic expansion is exact within a
+       * record, up to the rounding of the positions */
+      let vel_ms: Array1<f64> = (kernel_le.calc_pos_m(NAIF_ID_EARTH, NAIF_ID_SOLAR_SYSTEM_BARYCENTER, et_s + dt_s)
+                                 - kernel_le.calc_pos_m(NAIF_ID_EARTH, NAIF_ID_SOLAR_SYSTEM_BARYCENTER, et_s - dt_s))
+                                / (2.0 * dt_s);
+
+      for component in 0..3
+      {
+        let pos_ref_m: f64 = (pos_emb_km[component] + pos_earth_km[component]) * 1000.0;
+        let vel_ref_ms: f64 = (vel_emb_kmps[component] + vel_earth_kmps[component]) * 1000.0;
+        assert!((pos_m[component] - pos_ref_m).abs() < 1.0e-6 * pos_ref_m.abs().max(1.0));
+        assert!((vel_ms[component] - vel_ref_ms).abs() < 1.0e-5 * vel_ref_ms.abs().max(1.0));
+      }
+
+      /* Position relative to another body of the chain */
+      let pos_earth_wrt_emb_m: Array1<f64> = kernel_le.calc_pos_m(NAIF_ID_EARTH, NAIF_ID_EARTH_MOON_BARYCENTER, et_s);
+      for component in 0..3
+      {
+        assert!((pos_earth_wrt_emb_m[component] - pos_earth_km[component] * 1000.0).abs()
+                < 1.0e-6 * (pos_earth_km[component] * 1000.0).abs().max(1.0));
+      }
+    }
+  }
+}
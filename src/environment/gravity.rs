@@ -1,2 +1,7 @@
+pub mod gravity;
 
-pub mod gravity;
\ No newline at end of file
+pub mod gravity_model;
+
+pub mod spherical_harmonics;
+
+pub mod zonal;
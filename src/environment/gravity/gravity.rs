@@ -1,48 +1,28 @@
 /* Include external crates */
-use ndarray::{Array1, ArrayView1, s};
+use ndarray::{Array1, Array2, s};
 
 /* Include local crates */
 use crate::environment::environment::Environment;
-use crate::math::vec_math::{l2_norm_array1, normalize_array1};
+use crate::environment::gravity::gravity_model::GravityModelType;
+use crate::environment::gravity::zonal;
+use crate::environment::planet::earth_orientation::EarthOrientation;
+use crate::math::epoch::Epoch;
+use crate::math::vec_math::{l2_norm_array1, normalize_array1, cross_product_array1};
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
 
 /* Include constants */
 use crate::constants::state::*;
+use crate::constants::general::*;
 
 pub fn get_force_vec_pci(state_in: &Array1<f64>, environment: &Environment) -> Array1<f64>
-{ 
-  /* Initialize Array1 to store gravity force 
-   * @unit: m/ss
-   * */
-  let grav_acceleration_mss: f64 = get_grav_acc(state_in, &environment);
-
-  let mut position_vec_xyz_m: Array1<f64> = Array1::zeros(3);
-  position_vec_xyz_m.assign(&state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]));
-  let local_radius_m: f64 = l2_norm_array1(position_vec_xyz_m.view());
-  
+{
   let sc_mass_kg: f64 = state_in[STATE_VEC_INDX_MASS];
-  
- /* Initialise output vector to store the gravitational force  
+
+ /* Compute vector of the gravitational force on the S/C
   * @unit: Newton
   * @frame: PCI
   */
-  let mut grav_force_pci_n_out: Array1<f64> = Array1::zeros(3);
-  
- /* Check position is at least 10 cm away from the center of the inertial 
-  * planet centered frame in which case the gravitational force cannot be 
-  * computed
-  * */
-  if local_radius_m > 0.1
-  {
-    /* Comppute normalized direction vector of the gravitational acceleration */
-    let grav_acc_dir_norm: Array1<f64> = -1.0 * normalize_array1(position_vec_xyz_m);
-    /* Compute vector of the gravitational force on the S/C  */
-    grav_force_pci_n_out = grav_acceleration_mss * grav_acc_dir_norm * sc_mass_kg; 
-  }
-  else 
-  {
-    println!("[ERR] Computing gravitation force failed. PCI Position is [0,0,0]");
-  }
-  grav_force_pci_n_out
+  get_acc_vec_pci(state_in, environment) * sc_mass_kg
 }
 
 /*
@@ -51,28 +31,163 @@ pub fn get_force_vec_pci(state_in: &Array1<f64>, environment: &Environment) -> A
  */
 pub fn get_grav_acc(state_in: &Array1<f64>, environment: &Environment)
 -> f64
+{
+  l2_norm_array1(get_acc_vec_pci(state_in, environment).view())
+}
+
+/*
+ * @brief: Function to compute the gravity gradient torque acting on the 
+ *         spacecraft from its inertia tensor (point mass central body)
+ *
+ *         T = 3 * mu / r^5 * r_b x (I * r_b)
+ *
+ *         Ref: Wertz - Spacecraft Attitude Determination and Control, 17.2
+ *
+ * @unit: Nm
+ * @frame: Body
+ */
+pub fn get_gravity_gradient_torque_vec_b(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  /* Position of the spacecraft in the body frame */
+  let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+  let pos_b_m: Array1<f64> = dcm_pci_to_b.dot(&state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]));
+  let radius_m: f64 = l2_norm_array1(pos_b_m.view());
+
+  let inertia_pos_b: Array1<f64> = environment.get_spacecraft().get_sc_inertia_tensor_kgmm().dot(&pos_b_m);
+  let grav_grad_torque_b_nm: Array1<f64> = 3.0 * environment.get_planet().get_gravitational_constant() 
+                                           / radius_m.powi(5)
+                                           * cross_product_array1(pos_b_m.view(), inertia_pos_b.view());
+
+  environment.get_mut_spacecraft().set_grav_grad_torque_b_nm_x(&grav_grad_torque_b_nm[VEC_X]);
+  environment.get_mut_spacecraft().set_grav_grad_torque_b_nm_y(&grav_grad_torque_b_nm[VEC_Y]);
+  environment.get_mut_spacecraft().set_grav_grad_torque_b_nm_z(&grav_grad_torque_b_nm[VEC_Z]);
+
+  grav_grad_torque_b_nm
+}
+
+/*
+ * @brief: Function to compute the gravitational acceleration vector from the
+ *         gravity model selected in gravity.ini
+ *
+ * @unit: m/second_squared
+ * @frame: PCI
+ */
+pub fn get_acc_vec_pci(state_in: &Array1<f64>, environment: &Environment)
+-> Array1<f64>
+{
+  match environment.get_planet().get_gravity_model().get_model_type()
+  {
+    GravityModelType::Simple => get_simple_acc_vec_pci(state_in, environment),
+    GravityModelType::PointMassZonal => get_zonal_acc_vec_pci(state_in, environment),
+    GravityModelType::SphericalHarmonics => get_spherical_harmonics_acc_vec_pci(state_in, environment)
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the gravitational acceleration vector from a
+ *         simple inverse square law scaled from the standard surface gravity.
+ *
+ * @unit: m/second_squared
+ * @frame: PCI
+ */
+fn get_simple_acc_vec_pci(state_in: &Array1<f64>, environment: &Environment)
+-> Array1<f64>
 {
   /* Get S/C position in PCI */
   let mut position_vec_xyz_m: Array1<f64> = Array1::zeros(3);
   position_vec_xyz_m.assign(&state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]));
+  let local_radius_m: f64 = l2_norm_array1(position_vec_xyz_m.view());
 
-  /* Estimate local radius of the planet at the longitude and latitude of the 
+  /* Estimate local radius of the planet at the longitude and latitude of the
      current S/C's position */
-  let mean_radius_m: f64 = ( environment.get_planet().get_semi_major_axis() 
+  let mean_radius_m: f64 = ( environment.get_planet().get_semi_major_axis()
       + environment.get_planet().get_semi_minor_axis()) * 0.5;
   /*
-   * Note: We can compare the radii in PCI vs PCPF because we only look at the 
-   *       magnitude of both vectors and both coordinate systems share the same 
-   *       origin.  
+   * Note: We can compare the radii in PCI vs PCPF because we only look at the
+   *       magnitude of both vectors and both coordinate systems share the same
+   *       origin.
    */
-  let local_altitude_m: f64 = l2_norm_array1(position_vec_xyz_m.view())
-                              - mean_radius_m;
+  let local_altitude_m: f64 = local_radius_m - mean_radius_m;
 
-  /* Initialize Array1 to store gravity force 
+  /* Initialize Array1 to store gravity force
   * @unit: m/ss
   * */
-  let grav_acceleration_mss: f64 = 9.80665 * (mean_radius_m 
-    * (1.0 / (mean_radius_m + local_altitude_m))).powf(2.0); 
+  let grav_acceleration_mss: f64 = 9.80665 * (mean_radius_m
+    * (1.0 / (mean_radius_m + local_altitude_m))).powf(2.0);
+
+ /* Initialise output vector to store the gravitational acceleration
+  * @unit: m/ss
+  * @frame: PCI
+  */
+  let mut grav_acc_pci_mss_out: Array1<f64> = Array1::zeros(3);
 
-  grav_acceleration_mss
-}
\ No newline at end of file
+ /* Check position is at least 10 cm away from the center of the inertial
+  * planet centered frame in which case the gravitational force cannot be
+  * computed
+  * */
+  if local_radius_m > 0.1
+  {
+    /* Comppute normalized direction vector of the gravitational acceleration */
+    let grav_acc_dir_norm: Array1<f64> = -normalize_array1(position_vec_xyz_m);
+    grav_acc_pci_mss_out = grav_acceleration_mss * grav_acc_dir_norm;
+  }
+  else
+  {
+    println!("[ERR] Computing gravitation force failed. PCI Position is [0,0,0]");
+  }
+  grav_acc_pci_mss_out
+}
+
+/*
+ * @brief: Function to compute the gravitational acceleration vector from the
+ *         planet's gravitational constant and the zonal terms J2 to J6 
+ *         configured in gravity.ini
+ *
+ * @unit: m/second_squared
+ * @frame: PCI
+ */
+fn get_zonal_acc_vec_pci(state_in: &Array1<f64>, environment: &Environment)
+-> Array1<f64>
+{
+  zonal::get_acc_vec_pci(state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]),
+                         *environment.get_planet().get_gravitational_constant(),
+                         *environment.get_planet().get_semi_major_axis(),
+                         environment.get_planet().get_gravity_model().get_zonal_coefficients())
+}
+
+/*
+ * @brief: Function to compute the gravitational acceleration vector from the
+ *         spherical harmonic expansion of the gravity field.
+ *
+ * @description: The expansion is evaluated in the planet fixed frame. The
+ *               position is rotated from PCI into PCPF with the planet's
+ *               orientation model and the resulting acceleration is rotated
+ *               back into PCI.
+ *
+ * @unit: m/second_squared
+ * @frame: PCI
+ */
+fn get_spherical_harmonics_acc_vec_pci(state_in: &Array1<f64>, environment: &Environment)
+-> Array1<f64>
+{
+  /* Rotate S/C position into planet fixed frame */
+  let epoch: Epoch = Epoch::from_state_vec(state_in.view());
+  let earth_orientation: &EarthOrientation = environment.get_planet().get_earth_orientation();
+  let pos_pcpf_m: Array1<f64> = earth_orientation
+    .convert_pci_to_pcpf(state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]), &epoch);
+
+  let acc_pcpf_mss: Array1<f64> = environment.get_planet()
+                                             .get_gravity_model()
+                                             .get_spherical_harmonics()
+                                             .get_acc_vec_pcpf(pos_pcpf_m.view());
+
+  /* Rotate acceleration back into the inertial frame */
+  earth_orientation.convert_pcpf_to_pci(acc_pcpf_mss.view(), &epoch)
+}
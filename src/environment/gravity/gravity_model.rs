@@ -0,0 +1,151 @@
+/*
+ * @brief: This struct holds the configuration and data of the gravity model
+ *         selected for the central body.
+ *
+ * @description: The gravity model is selected with the gravity_model
+ *               parameter in gravity.ini. Available models are:
+ *
+ *               * simple              - Inverse square law scaled from the
+ *                                       standard surface gravity
+ *               * point_mass_zonal    - Point mass gravity from the planet's
+ *                                       gravitational constant with analytic
+ *                                       zonal terms J2 to J6
+ *               * spherical_harmonics - Spherical harmonic expansion loaded
+ *                                       from an ICGEM coefficient file
+ *
+ */
+
+/* Include local crates */
+use crate::environment::gravity::spherical_harmonics::SphericalHarmonics;
+
+/* Import constants */
+use crate::constants::gravity::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum GravityModelType {
+  Simple,
+  PointMassZonal,
+  SphericalHarmonics
+}
+
+impl GravityModelType {
+ /*
+  * @brief: Function to map the gravity_model parameter string to the model
+  *         type.
+  */
+  pub fn from_param_str(model_str: &str) -> GravityModelType
+  {
+    match model_str.trim().trim_matches('"')
+    {
+      "simple" => GravityModelType::Simple,
+      "point_mass_zonal" => GravityModelType::PointMassZonal,
+      "spherical_harmonics" => GravityModelType::SphericalHarmonics,
+      _ => panic!("! [ERROR] ! > Unknown gravity model {} in gravity.ini! <", model_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct GravityModel {
+  /* [gravity model type]
+   * @description : Selected gravity model
+   * @unit        : N/A
+   *
+   * */
+  model_type: GravityModelType,
+  /* [coefficient file path]
+   * @description : Path to the ICGEM (.gfc) spherical harmonic coefficient file
+   * @unit        : N/A
+   *
+   * */
+  coefficient_file_path: String,
+  /* [zonal coefficients]
+   * @description : Unnormalized zonal coefficients Jn of the point_mass_zonal
+   *                model indexed by degree n (J0 and J1 are unused)
+   * @unit        : N/A
+   *
+   * */
+  zonal_coefficients: Vec<f64>,
+  /* [degree and order]
+   * @description : Maximum degree and order of the spherical harmonic
+   *                expansion
+   * @unit        : N/A
+   *
+   * */
+  max_degree: usize,
+  max_order: usize,
+  /* [spherical harmonics struct]
+   * @description : Spherical harmonic coefficients and evaluation
+   * @unit        : N/A
+   *
+   * */
+  spherical_harmonics: SphericalHarmonics
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl GravityModel {
+  pub fn new() -> GravityModel {
+    GravityModel {
+      model_type: GravityModelType::Simple,
+      coefficient_file_path: String::new(),
+      zonal_coefficients: vec![0.0; GRAV_ZONAL_MAX_DEGREE + 1],
+      max_degree: 0,
+      max_order: 0,
+      spherical_harmonics: SphericalHarmonics::new()
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. This function usually contains file loaders.
+   */
+  pub fn init(&mut self)
+  {
+    println!("[x] Initialize gravity model: {:?}", self.model_type);
+    if self.model_type == GravityModelType::SphericalHarmonics
+    {
+      let filepath: String = self.coefficient_file_path.clone();
+      self.spherical_harmonics.load_coefficients(&filepath, self.max_degree, self.max_order);
+    }
+  }
+}
+
+impl Default for GravityModel {
+  fn default() -> GravityModel {
+    GravityModel::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl GravityModel {
+  pub fn set_model_type(&mut self, val_in: &GravityModelType) {self.model_type = *val_in;}
+  pub fn set_coefficient_file_path(&mut self, val_in: &str) {self.coefficient_file_path = val_in.trim_matches('"').to_string();}
+  pub fn set_zonal_coefficient(&mut self, degree: usize, val_in: &f64) {self.zonal_coefficients[degree] = *val_in;}
+  pub fn set_max_degree(&mut self, val_in: &usize) {self.max_degree = *val_in;}
+  pub fn set_max_order(&mut self, val_in: &usize) {self.max_order = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl GravityModel {
+  pub fn get_model_type(&self) -> &GravityModelType {&self.model_type}
+  pub fn get_coefficient_file_path(&self) -> &String {&self.coefficient_file_path}
+  pub fn get_zonal_coefficients(&self) -> &Vec<f64> {&self.zonal_coefficients}
+  pub fn get_max_degree(&self) -> &usize {&self.max_degree}
+  pub fn get_max_order(&self) -> &usize {&self.max_order}
+  pub fn get_spherical_harmonics(&self) -> &SphericalHarmonics {&self.spherical_harmonics}
+}
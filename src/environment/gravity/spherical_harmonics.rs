@@ -0,0 +1,418 @@
+/*
+ * @brief: Spherical harmonic expansion of the central body's gravitational
+ *         potential.
+ *
+ * @description: The gravity field is described by fully normalized Stokes
+ *               coefficients Cnm/Snm which are loaded from an ICGEM style
+ *               coefficient file (.gfc). This allows to use EGM96, EGM2008,
+ *               GGM03 and other models published by the International Centre
+ *               for Global Earth Models (ICGEM).
+ *
+ *               The acceleration is computed from the gradient of the
+ *               potential in spherical coordinates (radius, geocentric latitude,
+ *               longitude) and transformed into cartesian PCPF coordinates.
+ *
+ *               See: Vallado, Fundamentals of Astrodynamics and Applications,
+ *                    4th edition, Section 8.6.1
+ *
+ *               See: http://icgem.gfz-potsdam.de/ICGEM-Format-2023.pdf
+ *
+ */
+
+/* Include external crates */
+use std::fs;
+use ndarray::{Array1, ArrayView1};
+
+/* Include local crates */
+/* None */
+
+/* Import constants */
+use crate::constants::general::*;
+
+#[derive(Clone)]
+
+pub struct SphericalHarmonics {
+  /*
+   * @description : Gravitational constant the coefficients have been derived
+   *                with (read from the coefficient file)
+   * @unit        : m3/s2
+   *
+   * */
+  gravitational_constant: f64,
+  /*
+   * @description : Reference radius the coefficients have been derived with
+   *                (read from the coefficient file)
+   * @unit        : m
+   *
+   * */
+  reference_radius_m: f64,
+  /*
+   * @description : Maximum degree (n) used to evaluate the expansion
+   * @unit        : N/A
+   *
+   * */
+  max_degree: usize,
+  /*
+   * @description : Maximum order (m) used to evaluate the expansion
+   * @unit        : N/A
+   *
+   * */
+  max_order: usize,
+  /*
+   * @description : Fully normalized coefficients Cnm and Snm stored as
+   *                triangular matrices indexed [n][m]
+   * @unit        : N/A
+   *
+   * */
+  coeff_c: Vec<Vec<f64>>,
+  coeff_s: Vec<Vec<f64>>
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl SphericalHarmonics {
+  pub fn new() -> SphericalHarmonics {
+    SphericalHarmonics {
+      gravitational_constant: 0.0,
+      reference_radius_m: 0.0,
+      max_degree: 0,
+      max_order: 0,
+      coeff_c: Vec::new(),
+      coeff_s: Vec::new()
+    }
+  }
+}
+
+impl Default for SphericalHarmonics {
+  fn default() -> SphericalHarmonics {
+    SphericalHarmonics::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl SphericalHarmonics {
+  pub fn get_gravitational_constant(&self) -> &f64 {&self.gravitational_constant}
+  pub fn get_reference_radius_m(&self) -> &f64 {&self.reference_radius_m}
+  pub fn get_max_degree(&self) -> &usize {&self.max_degree}
+  pub fn get_max_order(&self) -> &usize {&self.max_order}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ * */
+impl SphericalHarmonics {
+ /*
+  * @brief: Read gravity field coefficients from an ICGEM (.gfc) file up to a
+  *         given degree and order. This function shall be called before
+  *         running the simulation!
+  *
+  * @description: The header is parsed for the gravitational constant, the
+  *               reference radius and the normalization. Coefficients stored
+  *               unnormalized are converted to fully normalized values.
+  *               Time variable terms (trnd, asin, acos) are ignored, gfct
+  *               records are used with their reference epoch values.
+  *
+  * @param[in] filepath   - Path to the coefficient file
+  * @param[in] max_degree - Maximum degree to load
+  * @param[in] max_order  - Maximum order to load (capped to max_degree)
+  *
+  */
+  pub fn load_coefficients(&mut self, filepath: &str, max_degree: usize, max_order: usize)
+  {
+    let file_content: String = fs::read_to_string(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > Gravity coefficient file {} not found! <", filepath));
+
+    self.max_degree = max_degree;
+    self.max_order = max_order.min(max_degree);
+
+    /* Initialize triangular coefficient matrices */
+    self.coeff_c = (0..=max_degree).map(|n| vec![0.0; n + 1]).collect();
+    self.coeff_s = (0..=max_degree).map(|n| vec![0.0; n + 1]).collect();
+
+    let mut is_header: bool = true;
+    let mut is_normalized: bool = true;
+    let mut file_max_degree: usize = 0;
+
+    for line in file_content.lines()
+    {
+      let fields: Vec<&str> = line.split_whitespace().collect();
+      if fields.is_empty()
+      {
+        continue;
+      }
+
+      if is_header
+      {
+        match fields[0]
+        {
+          "earth_gravity_constant" | "gravity_constant" =>
+            self.gravitational_constant = parse_gfc_f64(fields[1]),
+          "radius" => self.reference_radius_m = parse_gfc_f64(fields[1]),
+          "max_degree" => file_max_degree = fields[1].parse::<usize>().unwrap(),
+          "norm" => is_normalized = fields[1] != "unnormalized",
+          "end_of_head" => is_header = false,
+          _ => {}
+        }
+        continue;
+      }
+
+      if fields[0] != "gfc" && fields[0] != "gfct"
+      {
+        continue;
+      }
+
+      let n: usize = fields[1].parse::<usize>().unwrap();
+      let m: usize = fields[2].parse::<usize>().unwrap();
+      if n > self.max_degree || m > self.max_order
+      {
+        continue;
+      }
+
+      let mut c_nm: f64 = parse_gfc_f64(fields[3]);
+      let mut s_nm: f64 = parse_gfc_f64(fields[4]);
+      if !is_normalized
+      {
+        let norm_factor: f64 = calc_normalization_factor(n, m);
+        c_nm /= norm_factor;
+        s_nm /= norm_factor;
+      }
+      self.coeff_c[n][m] = c_nm;
+      self.coeff_s[n][m] = s_nm;
+    }
+
+    if self.gravitational_constant == 0.0 || self.reference_radius_m == 0.0
+    {
+      panic!("! [ERROR] ! > Gravity coefficient file {} is missing gravity constant or radius in its header! <", filepath);
+    }
+    if file_max_degree != 0 && file_max_degree < self.max_degree
+    {
+      println!("[WRN] Requested gravity degree {} exceeds the maximum degree {} of {}. Missing coefficients are set to zero.",
+               self.max_degree, file_max_degree, filepath);
+    }
+
+    /* The central term is implicit in some coefficient files */
+    self.coeff_c[0][0] = 1.0;
+
+    println!("[x] Loaded gravity field coefficients {}x{} from {}",
+             self.max_degree, self.max_order, filepath);
+  }
+
+ /*
+  * @brief: Set the expansion from fully normalized coefficients in memory
+  *
+  * Note: Also used to evaluate other potentials of the same form, e.g. the
+  *       geomagnetic scalar potential (see magnetic_field/igrf.rs)
+  *
+  * @param[in] gravitational_constant - Scale factor of the potential
+  * @param[in] reference_radius_m     - Reference radius of the coefficients
+  * @param[in] coeff_c                - Coefficients Cnm as triangular matrix
+  *                                     indexed [n][m]
+  * @param[in] coeff_s                - Coefficients Snm as triangular matrix
+  *                                     indexed [n][m]
+  *
+  */
+  pub fn set_coefficients(&mut self,
+                          gravitational_constant: f64,
+                          reference_radius_m: f64,
+                          coeff_c: Vec<Vec<f64>>,
+                          coeff_s: Vec<Vec<f64>>)
+  {
+    self.gravitational_constant = gravitational_constant;
+    self.reference_radius_m = reference_radius_m;
+    self.max_degree = coeff_c.len() - 1;
+    self.max_order = self.max_degree;
+    self.coeff_c = coeff_c;
+    self.coeff_s = coeff_s;
+  }
+
+ /*
+  * @brief: Function to compute the gravitational acceleration vector for a
+  *         given position in the planet fixed frame.
+  *
+  * @param[in] pos_pcpf_m - Position vector in PCPF frame
+  *
+  * @returns: Gravitational acceleration vector
+  * @unit: m/ss
+  * @frame: PCPF
+  */
+  pub fn get_acc_vec_pcpf(&self, pos_pcpf_m: ArrayView1<f64>)
+  -> Array1<f64>
+  {
+    let mut acc_vec_pcpf_mss: Array1<f64> = Array1::zeros(3);
+
+    let x: f64 = pos_pcpf_m[VEC_X];
+    let y: f64 = pos_pcpf_m[VEC_Y];
+    let z: f64 = pos_pcpf_m[VEC_Z];
+
+    let rho_squared: f64 = x * x + y * y;
+    let r_squared: f64 = rho_squared + z * z;
+    let r: f64 = r_squared.sqrt();
+    /* Keep a minimum distance to the polar axis to avoid the singularity of
+     * the longitude derivative at the poles */
+    let rho: f64 = rho_squared.sqrt().max(1.0e-3);
+
+    if r < 0.1
+    {
+      println!("[ERR] Computing spherical harmonic gravity failed. PCPF Position is [0,0,0]");
+      return acc_vec_pcpf_mss;
+    }
+
+    let sin_phi: f64 = z / r;
+    let cos_phi: f64 = rho / r;
+    let tan_phi: f64 = sin_phi / cos_phi;
+    let lambda: f64 = y.atan2(x);
+
+    let legendre: Vec<Vec<f64>> = calc_normalized_legendre(self.max_degree, sin_phi, cos_phi);
+
+    /* Partial derivatives of the potential wrt radius, latitude and longitude */
+    let mut du_dr: f64 = 0.0;
+    let mut du_dphi: f64 = 0.0;
+    let mut du_dlambda: f64 = 0.0;
+
+    let radius_ratio: f64 = self.reference_radius_m / r;
+    let mut radius_ratio_n: f64 = 1.0;
+
+    for (n, legendre_n) in legendre.iter().enumerate()
+    {
+      let mut sum_r: f64 = 0.0;
+      let mut sum_phi: f64 = 0.0;
+      let mut sum_lambda: f64 = 0.0;
+
+      for m in 0..=n.min(self.max_order)
+      {
+        let cos_m_lambda: f64 = (m as f64 * lambda).cos();
+        let sin_m_lambda: f64 = (m as f64 * lambda).sin();
+
+        let c_nm: f64 = self.coeff_c[n][m];
+        let s_nm: f64 = self.coeff_s[n][m];
+        let p_nm: f64 = legendre_n[m];
+
+        /* Derivative of the normalized Legendre function wrt latitude */
+        let p_nm1: f64 = if m < n {legendre_n[m + 1]} else {0.0};
+        let k_nm: f64 = if m == 0
+        {
+          ((n * (n + 1)) as f64 * 0.5).sqrt()
+        }
+        else
+        {
+          (((n - m) * (n + m + 1)) as f64).sqrt()
+        };
+        let dp_nm: f64 = k_nm * p_nm1 - m as f64 * tan_phi * p_nm;
+
+        let cs_term: f64 = c_nm * cos_m_lambda + s_nm * sin_m_lambda;
+
+        sum_r += p_nm * cs_term;
+        sum_phi += dp_nm * cs_term;
+        sum_lambda += m as f64 * p_nm * (s_nm * cos_m_lambda - c_nm * sin_m_lambda);
+      }
+
+      du_dr += (n + 1) as f64 * radius_ratio_n * sum_r;
+      du_dphi += radius_ratio_n * sum_phi;
+      du_dlambda += radius_ratio_n * sum_lambda;
+
+      radius_ratio_n *= radius_ratio;
+    }
+
+    du_dr *= -self.gravitational_constant / r_squared;
+    du_dphi *= self.gravitational_constant / r;
+    du_dlambda *= self.gravitational_constant / r;
+
+    /* Convert spherical gradient to cartesian acceleration */
+    let radial_term: f64 = du_dr / r - z / (r_squared * rho) * du_dphi;
+
+    acc_vec_pcpf_mss[VEC_X] = radial_term * x - du_dlambda * y / (rho * rho);
+    acc_vec_pcpf_mss[VEC_Y] = radial_term * y + du_dlambda * x / (rho * rho);
+    acc_vec_pcpf_mss[VEC_Z] = du_dr * z / r + rho / r_squared * du_dphi;
+
+    acc_vec_pcpf_mss
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the fully normalized associated Legendre functions
+ *         Pnm(sin(phi)) up to degree max_degree using the standard forward
+ *         column recursion.
+ *
+ * @returns: Triangular matrix indexed [n][m]
+ */
+fn calc_normalized_legendre(max_degree: usize, sin_phi: f64, cos_phi: f64)
+-> Vec<Vec<f64>>
+{
+  let mut p: Vec<Vec<f64>> = vec![vec![1.0]];
+  if max_degree == 0
+  {
+    return p;
+  }
+  p.push(vec![3.0_f64.sqrt() * sin_phi, 3.0_f64.sqrt() * cos_phi]);
+
+  for n in 2..=max_degree
+  {
+    let nf: f64 = n as f64;
+    let mut p_n: Vec<f64> = vec![0.0; n + 1];
+
+    /* Zonal and tesseral terms */
+    for (m, p_nm) in p_n.iter_mut().enumerate().take(n)
+    {
+      let mf: f64 = m as f64;
+      let a_nm: f64 = ((2.0 * nf - 1.0) * (2.0 * nf + 1.0)
+                      / ((nf - mf) * (nf + mf))).sqrt();
+      let b_nm: f64 = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0)
+                      / ((nf - mf) * (nf + mf) * (2.0 * nf - 3.0))).sqrt();
+      let p_n2m: f64 = if m + 2 <= n {p[n - 2][m]} else {0.0};
+      *p_nm = a_nm * sin_phi * p[n - 1][m] - b_nm * p_n2m;
+    }
+
+    /* Sectorial term */
+    p_n[n] = ((2.0 * nf + 1.0) / (2.0 * nf)).sqrt() * cos_phi * p[n - 1][n - 1];
+
+    p.push(p_n);
+  }
+  p
+}
+
+/*
+ * @brief: Function to compute the normalization factor between unnormalized
+ *         and fully normalized coefficients: C_unnorm = N_nm * C_norm
+ *
+ * @returns: N_nm = sqrt((2 - delta_0m) (2n + 1) (n - m)! / (n + m)!)
+ */
+fn calc_normalization_factor(n: usize, m: usize)
+-> f64
+{
+  /* (n - m)! / (n + m)! computed as product to avoid overflow */
+  let mut factorial_ratio: f64 = 1.0;
+  for k in (n - m + 1)..=(n + m)
+  {
+    factorial_ratio /= k as f64;
+  }
+  let delta: f64 = if m == 0 {1.0} else {2.0};
+  (delta * (2 * n + 1) as f64 * factorial_ratio).sqrt()
+}
+
+/*
+ * @brief: Parse a floating point number from a coefficient file. Accepts
+ *         Fortran style exponents (1.0D-06).
+ */
+fn parse_gfc_f64(value_str: &str)
+-> f64
+{
+  value_str.replace(['D', 'd'], "E")
+           .parse::<f64>()
+           .unwrap_or_else(|_| panic!("! [ERROR] ! > Failed to parse gravity coefficient {} <", value_str))
+}
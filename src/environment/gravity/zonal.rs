@@ -0,0 +1,132 @@
+/*
+ * @brief: Point mass gravity with analytic zonal harmonic perturbations
+ *         J2 to J6.
+ *
+ * @description: The zonal harmonics only depend on the distance to the centre
+ *               of the central body and the distance to its equatorial plane.
+ *               The perturbing acceleration of each zonal term Jn is computed
+ *               analytically from the gradient of its potential
+ *
+ *               U_n = - mu / r * Jn * (R / r)^n * Pn(z / r)
+ *
+ *               which gives
+ *
+ *               a_n = mu Jn R^n / r^(n+2) * [ ((n+1) Pn(s) + s Pn'(s)) * r/|r|
+ *                                             - Pn'(s) * e_z ]
+ *
+ *               with s = z / r and the Legendre polynomial Pn.
+ *
+ *         Note: The rotation axis of the central body is assumed to be aligned
+ *               with the z-axis of the PCI frame (precession and nutation are
+ *               neglected).
+ *
+ *               See: Vallado, Fundamentals of Astrodynamics and Applications,
+ *                    4th edition, Section 8.6.1
+ *
+ */
+
+/* Include external crates */
+use ndarray::{Array1, ArrayView1};
+
+/* Import constants */
+use crate::constants::general::*;
+use crate::constants::gravity::*;
+
+/*
+ * @brief: Function to compute the gravitational acceleration vector of a point
+ *         mass with zonal perturbations.
+ *
+ * @param[in] pos_pci_m              - Position vector in PCI frame
+ * @param[in] gravitational_constant - Gravitational constant mu of the central
+ *                                     body [m3/s2]
+ * @param[in] reference_radius_m     - Reference (equatorial) radius [m]
+ * @param[in] zonal_coefficients     - Unnormalized zonal coefficients indexed
+ *                                     by degree n (entries 0 and 1 are ignored)
+ *
+ * @returns: Gravitational acceleration vector
+ * @unit: m/ss
+ * @frame: PCI
+ */
+pub fn get_acc_vec_pci(pos_pci_m: ArrayView1<f64>,
+                       gravitational_constant: f64,
+                       reference_radius_m: f64,
+                       zonal_coefficients: &[f64])
+-> Array1<f64>
+{
+  let mut acc_vec_pci_mss: Array1<f64> = Array1::zeros(3);
+
+  let r: f64 = pos_pci_m.dot(&pos_pci_m).sqrt();
+  if r < 0.1
+  {
+    println!("[ERR] Computing zonal gravity failed. PCI Position is [0,0,0]");
+    return acc_vec_pci_mss;
+  }
+
+  let r_unit: Array1<f64> = pos_pci_m.to_owned() / r;
+  let s: f64 = pos_pci_m[VEC_Z] / r;
+
+  /* [CENTRAL TERM] */
+  acc_vec_pci_mss.scaled_add(-gravitational_constant / (r * r), &r_unit);
+
+  /* [ZONAL TERMS] */
+  let radius_ratio: f64 = reference_radius_m / r;
+  let mut radius_ratio_n: f64 = radius_ratio;
+
+  for (n, j_n) in zonal_coefficients.iter()
+                                   .enumerate()
+                                   .take(GRAV_ZONAL_MAX_DEGREE + 1)
+                                   .skip(2)
+  {
+    radius_ratio_n *= radius_ratio;
+
+    if *j_n == 0.0
+    {
+      continue;
+    }
+
+    let (p_n, dp_n): (f64, f64) = calc_legendre_polynomial(n, s);
+    let scale: f64 = gravitational_constant * *j_n * radius_ratio_n / (r * r);
+
+    acc_vec_pci_mss.scaled_add(scale * ((n + 1) as f64 * p_n + s * dp_n), &r_unit);
+    acc_vec_pci_mss[VEC_Z] -= scale * dp_n;
+  }
+
+  acc_vec_pci_mss
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Legendre polynomial Pn(s) and its derivative dPn/ds for the degrees
+ *         2 to 6
+ *
+ * @returns: (Pn(s), dPn/ds(s))
+ */
+fn calc_legendre_polynomial(n: usize, s: f64)
+-> (f64, f64)
+{
+  let s2: f64 = s * s;
+  let s3: f64 = s2 * s;
+  let s4: f64 = s3 * s;
+  let s5: f64 = s4 * s;
+  let s6: f64 = s5 * s;
+
+  match n
+  {
+    2 => ((3.0 * s2 - 1.0) / 2.0,
+          3.0 * s),
+    3 => ((5.0 * s3 - 3.0 * s) / 2.0,
+          (15.0 * s2 - 3.0) / 2.0),
+    4 => ((35.0 * s4 - 30.0 * s2 + 3.0) / 8.0,
+          (35.0 * s3 - 15.0 * s) / 2.0),
+    5 => ((63.0 * s5 - 70.0 * s3 + 15.0 * s) / 8.0,
+          (315.0 * s4 - 210.0 * s2 + 15.0) / 8.0),
+    6 => ((231.0 * s6 - 315.0 * s4 + 105.0 * s2 - 5.0) / 16.0,
+          (1386.0 * s5 - 1260.0 * s3 + 210.0 * s) / 16.0),
+    _ => (0.0, 0.0)
+  }
+}
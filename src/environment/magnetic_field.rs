@@ -0,0 +1,3 @@
+pub mod magnetic_field_model;
+
+pub mod igrf;
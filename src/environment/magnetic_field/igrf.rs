@@ -0,0 +1,371 @@
+/*
+ * @brief: International Geomagnetic Reference Field (IGRF)
+ *
+ * @description: The Schmidt semi-normalized coefficients g_nm / h_nm are loaded
+ *               from the coefficient table published by IAGA (e.g.
+ *               igrf13coeffs.txt). The coefficients are interpolated linearly
+ *               between the 5 year model epochs and extrapolated with the
+ *               secular variation after the last epoch.
+ *
+ *               The field is the negative gradient of the scalar potential
+ *
+ *               V = R^2 / r * sum( (R/r)^n * (g_nm cos(m lon) + h_nm sin(m lon))
+ *                                  * P_nm(sin(lat)) )
+ *
+ *               which has the form of the gravitational potential with GM
+ *               replaced by R^2. The gradient is evaluated with the spherical
+ *               harmonic expansion of the gravity model, the coefficients are
+ *               converted to full normalization: C_nm = g_nm / sqrt(2n + 1).
+ *
+ *               The interpolated coefficients change slowly, the spherical
+ *               harmonic expansion is cached and rebuilt after
+ *               IGRF_UPDATE_INTERVAL_DAYS only.
+ *
+ *               See: Alken et al. - International Geomagnetic Reference Field:
+ *                    the thirteenth generation, Earth, Planets and Space 73:49
+ *                    (2021)
+ *
+ *               See: https://www.ncei.noaa.gov/products/international-geomagnetic-reference-field
+ *
+ */
+
+/* Include external crates */
+use std::fs;
+use ndarray::{Array1, ArrayView1};
+
+/* Include local crates */
+use crate::environment::gravity::spherical_harmonics::SphericalHarmonics;
+
+/* Import constants */
+use crate::constants::magnetic_field::*;
+use crate::constants::time::MEAN_DAYS_IN_EARTH_YEAR;
+
+#[derive(Clone)]
+
+pub struct Igrf {
+  /*
+   * @description : Model epochs of the coefficient table
+   * @unit        : years
+   *
+   * */
+  epochs_yr: Vec<f64>,
+  /*
+   * @description : Maximum degree (n) of the coefficient table
+   * @unit        : N/A
+   *
+   * */
+  max_degree: usize,
+  /*
+   * @description : Schmidt semi-normalized coefficients g_nm and h_nm of each
+   *                model epoch, indexed [n][m][epoch]
+   * @unit        : nT
+   *
+   * */
+  coeff_g: Vec<Vec<Vec<f64>>>,
+  coeff_h: Vec<Vec<Vec<f64>>>,
+  /*
+   * @description : Secular variation of g_nm and h_nm after the last model
+   *                epoch, indexed [n][m]
+   * @unit        : nT/year
+   *
+   * */
+  sv_g: Vec<Vec<f64>>,
+  sv_h: Vec<Vec<f64>>,
+  /* [cache]
+   * @description : Spherical harmonic expansion of the coefficients at the
+   *                cached epoch
+   * @unit        : N/A
+   *
+   * */
+  field_expansion: SphericalHarmonics,
+  /*
+   * @description : Epoch of the cached expansion
+   * @unit        : years
+   *
+   * */
+  cache_decimal_year: Option<f64>
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Igrf {
+  pub fn new() -> Igrf {
+    Igrf {
+      epochs_yr: Vec::new(),
+      max_degree: 0,
+      coeff_g: Vec::new(),
+      coeff_h: Vec::new(),
+      sv_g: Vec::new(),
+      sv_h: Vec::new(),
+      field_expansion: SphericalHarmonics::new(),
+      cache_decimal_year: None
+    }
+  }
+}
+
+impl Default for Igrf {
+  fn default() -> Igrf {
+    Igrf::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Igrf {
+  pub fn get_epochs_yr(&self) -> &Vec<f64> {&self.epochs_yr}
+  pub fn get_max_degree(&self) -> &usize {&self.max_degree}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ * */
+impl Igrf {
+ /*
+  * @brief: Read the IGRF coefficient table. This function shall be called
+  *         before running the simulation!
+  *
+  * @description: Lines starting with # are comments. The line starting with
+  *               g/h lists the model epochs, the last column holds the
+  *               secular variation. Each coefficient line reads:
+  *
+  *               g/h n m value_epoch_1 ... value_epoch_k sv
+  *
+  * @param[in] filepath - Path to the coefficient file
+  *
+  */
+  pub fn load(&mut self, filepath: &str)
+  {
+    let file_content: String = fs::read_to_string(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > IGRF coefficient file {} not found! \
+                                  Run assets/magnetic_field/download_igrf.sh or select \
+                                  magnetic_field_model = tilted_dipole in magnetic_field.ini! <", filepath));
+
+    /* Coefficient records (is g, n, m, values at the model epochs, secular variation) */
+    let mut records: Vec<(bool, usize, usize, Vec<f64>, f64)> = Vec::new();
+
+    for line in file_content.lines()
+    {
+      let fields: Vec<&str> = line.split_whitespace().collect();
+      if fields.len() < 4 || fields[0].starts_with('#')
+      {
+        continue;
+      }
+
+      match fields[0]
+      {
+        "g/h" =>
+        {
+          self.epochs_yr = fields[3..(fields.len() - 1)].iter()
+            .map(|epoch_str| parse_igrf_f64(epoch_str, filepath))
+            .collect();
+        }
+        "g" | "h" =>
+        {
+          let values: Vec<f64> = fields[3..].iter()
+            .map(|value_str| parse_igrf_f64(value_str, filepath))
+            .collect();
+          let (sv, coeffs): (&f64, &[f64]) = values.split_last().unwrap();
+          records.push((fields[0] == "g",
+                        fields[1].parse::<usize>().unwrap(),
+                        fields[2].parse::<usize>().unwrap(),
+                        coeffs.to_vec(),
+                        *sv));
+        }
+        _ => {}
+      }
+    }
+
+    if self.epochs_yr.is_empty() || records.is_empty()
+    {
+      panic!("! [ERROR] ! > No IGRF coefficients found in {}! <", filepath);
+    }
+
+    /* Initialize triangular coefficient matrices */
+    let num_epochs: usize = self.epochs_yr.len();
+    self.max_degree = records.iter().map(|record| record.1).max().unwrap();
+    self.coeff_g = (0..=self.max_degree).map(|n| vec![vec![0.0; num_epochs]; n + 1]).collect();
+    self.coeff_h = self.coeff_g.clone();
+    self.sv_g = (0..=self.max_degree).map(|n| vec![0.0; n + 1]).collect();
+    self.sv_h = self.sv_g.clone();
+
+    for (is_g, n, m, coeffs, sv) in records
+    {
+      if coeffs.len() != num_epochs || m > n
+      {
+        panic!("! [ERROR] ! > Invalid IGRF coefficient record n = {} m = {} in {}! <", n, m, filepath);
+      }
+      if is_g
+      {
+        self.coeff_g[n][m] = coeffs;
+        self.sv_g[n][m] = sv;
+      }
+      else
+      {
+        self.coeff_h[n][m] = coeffs;
+        self.sv_h[n][m] = sv;
+      }
+    }
+
+    println!("[x] Loaded IGRF coefficients of degree {} ({:.1} - {:.1}) from {}",
+             self.max_degree, self.epochs_yr[0], self.epochs_yr[num_epochs - 1], filepath);
+  }
+
+ /*
+  * @brief: Function to compute the Schmidt semi-normalized coefficients at a
+  *         given epoch
+  *
+  * Note: Epochs before the first model epoch use the first model's
+  *       coefficients.
+  *
+  * @param[in] decimal_year - Epoch as decimal year
+  *
+  * @returns: (g_nm, h_nm) as triangular matrices indexed [n][m] [nT]
+  */
+  pub fn calc_coefficients(&self, decimal_year: f64) -> (Vec<Vec<f64>>, Vec<Vec<f64>>)
+  {
+    let num_epochs: usize = self.epochs_yr.len();
+    let last_epoch_yr: f64 = self.epochs_yr[num_epochs - 1];
+
+    /* Evaluate a coefficient from its values at the model epochs and its
+     * secular variation */
+    let calc_coefficient = |coeffs: &Vec<f64>, sv: f64| -> f64 {
+      if decimal_year >= last_epoch_yr
+      {
+        coeffs[num_epochs - 1] + sv * (decimal_year - last_epoch_yr)
+      }
+      else if decimal_year <= self.epochs_yr[0]
+      {
+        coeffs[0]
+      }
+      else
+      {
+        let i: usize = self.epochs_yr.iter().rposition(|epoch_yr| *epoch_yr <= decimal_year).unwrap();
+        let fraction: f64 = (decimal_year - self.epochs_yr[i]) / (self.epochs_yr[i + 1] - self.epochs_yr[i]);
+        coeffs[i] + fraction * (coeffs[i + 1] - coeffs[i])
+      }
+    };
+
+    let coeff_g: Vec<Vec<f64>> = self.coeff_g.iter().zip(self.sv_g.iter())
+      .map(|(coeffs_n, sv_n)| coeffs_n.iter().zip(sv_n.iter())
+                                      .map(|(coeffs, sv)| calc_coefficient(coeffs, *sv)).collect())
+      .collect();
+    let coeff_h: Vec<Vec<f64>> = self.coeff_h.iter().zip(self.sv_h.iter())
+      .map(|(coeffs_n, sv_n)| coeffs_n.iter().zip(sv_n.iter())
+                                      .map(|(coeffs, sv)| calc_coefficient(coeffs, *sv)).collect())
+      .collect();
+
+    (coeff_g, coeff_h)
+  }
+
+ /*
+  * @brief: Function to update the cached spherical harmonic expansion if the
+  *         epoch moved by more than IGRF_UPDATE_INTERVAL_DAYS.
+  *
+  * @param[in] decimal_year - Epoch as decimal year
+  */
+  pub fn update(&mut self, decimal_year: f64)
+  {
+    if self.is_cache_valid(decimal_year)
+    {
+      return;
+    }
+    self.field_expansion = self.calc_field_expansion(decimal_year);
+    self.cache_decimal_year = Some(decimal_year);
+  }
+
+ /*
+  * @brief: Function to compute the magnetic field vector for a given position
+  *         in the planet fixed frame
+  *
+  * @description: Uses the cached expansion if it is valid for the epoch,
+  *               otherwise the expansion is computed for the epoch.
+  *
+  * @param[in] pos_pcpf_m   - Position vector in PCPF frame
+  * @param[in] decimal_year - Epoch as decimal year
+  *
+  * @returns: Magnetic field vector
+  * @unit: T
+  * @frame: PCPF
+  */
+  pub fn get_field_vec_pcpf(&self, pos_pcpf_m: ArrayView1<f64>, decimal_year: f64) -> Array1<f64>
+  {
+    /* B = - grad V */
+    if self.is_cache_valid(decimal_year)
+    {
+      -self.field_expansion.get_acc_vec_pcpf(pos_pcpf_m)
+    }
+    else
+    {
+      -self.calc_field_expansion(decimal_year).get_acc_vec_pcpf(pos_pcpf_m)
+    }
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+impl Igrf {
+ /*
+  * @brief: Function to check if the cached expansion is valid for the epoch
+  */
+  fn is_cache_valid(&self, decimal_year: f64)
+  -> bool
+  {
+    match self.cache_decimal_year
+    {
+      Some(cache_decimal_year) => (decimal_year - cache_decimal_year).abs() * MEAN_DAYS_IN_EARTH_YEAR
+                                  <= IGRF_UPDATE_INTERVAL_DAYS,
+      None => false
+    }
+  }
+
+ /*
+  * @brief: Function to build the spherical harmonic expansion of the scalar
+  *         potential at a given epoch
+  */
+  fn calc_field_expansion(&self, decimal_year: f64)
+  -> SphericalHarmonics
+  {
+    let (coeff_g, coeff_h): (Vec<Vec<f64>>, Vec<Vec<f64>>) = self.calc_coefficients(decimal_year);
+
+    /* Convert to fully normalized coefficients in tesla, the potential has no
+     * central term */
+    let convert_coefficients = |coeffs: Vec<Vec<f64>>| -> Vec<Vec<f64>> {
+      coeffs.into_iter().enumerate()
+        .map(|(n, coeffs_n)| coeffs_n.into_iter()
+                               .map(|coeff| if n == 0 {0.0}
+                                            else {coeff * NANOTESLA_TO_TESLA / ((2 * n + 1) as f64).sqrt()})
+                               .collect())
+        .collect()
+    };
+
+    let mut field_expansion: SphericalHarmonics = SphericalHarmonics::new();
+    field_expansion.set_coefficients(EARTH_MAGNETIC_REFERENCE_RADIUS_M * EARTH_MAGNETIC_REFERENCE_RADIUS_M,
+                                     EARTH_MAGNETIC_REFERENCE_RADIUS_M,
+                                     convert_coefficients(coeff_g),
+                                     convert_coefficients(coeff_h));
+    field_expansion
+  }
+}
+
+/*
+ * @brief: Parse a floating point number from the IGRF coefficient file
+ */
+fn parse_igrf_f64(value_str: &str, filepath: &str)
+-> f64
+{
+  value_str.parse::<f64>()
+           .unwrap_or_else(|_| panic!("! [ERROR] ! > Failed to parse IGRF value {} in {} <", value_str, filepath))
+}
@@ -0,0 +1,227 @@
+/*
+ * @brief: Geomagnetic field
+ *
+ * @description: The magnetic field vector is computed in the planet fixed
+ *               frame (PCPF) from one of the following models (selected in
+ *               magnetic_field.ini):
+ *
+ *               * tilted_dipole - Centred dipole from the degree one IGRF-13
+ *                                 coefficients g10, g11, h11 (2020.0) and
+ *                                 their secular variation:
+ *                                 B = (R/r)^3 * (3 * (m . e_r) * e_r - m)
+ *                                 with m = [g11, h11, g10]
+ *               * igrf          - Full IGRF spherical harmonic expansion
+ *                                 from the coefficient file (see igrf.rs)
+ *
+ *               and transformed to the local north-east-down frame (NED) at
+ *               the geodetic position and to the body frame.
+ *
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, ArrayView1, s};
+
+/* Include local crates */
+use crate::environment::planet::planet::Planet;
+use crate::environment::magnetic_field::igrf::Igrf;
+use crate::math::epoch::Epoch;
+use crate::math::frame_math::convert_ecef_to_ned;
+use crate::math::vec_math::l2_norm_array1;
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::magnetic_field::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum MagneticFieldModelType {
+  TiltedDipole,
+  Igrf
+}
+
+impl MagneticFieldModelType {
+  pub fn from_param_str(model_str: &str) -> MagneticFieldModelType
+  {
+    match model_str.trim().trim_matches('"')
+    {
+      "tilted_dipole" => MagneticFieldModelType::TiltedDipole,
+      "igrf" => MagneticFieldModelType::Igrf,
+      _ => panic!("! [ERROR] ! > Unknown magnetic_field_model {} in magnetic_field.ini! <", model_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct MagneticField {
+  /*
+   * @description : Flag, if true the magnetic field vector is written to
+   *                out.csv
+   * @unit        : N/A
+   *
+   * */
+  enable_magnetic_field_output: bool,
+  /*
+   * @description : Selected magnetic field model
+   * @unit        : N/A
+   *
+   * */
+  model_type: MagneticFieldModelType,
+  /*
+   * @description : Path of the IGRF coefficient file
+   * @unit        : N/A
+   *
+   * */
+  igrf_file_path: String,
+  /*
+   * @description : IGRF coefficient table
+   * @unit        : N/A
+   *
+   * */
+  igrf: Igrf
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl MagneticField {
+  pub fn new() -> MagneticField {
+    MagneticField {
+      enable_magnetic_field_output: false,
+      model_type: MagneticFieldModelType::TiltedDipole,
+      igrf_file_path: String::new(),
+      igrf: Igrf::new()
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. Loads the IGRF coefficients if selected.
+   */
+  pub fn init(&mut self)
+  {
+    if self.model_type == MagneticFieldModelType::Igrf
+    {
+      self.igrf.load(&self.igrf_file_path);
+    }
+  }
+}
+
+impl Default for MagneticField {
+  fn default() -> MagneticField {
+    MagneticField::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl MagneticField {
+  pub fn set_enable_magnetic_field_output(&mut self, val_in: &bool) {self.enable_magnetic_field_output = *val_in;}
+  pub fn set_model_type(&mut self, val_in: &MagneticFieldModelType) {self.model_type = *val_in;}
+  pub fn set_igrf_file_path(&mut self, val_in: &str) {self.igrf_file_path = val_in.trim().trim_matches('"').to_string();}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl MagneticField {
+  pub fn is_magnetic_field_output_enabled(&self) -> &bool {&self.enable_magnetic_field_output}
+  pub fn get_model_type(&self) -> &MagneticFieldModelType {&self.model_type}
+  pub fn get_igrf(&self) -> &Igrf {&self.igrf}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl MagneticField {
+ /*
+  * @brief: Function to update the cached IGRF expansion if the epoch moved by
+  *         more than the update interval
+  *
+  * @param[in] epoch - Epoch (UTC)
+  */
+  pub fn update(&mut self, epoch: &Epoch)
+  {
+    if self.model_type == MagneticFieldModelType::Igrf
+    {
+      self.igrf.update(epoch.calc_decimal_year());
+    }
+  }
+
+ /*
+  * @brief: Function to compute the magnetic field vector for a given position
+  *         in the planet fixed frame
+  *
+  * @param[in] pos_pcpf_m_in - Position vector in PCPF frame
+  * @param[in] epoch         - Epoch of the position
+  *
+  * @unit: T
+  * @frame: PCPF
+  */
+  pub fn calc_field_vec_pcpf(&self, pos_pcpf_m_in: ArrayView1<f64>, epoch: &Epoch) -> Array1<f64>
+  {
+    let decimal_year: f64 = epoch.calc_decimal_year();
+
+    match self.model_type
+    {
+      MagneticFieldModelType::TiltedDipole =>
+      {
+        let years_since_epoch: f64 = decimal_year - IGRF_DIPOLE_EPOCH_YR;
+        let coeff_nt: Vec<f64> = IGRF_DIPOLE_COEFF_NT.iter().zip(IGRF_DIPOLE_SV_NTPY.iter())
+          .map(|(coeff, sv)| coeff + sv * years_since_epoch)
+          .collect();
+        let dipole_vec_t: Array1<f64> = NANOTESLA_TO_TESLA
+          * Array1::from(vec![coeff_nt[1], coeff_nt[2], coeff_nt[0]]);
+
+        let radius_m: f64 = l2_norm_array1(pos_pcpf_m_in);
+        let pos_unit_vec: Array1<f64> = &pos_pcpf_m_in / radius_m;
+
+        (EARTH_MAGNETIC_REFERENCE_RADIUS_M / radius_m).powi(3)
+          * (3.0 * dipole_vec_t.dot(&pos_unit_vec) * &pos_unit_vec - &dipole_vec_t)
+      }
+      MagneticFieldModelType::Igrf => self.igrf.get_field_vec_pcpf(pos_pcpf_m_in, decimal_year)
+    }
+  }
+
+ /*
+  * @brief: Function to compute the magnetic field vector at the spacecraft's
+  *         position in the planet fixed, north-east-down and body frame
+  *
+  * @param[in] state_in - Full state vector
+  * @param[in] planet   - Planet providing the orientation and reference
+  *                       ellipsoid
+  *
+  * @returns: (B in PCPF, B in NED, B in body frame)
+  * @unit: T
+  */
+  pub fn calc_field_vecs(&self, state_in: ArrayView1<f64>, planet: &Planet)
+  -> (Array1<f64>, Array1<f64>, Array1<f64>)
+  {
+    let epoch: Epoch = Epoch::from_state_vec(state_in);
+    let pos_pcpf_m: Array1<f64> = planet.get_earth_orientation()
+      .convert_pci_to_pcpf(state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]), &epoch);
+
+    let field_pcpf_t: Array1<f64> = self.calc_field_vec_pcpf(pos_pcpf_m.view(), &epoch);
+
+    let pos_lla: Array1<f64> = planet.calc_geodetic_pos(pos_pcpf_m.view());
+    let field_ned_t: Array1<f64> = convert_ecef_to_ned(field_pcpf_t.view(), pos_lla[0], pos_lla[1]);
+
+    let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(
+      state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+    let field_b_t: Array1<f64> = dcm_pci_to_b.dot(&planet.get_earth_orientation()
+      .convert_pcpf_to_pci(field_pcpf_t.view(), &epoch));
+
+    (field_pcpf_t, field_ned_t, field_b_t)
+  }
+}
@@ -1,3 +1,11 @@
 pub mod planet;
 
-pub mod atmosphere;
\ No newline at end of file
+pub mod atmosphere;
+
+pub mod nrlmsise00;
+
+pub mod earth_orientation;
+
+pub mod eop;
+
+pub mod cip_series;
@@ -1,14 +1,40 @@
 
 /* Include external crates */
 use libm::exp;
+use chrono::{DateTime, Datelike, Timelike, Utc};
 
 /* Include local crates */
+use crate::environment::planet::nrlmsise00::{Nrlmsise00, Nrlmsise00Input, Nrlmsise00Output};
 use crate::io::read_csv::*;
 use crate::math::lin_math::*;
+use crate::math::epoch::Epoch;
 
 /* constants */
 use crate::constants::atmosphere::*;
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum AtmosphereModelType {
+  Simple,
+  Nrlmsise00
+}
+
+impl AtmosphereModelType {
+ /*
+  * @brief: Function to map the atmosphere_model parameter string to the model
+  *         type.
+  */
+  pub fn from_param_str(model_str: &str) -> AtmosphereModelType
+  {
+    match model_str.trim().trim_matches('"')
+    {
+      "simple" => AtmosphereModelType::Simple,
+      "nrlmsise00" => AtmosphereModelType::Nrlmsise00,
+      _ => panic!("! [ERROR] ! > Unknown atmosphere model {} in atmosphere.ini! <", model_str)
+    }
+  }
+}
+
 #[derive(Clone)]
 
 pub struct Atmosphere {
@@ -27,7 +53,46 @@ pub struct Atmosphere {
   geomagnetic_ap_index: f64,
   enable_atmosphere_modelling: bool,
   mean_free_path_lut_vec: Vec<(f64, f64)>,
-  speed_of_sound_lut_vec: Vec<(f64, f64)>
+  speed_of_sound_lut_vec: Vec<(f64, f64)>,
+  /* [atmosphere model type] 
+   * @description : Selected density model
+   * @unit        : N/A
+   * 
+   * */
+  model_type: AtmosphereModelType,
+  /* [81 day average radio flux] 
+   * @description : 81 day average of the solar radio ten centimetre flux 
+   *                (F10.7) centred on the simulated day. Used by NRLMSISE-00.
+   * @unit        : 10e-22 W/m2/Hz (solar flux units)
+   * 
+   * */
+  radio_10_cm_flux_81_day_avg: f64,
+  /* [NRLMSISE-00 coefficient file path] 
+   * @description : Path to the NRLMSISE-00 coefficient data file
+   * @unit        : N/A
+   * 
+   * */
+  nrlmsise00_coefficient_file_path: String,
+  /* [NRLMSISE-00 struct] 
+   * @description : NRLMSISE-00 coefficients and evaluation
+   * @unit        : N/A
+   * 
+   * */
+  nrlmsise00: Nrlmsise00,
+  /* [exospheric temperature] 
+   * @description : Exospheric temperature (NRLMSISE-00 only)
+   * @unit        : K
+   * 
+   * */
+  exospheric_temperature_k: f64,
+  /* [composition] 
+   * @description : Number densities of He, O, N2, O2, Ar, H, N and anomalous 
+   *                O in this order, see NRLMSISE_SPECIES_INDICES. The total 
+   *                mass density is density_kgmmm. (NRLMSISE-00 only)
+   * @unit        : 1/m3
+   * 
+   * */
+  number_densities_m3: [f64; 8]
 }
 
 /*
@@ -50,7 +115,13 @@ impl Atmosphere {
       geomagnetic_ap_index: 0.0,
       enable_atmosphere_modelling: false,
       mean_free_path_lut_vec: Vec::new(),
-      speed_of_sound_lut_vec: Vec::new()
+      speed_of_sound_lut_vec: Vec::new(),
+      model_type: AtmosphereModelType::Simple,
+      radio_10_cm_flux_81_day_avg: 0.0,
+      nrlmsise00_coefficient_file_path: String::new(),
+      nrlmsise00: Nrlmsise00::new(),
+      exospheric_temperature_k: 0.0,
+      number_densities_m3: [0.0; 8]
     }
   }
 
@@ -60,8 +131,14 @@ impl Atmosphere {
    */
   pub fn init(&mut self) 
   {
+    println!("[x] Initialize atmosphere model: {:?}", self.model_type);
     self.load_mean_free_path_lut();
     self.load_speed_of_sound_lut();
+    if self.model_type == AtmosphereModelType::Nrlmsise00
+    {
+      let filepath: String = self.nrlmsise00_coefficient_file_path.clone();
+      self.nrlmsise00.load_coefficients(&filepath);
+    }
   }
 }
 
@@ -75,6 +152,9 @@ impl Atmosphere
   pub fn set_radio_10_cm_flux(&mut self, val_in: &f64) {self.radio_10_cm_flux = *val_in;}
   pub fn set_geomagnetic_ap_index(&mut self, val_in: &f64) {self.geomagnetic_ap_index = *val_in;}
   pub fn set_enable_atmophere_modelling(&mut self, val_in: &bool) {self.enable_atmosphere_modelling = *val_in}
+  pub fn set_model_type(&mut self, val_in: &AtmosphereModelType) {self.model_type = *val_in;}
+  pub fn set_radio_10_cm_flux_81_day_avg(&mut self, val_in: &f64) {self.radio_10_cm_flux_81_day_avg = *val_in;}
+  pub fn set_nrlmsise00_coefficient_file_path(&mut self, val_in: &str) {self.nrlmsise00_coefficient_file_path = val_in.trim_matches('"').to_string();}
 }
 /*
  * ----------------------------------------------------------------------
@@ -89,6 +169,11 @@ impl Atmosphere
   pub fn get_geomagnetic_ap_index(&self) -> &f64 {&self.geomagnetic_ap_index}
   pub fn get_knudsen_number(&self) -> &f64 {&self.knudsen_number}
   pub fn get_speed_of_sound_ms(&self) -> &f64 {&self.speed_of_sound_ms}
+  pub fn get_temperature_k(&self) -> &f64 {&self.temperature_k}
+  pub fn get_model_type(&self) -> &AtmosphereModelType {&self.model_type}
+  pub fn get_radio_10_cm_flux_81_day_avg(&self) -> &f64 {&self.radio_10_cm_flux_81_day_avg}
+  pub fn get_exospheric_temperature_k(&self) -> &f64 {&self.exospheric_temperature_k}
+  pub fn get_number_densities_m3(&self) -> &[f64; 8] {&self.number_densities_m3}
 
   pub fn get_mean_free_path_lut_vec(&self) -> &Vec<(f64, f64)> {&self.mean_free_path_lut_vec}
   pub fn get_speed_of_sound_lut_vec(&self) -> &Vec<(f64, f64)> {&self.speed_of_sound_lut_vec}
@@ -146,10 +231,28 @@ impl Atmosphere {
  /*
   * @brief: Wrapper to update the atmospheric density value
   * 
+  * @description: The simple model only depends on the altitude. NRLMSISE-00 
+  *               additionally updates the temperature and composition.
+  * 
+  * @param[in] altitude_m    - Altitude above the planet's surface
+  * @param[in] latitude_deg  - Latitude of the spacecraft in PCPF
+  * @param[in] longitude_deg - Longitude of the spacecraft in PCPF
+  * @param[in] epoch         - Epoch (UTC)
+  * 
   */
-  pub fn update_density(&mut self, altitude_m: f64)
+  pub fn update_density(&mut self, altitude_m: f64, latitude_deg: f64, longitude_deg: f64, epoch: &Epoch)
   {
-    self.density_kgmmm = self.calculate_density(altitude_m);
+    match self.model_type
+    {
+      AtmosphereModelType::Simple => 
+      {
+        self.density_kgmmm = self.calculate_density(altitude_m);
+      },
+      AtmosphereModelType::Nrlmsise00 => 
+      {
+        self.update_nrlmsise00(altitude_m, latitude_deg, longitude_deg, epoch);
+      }
+    }
   }
 
  /*
@@ -196,6 +299,39 @@ impl Atmosphere {
  */
 impl Atmosphere
 {
+  /*
+   * @brief: Function to evaluate NRLMSISE-00 and update density, temperature 
+   *         and composition.
+   * 
+   * Note: The local apparent solar time is approximated from UT and longitude
+   *       as recommended by the model authors.
+   */
+  fn update_nrlmsise00(&mut self, altitude_m: f64, latitude_deg: f64, longitude_deg: f64, epoch: &Epoch)
+  {
+    let datetime: DateTime<Utc> = epoch.to_datetime();
+    let sec_of_day: f64 = datetime.num_seconds_from_midnight() as f64
+                          + datetime.nanosecond() as f64 * 1.0e-9;
+
+    let input: Nrlmsise00Input = Nrlmsise00Input {
+      doy: datetime.ordinal() as f64,
+      sec: sec_of_day,
+      alt_km: altitude_m / 1000.0,
+      g_lat_deg: latitude_deg,
+      g_long_deg: longitude_deg,
+      lst_h: sec_of_day / 3600.0 + longitude_deg / 15.0,
+      f107a: self.radio_10_cm_flux_81_day_avg,
+      f107: self.radio_10_cm_flux,
+      ap: self.geomagnetic_ap_index
+    };
+
+    let output: Nrlmsise00Output = self.nrlmsise00.gtd7d(&input);
+
+    self.density_kgmmm = output.d[NRLMSISE_INDX_TOTAL_MASS];
+    self.exospheric_temperature_k = output.t[NRLMSISE_INDX_TEMP_EXOSPHERE];
+    self.temperature_k = output.t[NRLMSISE_INDX_TEMP_ALTITUDE];
+    self.number_densities_m3 = NRLMSISE_SPECIES_INDICES.map(|indx| output.d[indx]);
+  }
+
   /*
    * @brief: Main function to return the atmospheric density for a given altitude.
    * 
@@ -0,0 +1,175 @@
+/*
+ * @brief: This file contains the loader and evaluation of the periodic part of
+ *         the IAU 2006/2000A series of the CIP coordinates X, Y and of the CIO
+ *         locator s + XY/2.
+ *
+ * @description: The series are read from the tables of the IERS Conventions
+ *               (2010), Chapter 5:
+ *
+ *               * tab5.2a.txt - X
+ *               * tab5.2b.txt - Y
+ *               * tab5.2d.txt - s + XY/2
+ *
+ *               Source: https://iers-conventions.obspm.fr/content/chapter5/additional_info/
+ *
+ *               Each data line contains the term index, the sine and cosine
+ *               amplitudes [microarcseconds] and the 14 multipliers of the
+ *               fundamental arguments. The terms are grouped by the power j of
+ *               the time argument, introduced by a line "j = <power> ...".
+ *
+ */
+use std::fs;
+
+/* Import constants */
+use crate::constants::earth_orientation::NUM_FUNDAMENTAL_ARGUMENTS;
+
+/* Number of tokens of a data line (index, 2 amplitudes, multipliers) */
+const CIP_SERIES_NUM_TOKENS: usize = 3 + NUM_FUNDAMENTAL_ARGUMENTS;
+
+#[derive(Clone)]
+
+pub struct CipSeriesTerm {
+  /* [amplitudes]
+   * @description : Amplitudes of the sine and cosine term
+   * @unit        : microarcseconds
+   * */
+  amplitude_sin_uas: f64,
+  amplitude_cos_uas: f64,
+  /* [multipliers]
+   * @description : Multipliers of the fundamental arguments
+   * @unit        : N/A
+   * */
+  multipliers: [f64; NUM_FUNDAMENTAL_ARGUMENTS],
+}
+
+#[derive(Clone)]
+
+pub struct CipSeries {
+  /* [terms]
+   * @description : Periodic terms grouped by the power of the time argument
+   * @unit        : N/A
+   * */
+  terms: Vec<Vec<CipSeriesTerm>>,
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl CipSeries {
+  pub fn new() -> CipSeries
+  {
+    CipSeries {
+      terms: Vec::new(),
+    }
+  }
+}
+
+impl Default for CipSeries {
+  fn default() -> CipSeries {
+    CipSeries::new()
+  }
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl CipSeries {
+  pub fn is_loaded(&self) -> bool {!self.terms.is_empty()}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl CipSeries {
+ /*
+  * @brief: Read the series from an IERS table. This function shall be called
+  *         before running the simulation!
+  *
+  * @param[in] filepath - Path to the IERS table (tab5.2a, tab5.2b or tab5.2d)
+  */
+  pub fn load(&mut self, filepath: &str)
+  {
+    let file_content: String = fs::read_to_string(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > CIP series file {} not found! <", filepath));
+
+    self.terms.clear();
+    let mut num_terms: usize = 0;
+
+    for line in file_content.lines()
+    {
+      let tokens: Vec<&str> = line.split_whitespace().collect();
+
+      /* Start of the terms of the next power "j = <power>" */
+      if tokens.len() >= 3 && tokens[0] == "j" && tokens[1] == "="
+      {
+        let power: usize = tokens[2].parse::<usize>()
+          .unwrap_or_else(|_| panic!("! [ERROR] ! > Invalid power {} in {}! <", tokens[2], filepath));
+        self.terms.resize(power + 1, Vec::new());
+        continue;
+      }
+
+      /* Data lines start with the integer term index */
+      if tokens.len() != CIP_SERIES_NUM_TOKENS || tokens[0].parse::<usize>().is_err() || self.terms.is_empty()
+      {
+        continue;
+      }
+      let values: Vec<f64> = tokens[1..].iter()
+                                        .map(|token| token.parse::<f64>()
+                                          .unwrap_or_else(|_| panic!("! [ERROR] ! > Invalid value {} in {}! <", token, filepath)))
+                                        .collect();
+      let mut multipliers: [f64; NUM_FUNDAMENTAL_ARGUMENTS] = [0.0; NUM_FUNDAMENTAL_ARGUMENTS];
+      multipliers.copy_from_slice(&values[2..]);
+
+      self.terms.last_mut().unwrap().push(CipSeriesTerm {
+        amplitude_sin_uas: values[0],
+        amplitude_cos_uas: values[1],
+        multipliers,
+      });
+      num_terms += 1;
+    }
+
+    if num_terms == 0
+    {
+      panic!("! [ERROR] ! > No series terms found in {}! <", filepath);
+    }
+    println!("[x] Loaded {} CIP series terms from {}", num_terms, filepath);
+  }
+
+ /*
+  * @brief: Function to evaluate the periodic part of the series
+  *
+  * @param[in] t                     - Julian centuries TT since J2000.0
+  * @param[in] fundamental_arguments - See calc_fundamental_arguments()
+  *
+  * @unit: microarcseconds
+  */
+  pub fn eval(&self, t: f64, fundamental_arguments: &[f64; NUM_FUNDAMENTAL_ARGUMENTS])
+  -> f64
+  {
+    let mut sum_out: f64 = 0.0;
+    let mut t_power: f64 = 1.0;
+
+    for terms_of_power in self.terms.iter()
+    {
+      /* Sum from the smallest terms to preserve precision */
+      let sum_of_power: f64 = terms_of_power.iter()
+                                            .rev()
+                                            .map(|term| {
+                                              let argument: f64 = term.multipliers.iter()
+                                                                      .zip(fundamental_arguments.iter())
+                                                                      .map(|(multiplier, angle)| multiplier * angle)
+                                                                      .sum();
+                                              let (sin_arg, cos_arg): (f64, f64) = argument.sin_cos();
+                                              term.amplitude_sin_uas * sin_arg + term.amplitude_cos_uas * cos_arg
+                                            })
+                                            .sum();
+      sum_out += sum_of_power * t_power;
+      t_power *= t;
+    }
+    sum_out
+  }
+}
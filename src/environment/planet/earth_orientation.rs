@@ -0,0 +1,487 @@
+/*
+ * @brief: This file contains the orientation of the Earth with respect to the
+ *         inertial frame, i.e. the transformation between PCI (GCRF) and
+ *         PCPF (ITRF).
+ *
+ * @description: Supported models (rotation_model in planet.ini):
+ *
+ *               * gast    - Single z-rotation by the Greenwich apparent
+ *                           sidereal time (legacy model, ~arcminute accuracy)
+ *               * iau2006 - CIO based IAU 2006/2000A transformation with
+ *                           precession-nutation, Earth rotation angle and polar
+ *                           motion (IERS Conventions 2010, Chapter 5)
+ *
+ *               The iau2006 model uses the Earth Orientation Parameters
+ *               (UT1-UTC, xp, yp, LOD, dX, dY) of an IERS EOP file. If the
+ *               full IAU 2006/2000A series of X, Y and s are not provided,
+ *               the CIP is computed from the IAU 2006 precession and the
+ *               IAU 2000B nutation (~1 mas, i.e. ~3 cm on the Earth's
+ *               surface).
+ *
+ *               Precession-nutation and polar motion change slowly and are
+ *               cached for EOP_UPDATE_INTERVAL_S, the Earth rotation angle is
+ *               computed at every call.
+ *
+ */
+
+/* Include external crates */
+use std::path::Path;
+use ndarray::{Array1, Array2, ArrayView1};
+
+/* Include local crates */
+use crate::environment::planet::cip_series::CipSeries;
+use crate::environment::planet::eop::{EopFileFormat, EopRecord, EopTable};
+use crate::math::earth_orientation_math::*;
+use crate::math::epoch::Epoch;
+use crate::math::rotation_math::dcm_from_zrot;
+use crate::math::time_math::calc_earth_gast_deg;
+use crate::math::time_scale_math::TimeScale;
+use crate::math::vec_math::cross_product_array1;
+
+/* Import constants */
+use crate::constants::earth_orientation::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum EarthRotationModelType {
+  Gast,
+  Iau2006
+}
+
+impl EarthRotationModelType {
+ /*
+  * @brief: Function to map the rotation_model parameter string to the model
+  *         type.
+  */
+  pub fn from_param_str(model_str: &str) -> EarthRotationModelType
+  {
+    match model_str.trim().trim_matches('"')
+    {
+      "gast" => EarthRotationModelType::Gast,
+      "iau2006" => EarthRotationModelType::Iau2006,
+      _ => panic!("! [ERROR] ! > Unknown Earth rotation model {} in planet.ini! <", model_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct EarthOrientation {
+  /* [rotation model]
+   * @description : Model of the transformation between PCI and PCPF
+   * @unit        : N/A
+   * */
+  rotation_model: EarthRotationModelType,
+  /* [file paths]
+   * @description : Paths to the EOP file and the IERS tables of the series of
+   *                X, Y and s + XY/2. Empty paths are not loaded.
+   * @unit        : N/A
+   * */
+  eop_file_path: String,
+  eop_file_format: EopFileFormat,
+  cip_series_x_file_path: String,
+  cip_series_y_file_path: String,
+  cip_series_s_file_path: String,
+  /* [EOP]
+   * @description : Earth Orientation Parameters loaded from file
+   * @unit        : N/A
+   * */
+  eop_table: EopTable,
+  /* [CIP series]
+   * @description : Periodic part of the IAU 2006/2000A series of X, Y and
+   *                s + XY/2
+   * @unit        : N/A
+   * */
+  cip_series_x: CipSeries,
+  cip_series_y: CipSeries,
+  cip_series_s: CipSeries,
+  /* [cache]
+   * @description : Epoch of the cached precession-nutation and polar motion
+   *                matrices and of the interpolated EOP (None if not set)
+   * @unit        : N/A
+   * */
+  cache_epoch: Option<Epoch>,
+  dcm_gcrs_to_cirs: Array2<f64>,
+  dcm_tirs_to_itrs: Array2<f64>,
+  eop: EopRecord,
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl EarthOrientation {
+  pub fn new() -> EarthOrientation
+  {
+    EarthOrientation {
+      rotation_model: EarthRotationModelType::Iau2006,
+      eop_file_path: String::new(),
+      eop_file_format: EopFileFormat::Finals2000a,
+      cip_series_x_file_path: String::new(),
+      cip_series_y_file_path: String::new(),
+      cip_series_s_file_path: String::new(),
+      eop_table: EopTable::new(),
+      cip_series_x: CipSeries::new(),
+      cip_series_y: CipSeries::new(),
+      cip_series_s: CipSeries::new(),
+      cache_epoch: None,
+      dcm_gcrs_to_cirs: Array2::eye(3),
+      dcm_tirs_to_itrs: Array2::eye(3),
+      eop: EopRecord::new(),
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. This function usually contains file loaders.
+   */
+  pub fn init(&mut self)
+  {
+    if self.rotation_model == EarthRotationModelType::Gast
+    {
+      println!("[x] Earth rotation model GAST -> Skip loading Earth orientation parameters");
+      return;
+    }
+
+    if !is_data_file_available(&self.eop_file_path)
+    {
+      println!("[WRN] ************************************************************************");
+      println!("[WRN] EOP file '{}' not found!", self.eop_file_path);
+      println!("[WRN] UT1-UTC, polar motion and celestial pole offsets are set to zero,");
+      println!("[WRN] i.e. the PCI <-> PCPF transformation is off by up to ~1 km!");
+      println!("[WRN] Run assets/earth_orientation/download_eop.sh to fetch finals2000A.all.");
+      println!("[WRN] ************************************************************************");
+    }
+    else
+    {
+      let eop_file_path: String = self.eop_file_path.clone();
+      self.eop_table.load(&eop_file_path, self.eop_file_format);
+    }
+
+    if !is_data_file_available(&self.cip_series_x_file_path)
+       || !is_data_file_available(&self.cip_series_y_file_path)
+       || !is_data_file_available(&self.cip_series_s_file_path)
+    {
+      println!("[WRN] ************************************************************************");
+      println!("[WRN] CIP series files not found. Using IAU 2006 precession and IAU 2000B");
+      println!("[WRN] nutation (~1 mas)!");
+      println!("[WRN] Run assets/earth_orientation/download_eop.sh to fetch tab5.2a/b/d.txt.");
+      println!("[WRN] ************************************************************************");
+    }
+    else
+    {
+      let file_paths: [String; 3] = [self.cip_series_x_file_path.clone(),
+                                     self.cip_series_y_file_path.clone(),
+                                     self.cip_series_s_file_path.clone()];
+      self.cip_series_x.load(&file_paths[0]);
+      self.cip_series_y.load(&file_paths[1]);
+      self.cip_series_s.load(&file_paths[2]);
+    }
+  }
+}
+
+impl Default for EarthOrientation {
+  fn default() -> EarthOrientation {
+    EarthOrientation::new()
+  }
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl EarthOrientation {
+  pub fn set_rotation_model(&mut self, val_in: &EarthRotationModelType) {self.rotation_model = *val_in;}
+  pub fn set_eop_file_path(&mut self, val_in: &str) {self.eop_file_path = val_in.trim().trim_matches('"').to_string();}
+  pub fn set_eop_file_format(&mut self, val_in: &EopFileFormat) {self.eop_file_format = *val_in;}
+  pub fn set_cip_series_x_file_path(&mut self, val_in: &str) {self.cip_series_x_file_path = val_in.trim().trim_matches('"').to_string();}
+  pub fn set_cip_series_y_file_path(&mut self, val_in: &str) {self.cip_series_y_file_path = val_in.trim().trim_matches('"').to_string();}
+  pub fn set_cip_series_s_file_path(&mut self, val_in: &str) {self.cip_series_s_file_path = val_in.trim().trim_matches('"').to_string();}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl EarthOrientation {
+  pub fn get_rotation_model(&self) -> &EarthRotationModelType {&self.rotation_model}
+  pub fn get_eop_table(&self) -> &EopTable {&self.eop_table}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl EarthOrientation {
+ /*
+  * @brief: Function to update the cached precession-nutation and polar motion
+  *         matrices if the epoch moved by more than EOP_UPDATE_INTERVAL_S.
+  *
+  * @param[in] epoch - Epoch (UTC)
+  */
+  pub fn update(&mut self, epoch: &Epoch)
+  {
+    if self.rotation_model == EarthRotationModelType::Gast || self.is_cache_valid(epoch)
+    {
+      return;
+    }
+    let (dcm_gcrs_to_cirs, dcm_tirs_to_itrs, eop) = self.calc_orientation(epoch);
+    self.dcm_gcrs_to_cirs = dcm_gcrs_to_cirs;
+    self.dcm_tirs_to_itrs = dcm_tirs_to_itrs;
+    self.eop = eop;
+    self.cache_epoch = Some(*epoch);
+  }
+
+ /*
+  * @brief: Function to compute the direction cosine matrix from PCI to PCPF
+  *
+  * @description: Uses the cached matrices if they are valid for the epoch,
+  *               otherwise they are computed for the given epoch.
+  *
+  * @param[in] epoch - Epoch (UTC)
+  */
+  pub fn calc_dcm_pci_to_pcpf(&self, epoch: &Epoch)
+  -> Array2<f64>
+  {
+    match self.rotation_model
+    {
+      EarthRotationModelType::Gast =>
+        dcm_from_zrot(-calc_earth_gast_deg(epoch.to_datetime())),
+      EarthRotationModelType::Iau2006 =>
+      {
+        let (dcm_gcrs_to_tirs, dcm_tirs_to_itrs, _) = self.calc_dcm_gcrs_to_tirs(epoch);
+        dcm_tirs_to_itrs.dot(&dcm_gcrs_to_tirs)
+      }
+    }
+  }
+
+ /*
+  * @brief: Function to convert a vector (e.g. position or acceleration) from
+  *         PCI to PCPF
+  */
+  pub fn convert_pci_to_pcpf(&self, vec_pci_in: ArrayView1<f64>, epoch: &Epoch)
+  -> Array1<f64>
+  {
+    self.calc_dcm_pci_to_pcpf(epoch).dot(&vec_pci_in)
+  }
+
+ /*
+  * @brief: Function to convert a vector (e.g. position or acceleration) from
+  *         PCPF to PCI
+  */
+  pub fn convert_pcpf_to_pci(&self, vec_pcpf_in: ArrayView1<f64>, epoch: &Epoch)
+  -> Array1<f64>
+  {
+    self.calc_dcm_pci_to_pcpf(epoch).t().dot(&vec_pcpf_in)
+  }
+
+ /*
+  * @brief: Function to convert position and velocity from PCI to PCPF
+  *
+  * @description: The velocity is corrected by the Earth's rotation about the
+  *               CIP in the terrestrial intermediate frame:
+  *               v_tirs = R3(ERA) Q^T v_pci - omega x r_tirs
+  *               The rotation rate is reduced by the excess length of day.
+  *               The rate of polar motion is neglected.
+  *
+  * @param[in] omega_rads - Nominal rotation rate of the planet
+  *
+  * @returns: (position [m], velocity [m/s]) in PCPF
+  */
+  pub fn convert_pci_to_pcpf_state(&self,
+                                   pos_pci_m_in: ArrayView1<f64>,
+                                   vel_pci_ms_in: ArrayView1<f64>,
+                                   epoch: &Epoch,
+                                   omega_rads: f64)
+  -> (Array1<f64>, Array1<f64>)
+  {
+    let (dcm_gcrs_to_tirs, dcm_tirs_to_itrs, eop) = match self.rotation_model
+    {
+      EarthRotationModelType::Gast => (self.calc_dcm_pci_to_pcpf(epoch), Array2::eye(3), EopRecord::new()),
+      EarthRotationModelType::Iau2006 => self.calc_dcm_gcrs_to_tirs(epoch)
+    };
+    let omega_vec: Array1<f64> = Array1::from(vec![0.0, 0.0, calc_rotation_rate(omega_rads, &eop)]);
+
+    let pos_tirs_m: Array1<f64> = dcm_gcrs_to_tirs.dot(&pos_pci_m_in);
+    let vel_tirs_ms: Array1<f64> = dcm_gcrs_to_tirs.dot(&vel_pci_ms_in)
+                                   - cross_product_array1(omega_vec.view(), pos_tirs_m.view());
+
+    (dcm_tirs_to_itrs.dot(&pos_tirs_m), dcm_tirs_to_itrs.dot(&vel_tirs_ms))
+  }
+
+ /*
+  * @brief: Function to convert position and velocity from PCPF to PCI (inverse
+  *         of convert_pci_to_pcpf_state())
+  *
+  * @param[in] omega_rads - Nominal rotation rate of the planet
+  *
+  * @returns: (position [m], velocity [m/s]) in PCI
+  */
+  pub fn convert_pcpf_to_pci_state(&self,
+                                   pos_pcpf_m_in: ArrayView1<f64>,
+                                   vel_pcpf_ms_in: ArrayView1<f64>,
+                                   epoch: &Epoch,
+                                   omega_rads: f64)
+  -> (Array1<f64>, Array1<f64>)
+  {
+    let (dcm_gcrs_to_tirs, dcm_tirs_to_itrs, eop) = match self.rotation_model
+    {
+      EarthRotationModelType::Gast => (self.calc_dcm_pci_to_pcpf(epoch), Array2::eye(3), EopRecord::new()),
+      EarthRotationModelType::Iau2006 => self.calc_dcm_gcrs_to_tirs(epoch)
+    };
+    let omega_vec: Array1<f64> = Array1::from(vec![0.0, 0.0, calc_rotation_rate(omega_rads, &eop)]);
+
+    let pos_tirs_m: Array1<f64> = dcm_tirs_to_itrs.t().dot(&pos_pcpf_m_in);
+    let vel_tirs_ms: Array1<f64> = dcm_tirs_to_itrs.t().dot(&vel_pcpf_ms_in)
+                                   + cross_product_array1(omega_vec.view(), pos_tirs_m.view());
+
+    (dcm_gcrs_to_tirs.t().dot(&pos_tirs_m), dcm_gcrs_to_tirs.t().dot(&vel_tirs_ms))
+  }
+
+ /*
+  * @brief: Function to compute the Greenwich apparent sidereal time
+  *
+  * @description: For the iau2006 model the GAST is the angle between the true
+  *               equinox of date and the terrestrial intermediate origin,
+  *               i.e. the Earth rotation angle minus the equation of the
+  *               origins.
+  *
+  * @param[in] epoch - Epoch (UTC)
+  *
+  * @returns: GAST in [0, 360)
+  * @unit: degree
+  */
+  pub fn calc_gast_deg(&self, epoch: &Epoch)
+  -> f64
+  {
+    match self.rotation_model
+    {
+      EarthRotationModelType::Gast => calc_earth_gast_deg(epoch.to_datetime()),
+      EarthRotationModelType::Iau2006 =>
+      {
+        let (dcm_gcrs_to_tirs, _, _) = self.calc_dcm_gcrs_to_tirs(epoch);
+        let t: f64 = calc_julian_centuries_tt(epoch);
+
+        /* CIP and true equinox of date in GCRS */
+        let cip_gcrs: Array1<f64> = dcm_gcrs_to_tirs.row(2).to_owned();
+        let equinox_gcrs: Array1<f64> = cross_product_array1(cip_gcrs.view(), calc_ecliptic_pole(t).view());
+        let equinox_tirs: Array1<f64> = dcm_gcrs_to_tirs.dot(&equinox_gcrs);
+
+        (-equinox_tirs[1].atan2(equinox_tirs[0])).to_degrees().rem_euclid(360.0)
+      }
+    }
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+impl EarthOrientation {
+ /*
+  * @brief: Function to check if the cached matrices are valid for the epoch
+  */
+  fn is_cache_valid(&self, epoch: &Epoch)
+  -> bool
+  {
+    match self.cache_epoch
+    {
+      Some(cache_epoch) => epoch.diff_s(&cache_epoch).abs() <= EOP_UPDATE_INTERVAL_S,
+      None => false
+    }
+  }
+
+ /*
+  * @brief: Function to compute the rotation from GCRS to TIRS, R3(ERA) Q^T,
+  *         and the polar motion matrix W^T with the EOP used for both.
+  */
+  fn calc_dcm_gcrs_to_tirs(&self, epoch: &Epoch)
+  -> (Array2<f64>, Array2<f64>, EopRecord)
+  {
+    let (dcm_gcrs_to_cirs, dcm_tirs_to_itrs, eop) = if self.is_cache_valid(epoch)
+    {
+      (self.dcm_gcrs_to_cirs.clone(), self.dcm_tirs_to_itrs.clone(), self.eop)
+    }
+    else
+    {
+      self.calc_orientation(epoch)
+    };
+
+    let epoch_ut1: Epoch = epoch.convert_time_scale(TimeScale::Utc, TimeScale::Ut1, eop.ut1_minus_utc_s);
+    let dcm_gcrs_to_tirs: Array2<f64> = calc_frame_rot_z(calc_earth_rotation_angle(*epoch_ut1.get_days() as f64,
+                                                                                   epoch_ut1.calc_day_fraction()))
+                                          .dot(&dcm_gcrs_to_cirs);
+
+    (dcm_gcrs_to_tirs, dcm_tirs_to_itrs, eop)
+  }
+
+ /*
+  * @brief: Function to compute the precession-nutation matrix Q^T, the polar
+  *         motion matrix W^T and the interpolated EOP for the epoch.
+  */
+  fn calc_orientation(&self, epoch: &Epoch)
+  -> (Array2<f64>, Array2<f64>, EopRecord)
+  {
+    let eop: EopRecord = self.eop_table.interpolate(epoch.calc_mjd());
+    let t: f64 = calc_julian_centuries_tt(epoch);
+    let fundamental_arguments: [f64; NUM_FUNDAMENTAL_ARGUMENTS] = calc_fundamental_arguments(t);
+
+    let (cip_x, cip_y, cio_s): (f64, f64, f64) = if self.cip_series_x.is_loaded()
+    {
+      /* Full IAU 2006/2000A series */
+      let cip_x: f64 = (eval_polynomial(&CIP_X_POLYNOMIAL_UAS, t)
+                        + self.cip_series_x.eval(t, &fundamental_arguments)) * MICROARCSEC_TO_RAD;
+      let cip_y: f64 = (eval_polynomial(&CIP_Y_POLYNOMIAL_UAS, t)
+                        + self.cip_series_y.eval(t, &fundamental_arguments)) * MICROARCSEC_TO_RAD;
+      let cio_s_plus_xy_half: f64 = (eval_polynomial(&CIO_S_POLYNOMIAL_UAS, t)
+                                     + self.cip_series_s.eval(t, &fundamental_arguments)) * MICROARCSEC_TO_RAD;
+      (cip_x, cip_y, cio_s_plus_xy_half - 0.5 * cip_x * cip_y)
+    }
+    else
+    {
+      /* IAU 2006 precession and IAU 2000B nutation */
+      let (dpsi, deps): (f64, f64) = calc_nutation_iau2000b(t, &fundamental_arguments);
+      let (cip_x, cip_y): (f64, f64) = calc_cip_xy_from_nutation(t, dpsi, deps);
+      let cio_s_plus_xy_half: f64 = (eval_polynomial(&CIO_S_POLYNOMIAL_UAS, t)
+                                     + calc_cio_locator_leading_terms_uas(&fundamental_arguments)) * MICROARCSEC_TO_RAD;
+      (cip_x, cip_y, cio_s_plus_xy_half - 0.5 * cip_x * cip_y)
+    };
+
+    /* Apply the observed celestial pole offsets */
+    (calc_dcm_gcrs_to_cirs(cip_x + eop.dx_rad, cip_y + eop.dy_rad, cio_s),
+     calc_dcm_tirs_to_itrs(eop.xp_rad, eop.yp_rad, calc_tio_locator(t)),
+     eop)
+  }
+}
+
+/*
+ * @brief: Function to compute Julian centuries TT since J2000.0 from a UTC
+ *         epoch
+ */
+fn calc_julian_centuries_tt(epoch: &Epoch)
+-> f64
+{
+  epoch.convert_utc_to_tt().calc_julian_centuries()
+}
+
+/*
+ * @brief: Function to compute the Earth's rotation rate corrected by the
+ *         excess length of day
+ */
+fn calc_rotation_rate(omega_rads: f64, eop: &EopRecord)
+-> f64
+{
+  omega_rads * (1.0 - eop.lod_s / 86400.0)
+}
+
+/*
+ * @brief: Function to check if a configured data file path is set and exists
+ */
+fn is_data_file_available(file_path: &str)
+-> bool
+{
+  !file_path.is_empty() && Path::new(file_path).is_file()
+}
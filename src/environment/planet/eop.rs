@@ -0,0 +1,381 @@
+/*
+ * @brief: This file contains the loader and interpolation of IERS Earth
+ *         Orientation Parameters (EOP).
+ *
+ * @description: Supported file formats (eop_file_format in planet.ini):
+ *
+ *               * finals2000a - IERS Rapid Service/Prediction Center
+ *                               finals2000A.all / finals2000A.data (fixed
+ *                               width columns, dX/dY w.r.t. IAU 2000A)
+ *               * c04         - IERS EOP 14 C04 (IAU2000A) series
+ *                               eopc04_IAU2000.62-now (whitespace separated
+ *                               columns: year month day MJD x y UT1-UTC LOD
+ *                               dX dY ...)
+ *               * c04_20      - IERS EOP 20 C04 series eopc04.1962-now
+ *                               (whitespace separated columns: year month
+ *                               day hour MJD x y UT1-UTC dX dY xrt yrt LOD
+ *                               ...)
+ *
+ *               The MJD column of the C04 series is checked against the
+ *               calendar date columns, a file that does not match the
+ *               configured layout is rejected.
+ *
+ *               Values are linearly interpolated between the daily records
+ *               and held constant outside of the file's time span. UT1-UTC
+ *               jumps by 1 s at each leap second, hence UT1-TAI is
+ *               interpolated instead and converted back to UT1-UTC with the
+ *               leap second table.
+ *
+ */
+use std::fs;
+use chrono::NaiveDate;
+
+/* Include local crates */
+use crate::math::time_scale_math::calc_tai_minus_utc_s;
+
+/* Import constants */
+use crate::constants::earth_orientation::{ARCSEC_TO_RAD, MILLIARCSEC_TO_RAD};
+use crate::constants::time::{JULIAN_DAYS_AT_J2000_EPOCH, DIFF_MJD_TO_JD, SECONDS_OF_EARTH_DAY};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum EopFileFormat {
+  Finals2000a,
+  C04,
+  C04Eop20
+}
+
+impl EopFileFormat {
+ /*
+  * @brief: Function to map the eop_file_format parameter string to the file
+  *         format.
+  */
+  pub fn from_param_str(format_str: &str) -> EopFileFormat
+  {
+    match format_str.trim().trim_matches('"')
+    {
+      "finals2000a" => EopFileFormat::Finals2000a,
+      "c04" => EopFileFormat::C04,
+      "c04_20" => EopFileFormat::C04Eop20,
+      _ => panic!("! [ERROR] ! > Unknown EOP file format {} in planet.ini! <", format_str)
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub struct EopRecord {
+  /* [epoch]
+   * @description : Modified Julian date (UTC)
+   * @unit        : days
+   * */
+  pub mjd_utc: f64,
+  /* [polar motion]
+   * @description : Coordinates of the CIP in the ITRS
+   * @unit        : rad
+   * */
+  pub xp_rad: f64,
+  pub yp_rad: f64,
+  /* [UT1-UTC]
+   * @description : Difference between UT1 and UTC
+   * @unit        : seconds
+   * */
+  pub ut1_minus_utc_s: f64,
+  /* [length of day]
+   * @description : Excess length of day
+   * @unit        : seconds
+   * */
+  pub lod_s: f64,
+  /* [celestial pole offsets]
+   * @description : Corrections dX, dY to the IAU 2006/2000A CIP coordinates
+   * @unit        : rad
+   * */
+  pub dx_rad: f64,
+  pub dy_rad: f64,
+}
+
+impl EopRecord {
+  pub fn new() -> EopRecord
+  {
+    EopRecord {
+      mjd_utc: 0.0,
+      xp_rad: 0.0,
+      yp_rad: 0.0,
+      ut1_minus_utc_s: 0.0,
+      lod_s: 0.0,
+      dx_rad: 0.0,
+      dy_rad: 0.0,
+    }
+  }
+}
+
+impl Default for EopRecord {
+  fn default() -> EopRecord {
+    EopRecord::new()
+  }
+}
+
+#[derive(Clone)]
+
+pub struct EopTable {
+  /* [records]
+   * @description : Daily EOP records sorted by epoch
+   * @unit        : N/A
+   * */
+  records: Vec<EopRecord>,
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl EopTable {
+  pub fn new() -> EopTable
+  {
+    EopTable {
+      records: Vec::new(),
+    }
+  }
+}
+
+impl Default for EopTable {
+  fn default() -> EopTable {
+    EopTable::new()
+  }
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl EopTable {
+  pub fn get_records(&self) -> &Vec<EopRecord> {&self.records}
+  pub fn is_empty(&self) -> bool {self.records.is_empty()}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl EopTable {
+ /*
+  * @brief: Read EOP records from an IERS file. This function shall be called
+  *         before running the simulation!
+  *
+  * @param[in] filepath    - Path to the EOP file
+  * @param[in] file_format - Format of the EOP file
+  */
+  pub fn load(&mut self, filepath: &str, file_format: EopFileFormat)
+  {
+    let file_content: String = fs::read_to_string(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > EOP file {} not found! <", filepath));
+
+    self.records = file_content.lines()
+                               .filter_map(|line| match file_format
+                               {
+                                 EopFileFormat::Finals2000a => parse_finals2000a_line(line),
+                                 EopFileFormat::C04 => parse_c04_line(line),
+                                 EopFileFormat::C04Eop20 => parse_c04_eop20_line(line)
+                               })
+                               .collect();
+    self.records.sort_by(|a, b| a.mjd_utc.total_cmp(&b.mjd_utc));
+
+    if self.records.is_empty()
+    {
+      panic!("! [ERROR] ! > No EOP records found in {}! <", filepath);
+    }
+    println!("[x] Loaded {} EOP records (MJD {} - {}) from {}",
+             self.records.len(),
+             self.records[0].mjd_utc,
+             self.records[self.records.len() - 1].mjd_utc,
+             filepath);
+  }
+
+ /*
+  * @brief: Function to interpolate the EOP at a given epoch
+  *
+  * @description: Linear interpolation between the enclosing daily records.
+  *               Epochs outside of the table return the first or last record.
+  *               Without loaded records all parameters are zero.
+  *
+  *               UT1-UTC is interpolated as UT1-TAI = (UT1-UTC) - (TAI-UTC),
+  *               which is continuous across leap seconds, and converted back
+  *               with TAI-UTC at the requested epoch.
+  *
+  * @param[in] mjd_utc - Modified Julian date UTC
+  */
+  pub fn interpolate(&self, mjd_utc: f64) -> EopRecord
+  {
+    if self.records.is_empty()
+    {
+      let mut record_out: EopRecord = EopRecord::new();
+      record_out.mjd_utc = mjd_utc;
+      return record_out;
+    }
+
+    let indx: usize = self.records.partition_point(|record| record.mjd_utc <= mjd_utc);
+    if indx == 0
+    {
+      return self.records[0];
+    }
+    if indx == self.records.len()
+    {
+      return self.records[indx - 1];
+    }
+
+    let rec_0: &EopRecord = &self.records[indx - 1];
+    let rec_1: &EopRecord = &self.records[indx];
+    let tau: f64 = (mjd_utc - rec_0.mjd_utc) / (rec_1.mjd_utc - rec_0.mjd_utc);
+    let lerp = |v_0: f64, v_1: f64| v_0 + tau * (v_1 - v_0);
+    let ut1_minus_tai_s: f64 = lerp(rec_0.ut1_minus_utc_s - calc_tai_minus_utc_s_at_mjd(rec_0.mjd_utc),
+                                    rec_1.ut1_minus_utc_s - calc_tai_minus_utc_s_at_mjd(rec_1.mjd_utc));
+
+    EopRecord {
+      mjd_utc,
+      xp_rad: lerp(rec_0.xp_rad, rec_1.xp_rad),
+      yp_rad: lerp(rec_0.yp_rad, rec_1.yp_rad),
+      ut1_minus_utc_s: ut1_minus_tai_s + calc_tai_minus_utc_s_at_mjd(mjd_utc),
+      lod_s: lerp(rec_0.lod_s, rec_1.lod_s),
+      dx_rad: lerp(rec_0.dx_rad, rec_1.dx_rad),
+      dy_rad: lerp(rec_0.dy_rad, rec_1.dy_rad),
+    }
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to get TAI-UTC from the leap second table at a modified
+ *         Julian date (UTC)
+ */
+fn calc_tai_minus_utc_s_at_mjd(mjd_utc: f64)
+-> f64
+{
+  calc_tai_minus_utc_s((mjd_utc + DIFF_MJD_TO_JD - JULIAN_DAYS_AT_J2000_EPOCH) * SECONDS_OF_EARTH_DAY)
+}
+
+/*
+ * @brief: Function to parse a line of a finals2000A file
+ *
+ * @description: Fixed width format (1-based columns): MJD 8-15, PM-x 19-27
+ *               [arcsec], PM-y 38-46 [arcsec], UT1-UTC 59-68 [s], LOD 80-86
+ *               [ms], dX 98-106 [mas], dY 117-125 [mas]. Lines without polar
+ *               motion or UT1-UTC values are skipped, missing LOD and
+ *               celestial pole offsets are set to zero.
+ */
+fn parse_finals2000a_line(line: &str)
+-> Option<EopRecord>
+{
+  let read_column = |first: usize, last: usize| -> Option<f64> {
+    line.get(first..last.min(line.len()))
+        .and_then(|field| field.trim().parse::<f64>().ok())
+  };
+
+  Some(EopRecord {
+    mjd_utc: read_column(7, 15)?,
+    xp_rad: read_column(18, 27)? * ARCSEC_TO_RAD,
+    yp_rad: read_column(37, 46)? * ARCSEC_TO_RAD,
+    ut1_minus_utc_s: read_column(58, 68)?,
+    lod_s: read_column(79, 86).unwrap_or(0.0) * 1.0e-3,
+    dx_rad: read_column(97, 106).unwrap_or(0.0) * MILLIARCSEC_TO_RAD,
+    dy_rad: read_column(116, 125).unwrap_or(0.0) * MILLIARCSEC_TO_RAD,
+  })
+}
+
+/*
+ * @brief: Function to parse a line of an EOP 14 C04 file
+ *
+ * @description: Whitespace separated columns: year, month, day, MJD, x
+ *               [arcsec], y [arcsec], UT1-UTC [s], LOD [s], dX [arcsec],
+ *               dY [arcsec], followed by the uncertainties. Header lines are
+ *               skipped.
+ */
+fn parse_c04_line(line: &str)
+-> Option<EopRecord>
+{
+  let fields: Vec<f64> = split_c04_line(line)?;
+  check_c04_layout(&fields, 3, "c04");
+
+  Some(EopRecord {
+    mjd_utc: fields[3],
+    xp_rad: fields[4] * ARCSEC_TO_RAD,
+    yp_rad: fields[5] * ARCSEC_TO_RAD,
+    ut1_minus_utc_s: fields[6],
+    lod_s: fields[7],
+    dx_rad: fields[8] * ARCSEC_TO_RAD,
+    dy_rad: fields[9] * ARCSEC_TO_RAD,
+  })
+}
+
+/*
+ * @brief: Function to parse a line of an EOP 20 C04 file
+ *
+ * @description: Whitespace separated columns: year, month, day, hour, MJD,
+ *               x [arcsec], y [arcsec], UT1-UTC [s], dX [arcsec], dY
+ *               [arcsec], x rate [arcsec/day], y rate [arcsec/day], LOD [s],
+ *               followed by the uncertainties. Header lines are skipped.
+ */
+fn parse_c04_eop20_line(line: &str)
+-> Option<EopRecord>
+{
+  let fields: Vec<f64> = split_c04_line(line)?;
+  if fields.len() < 13
+  {
+    return None;
+  }
+  check_c04_layout(&fields, 4, "c04_20");
+
+  Some(EopRecord {
+    mjd_utc: fields[4],
+    xp_rad: fields[5] * ARCSEC_TO_RAD,
+    yp_rad: fields[6] * ARCSEC_TO_RAD,
+    ut1_minus_utc_s: fields[7],
+    lod_s: fields[12],
+    dx_rad: fields[8] * ARCSEC_TO_RAD,
+    dy_rad: fields[9] * ARCSEC_TO_RAD,
+  })
+}
+
+/*
+ * @brief: Function to split a data line of a C04 file into its numeric
+ *         fields. Header and short lines return None.
+ */
+fn split_c04_line(line: &str)
+-> Option<Vec<f64>>
+{
+  let fields: Vec<f64> = line.split_whitespace()
+                             .map(|field| field.parse::<f64>())
+                             .collect::<Result<Vec<f64>, _>>()
+                             .ok()?;
+  if fields.len() < 10
+  {
+    return None;
+  }
+  Some(fields)
+}
+
+/*
+ * @brief: Function to check that the MJD column of a C04 data line matches
+ *         its calendar date columns (year, month, day). Panics if the file
+ *         layout does not match the configured eop_file_format.
+ */
+fn check_c04_layout(fields: &[f64], mjd_indx: usize, format_str: &str)
+{
+  let mjd_epoch: NaiveDate = NaiveDate::from_ymd_opt(1858, 11, 17).unwrap();
+  let mjd_calendar: Option<f64> = NaiveDate::from_ymd_opt(fields[0] as i32,
+                                                          fields[1] as u32,
+                                                          fields[2] as u32)
+    .map(|date| (date - mjd_epoch).num_days() as f64);
+
+  match mjd_calendar
+  {
+    Some(mjd) if (fields[mjd_indx] - mjd).abs() < 1.0 => (),
+    _ => panic!("! [ERROR] ! > EOP file layout does not match eop_file_format = {} in planet.ini! \
+                 Use c04 for EOP 14 C04 or c04_20 for EOP 20 C04 files. <", format_str)
+  }
+}
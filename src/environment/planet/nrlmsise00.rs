@@ -0,0 +1,1516 @@
+/*
+ * @brief: NRLMSISE-00 empirical model of the Earth's atmosphere from the
+ *         ground to the exosphere.
+ *
+ * @description: Port of the NRLMSISE-00 model (Picone, Hedin, Drob, Aikin
+ *               2002) following the structure of the reference C
+ *               implementation by Dominik Brodowski (gtd7/gtd7d).
+ *
+ *               The model computes the number densities of He, O, N2, O2, Ar,
+ *               H, N and anomalous O, the total mass density, the exospheric
+ *               temperature and the temperature at altitude as a function of
+ *               position, local solar time, day of year and solar/geomagnetic
+ *               activity (F10.7, 81 day average F10.7, daily Ap).
+ *
+ *               The standard model switches are used (all variations on, daily
+ *               Ap instead of the 3-hour Ap history) and all outputs are in SI
+ *               units (m^-3, kg/m^3, K).
+ *
+ *               The model coefficients are loaded at initialization from a
+ *               plain coefficient file converted from the data file of the
+ *               reference C implementation (nrlmsise-00_data.c), see
+ *               assets/atmosphere/earth/DATA_README.txt
+ *
+ *         Note: The total mass density returned by gtd7d includes the
+ *               anomalous oxygen contribution and is the one recommended for
+ *               drag computations.
+ *
+ *         Ref: J.M. Picone, A.E. Hedin, D.P. Drob, A.C. Aikin - NRLMSISE-00
+ *              empirical model of the atmosphere: Statistical comparisons and
+ *              scientific issues, J. Geophys. Res., 107(A12), 1468, 2002
+ */
+
+/* Include external crates */
+use std::fs;
+use std::collections::HashMap;
+
+/* Import constants */
+use crate::constants::atmosphere::*;
+
+/*
+ * @brief: Model switches. Index 0 selects SI output units, all other
+ *         variations are switched on (standard settings). Switch 9 = 1
+ *         selects the daily Ap index for the magnetic activity.
+ */
+const SW: [f64; 24] = [1.0; 24];
+const SWC: [f64; 24] = [1.0; 24];
+
+/* Conversion factors of the reference implementation */
+const DGTR: f64 = 1.74533E-2;
+const DR: f64 = 1.72142E-2;
+const SR: f64 = 7.2722E-5;
+const HR: f64 = 0.2618;
+const RGAS: f64 = 831.4;
+
+/*
+ * @brief: Input set of a single model evaluation
+ */
+#[derive(Clone, Copy)]
+
+pub struct Nrlmsise00Input {
+  /* Day of year [1 - 366] */
+  pub doy: f64,
+  /* Seconds in day (UT) [s] */
+  pub sec: f64,
+  /* Geodetic altitude [km] */
+  pub alt_km: f64,
+  /* Geodetic latitude [deg] */
+  pub g_lat_deg: f64,
+  /* Geodetic longitude [deg] */
+  pub g_long_deg: f64,
+  /* Local apparent solar time [h] */
+  pub lst_h: f64,
+  /* 81 day average of the F10.7 flux (centred on doy) */
+  pub f107a: f64,
+  /* Daily F10.7 flux of the previous day */
+  pub f107: f64,
+  /* Daily magnetic index Ap */
+  pub ap: f64
+}
+
+/*
+ * @brief: Output set of a single model evaluation
+ *
+ * @description: d - Number densities [m^-3] and total mass density [kg/m^3],
+ *                   see NRLMSISE_INDX_* for the individual entries
+ *               t - Exospheric temperature and temperature at altitude [K]
+ */
+#[derive(Clone, Copy)]
+
+pub struct Nrlmsise00Output {
+  pub d: [f64; 9],
+  pub t: [f64; 2]
+}
+
+#[derive(Clone)]
+
+pub struct Nrlmsise00 {
+  /*
+   * @description : Model coefficients as named in the reference
+   *                implementation
+   * @unit        : N/A
+   *
+   * */
+  pt: Vec<f64>,
+  pd: Vec<Vec<f64>>,
+  ps: Vec<f64>,
+  pdl: Vec<Vec<f64>>,
+  ptl: Vec<Vec<f64>>,
+  pma: Vec<Vec<f64>>,
+  ptm: Vec<f64>,
+  pdm: Vec<Vec<f64>>,
+  pavgm: Vec<f64>
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Nrlmsise00 {
+  pub fn new() -> Nrlmsise00 {
+    Nrlmsise00 {
+      pt: Vec::new(),
+      pd: Vec::new(),
+      ps: Vec::new(),
+      pdl: Vec::new(),
+      ptl: Vec::new(),
+      pma: Vec::new(),
+      ptm: Vec::new(),
+      pdm: Vec::new(),
+      pavgm: Vec::new()
+    }
+  }
+}
+
+impl Default for Nrlmsise00 {
+  fn default() -> Nrlmsise00 {
+    Nrlmsise00::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ * */
+impl Nrlmsise00 {
+ /*
+  * @brief: Load the model coefficients from the plain coefficient file
+  *         (see assets/atmosphere/earth/convert_nrlmsise00.py).
+  *
+  * @description: Each coefficient array starts with a header line
+  *               "<name> <rows> <cols>" followed by its values in row-major
+  *               order. The arrays are identified by the variable names of the
+  *               reference implementation (pt, pd, ps, pdl, ptl, pma, ptm,
+  *               pdm, pavgm). Lines starting with # are comments.
+  *
+  */
+  pub fn load_coefficients(&mut self, filepath: &str)
+  {
+    let content: String = fs::read_to_string(filepath)
+      .unwrap_or_else(|_| panic!("! [ERROR] ! > NRLMSISE-00 coefficient file {} not found! \
+                                  Run assets/atmosphere/earth/download_nrlmsise00.sh! <", filepath));
+    let mut arrays: HashMap<String, Vec<Vec<f64>>> = parse_coefficient_file(&content);
+
+    self.pt    = take_coefficient_array(&mut arrays, "pt", 1, 150).remove(0);
+    self.pd    = take_coefficient_array(&mut arrays, "pd", 9, 150);
+    self.ps    = take_coefficient_array(&mut arrays, "ps", 1, 150).remove(0);
+    self.pdl   = take_coefficient_array(&mut arrays, "pdl", 2, 25);
+    self.ptl   = take_coefficient_array(&mut arrays, "ptl", 4, 100);
+    self.pma   = take_coefficient_array(&mut arrays, "pma", 10, 100);
+    self.ptm   = take_coefficient_array(&mut arrays, "ptm", 1, 10).remove(0);
+    self.pdm   = take_coefficient_array(&mut arrays, "pdm", 8, 10);
+    self.pavgm = take_coefficient_array(&mut arrays, "pavgm", 1, 10).remove(0);
+
+    println!("[x] Load NRLMSISE-00 coefficients from {}", filepath);
+  }
+
+ /*
+  * @brief: Evaluate the model. Equivalent to gtd7d of the reference
+  *         implementation, i.e. the total mass density includes anomalous
+  *         oxygen.
+  *
+  * @returns: Densities and temperatures at the given input conditions
+  *
+  */
+  pub fn gtd7d(&self, input: &Nrlmsise00Input)
+  -> Nrlmsise00Output
+  {
+    let mut evaluator: Evaluator = Evaluator::new(self);
+    let mut output: Nrlmsise00Output = evaluator.gtd7(input);
+
+    output.d[NRLMSISE_INDX_TOTAL_MASS] = 1.66E-24 * (4.0 * output.d[NRLMSISE_INDX_HE]
+                                                     + 16.0 * output.d[NRLMSISE_INDX_O]
+                                                     + 28.0 * output.d[NRLMSISE_INDX_N2]
+                                                     + 32.0 * output.d[NRLMSISE_INDX_O2]
+                                                     + 40.0 * output.d[NRLMSISE_INDX_AR]
+                                                     + output.d[NRLMSISE_INDX_H]
+                                                     + 14.0 * output.d[NRLMSISE_INDX_N]
+                                                     + 16.0 * output.d[NRLMSISE_INDX_ANOMALOUS_O]);
+    if SW[0] != 0.0
+    {
+      output.d[NRLMSISE_INDX_TOTAL_MASS] /= 1000.0;
+    }
+
+    output
+  }
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Working variables of a single model evaluation. These are the
+ *         static (common block) variables of the reference implementation.
+ */
+struct Evaluator<'a> {
+  coeff: &'a Nrlmsise00,
+  /* PARMB */
+  gsurf: f64,
+  re: f64,
+  /* DMIX */
+  dm28: f64,
+  /* MESO7 */
+  meso_tn1: [f64; 5],
+  meso_tn2: [f64; 4],
+  meso_tn3: [f64; 5],
+  meso_tgn1: [f64; 2],
+  meso_tgn2: [f64; 2],
+  meso_tgn3: [f64; 2],
+  /* LPOLY */
+  dfa: f64,
+  plg: [[f64; 9]; 4],
+  ctloc: f64,
+  stloc: f64,
+  c2tloc: f64,
+  s2tloc: f64,
+  s3tloc: f64,
+  c3tloc: f64,
+  apdf: f64
+}
+
+impl<'a> Evaluator<'a> {
+  fn new(coeff: &'a Nrlmsise00) -> Evaluator<'a>
+  {
+    Evaluator {
+      coeff,
+      gsurf: 0.0,
+      re: 0.0,
+      dm28: 0.0,
+      meso_tn1: [0.0; 5],
+      meso_tn2: [0.0; 4],
+      meso_tn3: [0.0; 5],
+      meso_tgn1: [0.0; 2],
+      meso_tgn2: [0.0; 2],
+      meso_tgn3: [0.0; 2],
+      dfa: 0.0,
+      plg: [[0.0; 9]; 4],
+      ctloc: 0.0,
+      stloc: 0.0,
+      c2tloc: 0.0,
+      s2tloc: 0.0,
+      s3tloc: 0.0,
+      c3tloc: 0.0,
+      apdf: 0.0
+    }
+  }
+
+ /*
+  * @brief: Latitude variation of the surface gravity and the effective Earth
+  *         radius
+  */
+  fn glatf(&mut self, lat: f64)
+  {
+    let c2: f64 = (2.0 * DGTR * lat).cos();
+    self.gsurf = 980.616 * (1.0 - 0.0026373 * c2);
+    self.re = 2.0 * self.gsurf / (3.085462E-6 + 2.27E-9 * c2) * 1.0E-5;
+  }
+
+ /*
+  * @brief: Pressure scale height
+  */
+  fn scalh(&self, alt: f64, xm: f64, temp: f64)
+  -> f64
+  {
+    let g: f64 = self.gsurf / (1.0 + alt / self.re).powi(2);
+    RGAS * temp / (g * xm)
+  }
+
+ /*
+  * @brief: Geopotential altitude difference
+  */
+  fn zeta(&self, zz: f64, zl: f64)
+  -> f64
+  {
+    (zz - zl) * (self.re + zl) / (self.re + zz)
+  }
+
+ /*
+  * @brief: Temperature and density profiles of the lower atmosphere
+  *         (below zn2[0])
+  */
+  #[allow(clippy::too_many_arguments)]
+  fn densm(&self, alt: f64, d0: f64, xm: f64, tz: &mut f64,
+           zn3: &[f64], tn3: &[f64], tgn3: &[f64],
+           zn2: &[f64], tn2: &[f64], tgn2: &[f64])
+  -> f64
+  {
+    let mut xs: [f64; 10] = [0.0; 10];
+    let mut ys: [f64; 10] = [0.0; 10];
+    let mut y2out: [f64; 10] = [0.0; 10];
+    let mut densm_tmp: f64 = d0;
+
+    if alt > zn2[0]
+    {
+      return if xm == 0.0 { *tz } else { d0 };
+    }
+
+    /* [STRATOSPHERE / MESOSPHERE TEMPERATURE] */
+    let mn: usize = zn2.len();
+    let z: f64 = if alt > zn2[mn - 1] { alt } else { zn2[mn - 1] };
+    let z1: f64 = zn2[0];
+    let z2: f64 = zn2[mn - 1];
+    let t1: f64 = tn2[0];
+    let t2: f64 = tn2[mn - 1];
+    let zg: f64 = self.zeta(z, z1);
+    let zgdif: f64 = self.zeta(z2, z1);
+
+    /* Set up spline nodes */
+    for k in 0..mn
+    {
+      xs[k] = self.zeta(zn2[k], z1) / zgdif;
+      ys[k] = 1.0 / tn2[k];
+    }
+    let yd1: f64 = -tgn2[0] / (t1 * t1) * zgdif;
+    let yd2: f64 = -tgn2[1] / (t2 * t2) * zgdif * ((self.re + z2) / (self.re + z1)).powi(2);
+
+    /* Calculate spline coefficients */
+    spline(&xs[..mn], &ys[..mn], yd1, yd2, &mut y2out[..mn]);
+    let x: f64 = zg / zgdif;
+    let y: f64 = splint(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+
+    /* Temperature at altitude */
+    *tz = 1.0 / y;
+    if xm != 0.0
+    {
+      /* Stratosphere / mesosphere density */
+      let glb: f64 = self.gsurf / (1.0 + z1 / self.re).powi(2);
+      let gamm: f64 = xm * glb * zgdif / RGAS;
+
+      /* Integrate temperature profile */
+      let yi: f64 = splini(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+      let expl: f64 = (gamm * yi).min(50.0);
+
+      /* Density at altitude */
+      densm_tmp = densm_tmp * (t1 / *tz) * (-expl).exp();
+    }
+
+    if alt > zn3[0]
+    {
+      return if xm == 0.0 { *tz } else { densm_tmp };
+    }
+
+    /* [TROPOSPHERE / STRATOSPHERE TEMPERATURE] */
+    let mn: usize = zn3.len();
+    let z: f64 = alt;
+    let z1: f64 = zn3[0];
+    let z2: f64 = zn3[mn - 1];
+    let t1: f64 = tn3[0];
+    let t2: f64 = tn3[mn - 1];
+    let zg: f64 = self.zeta(z, z1);
+    let zgdif: f64 = self.zeta(z2, z1);
+
+    /* Set up spline nodes */
+    for k in 0..mn
+    {
+      xs[k] = self.zeta(zn3[k], z1) / zgdif;
+      ys[k] = 1.0 / tn3[k];
+    }
+    let yd1: f64 = -tgn3[0] / (t1 * t1) * zgdif;
+    let yd2: f64 = -tgn3[1] / (t2 * t2) * zgdif * ((self.re + z2) / (self.re + z1)).powi(2);
+
+    /* Calculate spline coefficients */
+    spline(&xs[..mn], &ys[..mn], yd1, yd2, &mut y2out[..mn]);
+    let x: f64 = zg / zgdif;
+    let y: f64 = splint(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+
+    /* Temperature at altitude */
+    *tz = 1.0 / y;
+    if xm != 0.0
+    {
+      /* Troposphere / stratosphere density */
+      let glb: f64 = self.gsurf / (1.0 + z1 / self.re).powi(2);
+      let gamm: f64 = xm * glb * zgdif / RGAS;
+
+      /* Integrate temperature profile */
+      let yi: f64 = splini(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+      let expl: f64 = (gamm * yi).min(50.0);
+
+      /* Density at altitude */
+      densm_tmp = densm_tmp * (t1 / *tz) * (-expl).exp();
+    }
+
+    if xm == 0.0 { *tz } else { densm_tmp }
+  }
+
+ /*
+  * @brief: Temperature and density profiles of the thermosphere (Bates
+  *         profile above za, spline below)
+  */
+  #[allow(clippy::too_many_arguments)]
+  fn densu(&mut self, alt: f64, dlb: f64, tinf: f64, tlb: f64, xm: f64, alpha: f64,
+           tz: &mut f64, zlb: f64, s2: f64, zn1: &[f64])
+  -> f64
+  {
+    let mut xs: [f64; 5] = [0.0; 5];
+    let mut ys: [f64; 5] = [0.0; 5];
+    let mut y2out: [f64; 5] = [0.0; 5];
+    let mn: usize = zn1.len();
+    let mut x: f64 = 0.0;
+    let mut z1: f64 = 0.0;
+    let mut t1: f64 = 0.0;
+    let mut zgdif: f64 = 0.0;
+
+    /* Joining altitudes of Bates and spline */
+    let za: f64 = zn1[0];
+    let z: f64 = if alt > za { alt } else { za };
+
+    /* Geopotential altitude difference from zlb */
+    let zg2: f64 = self.zeta(z, zlb);
+
+    /* Bates temperature */
+    let tt: f64 = tinf - (tinf - tlb) * (-s2 * zg2).exp();
+    let ta: f64 = tt;
+    *tz = tt;
+    let mut densu_temp: f64 = *tz;
+
+    if alt < za
+    {
+      /* Temperature gradient at za from Bates profile */
+      let dta: f64 = (tinf - ta) * s2 * ((self.re + zlb) / (self.re + za)).powi(2);
+      self.meso_tgn1[0] = dta;
+      self.meso_tn1[0] = ta;
+      let z: f64 = if alt > zn1[mn - 1] { alt } else { zn1[mn - 1] };
+      z1 = zn1[0];
+      let z2: f64 = zn1[mn - 1];
+      t1 = self.meso_tn1[0];
+      let t2: f64 = self.meso_tn1[mn - 1];
+      /* Geopotential difference from z1 */
+      let zg: f64 = self.zeta(z, z1);
+      zgdif = self.zeta(z2, z1);
+      /* Set up spline nodes */
+      for k in 0..mn
+      {
+        xs[k] = self.zeta(zn1[k], z1) / zgdif;
+        ys[k] = 1.0 / self.meso_tn1[k];
+      }
+      /* End node derivatives */
+      let yd1: f64 = -self.meso_tgn1[0] / (t1 * t1) * zgdif;
+      let yd2: f64 = -self.meso_tgn1[1] / (t2 * t2) * zgdif * ((self.re + z2) / (self.re + z1)).powi(2);
+      /* Calculate spline coefficients */
+      spline(&xs[..mn], &ys[..mn], yd1, yd2, &mut y2out[..mn]);
+      x = zg / zgdif;
+      let y: f64 = splint(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+      /* Temperature at altitude */
+      *tz = 1.0 / y;
+      densu_temp = *tz;
+    }
+    if xm == 0.0
+    {
+      return densu_temp;
+    }
+
+    /* Calculate density above za */
+    let glb: f64 = self.gsurf / (1.0 + zlb / self.re).powi(2);
+    let gamma: f64 = xm * glb / (s2 * RGAS * tinf);
+    let mut expl: f64 = (-s2 * gamma * zg2).exp();
+    if expl > 50.0 || tt <= 0.0
+    {
+      expl = 50.0;
+    }
+
+    /* Density at altitude */
+    let densa: f64 = dlb * (tlb / tt).powf(1.0 + alpha + gamma) * expl;
+    densu_temp = densa;
+    if alt >= za
+    {
+      return densu_temp;
+    }
+
+    /* Calculate density below za */
+    let glb: f64 = self.gsurf / (1.0 + z1 / self.re).powi(2);
+    let gamm: f64 = xm * glb * zgdif / RGAS;
+
+    /* Integrate spline temperatures */
+    let yi: f64 = splini(&xs[..mn], &ys[..mn], &y2out[..mn], x);
+    let mut expl: f64 = (gamm * yi).min(50.0);
+    if *tz <= 0.0
+    {
+      expl = 50.0;
+    }
+
+    /* Density at altitude */
+    densu_temp * (t1 / *tz).powf(1.0 + alpha) * (-expl).exp()
+  }
+
+ /*
+  * @brief: G(L) function of the upper thermosphere parameters
+  */
+  fn globe7(&mut self, p: &[f64], input: &Nrlmsise00Input)
+  -> f64
+  {
+    let mut t: [f64; 15] = [0.0; 15];
+    let tloc: f64 = input.lst_h;
+
+    /* Calculate Legendre polynomials */
+    let c: f64 = (input.g_lat_deg * DGTR).sin();
+    let s: f64 = (input.g_lat_deg * DGTR).cos();
+    let c2: f64 = c * c;
+    let c4: f64 = c2 * c2;
+    let s2: f64 = s * s;
+
+    let plg = &mut self.plg;
+    plg[0][1] = c;
+    plg[0][2] = 0.5 * (3.0 * c2 - 1.0);
+    plg[0][3] = 0.5 * (5.0 * c * c2 - 3.0 * c);
+    plg[0][4] = (35.0 * c4 - 30.0 * c2 + 3.0) / 8.0;
+    plg[0][5] = (63.0 * c2 * c2 * c - 70.0 * c2 * c + 15.0 * c) / 8.0;
+    plg[0][6] = (11.0 * c * plg[0][5] - 5.0 * plg[0][4]) / 6.0;
+    plg[1][1] = s;
+    plg[1][2] = 3.0 * c * s;
+    plg[1][3] = 1.5 * (5.0 * c2 - 1.0) * s;
+    plg[1][4] = 2.5 * (7.0 * c2 * c - 3.0 * c) * s;
+    plg[1][5] = 1.875 * (21.0 * c4 - 14.0 * c2 + 1.0) * s;
+    plg[1][6] = (11.0 * c * plg[1][5] - 6.0 * plg[1][4]) / 5.0;
+    plg[2][2] = 3.0 * s2;
+    plg[2][3] = 15.0 * s2 * c;
+    plg[2][4] = 7.5 * (7.0 * c2 - 1.0) * s2;
+    plg[2][5] = 3.0 * c * plg[2][4] - 2.0 * plg[2][3];
+    plg[2][6] = (11.0 * c * plg[2][5] - 7.0 * plg[2][4]) / 4.0;
+    plg[2][7] = (13.0 * c * plg[2][6] - 8.0 * plg[2][5]) / 5.0;
+    plg[3][3] = 15.0 * s2 * s;
+    plg[3][4] = 105.0 * s2 * s * c;
+    plg[3][5] = (9.0 * c * plg[3][4] - 7.0 * plg[3][3]) / 2.0;
+    plg[3][6] = (11.0 * c * plg[3][5] - 8.0 * plg[3][4]) / 3.0;
+    let plg = self.plg;
+
+    if !(SW[7] == 0.0 && SW[8] == 0.0 && SW[14] == 0.0)
+    {
+      self.stloc = (HR * tloc).sin();
+      self.ctloc = (HR * tloc).cos();
+      self.s2tloc = (2.0 * HR * tloc).sin();
+      self.c2tloc = (2.0 * HR * tloc).cos();
+      self.s3tloc = (3.0 * HR * tloc).sin();
+      self.c3tloc = (3.0 * HR * tloc).cos();
+    }
+
+    let cd32: f64 = (DR * (input.doy - p[31])).cos();
+    let cd18: f64 = (2.0 * DR * (input.doy - p[17])).cos();
+    let cd14: f64 = (DR * (input.doy - p[13])).cos();
+    let cd39: f64 = (2.0 * DR * (input.doy - p[38])).cos();
+
+    /* F10.7 effect */
+    let df: f64 = input.f107 - input.f107a;
+    self.dfa = input.f107a - 150.0;
+    let dfa: f64 = self.dfa;
+    t[0] = p[19] * df * (1.0 + p[59] * dfa) + p[20] * df * df + p[21] * dfa + p[29] * dfa.powi(2);
+    let f1: f64 = 1.0 + (p[47] * dfa + p[19] * df + p[20] * df * df) * SWC[1];
+    let f2: f64 = 1.0 + (p[49] * dfa + p[19] * df + p[20] * df * df) * SWC[1];
+
+    /* Time independent */
+    t[1] = (p[1] * plg[0][2] + p[2] * plg[0][4] + p[22] * plg[0][6])
+           + (p[14] * plg[0][2]) * dfa * SWC[1] + p[26] * plg[0][1];
+
+    /* Symmetrical annual */
+    t[2] = p[18] * cd32;
+
+    /* Symmetrical semiannual */
+    t[3] = (p[15] + p[16] * plg[0][2]) * cd18;
+
+    /* Asymmetrical annual */
+    t[4] = f1 * (p[9] * plg[0][1] + p[10] * plg[0][3]) * cd14;
+
+    /* Asymmetrical semiannual */
+    t[5] = p[37] * plg[0][1] * cd39;
+
+    /* Diurnal */
+    if SW[7] != 0.0
+    {
+      let t71: f64 = (p[11] * plg[1][2]) * cd14 * SWC[5];
+      let t72: f64 = (p[12] * plg[1][2]) * cd14 * SWC[5];
+      t[6] = f2 * ((p[3] * plg[1][1] + p[4] * plg[1][3] + p[27] * plg[1][5] + t71) * self.ctloc
+                   + (p[6] * plg[1][1] + p[7] * plg[1][3] + p[28] * plg[1][5] + t72) * self.stloc);
+    }
+
+    /* Semidiurnal */
+    if SW[8] != 0.0
+    {
+      let t81: f64 = (p[23] * plg[2][3] + p[35] * plg[2][5]) * cd14 * SWC[5];
+      let t82: f64 = (p[33] * plg[2][3] + p[36] * plg[2][5]) * cd14 * SWC[5];
+      t[7] = f2 * ((p[5] * plg[2][2] + p[41] * plg[2][4] + t81) * self.c2tloc
+                   + (p[8] * plg[2][2] + p[42] * plg[2][4] + t82) * self.s2tloc);
+    }
+
+    /* Terdiurnal */
+    if SW[14] != 0.0
+    {
+      t[13] = f2 * ((p[39] * plg[3][3] + (p[93] * plg[3][4] + p[46] * plg[3][6]) * cd14 * SWC[5]) * self.s3tloc
+                    + (p[40] * plg[3][3] + (p[94] * plg[3][4] + p[48] * plg[3][6]) * cd14 * SWC[5]) * self.c3tloc);
+    }
+
+    /* Magnetic activity based on daily Ap */
+    let apd: f64 = input.ap - 4.0;
+    let mut p44: f64 = p[43];
+    let p45: f64 = p[44];
+    if p44 < 0.0
+    {
+      p44 = 1.0E-5;
+    }
+    self.apdf = apd + (p45 - 1.0) * (apd + ((-p44 * apd).exp() - 1.0) / p44);
+    if SW[9] != 0.0
+    {
+      t[8] = self.apdf * (p[32] + p[45] * plg[0][2] + p[34] * plg[0][4]
+                          + (p[100] * plg[0][1] + p[101] * plg[0][3] + p[102] * plg[0][5]) * cd14 * SWC[5]
+                          + (p[121] * plg[1][1] + p[122] * plg[1][3] + p[123] * plg[1][5]) * SWC[7]
+                            * (HR * (tloc - p[124])).cos());
+    }
+
+    if SW[10] != 0.0 && input.g_long_deg > -1000.0
+    {
+      /* Longitudinal */
+      if SW[11] != 0.0
+      {
+        t[10] = (1.0 + p[80] * dfa * SWC[1])
+                * ((p[64] * plg[1][2] + p[65] * plg[1][4] + p[66] * plg[1][6]
+                    + p[103] * plg[1][1] + p[104] * plg[1][3] + p[105] * plg[1][5]
+                    + SWC[5] * (p[109] * plg[1][1] + p[110] * plg[1][3] + p[111] * plg[1][5]) * cd14)
+                   * (DGTR * input.g_long_deg).cos()
+                   + (p[90] * plg[1][2] + p[91] * plg[1][4] + p[92] * plg[1][6]
+                      + p[106] * plg[1][1] + p[107] * plg[1][3] + p[108] * plg[1][5]
+                      + SWC[5] * (p[112] * plg[1][1] + p[113] * plg[1][3] + p[114] * plg[1][5]) * cd14)
+                   * (DGTR * input.g_long_deg).sin());
+      }
+
+      /* UT and mixed UT, longitude */
+      if SW[12] != 0.0
+      {
+        t[11] = (1.0 + p[95] * plg[0][1]) * (1.0 + p[81] * dfa * SWC[1])
+                * (1.0 + p[119] * plg[0][1] * SWC[5] * cd14)
+                * ((p[68] * plg[0][1] + p[69] * plg[0][3] + p[70] * plg[0][5])
+                   * (SR * (input.sec - p[71])).cos());
+        t[11] += SWC[11] * (p[76] * plg[2][3] + p[77] * plg[2][5] + p[78] * plg[2][7])
+                 * (SR * (input.sec - p[79]) + 2.0 * DGTR * input.g_long_deg).cos()
+                 * (1.0 + p[137] * dfa * SWC[1]);
+      }
+
+      /* UT, longitude magnetic activity */
+      if SW[13] != 0.0
+      {
+        t[12] = self.apdf * SWC[11] * (1.0 + p[120] * plg[0][1])
+                * ((p[60] * plg[1][2] + p[61] * plg[1][4] + p[62] * plg[1][6])
+                   * (DGTR * (input.g_long_deg - p[63])).cos())
+                + self.apdf * SWC[11] * SWC[5]
+                  * (p[115] * plg[1][1] + p[116] * plg[1][3] + p[117] * plg[1][5])
+                  * cd14 * (DGTR * (input.g_long_deg - p[118])).cos()
+                + self.apdf * SWC[12]
+                  * (p[83] * plg[0][1] + p[84] * plg[0][3] + p[85] * plg[0][5])
+                  * (SR * (input.sec - p[75])).cos();
+      }
+    }
+
+    /* Parameters not used: 82, 89, 99, 139-149 */
+    let mut tinf: f64 = p[30];
+    for i in 0..14
+    {
+      tinf += SW[i + 1].abs() * t[i];
+    }
+    tinf
+  }
+
+ /*
+  * @brief: Version of globe7 for the lower atmosphere
+  */
+  fn glob7s(&self, p: &[f64], input: &Nrlmsise00Input)
+  -> f64
+  {
+    let mut t: [f64; 14] = [0.0; 14];
+    let plg = &self.plg;
+
+    let cd32: f64 = (DR * (input.doy - p[31])).cos();
+    let cd18: f64 = (2.0 * DR * (input.doy - p[17])).cos();
+    let cd14: f64 = (DR * (input.doy - p[13])).cos();
+    let cd39: f64 = (2.0 * DR * (input.doy - p[38])).cos();
+
+    /* F10.7 */
+    t[0] = p[21] * self.dfa;
+
+    /* Time independent */
+    t[1] = p[1] * plg[0][2] + p[2] * plg[0][4] + p[22] * plg[0][6] + p[26] * plg[0][1]
+           + p[14] * plg[0][3] + p[59] * plg[0][5];
+
+    /* Symmetrical annual */
+    t[2] = (p[18] + p[47] * plg[0][2] + p[29] * plg[0][4]) * cd32;
+
+    /* Symmetrical semiannual */
+    t[3] = (p[15] + p[16] * plg[0][2] + p[30] * plg[0][4]) * cd18;
+
+    /* Asymmetrical annual */
+    t[4] = (p[9] * plg[0][1] + p[10] * plg[0][3] + p[20] * plg[0][5]) * cd14;
+
+    /* Asymmetrical semiannual */
+    t[5] = (p[37] * plg[0][1]) * cd39;
+
+    /* Diurnal */
+    if SW[7] != 0.0
+    {
+      let t71: f64 = p[11] * plg[1][2] * cd14 * SWC[5];
+      let t72: f64 = p[12] * plg[1][2] * cd14 * SWC[5];
+      t[6] = (p[3] * plg[1][1] + p[4] * plg[1][3] + t71) * self.ctloc
+             + (p[6] * plg[1][1] + p[7] * plg[1][3] + t72) * self.stloc;
+    }
+
+    /* Semidiurnal */
+    if SW[8] != 0.0
+    {
+      let t81: f64 = (p[23] * plg[2][3] + p[35] * plg[2][5]) * cd14 * SWC[5];
+      let t82: f64 = (p[33] * plg[2][3] + p[36] * plg[2][5]) * cd14 * SWC[5];
+      t[7] = (p[5] * plg[2][2] + p[41] * plg[2][4] + t81) * self.c2tloc
+             + (p[8] * plg[2][2] + p[42] * plg[2][4] + t82) * self.s2tloc;
+    }
+
+    /* Terdiurnal */
+    if SW[14] != 0.0
+    {
+      t[13] = p[39] * plg[3][3] * self.s3tloc + p[40] * plg[3][3] * self.c3tloc;
+    }
+
+    /* Magnetic activity (daily Ap) */
+    if SW[9] != 0.0
+    {
+      t[8] = self.apdf * (p[32] + p[45] * plg[0][2] * SWC[2]);
+    }
+
+    /* Longitudinal */
+    if !(SW[10] == 0.0 || SW[11] == 0.0 || input.g_long_deg <= -1000.0)
+    {
+      t[10] = (1.0 + plg[0][1] * (p[80] * SWC[5] * (DR * (input.doy - p[81])).cos()
+                                  + p[85] * SWC[6] * (2.0 * DR * (input.doy - p[86])).cos())
+               + p[83] * SWC[3] * (DR * (input.doy - p[84])).cos()
+               + p[87] * SWC[4] * (2.0 * DR * (input.doy - p[88])).cos())
+              * ((p[64] * plg[1][2] + p[65] * plg[1][4] + p[66] * plg[1][6]
+                  + p[74] * plg[1][1] + p[75] * plg[1][3] + p[76] * plg[1][5])
+                 * (DGTR * input.g_long_deg).cos()
+                 + (p[90] * plg[1][2] + p[91] * plg[1][4] + p[92] * plg[1][6]
+                    + p[77] * plg[1][1] + p[78] * plg[1][3] + p[79] * plg[1][5])
+                 * (DGTR * input.g_long_deg).sin());
+    }
+
+    let mut tt: f64 = 0.0;
+    for i in 0..14
+    {
+      tt += SW[i + 1].abs() * t[i];
+    }
+    tt
+  }
+
+ /*
+  * @brief: Neutral atmosphere empirical model from the surface to the lower
+  *         exosphere
+  */
+  fn gtd7(&mut self, input: &Nrlmsise00Input)
+  -> Nrlmsise00Output
+  {
+    let coeff: &Nrlmsise00 = self.coeff;
+    let mut output: Nrlmsise00Output = Nrlmsise00Output { d: [0.0; 9], t: [0.0; 2] };
+    let zn3: [f64; 5] = [32.5, 20.0, 15.0, 10.0, 0.0];
+    let zn2: [f64; 4] = [72.5, 55.0, 45.0, 32.5];
+    let zmix: f64 = 62.5;
+    let mut tz: f64 = 0.0;
+
+    /* Latitude variation of gravity (none for sw[2] = 0) */
+    let xlat: f64 = if SW[2] == 0.0 { 45.0 } else { input.g_lat_deg };
+    self.glatf(xlat);
+
+    let xmm: f64 = coeff.pdm[2][4];
+
+    /* [THERMOSPHERE / MESOSPHERE] (above zn2[0]) */
+    let mut input_thermo: Nrlmsise00Input = *input;
+    input_thermo.alt_km = input.alt_km.max(zn2[0]);
+    let soutput: Nrlmsise00Output = self.gts7(&input_thermo);
+
+    /* Metric adjustment */
+    let dm28m: f64 = if SW[0] != 0.0 { self.dm28 * 1.0E6 } else { self.dm28 };
+
+    output.t[0] = soutput.t[0];
+    output.t[1] = soutput.t[1];
+    if input.alt_km >= zn2[0]
+    {
+      output.d = soutput.d;
+      return output;
+    }
+
+    /* [LOWER MESOSPHERE / UPPER STRATOSPHERE] (between zn3[0] and zn2[0])
+     * Temperature at nodes and gradients at end nodes. Inverse temperature
+     * a linear function of spherical harmonics */
+    self.meso_tgn2[0] = self.meso_tgn1[1];
+    self.meso_tn2[0] = self.meso_tn1[4];
+    self.meso_tn2[1] = coeff.pma[0][0] * coeff.pavgm[0] / (1.0 - SW[20] * self.glob7s(&coeff.pma[0], input));
+    self.meso_tn2[2] = coeff.pma[1][0] * coeff.pavgm[1] / (1.0 - SW[20] * self.glob7s(&coeff.pma[1], input));
+    self.meso_tn2[3] = coeff.pma[2][0] * coeff.pavgm[2] / (1.0 - SW[20] * SW[22] * self.glob7s(&coeff.pma[2], input));
+    self.meso_tgn2[1] = coeff.pavgm[8] * coeff.pma[9][0]
+                        * (1.0 + SW[20] * SW[22] * self.glob7s(&coeff.pma[9], input))
+                        * self.meso_tn2[3] * self.meso_tn2[3] / (coeff.pma[2][0] * coeff.pavgm[2]).powi(2);
+    self.meso_tn3[0] = self.meso_tn2[3];
+
+    if input.alt_km < zn3[0]
+    {
+      /* [LOWER STRATOSPHERE AND TROPOSPHERE] (below zn3[0]) */
+      self.meso_tgn3[0] = self.meso_tgn2[1];
+      self.meso_tn3[1] = coeff.pma[3][0] * coeff.pavgm[3] / (1.0 - SW[22] * self.glob7s(&coeff.pma[3], input));
+      self.meso_tn3[2] = coeff.pma[4][0] * coeff.pavgm[4] / (1.0 - SW[22] * self.glob7s(&coeff.pma[4], input));
+      self.meso_tn3[3] = coeff.pma[5][0] * coeff.pavgm[5] / (1.0 - SW[22] * self.glob7s(&coeff.pma[5], input));
+      self.meso_tn3[4] = coeff.pma[6][0] * coeff.pavgm[6] / (1.0 - SW[22] * self.glob7s(&coeff.pma[6], input));
+      self.meso_tgn3[1] = coeff.pma[7][0] * coeff.pavgm[7]
+                          * (1.0 + SW[22] * self.glob7s(&coeff.pma[7], input))
+                          * self.meso_tn3[4] * self.meso_tn3[4] / (coeff.pma[6][0] * coeff.pavgm[6]).powi(2);
+    }
+
+    /* Linear transition to full mixing below zn2[0] */
+    let mut dmc: f64 = 0.0;
+    if input.alt_km > zmix
+    {
+      dmc = 1.0 - (zn2[0] - input.alt_km) / (zn2[0] - zmix);
+    }
+    let dz28: f64 = soutput.d[NRLMSISE_INDX_N2];
+
+    let (tn3, tgn3, tn2, tgn2) = (self.meso_tn3, self.meso_tgn3, self.meso_tn2, self.meso_tgn2);
+
+    /* N2 density */
+    let dmr: f64 = soutput.d[NRLMSISE_INDX_N2] / dm28m - 1.0;
+    output.d[NRLMSISE_INDX_N2] = self.densm(input.alt_km, dm28m, xmm, &mut tz, &zn3, &tn3, &tgn3, &zn2, &tn2, &tgn2);
+    output.d[NRLMSISE_INDX_N2] *= 1.0 + dmr * dmc;
+
+    /* He density */
+    let dmr: f64 = soutput.d[NRLMSISE_INDX_HE] / (dz28 * coeff.pdm[0][1]) - 1.0;
+    output.d[NRLMSISE_INDX_HE] = output.d[NRLMSISE_INDX_N2] * coeff.pdm[0][1] * (1.0 + dmr * dmc);
+
+    /* O density */
+    output.d[NRLMSISE_INDX_O] = 0.0;
+    output.d[NRLMSISE_INDX_ANOMALOUS_O] = 0.0;
+
+    /* O2 density */
+    let dmr: f64 = soutput.d[NRLMSISE_INDX_O2] / (dz28 * coeff.pdm[3][1]) - 1.0;
+    output.d[NRLMSISE_INDX_O2] = output.d[NRLMSISE_INDX_N2] * coeff.pdm[3][1] * (1.0 + dmr * dmc);
+
+    /* Ar density */
+    let dmr: f64 = soutput.d[NRLMSISE_INDX_AR] / (dz28 * coeff.pdm[4][1]) - 1.0;
+    output.d[NRLMSISE_INDX_AR] = output.d[NRLMSISE_INDX_N2] * coeff.pdm[4][1] * (1.0 + dmr * dmc);
+
+    /* Hydrogen density */
+    output.d[NRLMSISE_INDX_H] = 0.0;
+
+    /* Atomic nitrogen density */
+    output.d[NRLMSISE_INDX_N] = 0.0;
+
+    /* Total mass density */
+    output.d[NRLMSISE_INDX_TOTAL_MASS] = 1.66E-24 * (4.0 * output.d[NRLMSISE_INDX_HE]
+                                                     + 32.0 * output.d[NRLMSISE_INDX_O2]
+                                                     + 28.0 * output.d[NRLMSISE_INDX_N2]
+                                                     + 40.0 * output.d[NRLMSISE_INDX_AR]);
+    if SW[0] != 0.0
+    {
+      output.d[NRLMSISE_INDX_TOTAL_MASS] /= 1000.0;
+    }
+
+    /* Temperature at altitude */
+    self.densm(input.alt_km, 1.0, 0.0, &mut tz, &zn3, &tn3, &tgn3, &zn2, &tn2, &tgn2);
+    output.t[1] = tz;
+
+    output
+  }
+
+ /*
+  * @brief: Thermospheric portion of NRLMSISE-00 (altitude > 72.5 km)
+  */
+  fn gts7(&mut self, input: &Nrlmsise00Input)
+  -> Nrlmsise00Output
+  {
+    let coeff: &Nrlmsise00 = self.coeff;
+    let mut output: Nrlmsise00Output = Nrlmsise00Output { d: [0.0; 9], t: [0.0; 2] };
+    let mut zn1: [f64; 5] = [120.0, 110.0, 100.0, 90.0, 72.5];
+    let alpha: [f64; 9] = [-0.38, 0.0, 0.0, 0.0, 0.17, 0.0, -0.38, 0.0, 0.0];
+    let altl: [f64; 8] = [200.0, 300.0, 160.0, 250.0, 240.0, 450.0, 320.0, 450.0];
+    let mut tz: f64 = 0.0;
+    let mut t_alt: f64 = 0.0;
+
+    let za: f64 = coeff.pdl[1][15];
+    zn1[0] = za;
+
+    /* Tinf variations not important below za or zn1[0] */
+    let tinf: f64 = if input.alt_km > zn1[0]
+    {
+      coeff.ptm[0] * coeff.pt[0] * (1.0 + SW[16] * self.globe7(&coeff.pt, input))
+    }
+    else
+    {
+      coeff.ptm[0] * coeff.pt[0]
+    };
+    output.t[0] = tinf;
+
+    /* Gradient variations not important below zn1[4] */
+    let g0: f64 = if input.alt_km > zn1[4]
+    {
+      coeff.ptm[3] * coeff.ps[0] * (1.0 + SW[19] * self.globe7(&coeff.ps, input))
+    }
+    else
+    {
+      coeff.ptm[3] * coeff.ps[0]
+    };
+    let tlb: f64 = coeff.ptm[1] * (1.0 + SW[17] * self.globe7(&coeff.pd[3], input)) * coeff.pd[3][0];
+    let s: f64 = g0 / (tinf - tlb);
+
+    /* Lower thermosphere temperature variations not significant for density
+     * above 300 km */
+    if input.alt_km < 300.0
+    {
+      self.meso_tn1[1] = coeff.ptm[6] * coeff.ptl[0][0] / (1.0 - SW[18] * self.glob7s(&coeff.ptl[0], input));
+      self.meso_tn1[2] = coeff.ptm[2] * coeff.ptl[1][0] / (1.0 - SW[18] * self.glob7s(&coeff.ptl[1], input));
+      self.meso_tn1[3] = coeff.ptm[7] * coeff.ptl[2][0] / (1.0 - SW[18] * self.glob7s(&coeff.ptl[2], input));
+      self.meso_tn1[4] = coeff.ptm[4] * coeff.ptl[3][0] / (1.0 - SW[18] * SW[20] * self.glob7s(&coeff.ptl[3], input));
+      self.meso_tgn1[1] = coeff.ptm[8] * coeff.pma[8][0]
+                          * (1.0 + SW[18] * SW[20] * self.glob7s(&coeff.pma[8], input))
+                          * self.meso_tn1[4] * self.meso_tn1[4] / (coeff.ptm[4] * coeff.ptl[3][0]).powi(2);
+    }
+    else
+    {
+      self.meso_tn1[1] = coeff.ptm[6] * coeff.ptl[0][0];
+      self.meso_tn1[2] = coeff.ptm[2] * coeff.ptl[1][0];
+      self.meso_tn1[3] = coeff.ptm[7] * coeff.ptl[2][0];
+      self.meso_tn1[4] = coeff.ptm[4] * coeff.ptl[3][0];
+      self.meso_tgn1[1] = coeff.ptm[8] * coeff.pma[8][0]
+                          * self.meso_tn1[4] * self.meso_tn1[4] / (coeff.ptm[4] * coeff.ptl[3][0]).powi(2);
+    }
+
+    /* N2 variation factor at zlb */
+    let g28: f64 = SW[21] * self.globe7(&coeff.pd[2], input);
+
+    /* Variation of turbopause height */
+    let zhf: f64 = coeff.pdl[1][24] * (1.0 + SW[5] * coeff.pdl[0][24] * (DGTR * input.g_lat_deg).sin()
+                                             * (DR * (input.doy - coeff.pt[13])).cos());
+    let xmm: f64 = coeff.pdm[2][4];
+    let z: f64 = input.alt_km;
+
+    /* [N2 DENSITY] */
+    /* Diffusive density at zlb */
+    let db28: f64 = coeff.pdm[2][0] * g28.exp() * coeff.pd[2][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_N2] = self.densu(z, db28, tinf, tlb, 28.0, alpha[2], &mut t_alt, coeff.ptm[5], s, &zn1);
+    /* Turbopause */
+    let zh28: f64 = coeff.pdm[2][2] * zhf;
+    let zhm28: f64 = coeff.pdm[2][3] * coeff.pdl[1][5];
+    let xmd: f64 = 28.0 - xmm;
+    /* Mixed density at zlb */
+    let b28: f64 = self.densu(zh28, db28, tinf, tlb, xmd, alpha[2] - 1.0, &mut tz, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z <= altl[2]
+    {
+      /* Mixed density at altitude */
+      self.dm28 = self.densu(z, b28, tinf, tlb, xmm, alpha[2], &mut tz, coeff.ptm[5], s, &zn1);
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_N2] = dnet(output.d[NRLMSISE_INDX_N2], self.dm28, zhm28, xmm, 28.0);
+    }
+
+    /* [HE DENSITY] */
+    /* Density variation factor at zlb */
+    let g4: f64 = SW[21] * self.globe7(&coeff.pd[0], input);
+    /* Diffusive density at zlb */
+    let db04: f64 = coeff.pdm[0][0] * g4.exp() * coeff.pd[0][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_HE] = self.densu(z, db04, tinf, tlb, 4.0, alpha[0], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z < altl[0]
+    {
+      /* Turbopause */
+      let zh04: f64 = coeff.pdm[0][2];
+      /* Mixed density at zlb */
+      let b04: f64 = self.densu(zh04, db04, tinf, tlb, 4.0 - xmm, alpha[0] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      /* Mixed density at altitude */
+      let dm04: f64 = self.densu(z, b04, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      let zhm04: f64 = zhm28;
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_HE] = dnet(output.d[NRLMSISE_INDX_HE], dm04, zhm04, xmm, 4.0);
+      /* Correction to specified mixing ratio at ground */
+      let rl: f64 = (b28 * coeff.pdm[0][1] / b04).ln();
+      let zc04: f64 = coeff.pdm[0][4] * coeff.pdl[1][0];
+      let hc04: f64 = coeff.pdm[0][5] * coeff.pdl[1][1];
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_HE] *= ccor(z, rl, hc04, zc04);
+    }
+
+    /* [O DENSITY] */
+    /* Density variation factor at zlb */
+    let g16: f64 = SW[21] * self.globe7(&coeff.pd[1], input);
+    /* Diffusive density at zlb */
+    let db16: f64 = coeff.pdm[1][0] * g16.exp() * coeff.pd[1][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_O] = self.densu(z, db16, tinf, tlb, 16.0, alpha[1], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z <= altl[1]
+    {
+      /* Turbopause */
+      let zh16: f64 = coeff.pdm[1][2];
+      /* Mixed density at zlb */
+      let b16: f64 = self.densu(zh16, db16, tinf, tlb, 16.0 - xmm, alpha[1] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      /* Mixed density at altitude */
+      let dm16: f64 = self.densu(z, b16, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      let zhm16: f64 = zhm28;
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_O] = dnet(output.d[NRLMSISE_INDX_O], dm16, zhm16, xmm, 16.0);
+      let rl: f64 = coeff.pdm[1][1] * coeff.pdl[1][16] * (1.0 + SW[1] * coeff.pdl[0][23] * (input.f107a - 150.0));
+      let hc16: f64 = coeff.pdm[1][5] * coeff.pdl[1][3];
+      let zc16: f64 = coeff.pdm[1][4] * coeff.pdl[1][2];
+      let hc216: f64 = coeff.pdm[1][5] * coeff.pdl[1][4];
+      output.d[NRLMSISE_INDX_O] *= ccor2(z, rl, hc16, zc16, hc216);
+      /* Chemistry correction */
+      let hcc16: f64 = coeff.pdm[1][7] * coeff.pdl[1][13];
+      let zcc16: f64 = coeff.pdm[1][6] * coeff.pdl[1][12];
+      let rc16: f64 = coeff.pdm[1][3] * coeff.pdl[1][14];
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_O] *= ccor(z, rc16, hcc16, zcc16);
+    }
+
+    /* [O2 DENSITY] */
+    /* Density variation factor at zlb */
+    let g32: f64 = SW[21] * self.globe7(&coeff.pd[4], input);
+    /* Diffusive density at zlb */
+    let db32: f64 = coeff.pdm[3][0] * g32.exp() * coeff.pd[4][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_O2] = self.densu(z, db32, tinf, tlb, 32.0, alpha[3], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0
+    {
+      if z <= altl[3]
+      {
+        /* Turbopause */
+        let zh32: f64 = coeff.pdm[3][2];
+        /* Mixed density at zlb */
+        let b32: f64 = self.densu(zh32, db32, tinf, tlb, 32.0 - xmm, alpha[3] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+        /* Mixed density at altitude */
+        let dm32: f64 = self.densu(z, b32, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+        let zhm32: f64 = zhm28;
+        /* Net density at altitude */
+        output.d[NRLMSISE_INDX_O2] = dnet(output.d[NRLMSISE_INDX_O2], dm32, zhm32, xmm, 32.0);
+        /* Correction to specified mixing ratio at ground */
+        let rl: f64 = (b28 * coeff.pdm[3][1] / b32).ln();
+        let hc32: f64 = coeff.pdm[3][5] * coeff.pdl[1][7];
+        let zc32: f64 = coeff.pdm[3][4] * coeff.pdl[1][6];
+        output.d[NRLMSISE_INDX_O2] *= ccor(z, rl, hc32, zc32);
+      }
+      /* Correction for general departure from diffusive equilibrium above zlb */
+      let hcc32: f64 = coeff.pdm[3][7] * coeff.pdl[1][22];
+      let hcc232: f64 = coeff.pdm[3][7] * coeff.pdl[0][22];
+      let zcc32: f64 = coeff.pdm[3][6] * coeff.pdl[1][21];
+      let rc32: f64 = coeff.pdm[3][3] * coeff.pdl[1][23] * (1.0 + SW[1] * coeff.pdl[0][23] * (input.f107a - 150.0));
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_O2] *= ccor2(z, rc32, hcc32, zcc32, hcc232);
+    }
+
+    /* [AR DENSITY] */
+    /* Density variation factor at zlb */
+    let g40: f64 = SW[21] * self.globe7(&coeff.pd[5], input);
+    /* Diffusive density at zlb */
+    let db40: f64 = coeff.pdm[4][0] * g40.exp() * coeff.pd[5][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_AR] = self.densu(z, db40, tinf, tlb, 40.0, alpha[4], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z <= altl[4]
+    {
+      /* Turbopause */
+      let zh40: f64 = coeff.pdm[4][2];
+      /* Mixed density at zlb */
+      let b40: f64 = self.densu(zh40, db40, tinf, tlb, 40.0 - xmm, alpha[4] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      /* Mixed density at altitude */
+      let dm40: f64 = self.densu(z, b40, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      let zhm40: f64 = zhm28;
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_AR] = dnet(output.d[NRLMSISE_INDX_AR], dm40, zhm40, xmm, 40.0);
+      /* Correction to specified mixing ratio at ground */
+      let rl: f64 = (b28 * coeff.pdm[4][1] / b40).ln();
+      let hc40: f64 = coeff.pdm[4][5] * coeff.pdl[1][9];
+      let zc40: f64 = coeff.pdm[4][4] * coeff.pdl[1][8];
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_AR] *= ccor(z, rl, hc40, zc40);
+    }
+
+    /* [HYDROGEN DENSITY] */
+    /* Density variation factor at zlb */
+    let g1: f64 = SW[21] * self.globe7(&coeff.pd[6], input);
+    /* Diffusive density at zlb */
+    let db01: f64 = coeff.pdm[5][0] * g1.exp() * coeff.pd[6][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_H] = self.densu(z, db01, tinf, tlb, 1.0, alpha[6], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z <= altl[6]
+    {
+      /* Turbopause */
+      let zh01: f64 = coeff.pdm[5][2];
+      /* Mixed density at zlb */
+      let b01: f64 = self.densu(zh01, db01, tinf, tlb, 1.0 - xmm, alpha[6] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      /* Mixed density at altitude */
+      let dm01: f64 = self.densu(z, b01, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      let zhm01: f64 = zhm28;
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_H] = dnet(output.d[NRLMSISE_INDX_H], dm01, zhm01, xmm, 1.0);
+      /* Correction to specified mixing ratio at ground */
+      let rl: f64 = (b28 * coeff.pdm[5][1] * coeff.pdl[1][17].abs() / b01).ln();
+      let hc01: f64 = coeff.pdm[5][5] * coeff.pdl[1][11];
+      let zc01: f64 = coeff.pdm[5][4] * coeff.pdl[1][10];
+      output.d[NRLMSISE_INDX_H] *= ccor(z, rl, hc01, zc01);
+      /* Chemistry correction */
+      let hcc01: f64 = coeff.pdm[5][7] * coeff.pdl[1][19];
+      let zcc01: f64 = coeff.pdm[5][6] * coeff.pdl[1][18];
+      let rc01: f64 = coeff.pdm[5][3] * coeff.pdl[1][20];
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_H] *= ccor(z, rc01, hcc01, zcc01);
+    }
+
+    /* [ATOMIC NITROGEN DENSITY] */
+    /* Density variation factor at zlb */
+    let g14: f64 = SW[21] * self.globe7(&coeff.pd[7], input);
+    /* Diffusive density at zlb */
+    let db14: f64 = coeff.pdm[6][0] * g14.exp() * coeff.pd[7][0];
+    /* Diffusive density at altitude */
+    output.d[NRLMSISE_INDX_N] = self.densu(z, db14, tinf, tlb, 14.0, alpha[7], &mut t_alt, coeff.ptm[5], s, &zn1);
+    if SW[15] != 0.0 && z <= altl[7]
+    {
+      /* Turbopause */
+      let zh14: f64 = coeff.pdm[6][2];
+      /* Mixed density at zlb */
+      let b14: f64 = self.densu(zh14, db14, tinf, tlb, 14.0 - xmm, alpha[7] - 1.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      /* Mixed density at altitude */
+      let dm14: f64 = self.densu(z, b14, tinf, tlb, xmm, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+      let zhm14: f64 = zhm28;
+      /* Net density at altitude */
+      output.d[NRLMSISE_INDX_N] = dnet(output.d[NRLMSISE_INDX_N], dm14, zhm14, xmm, 14.0);
+      /* Correction to specified mixing ratio at ground */
+      let rl: f64 = (b28 * coeff.pdm[6][1] * coeff.pdl[0][2].abs() / b14).ln();
+      let hc14: f64 = coeff.pdm[6][5] * coeff.pdl[0][1];
+      let zc14: f64 = coeff.pdm[6][4] * coeff.pdl[0][0];
+      output.d[NRLMSISE_INDX_N] *= ccor(z, rl, hc14, zc14);
+      /* Chemistry correction */
+      let hcc14: f64 = coeff.pdm[6][7] * coeff.pdl[0][4];
+      let zcc14: f64 = coeff.pdm[6][6] * coeff.pdl[0][3];
+      let rc14: f64 = coeff.pdm[6][3] * coeff.pdl[0][5];
+      /* Net density corrected at altitude */
+      output.d[NRLMSISE_INDX_N] *= ccor(z, rc14, hcc14, zcc14);
+    }
+
+    /* [ANOMALOUS OXYGEN DENSITY] */
+    let g16h: f64 = SW[21] * self.globe7(&coeff.pd[8], input);
+    let db16h: f64 = coeff.pdm[7][0] * g16h.exp() * coeff.pd[8][0];
+    let tho: f64 = coeff.pdm[7][9] * coeff.pdl[0][6];
+    let dd: f64 = self.densu(z, db16h, tho, tho, 16.0, alpha[8], &mut t_alt, coeff.ptm[5], s, &zn1);
+    let zsht: f64 = coeff.pdm[7][5];
+    let zmho: f64 = coeff.pdm[7][4];
+    let zsho: f64 = self.scalh(zmho, 16.0, tho);
+    output.d[NRLMSISE_INDX_ANOMALOUS_O] = dd * (-zsht / zsho * ((-(z - zmho) / zsht).exp() - 1.0)).exp();
+
+    /* Total mass density */
+    output.d[NRLMSISE_INDX_TOTAL_MASS] = 1.66E-24 * (4.0 * output.d[NRLMSISE_INDX_HE]
+                                                     + 16.0 * output.d[NRLMSISE_INDX_O]
+                                                     + 28.0 * output.d[NRLMSISE_INDX_N2]
+                                                     + 32.0 * output.d[NRLMSISE_INDX_O2]
+                                                     + 40.0 * output.d[NRLMSISE_INDX_AR]
+                                                     + output.d[NRLMSISE_INDX_H]
+                                                     + 14.0 * output.d[NRLMSISE_INDX_N]);
+
+    /* Temperature at altitude */
+    let z: f64 = input.alt_km.abs();
+    self.densu(z, 1.0, tinf, tlb, 0.0, 0.0, &mut t_alt, coeff.ptm[5], s, &zn1);
+    output.t[1] = t_alt;
+
+    if SW[0] != 0.0
+    {
+      for density in output.d.iter_mut()
+      {
+        *density *= 1.0E6;
+      }
+      output.d[NRLMSISE_INDX_TOTAL_MASS] /= 1000.0;
+    }
+
+    output
+  }
+}
+
+/*
+ * @brief: Chemistry / dissociation correction
+ *
+ * @param[in] alt - Altitude
+ * @param[in] r   - Target ratio
+ * @param[in] h1  - Transition scale length
+ * @param[in] zh  - Altitude of 1/2 r
+ */
+fn ccor(alt: f64, r: f64, h1: f64, zh: f64)
+-> f64
+{
+  let e: f64 = (alt - zh) / h1;
+  if e > 70.0
+  {
+    return 1.0;
+  }
+  if e < -70.0
+  {
+    return r.exp();
+  }
+  (r / (1.0 + e.exp())).exp()
+}
+
+/*
+ * @brief: O and O2 chemistry / dissociation correction
+ */
+fn ccor2(alt: f64, r: f64, h1: f64, zh: f64, h2: f64)
+-> f64
+{
+  let e1: f64 = (alt - zh) / h1;
+  let e2: f64 = (alt - zh) / h2;
+  if e1 > 70.0 || e2 > 70.0
+  {
+    return 1.0;
+  }
+  if e1 < -70.0 && e2 < -70.0
+  {
+    return r.exp();
+  }
+  (r / (1.0 + 0.5 * (e1.exp() + e2.exp()))).exp()
+}
+
+/*
+ * @brief: Turbopause correction
+ *
+ * @param[in] dd  - Diffusive density
+ * @param[in] dm  - Full mixed density
+ * @param[in] zhm - Transition scale length
+ * @param[in] xmm - Full mixed molecular weight
+ * @param[in] xm  - Species molecular weight
+ *
+ * @returns: Combined density
+ */
+fn dnet(dd: f64, dm: f64, zhm: f64, xmm: f64, xm: f64)
+-> f64
+{
+  let a: f64 = zhm / (xmm - xm);
+  if !(dm > 0.0 && dd > 0.0)
+  {
+    if dd == 0.0 && dm == 0.0
+    {
+      return 1.0;
+    }
+    if dm == 0.0
+    {
+      return dd;
+    }
+    if dd == 0.0
+    {
+      return dm;
+    }
+  }
+  let ylog: f64 = a * (dm / dd).ln();
+  if ylog < -10.0
+  {
+    return dd;
+  }
+  if ylog > 10.0
+  {
+    return dm;
+  }
+  dd * (1.0 + ylog.exp()).powf(1.0 / a)
+}
+
+/*
+ * @brief: Integrate cubic spline function from xa[0] to x
+ */
+fn splini(xa: &[f64], ya: &[f64], y2a: &[f64], x: f64)
+-> f64
+{
+  let n: usize = xa.len();
+  let mut yi: f64 = 0.0;
+  let mut klo: usize = 0;
+  let mut khi: usize = 1;
+
+  while x > xa[klo] && khi < n
+  {
+    let mut xx: f64 = x;
+    if khi < n - 1 && x >= xa[khi]
+    {
+      xx = xa[khi];
+    }
+    let h: f64 = xa[khi] - xa[klo];
+    let a: f64 = (xa[khi] - xx) / h;
+    let b: f64 = (xx - xa[klo]) / h;
+    let a2: f64 = a * a;
+    let b2: f64 = b * b;
+    yi += ((1.0 - a2) * ya[klo] / 2.0 + b2 * ya[khi] / 2.0
+           + ((-(1.0 + a2 * a2) / 4.0 + a2 / 2.0) * y2a[klo]
+              + (b2 * b2 / 4.0 - b2 / 2.0) * y2a[khi]) * h * h / 6.0) * h;
+    klo += 1;
+    khi += 1;
+  }
+  yi
+}
+
+/*
+ * @brief: Calculate cubic spline interpolation value
+ */
+fn splint(xa: &[f64], ya: &[f64], y2a: &[f64], x: f64)
+-> f64
+{
+  let mut klo: usize = 0;
+  let mut khi: usize = xa.len() - 1;
+
+  while khi - klo > 1
+  {
+    let k: usize = (khi + klo) / 2;
+    if xa[k] > x
+    {
+      khi = k;
+    }
+    else
+    {
+      klo = k;
+    }
+  }
+  let h: f64 = xa[khi] - xa[klo];
+  let a: f64 = (xa[khi] - x) / h;
+  let b: f64 = (x - xa[klo]) / h;
+
+  a * ya[klo] + b * ya[khi] + ((a * a * a - a) * y2a[klo] + (b * b * b - b) * y2a[khi]) * h * h / 6.0
+}
+
+/*
+ * @brief: Calculate 2nd derivatives of a cubic spline interpolating function
+ *
+ * @param[in] yp1, ypn - Specified derivatives at x[0] and x[n-1]. Values
+ *                       > 1e30 signal second derivative zero.
+ */
+fn spline(x: &[f64], y: &[f64], yp1: f64, ypn: f64, y2: &mut [f64])
+{
+  let n: usize = x.len();
+  let mut u: Vec<f64> = vec![0.0; n];
+
+  if yp1 > 0.99E30
+  {
+    y2[0] = 0.0;
+    u[0] = 0.0;
+  }
+  else
+  {
+    y2[0] = -0.5;
+    u[0] = (3.0 / (x[1] - x[0])) * ((y[1] - y[0]) / (x[1] - x[0]) - yp1);
+  }
+  for i in 1..(n - 1)
+  {
+    let sig: f64 = (x[i] - x[i - 1]) / (x[i + 1] - x[i - 1]);
+    let p: f64 = sig * y2[i - 1] + 2.0;
+    y2[i] = (sig - 1.0) / p;
+    u[i] = (6.0 * ((y[i + 1] - y[i]) / (x[i + 1] - x[i]) - (y[i] - y[i - 1]) / (x[i] - x[i - 1]))
+            / (x[i + 1] - x[i - 1]) - sig * u[i - 1]) / p;
+  }
+  let (qn, un): (f64, f64) = if ypn > 0.99E30
+  {
+    (0.0, 0.0)
+  }
+  else
+  {
+    (0.5, (3.0 / (x[n - 1] - x[n - 2])) * (ypn - (y[n - 1] - y[n - 2]) / (x[n - 1] - x[n - 2])))
+  };
+  y2[n - 1] = (un - qn * u[n - 2]) / (qn * y2[n - 2] + 1.0);
+  for k in (0..(n - 1)).rev()
+  {
+    y2[k] = y2[k] * y2[k + 1] + u[k];
+  }
+}
+
+/*
+ * @brief: Parse the plain coefficient file into arrays of rows x cols values
+ *         identified by their names.
+ */
+fn parse_coefficient_file(content: &str)
+-> HashMap<String, Vec<Vec<f64>>>
+{
+  let mut arrays: HashMap<String, Vec<Vec<f64>>> = HashMap::new();
+  let mut lines = content.lines()
+    .map(|line| line.trim())
+    .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+  while let Some(header) = lines.next()
+  {
+    let header_tokens: Vec<&str> = header.split_whitespace().collect();
+    let (array_name, rows, cols): (&str, usize, usize) = match header_tokens.as_slice()
+    {
+      [name, rows, cols] => match (rows.parse::<usize>(), cols.parse::<usize>())
+      {
+        (Ok(rows), Ok(cols)) => (*name, rows, cols),
+        _ => panic!("! [ERROR] ! > NRLMSISE-00 coefficient file: invalid array header {} <", header)
+      },
+      _ => panic!("! [ERROR] ! > NRLMSISE-00 coefficient file: invalid array header {} <", header)
+    };
+
+    let mut values: Vec<Vec<f64>> = Vec::with_capacity(rows);
+    for _ in 0..rows
+    {
+      let row: Vec<f64> = lines.next()
+        .unwrap_or_else(|| panic!("! [ERROR] ! > NRLMSISE-00 coefficient array {}: expected {} rows <",
+                                  array_name, rows))
+        .split_whitespace()
+        .map(|token| token.parse::<f64>()
+          .unwrap_or_else(|_| panic!("! [ERROR] ! > NRLMSISE-00 coefficient array {}: invalid value {} <",
+                                     array_name, token)))
+        .collect();
+      if row.len() != cols
+      {
+        panic!("! [ERROR] ! > NRLMSISE-00 coefficient array {}: expected {} values per row, found {} <",
+               array_name, cols, row.len());
+      }
+      values.push(row);
+    }
+    arrays.insert(array_name.to_string(), values);
+  }
+  arrays
+}
+
+/*
+ * @brief: Remove the coefficient array named array_name from the parsed
+ *         arrays and check its shape.
+ */
+fn take_coefficient_array(arrays: &mut HashMap<String, Vec<Vec<f64>>>, array_name: &str, rows: usize, cols: usize)
+-> Vec<Vec<f64>>
+{
+  let values: Vec<Vec<f64>> = arrays.remove(array_name)
+    .unwrap_or_else(|| panic!("! [ERROR] ! > NRLMSISE-00 coefficient array {} not found! <", array_name));
+
+  if values.len() != rows || values.iter().any(|row| row.len() != cols)
+  {
+    panic!("! [ERROR] ! > NRLMSISE-00 coefficient array {}: expected {} x {} values <",
+           array_name, rows, cols);
+  }
+  values
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /* Coefficient file as configured in parameters/atmosphere.ini */
+  const COEFFICIENT_FILE_PATH: &str = "assets/atmosphere/earth/nrlmsise-00_coefficients.txt";
+
+  /*
+   * @brief: Check the SI model outputs against the published test outputs of
+   *         the reference C implementation (gtd7, CGS units).
+   *
+   * @param[in] expected - He, O, N2, O2, Ar, mass density (without anomalous
+   *                       O), H, N, anomalous O [cm^-3, g/cm^3], exospheric
+   *                       temperature, temperature at altitude [K]
+   */
+  fn check_reference_case(model: &Nrlmsise00, input: &Nrlmsise00Input, expected: [f64; 11])
+  {
+    let output: Nrlmsise00Output = model.gtd7d(input);
+    let assert_close = |value: f64, expected: f64, name: &str| {
+      assert!(((value - expected) / expected).abs() < 1.0e-5,
+              "{} at {} km: {} != {}", name, input.alt_km, value, expected);
+    };
+
+    for indx in NRLMSISE_SPECIES_INDICES
+    {
+      assert_close(output.d[indx], expected[indx] * 1.0e6, "number density");
+    }
+    /* gtd7d adds anomalous oxygen to the mass density of gtd7 */
+    assert_close(output.d[NRLMSISE_INDX_TOTAL_MASS]
+                 - 1.66e-27 * 16.0 * output.d[NRLMSISE_INDX_ANOMALOUS_O],
+                 expected[NRLMSISE_INDX_TOTAL_MASS] * 1.0e3, "mass density");
+    assert_close(output.t[NRLMSISE_INDX_TEMP_EXOSPHERE], expected[9], "exospheric temperature");
+    assert_close(output.t[NRLMSISE_INDX_TEMP_ALTITUDE], expected[10], "temperature");
+  }
+
+  /*
+   * The coefficient file is not part of the repository. Run
+   * assets/atmosphere/earth/download_nrlmsise00.sh and then
+   * cargo test -- --ignored
+   */
+  #[test]
+  #[ignore = "requires assets/atmosphere/earth/nrlmsise-00_coefficients.txt (run download_nrlmsise00.sh)"]
+  fn reference_outputs()
+  {
+    let mut model: Nrlmsise00 = Nrlmsise00::new();
+    model.load_coefficients(COEFFICIENT_FILE_PATH);
+
+    let mut input: Nrlmsise00Input = Nrlmsise00Input {
+      doy: 172.0,
+      sec: 29000.0,
+      alt_km: 400.0,
+      g_lat_deg: 60.0,
+      g_long_deg: -70.0,
+      lst_h: 16.0,
+      f107a: 150.0,
+      f107: 150.0,
+      ap: 4.0
+    };
+    check_reference_case(&model, &input,
+                         [6.665177E+05, 1.138806E+08, 1.998211E+07, 4.022764E+05, 3.557465E+03,
+                          4.074714E-15, 3.475312E+04, 4.095913E+06, 2.667273E+04,
+                          1.250540E+03, 1.241416E+03]);
+
+    input.alt_km = 100.0;
+    check_reference_case(&model, &input,
+                         [5.411554E+07, 1.918893E+11, 6.115826E+12, 1.225201E+12, 6.023212E+10,
+                          3.584426E-10, 1.059880E+07, 2.615737E+05, 2.819879E-42,
+                          1.027318E+03, 2.068878E+02]);
+  }
+}
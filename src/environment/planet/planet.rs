@@ -1,6 +1,12 @@
 
 
 use crate::environment::planet::atmosphere::*;
+use crate::environment::gravity::gravity_model::GravityModel;
+use crate::environment::planet::earth_orientation::EarthOrientation;
+use crate::math::frame_math::convert_ecef_to_geodetic;
+
+/* external crates */
+use ndarray::{Array1, ArrayView1};
 
 /* constants */
 use crate::constants::atmosphere::*;
@@ -30,7 +36,21 @@ pub struct Planet {
    * @unit        : N/A
    * 
    * */
-   atmosphere: Atmosphere
+   atmosphere: Atmosphere,
+  /* [Gravity model struct] 
+   * @description : Data struct containing the gravity model configuration 
+   *                and coefficients
+   * @unit        : N/A
+   * 
+   * */
+   gravity_model: GravityModel,
+  /* [Earth orientation struct] 
+   * @description : Data struct containing the transformation between the 
+   *                inertial (PCI) and the planet fixed (PCPF) frame
+   * @unit        : N/A
+   * 
+   * */
+   earth_orientation: EarthOrientation
 }
 
 /*
@@ -46,7 +66,9 @@ impl Planet {
       gravitational_constant: 0.0,
       flattening_factor: 0.0,
       omega_rads: 0.0,
-      atmosphere: Atmosphere::new()
+      atmosphere: Atmosphere::new(),
+      gravity_model: GravityModel::new(),
+      earth_orientation: EarthOrientation::new()
     }
   }
 
@@ -57,6 +79,8 @@ impl Planet {
   pub fn init(&mut self) 
   {
     println!("[x] Initialize planet");
+    self.get_mut_gravity_model().init();
+    self.get_mut_earth_orientation().init();
     /* Only initialize atmosphere if it is enabled */
     if *self.get_atmosphere().is_atmoshpere_modelled() == true
     {
@@ -95,4 +119,26 @@ impl Planet {
 
   pub fn get_atmosphere(&self) -> &Atmosphere {&self.atmosphere}
   pub fn get_mut_atmosphere(&mut self) -> &mut Atmosphere {&mut self.atmosphere}
+
+  pub fn get_gravity_model(&self) -> &GravityModel {&self.gravity_model}
+  pub fn get_mut_gravity_model(&mut self) -> &mut GravityModel {&mut self.gravity_model}
+
+  pub fn get_earth_orientation(&self) -> &EarthOrientation {&self.earth_orientation}
+  pub fn get_mut_earth_orientation(&mut self) -> &mut EarthOrientation {&mut self.earth_orientation}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl Planet {
+ /*
+  * @brief: Function to convert a position in PCPF to geodetic latitude [rad],
+  *         longitude [rad] and height [m] above the planet's reference 
+  *         ellipsoid (semi-major axis and flattening factor)
+  */
+  pub fn calc_geodetic_pos(&self, pos_pcpf_m_in: ArrayView1<f64>) -> Array1<f64>
+  {
+    convert_ecef_to_geodetic(pos_pcpf_m_in, self.semi_major_axis_m, self.flattening_factor)
+  }
 }
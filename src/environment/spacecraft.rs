@@ -1 +1,7 @@
-pub mod spacecraft;
\ No newline at end of file
+pub mod spacecraft;
+
+pub mod reaction_wheels;
+
+pub mod magnetorquers;
+
+pub mod propulsion;
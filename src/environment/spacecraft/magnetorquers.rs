@@ -0,0 +1,364 @@
+/*
+ * @brief: Magnetorquer rods and B-dot detumbling controller
+ *
+ * @description: Three magnetorquer rods aligned with the body axes produce
+ *               the magnetic dipole m, which interacts with the geomagnetic
+ *               field B (magnetic_field.ini):
+ *
+ *               T = m x B
+ *
+ *               The dipole of each rod is limited to its maximum dipole and
+ *               quantized to the resolution of its driver.
+ *
+ *               The B-dot controller runs at a fixed sample interval on the
+ *               measurements of a simulated three axis magnetometer aligned
+ *               with the body axes:
+ *
+ *               B_meas = quantize( B_b + bias + noise )
+ *
+ *               where the noise is white and Gaussian. The field derivative
+ *               is the finite difference of two consecutive measurements and
+ *               the commanded dipole is held until the next sample:
+ *
+ *               m = - k / |B_meas|^2 * (B_meas_k - B_meas_k-1) / dt
+ *
+ *               As dB/dt ~ - w x B in the body frame, the resulting torque
+ *               T ~ - k * w_perp damps the angular rate normal to the field.
+ *               The rate converges to about twice the orbital rate as the
+ *               controller follows the rotation of the field along the orbit.
+ *               A gain close to the optimum is
+ *
+ *               k = 2 * n * (1 + sin(xi)) * J_min
+ *
+ *               with the mean motion n, the inclination of the orbit to the
+ *               geomagnetic equator xi and the minimum principal inertia
+ *               J_min.
+ *
+ *               Ref: Avanzini, Giulietti - Magnetic Detumbling of a Rigid
+ *                    Spacecraft, Journal of Guidance, Control, and Dynamics
+ *                    35(4), 2012
+ *                    Markley, Crassidis - Fundamentals of Spacecraft Attitude
+ *                    Determination and Control, Chapter 7.4
+ */
+
+/* Include external crates */
+use ndarray::{Array1, ArrayView1};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::vec_math::cross_product_array1;
+
+#[derive(Clone)]
+
+pub struct Magnetorquers {
+  /*
+   * @description : Flag, if true the magnetorquers are modelled and driven by
+   *                the B-dot controller
+   * @unit        : N/A
+   *
+   * */
+  enable_magnetorquers: bool,
+  /*
+   * @description : Maximum dipole and dipole resolution of each rod (zero
+   *                for a continuous dipole)
+   * @unit        : A * m * m
+   * @frame       : Body
+   *
+   * */
+  max_dipole_amm: Array1<f64>,
+  dipole_quantization_amm: Array1<f64>,
+  /*
+   * @description : Gain k of the B-dot control law and sample interval of
+   *                the controller
+   * @unit        : Nms, seconds
+   *
+   * */
+  bdot_gain_nms: f64,
+  sample_interval_s: f64,
+  /*
+   * @description : Angular rate below which the spacecraft is considered
+   *                detumbled (detumbled event)
+   * @unit        : rad/s
+   *
+   * */
+  detumble_rate_threshold_rads: f64,
+  /*
+   * @description : Magnetometer bias, standard deviation of the white noise
+   *                and resolution (zero for a continuous measurement)
+   * @unit        : T
+   * @frame       : Body
+   *
+   * */
+  magnetometer_bias_t: Array1<f64>,
+  magnetometer_noise_std_t: f64,
+  magnetometer_resolution_t: f64,
+  /*
+   * @description : State of the pseudo random number generator of the
+   *                magnetometer noise
+   * @unit        : N/A
+   *
+   * */
+  rng_state: u64,
+  /*
+   * @description : Last magnetometer measurement and its simulation time,
+   *                simulation time of the next controller sample
+   * @unit        : T, seconds
+   *
+   * */
+  last_measurement_b_t: Option<(Array1<f64>, f64)>,
+  next_sample_time_s: f64,
+  /*
+   * @description : Dipole commanded by the B-dot controller (held between
+   *                the samples) and resulting magnetic torque. The torque is
+   *                updated with every call of get_torque_vec_b.
+   * @unit        : A * m * m, Nm
+   * @frame       : Body
+   *
+   * */
+  dipole_b_amm: Array1<f64>,
+  torque_b_nm: Array1<f64>
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Magnetorquers {
+  pub fn new() -> Magnetorquers {
+    Magnetorquers {
+      enable_magnetorquers: false,
+      max_dipole_amm: Array1::zeros(3),
+      dipole_quantization_amm: Array1::zeros(3),
+      bdot_gain_nms: 0.0,
+      sample_interval_s: 1.0,
+      detumble_rate_threshold_rads: 0.0,
+      magnetometer_bias_t: Array1::zeros(3),
+      magnetometer_noise_std_t: 0.0,
+      magnetometer_resolution_t: 0.0,
+      rng_state: 1,
+      last_measurement_b_t: None,
+      next_sample_time_s: 0.0,
+      dipole_b_amm: Array1::zeros(3),
+      torque_b_nm: Array1::zeros(3)
+    }
+  }
+}
+
+impl Default for Magnetorquers {
+  fn default() -> Magnetorquers {
+    Magnetorquers::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl Magnetorquers {
+  pub fn set_enable_magnetorquers(&mut self, val_in: &bool) {self.enable_magnetorquers = *val_in;}
+  pub fn set_max_dipole_amm(&mut self, val_in: &Array1<f64>) {self.max_dipole_amm = val_in.clone();}
+  pub fn set_dipole_quantization_amm(&mut self, val_in: &Array1<f64>) {self.dipole_quantization_amm = val_in.clone();}
+  pub fn set_bdot_gain_nms(&mut self, val_in: &f64) {self.bdot_gain_nms = *val_in;}
+  pub fn set_sample_interval_s(&mut self, val_in: &f64)
+  {
+    if *val_in <= 0.0
+    {
+      panic!("! [ERROR] ! > sample_interval_s in magnetorquers.ini shall be positive! <");
+    }
+    self.sample_interval_s = *val_in;
+  }
+  pub fn set_detumble_rate_threshold_rads(&mut self, val_in: &f64) {self.detumble_rate_threshold_rads = *val_in;}
+  pub fn set_magnetometer_bias_t(&mut self, val_in: &Array1<f64>) {self.magnetometer_bias_t = val_in.clone();}
+  pub fn set_magnetometer_noise_std_t(&mut self, val_in: &f64) {self.magnetometer_noise_std_t = *val_in;}
+  pub fn set_magnetometer_resolution_t(&mut self, val_in: &f64) {self.magnetometer_resolution_t = *val_in;}
+  /* Note: The xorshift generator requires a non-zero state */
+  pub fn set_noise_seed(&mut self, val_in: &u64) {self.rng_state = (*val_in).max(1);}
+  pub fn set_next_sample_time_s(&mut self, val_in: &f64) {self.next_sample_time_s = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Magnetorquers {
+  pub fn is_magnetorquers_enabled(&self) -> &bool {&self.enable_magnetorquers}
+  pub fn get_max_dipole_amm(&self) -> &Array1<f64> {&self.max_dipole_amm}
+  pub fn get_bdot_gain_nms(&self) -> &f64 {&self.bdot_gain_nms}
+  pub fn get_sample_interval_s(&self) -> &f64 {&self.sample_interval_s}
+  pub fn get_detumble_rate_threshold_rads(&self) -> &f64 {&self.detumble_rate_threshold_rads}
+  pub fn get_next_sample_time_s(&self) -> &f64 {&self.next_sample_time_s}
+  pub fn get_last_measurement_b_t(&self) -> Option<&Array1<f64>>
+  {
+    self.last_measurement_b_t.as_ref().map(|(measurement_b_t, _)| measurement_b_t)
+  }
+  pub fn get_dipole_b_amm(&self) -> &Array1<f64> {&self.dipole_b_amm}
+  pub fn get_torque_b_nm(&self) -> &Array1<f64> {&self.torque_b_nm}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl Magnetorquers {
+ /*
+  * @brief: Function to limit and quantize a dipole command per rod
+  *
+  * @param[in] dipole_cmd_b_amm_in - Commanded dipole
+  *
+  * @unit: A * m * m
+  * @frame: Body
+  */
+  pub fn calc_realized_dipole_b_amm(&self, dipole_cmd_b_amm_in: ArrayView1<f64>) -> Array1<f64>
+  {
+    Array1::from_iter((0..3).map(|axis| {
+      let max_dipole_amm: f64 = self.max_dipole_amm[axis];
+      let quantization_amm: f64 = self.dipole_quantization_amm[axis];
+      let mut dipole_amm: f64 = dipole_cmd_b_amm_in[axis].clamp(-max_dipole_amm, max_dipole_amm);
+      if quantization_amm > 0.0
+      {
+        /* Round towards zero so that the quantized dipole never exceeds the
+         * limit */
+        dipole_amm = (dipole_amm / quantization_amm).trunc() * quantization_amm;
+      }
+      dipole_amm
+    }))
+  }
+
+ /*
+  * @brief: Function to simulate a magnetometer measurement
+  *
+  * @param[in] field_b_t_in - True magnetic field vector in the body frame
+  *
+  * @unit: T
+  * @frame: Body
+  */
+  pub fn measure_field_b_t(&mut self, field_b_t_in: ArrayView1<f64>) -> Array1<f64>
+  {
+    let mut measurement_b_t: Array1<f64> = &field_b_t_in + &self.magnetometer_bias_t;
+    if self.magnetometer_noise_std_t > 0.0
+    {
+      for axis in 0..3
+      {
+        measurement_b_t[axis] += self.magnetometer_noise_std_t * self.calc_standard_normal_sample();
+      }
+    }
+    if self.magnetometer_resolution_t > 0.0
+    {
+      measurement_b_t.mapv_inplace(|field: f64| (field / self.magnetometer_resolution_t).round()
+                                                * self.magnetometer_resolution_t);
+    }
+    measurement_b_t
+  }
+}
+
+/*
+ * @brief: Function to run the B-dot controller if the next sample time is
+ *         reached. The commanded dipole is held until the next sample.
+ *
+ * Note: This function shall be called once after each integration step and
+ *       not from the equations of motion. The first sample only initializes
+ *       the finite difference.
+ *
+ * @param[in] state_in         - Full state vector at the end of the step
+ * @param[in] sim_time_s       - Simulation time at the end of the step
+ * @param[in] time_tolerance_s - Tolerance to decide if the sample time is
+ *                               reached
+ *
+ * @returns: true if the held dipole changed, i.e. the torque is discontinuous
+ *           at the start of the next step
+ *
+ */
+pub fn update_bdot_controller(state_in: &Array1<f64>,
+                              sim_time_s: f64,
+                              time_tolerance_s: f64,
+                              environment: &mut Environment)
+-> bool
+{
+  if sim_time_s < *environment.get_spacecraft().get_magnetorquers().get_next_sample_time_s() - time_tolerance_s
+  {
+    return false;
+  }
+
+  let (_, _, field_b_t): (Array1<f64>, Array1<f64>, Array1<f64>) = environment.get_magnetic_field()
+    .calc_field_vecs(state_in.view(), environment.get_planet());
+
+  let magnetorquers: &mut Magnetorquers = environment.get_mut_spacecraft().get_mut_magnetorquers();
+  let measurement_b_t: Array1<f64> = magnetorquers.measure_field_b_t(field_b_t.view());
+  let last_dipole_b_amm: Array1<f64> = magnetorquers.dipole_b_amm.clone();
+
+  if let Some((last_measurement_b_t, last_sample_time_s)) = &magnetorquers.last_measurement_b_t
+  {
+    let dt_sample_s: f64 = sim_time_s - last_sample_time_s;
+    let field_norm_sq: f64 = measurement_b_t.dot(&measurement_b_t);
+    if dt_sample_s > 0.0 && field_norm_sq > 0.0
+    {
+      let field_rate_b_tps: Array1<f64> = (&measurement_b_t - last_measurement_b_t) / dt_sample_s;
+      let dipole_cmd_b_amm: Array1<f64> = -magnetorquers.bdot_gain_nms / field_norm_sq * &field_rate_b_tps;
+      magnetorquers.dipole_b_amm = magnetorquers.calc_realized_dipole_b_amm(dipole_cmd_b_amm.view());
+    }
+  }
+
+  magnetorquers.last_measurement_b_t = Some((measurement_b_t, sim_time_s));
+  /* Keep the sample grid, skip samples that were stepped over */
+  while magnetorquers.next_sample_time_s <= sim_time_s + time_tolerance_s
+  {
+    magnetorquers.next_sample_time_s += magnetorquers.sample_interval_s;
+  }
+
+  magnetorquers.dipole_b_amm != last_dipole_b_amm
+}
+
+/*
+ * @brief: Function to compute the torque of the magnetorquers T = m x B with
+ *         the dipole held by the B-dot controller
+ *
+ * @param[in] state_in - Full state vector
+ *
+ * @unit: Nm
+ * @frame: Body
+ *
+ */
+pub fn get_torque_vec_b(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let (_, _, field_b_t): (Array1<f64>, Array1<f64>, Array1<f64>) = environment.get_magnetic_field()
+    .calc_field_vecs(state_in.view(), environment.get_planet());
+
+  let magnetorquers: &mut Magnetorquers = environment.get_mut_spacecraft().get_mut_magnetorquers();
+  magnetorquers.torque_b_nm = cross_product_array1(magnetorquers.dipole_b_amm.view(), field_b_t.view());
+
+  magnetorquers.torque_b_nm.clone()
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+impl Magnetorquers {
+ /*
+  * @brief: Draw a sample of the standard normal distribution (xorshift64*
+  *         generator and Box-Muller transform). The sequence is reproducible
+  *         for a given seed.
+  */
+  fn calc_standard_normal_sample(&mut self) -> f64
+  {
+    let mut draw_uniform = || -> f64 {
+      self.rng_state ^= self.rng_state >> 12;
+      self.rng_state ^= self.rng_state << 25;
+      self.rng_state ^= self.rng_state >> 27;
+      /* Uniform in (0, 1] from the upper 53 bits */
+      ((self.rng_state.wrapping_mul(0x2545F4914F6CDD1D) >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    };
+    let u1: f64 = draw_uniform();
+    let u2: f64 = draw_uniform();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+  }
+}
@@ -0,0 +1,419 @@
+/*
+ * @brief: Propulsion subsystem with thrusters and mass depletion
+ *
+ * @description: Each thruster produces a constant thrust F along its mounting
+ *               direction d in the body frame while it fires. The thrust
+ *               acts through the centre of mass, the force in PCI is
+ *
+ *               F_pci = C_pci_to_b^T * sum( F_i * d_i )
+ *
+ *               The spacecraft mass and the propellant mass are part of the
+ *               state vector and decrease with the mass flow of the firing
+ *               thrusters:
+ *
+ *               dm/dt = - sum( F_i / (Isp_i * g0) )
+ *
+ *               The thrusters fire according to the burns defined in
+ *               propulsion.ini (start time and duration relative to the
+ *               simulation start). A burn shorter than the minimum on-time of
+ *               its thruster is skipped, a burn longer than the maximum
+ *               on-time is truncated. All thrusters stop firing when the
+ *               propellant is depleted.
+ *
+ *               The firing state is updated by the simulation loop before
+ *               each step and held during the step. The steps are truncated
+ *               at the start and end of each burn and at propellant depletion,
+ *               so that the thrust is constant within each step.
+ *
+ *               Ref: Sutton, Biblarz - Rocket Propulsion Elements, Chapter 2
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, s};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::vec_math::normalize_array1;
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::general::STANDARD_GRAVITY_MSS;
+use crate::constants::spacecraft::SC_PROPELLANT_TOLERANCE_KG;
+use crate::constants::time::SIM_TIME_TOLERANCE_S;
+
+#[derive(Clone)]
+
+pub struct Thruster {
+  /*
+   * @description : Direction of the thrust force acting on the spacecraft
+   *                (unit vector, opposite to the exhaust)
+   * @unit        : N/A
+   * @frame       : Body
+   *
+   * */
+  thrust_direction_b: Array1<f64>,
+  /*
+   * @description : Thrust and specific impulse
+   * @unit        : N, s
+   *
+   * */
+  thrust_n: f64,
+  isp_s: f64,
+  /*
+   * @description : Minimum and maximum on-time of a single burn
+   * @unit        : seconds
+   *
+   * */
+  min_on_time_s: f64,
+  max_on_time_s: f64
+}
+
+impl Thruster {
+  pub fn new(thrust_direction_b: &Array1<f64>,
+             thrust_n: f64,
+             isp_s: f64,
+             min_on_time_s: f64,
+             max_on_time_s: f64) -> Thruster
+  {
+    if thrust_n < 0.0 || isp_s <= 0.0
+    {
+      panic!("! [ERROR] ! > Thrust shall not be negative and Isp shall be positive in propulsion.ini! <");
+    }
+    Thruster {
+      thrust_direction_b: normalize_array1(thrust_direction_b.clone()),
+      thrust_n,
+      isp_s,
+      min_on_time_s,
+      max_on_time_s
+    }
+  }
+
+  pub fn get_thrust_direction_b(&self) -> &Array1<f64> {&self.thrust_direction_b}
+  pub fn get_thrust_n(&self) -> &f64 {&self.thrust_n}
+  pub fn get_isp_s(&self) -> &f64 {&self.isp_s}
+  pub fn get_min_on_time_s(&self) -> &f64 {&self.min_on_time_s}
+  pub fn get_max_on_time_s(&self) -> &f64 {&self.max_on_time_s}
+
+ /*
+  * @brief: Function to compute the mass flow of the firing thruster
+  *         F / (Isp * g0)
+  *
+  * @unit: kg/s
+  */
+  pub fn calc_mass_flow_kgps(&self) -> f64
+  {
+    self.thrust_n / (self.isp_s * STANDARD_GRAVITY_MSS)
+  }
+}
+
+#[derive(Clone)]
+
+pub struct ThrusterBurn {
+  /*
+   * @description : Index of the firing thruster (starting at zero)
+   * @unit        : N/A
+   *
+   * */
+  thruster_index: usize,
+  /*
+   * @description : Start time relative to the simulation start and duration
+   *                of the burn
+   * @unit        : seconds
+   *
+   * */
+  start_time_s: f64,
+  duration_s: f64
+}
+
+impl ThrusterBurn {
+  pub fn new(thruster_index: usize, start_time_s: f64, duration_s: f64) -> ThrusterBurn
+  {
+    ThrusterBurn {
+      thruster_index,
+      start_time_s,
+      duration_s
+    }
+  }
+
+  pub fn get_thruster_index(&self) -> &usize {&self.thruster_index}
+  pub fn get_start_time_s(&self) -> &f64 {&self.start_time_s}
+  pub fn get_duration_s(&self) -> &f64 {&self.duration_s}
+  pub fn get_end_time_s(&self) -> f64 {self.start_time_s + self.duration_s}
+}
+
+#[derive(Clone)]
+
+pub struct Propulsion {
+  /*
+   * @description : Flag, if true the thrusters and the propellant consumption
+   *                are modelled
+   * @unit        : N/A
+   *
+   * */
+  enable_propulsion: bool,
+  /*
+   * @description : Propellant mass at simulation start (part of the start
+   *                mass in sim.ini)
+   * @unit        : kg
+   *
+   * */
+  propellant_mass_start_kg: f64,
+  /*
+   * @description : Thrusters of the propulsion subsystem
+   * @unit        : N/A
+   *
+   * */
+  thrusters: Vec<Thruster>,
+  /*
+   * @description : Burns sorted by start time, after the on-time limits have
+   *                been applied
+   * @unit        : N/A
+   *
+   * */
+  burns: Vec<ThrusterBurn>,
+  /*
+   * @description : Simulation start time, the burn times are relative to it
+   * @unit        : seconds
+   *
+   * */
+  sim_start_time_s: f64,
+  /*
+   * @description : Firing state of each thruster during the current step
+   * @unit        : N/A
+   *
+   * */
+  is_firing: Vec<bool>,
+  /*
+   * @description : Thrust force and mass flow of the firing thrusters.
+   *                Updated with every call of get_force_vec_pci.
+   * @unit        : N, kg/s
+   * @frame       : PCI
+   *
+   * */
+  thrust_force_pci_n: Array1<f64>,
+  mass_flow_kgps: f64
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Propulsion {
+  pub fn new() -> Propulsion {
+    Propulsion {
+      enable_propulsion: false,
+      propellant_mass_start_kg: 0.0,
+      thrusters: Vec::new(),
+      burns: Vec::new(),
+      sim_start_time_s: 0.0,
+      is_firing: Vec::new(),
+      thrust_force_pci_n: Array1::zeros(3),
+      mass_flow_kgps: 0.0
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. Applies the on-time limits of the thrusters to the
+   *         burns and sorts them by start time.
+   */
+  pub fn init(&mut self)
+  {
+    let mut burns: Vec<ThrusterBurn> = Vec::new();
+    for (i, burn) in self.burns.iter().enumerate()
+    {
+      let thruster: &Thruster = self.thrusters.get(burn.thruster_index)
+        .unwrap_or_else(|| panic!("! [ERROR] ! > Burn {} uses unknown thruster {} in propulsion.ini! <",
+                                  i + 1, burn.thruster_index + 1));
+
+      if burn.duration_s < thruster.min_on_time_s
+      {
+        println!("[WRN] Burn {} ({:.3} s) is shorter than the minimum on-time of thruster {} and is skipped!",
+                 i + 1, burn.duration_s, burn.thruster_index + 1);
+        continue;
+      }
+      let mut burn_limited: ThrusterBurn = burn.clone();
+      if burn.duration_s > thruster.max_on_time_s
+      {
+        println!("[WRN] Burn {} ({:.3} s) is truncated to the maximum on-time of thruster {} ({:.3} s)!",
+                 i + 1, burn.duration_s, burn.thruster_index + 1, thruster.max_on_time_s);
+        burn_limited.duration_s = thruster.max_on_time_s;
+      }
+      burns.push(burn_limited);
+    }
+    burns.sort_by(|a, b| a.start_time_s.total_cmp(&b.start_time_s));
+
+    self.burns = burns;
+    self.is_firing = vec![false; self.thrusters.len()];
+  }
+}
+
+impl Default for Propulsion {
+  fn default() -> Propulsion {
+    Propulsion::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl Propulsion {
+  pub fn set_enable_propulsion(&mut self, val_in: &bool) {self.enable_propulsion = *val_in;}
+  pub fn set_propellant_mass_start_kg(&mut self, val_in: &f64) {self.propellant_mass_start_kg = *val_in;}
+  pub fn add_thruster(&mut self, thruster_in: Thruster) {self.thrusters.push(thruster_in);}
+  pub fn add_burn(&mut self, burn_in: ThrusterBurn) {self.burns.push(burn_in);}
+  pub fn set_sim_start_time_s(&mut self, val_in: &f64) {self.sim_start_time_s = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Propulsion {
+  pub fn is_propulsion_enabled(&self) -> &bool {&self.enable_propulsion}
+  pub fn get_propellant_mass_start_kg(&self) -> &f64 {&self.propellant_mass_start_kg}
+  pub fn get_thrusters(&self) -> &Vec<Thruster> {&self.thrusters}
+  pub fn get_burns(&self) -> &Vec<ThrusterBurn> {&self.burns}
+  pub fn is_any_thruster_firing(&self) -> bool {self.is_firing.iter().any(|is_firing| *is_firing)}
+  pub fn get_thrust_force_pci_n(&self) -> &Array1<f64> {&self.thrust_force_pci_n}
+  pub fn get_mass_flow_kgps(&self) -> &f64 {&self.mass_flow_kgps}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl Propulsion {
+ /*
+  * @brief: Function to update the firing state of the thrusters for the next
+  *         step. A thruster fires if one of its burns is active at the
+  *         current simulation time and propellant is left.
+  *
+  * @param[in] sim_time_s         - Simulation time at the start of the step
+  * @param[in] propellant_mass_kg - Propellant mass at the start of the step
+  *
+  * @returns: true if the firing state of any thruster changed
+  */
+  pub fn update_firing(&mut self, sim_time_s: f64, propellant_mass_kg: f64) -> bool
+  {
+    let burn_time_s: f64 = sim_time_s - self.sim_start_time_s;
+    let mut is_firing: Vec<bool> = vec![false; self.thrusters.len()];
+
+    if propellant_mass_kg > SC_PROPELLANT_TOLERANCE_KG
+    {
+      for burn in self.burns.iter()
+      {
+        if burn_time_s >= burn.start_time_s - SIM_TIME_TOLERANCE_S
+          && burn_time_s < burn.get_end_time_s() - SIM_TIME_TOLERANCE_S
+        {
+          is_firing[burn.thruster_index] = true;
+        }
+      }
+    }
+
+    let is_changed: bool = is_firing != self.is_firing;
+    self.is_firing = is_firing;
+    is_changed
+  }
+
+ /*
+  * @brief: Function to compute the maximum size of the next step, so that the
+  *         step ends at the next start or end of a burn or at propellant
+  *         depletion
+  *
+  * Note: Requires the firing state of this step from update_firing()
+  *
+  * @param[in] sim_time_s         - Simulation time at the start of the step
+  * @param[in] propellant_mass_kg - Propellant mass at the start of the step
+  *
+  * @unit: seconds
+  */
+  pub fn calc_max_step_s(&self, sim_time_s: f64, propellant_mass_kg: f64) -> f64
+  {
+    let burn_time_s: f64 = sim_time_s - self.sim_start_time_s;
+    let mut max_step_s: f64 = self.burns.iter()
+      .flat_map(|burn| [burn.start_time_s, burn.get_end_time_s()])
+      .filter(|switch_time_s| *switch_time_s > burn_time_s + SIM_TIME_TOLERANCE_S)
+      .fold(f64::INFINITY, |max_step_s, switch_time_s| max_step_s.min(switch_time_s - burn_time_s));
+
+    let mass_flow_kgps: f64 = self.calc_firing_mass_flow_kgps();
+    if mass_flow_kgps > 0.0
+    {
+      max_step_s = max_step_s.min(propellant_mass_kg / mass_flow_kgps);
+    }
+    max_step_s
+  }
+
+ /*
+  * @brief: Function to compute the mass flow of all firing thrusters
+  *
+  * @unit: kg/s
+  */
+  pub fn calc_firing_mass_flow_kgps(&self) -> f64
+  {
+    self.thrusters.iter().zip(self.is_firing.iter())
+                  .filter(|(_, is_firing)| **is_firing)
+                  .fold(0.0, |mass_flow_kgps, (thruster, _)| mass_flow_kgps + thruster.calc_mass_flow_kgps())
+  }
+
+ /*
+  * @brief: Function to compute the thrust force of all firing thrusters
+  *
+  * @unit: N
+  * @frame: Body
+  */
+  pub fn calc_firing_thrust_vec_b(&self) -> Array1<f64>
+  {
+    let mut thrust_b_n: Array1<f64> = Array1::zeros(3);
+    for (thruster, is_firing) in self.thrusters.iter().zip(self.is_firing.iter())
+    {
+      if *is_firing
+      {
+        thrust_b_n.scaled_add(thruster.thrust_n, &thruster.thrust_direction_b);
+      }
+    }
+    thrust_b_n
+  }
+
+ /*
+  * @brief: Function to copy the propellant mass at simulation start to the
+  *         state vector
+  */
+  pub fn write_propellant_mass_to_state_vec(&self, state_inout: &mut Array1<f64>)
+  {
+    if self.propellant_mass_start_kg > state_inout[STATE_VEC_INDX_MASS]
+    {
+      panic!("! [ERROR] ! > propellant_mass_kg in propulsion.ini exceeds sc_mass_start_kg in sim.ini! <");
+    }
+    state_inout[STATE_VEC_INDX_PROPELLANT_MASS] = self.propellant_mass_start_kg;
+  }
+}
+
+/*
+ * @brief: Function to compute the thrust force of the firing thrusters
+ *
+ * @param[in] state_in - Full state vector
+ *
+ * @unit: N
+ * @frame: PCI
+ *
+ */
+pub fn get_force_vec_pci(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let propulsion: &mut Propulsion = environment.get_mut_spacecraft().get_mut_propulsion();
+
+  let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(
+    state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+  propulsion.thrust_force_pci_n = dcm_pci_to_b.t().dot(&propulsion.calc_firing_thrust_vec_b());
+  propulsion.mass_flow_kgps = propulsion.calc_firing_mass_flow_kgps();
+
+  propulsion.thrust_force_pci_n.clone()
+}
@@ -0,0 +1,410 @@
+/*
+ * @brief: Reaction wheel assembly (RWA) with momentum dumping
+ *
+ * @description: The assembly consists of up to SC_RWA_MAX_NUM_WHEELS wheels
+ *               with arbitrary spin axes a_i in the body frame. The wheel
+ *               speeds W_i relative to the body are part of the state vector.
+ *               The angular momentum of the wheels in the body frame is
+ *
+ *               h_w = sum( a_i * J_i * (W_i + a_i . w) )
+ *
+ *               and enters Euler's equations as gyroscopic coupling:
+ *
+ *               I * dw/dt = T_ext - sum( a_i * T_i ) - w x (I * w + h_w)
+ *               J_i * (dW_i/dt + a_i . dw/dt) = T_i
+ *
+ *               where I is the inertia tensor of the spacecraft without the
+ *               spin axis inertia of the wheels and T_i the net torque acting
+ *               on wheel i (motor torque minus viscous and Coulomb friction).
+ *
+ *               The torque commanded by the attitude controller is distributed
+ *               to the wheels with the pseudo-inverse of the spin axis matrix.
+ *               Each motor torque is limited to the maximum wheel torque and
+ *               set to zero if it would accelerate a wheel beyond its maximum
+ *               speed.
+ *
+ *               Momentum dumping applies an external torque that reduces the
+ *               wheel momentum h = sum( a_i * J_i * W_i ), while the wheels
+ *               compensate the dumping torque:
+ *
+ *               * thrusters     - T_dump = - k * h, limited per body axis
+ *               * magnetorquers - m = k * (h x B) / |B|^2, limited per body
+ *                                 axis, T_dump = m x B (only the component of
+ *                                 - k * h normal to B can be dumped). The
+ *                                 field B is taken from the magnetic field
+ *                                 model (magnetic_field.ini).
+ *
+ *               Ref: Wie - Space Vehicle Dynamics and Control, Chapter 7
+ *                    Markley, Crassidis - Fundamentals of Spacecraft Attitude
+ *                    Determination and Control, Chapter 7.2
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, ArrayView1, s};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::vec_math::{normalize_array1, cross_product_array1};
+use crate::math::attitude_math::calc_inverse_mat33;
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::spacecraft::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum MomentumDumpingMode {
+  Off,
+  Thrusters,
+  Magnetorquers
+}
+
+impl MomentumDumpingMode {
+  pub fn from_param_str(mode_str: &str) -> MomentumDumpingMode
+  {
+    match mode_str.trim().trim_matches('"')
+    {
+      "off" => MomentumDumpingMode::Off,
+      "thrusters" => MomentumDumpingMode::Thrusters,
+      "magnetorquers" => MomentumDumpingMode::Magnetorquers,
+      _ => panic!("! [ERROR] ! > Unknown dumping_mode {} in reaction_wheels.ini! <", mode_str)
+    }
+  }
+}
+
+#[derive(Clone)]
+
+pub struct ReactionWheel {
+  /*
+   * @description : Spin axis of the wheel (unit vector)
+   * @unit        : N/A
+   * @frame       : Body
+   *
+   * */
+  spin_axis_b: Array1<f64>,
+  /*
+   * @description : Moment of inertia of the rotor about its spin axis
+   * @unit        : kg * m * m
+   *
+   * */
+  rotor_inertia_kgmm: f64,
+  /*
+   * @description : Maximum motor torque and maximum wheel speed
+   * @unit        : Nm, rad/s
+   *
+   * */
+  max_torque_nm: f64,
+  max_speed_rads: f64,
+  /*
+   * @description : Viscous friction coefficient and Coulomb friction torque
+   * @unit        : Nm/(rad/s), Nm
+   *
+   * */
+  viscous_friction_nms: f64,
+  coulomb_friction_nm: f64,
+  /*
+   * @description : Wheel speed at simulation start
+   * @unit        : rad/s
+   *
+   * */
+  start_speed_rads: f64
+}
+
+impl ReactionWheel {
+  pub fn new(spin_axis_b: &Array1<f64>,
+             rotor_inertia_kgmm: f64,
+             max_torque_nm: f64,
+             max_speed_rads: f64,
+             viscous_friction_nms: f64,
+             coulomb_friction_nm: f64,
+             start_speed_rads: f64) -> ReactionWheel
+  {
+    ReactionWheel {
+      spin_axis_b: normalize_array1(spin_axis_b.clone()),
+      rotor_inertia_kgmm,
+      max_torque_nm,
+      max_speed_rads,
+      viscous_friction_nms,
+      coulomb_friction_nm,
+      start_speed_rads
+    }
+  }
+
+  pub fn get_spin_axis_b(&self) -> &Array1<f64> {&self.spin_axis_b}
+  pub fn get_rotor_inertia_kgmm(&self) -> &f64 {&self.rotor_inertia_kgmm}
+  pub fn get_max_torque_nm(&self) -> &f64 {&self.max_torque_nm}
+  pub fn get_max_speed_rads(&self) -> &f64 {&self.max_speed_rads}
+  pub fn get_start_speed_rads(&self) -> &f64 {&self.start_speed_rads}
+
+ /*
+  * @brief: Function to compute the friction torque of the wheel bearings and
+  *         motor for a given wheel speed
+  *
+  * @unit: Nm
+  */
+  pub fn calc_friction_torque_nm(&self, speed_rads: f64) -> f64
+  {
+    let coulomb_friction_nm: f64 = if speed_rads == 0.0 {0.0}
+                                   else {self.coulomb_friction_nm * speed_rads.signum()};
+
+    self.viscous_friction_nms * speed_rads + coulomb_friction_nm
+  }
+}
+
+#[derive(Clone)]
+
+pub struct ReactionWheelAssembly {
+  /*
+   * @description : Flag, if true the reaction wheels are modelled and realize
+   *                the torque commanded by the attitude controller
+   * @unit        : N/A
+   *
+   * */
+  enable_reaction_wheels: bool,
+  /*
+   * @description : Reaction wheels of the assembly
+   * @unit        : N/A
+   *
+   * */
+  wheels: Vec<ReactionWheel>,
+  /*
+   * @description : Pseudo-inverse of the spin axis matrix [a_1 ... a_N] to
+   *                distribute a body torque to the wheels (N x 3)
+   * @unit        : N/A
+   *
+   * */
+  allocation_matrix: Array2<f64>,
+  /*
+   * @description : Actuator used for momentum dumping, dumping gain k and
+   *                the per axis limits of the dumping torque (thrusters) and
+   *                magnetic dipole (magnetorquers)
+   * @unit        : N/A, 1/s, Nm, A * m * m
+   *
+   * */
+  dumping_mode: MomentumDumpingMode,
+  dumping_gain_1ps: f64,
+  max_dumping_torque_nm: f64,
+  max_dumping_dipole_amm: f64,
+  /*
+   * @description : Net torque acting on each wheel and the dumping torque.
+   *                Updated with every call of get_torque_vec_b.
+   * @unit        : Nm
+   *
+   * */
+  wheel_torque_nm: Array1<f64>,
+  dumping_torque_b_nm: Array1<f64>
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl ReactionWheelAssembly {
+  pub fn new() -> ReactionWheelAssembly {
+    ReactionWheelAssembly {
+      enable_reaction_wheels: false,
+      wheels: Vec::new(),
+      allocation_matrix: Array2::zeros((0, 3)),
+      dumping_mode: MomentumDumpingMode::Off,
+      dumping_gain_1ps: 0.0,
+      max_dumping_torque_nm: 0.0,
+      max_dumping_dipole_amm: 0.0,
+      wheel_torque_nm: Array1::zeros(0),
+      dumping_torque_b_nm: Array1::zeros(3)
+    }
+  }
+
+  /*
+   * @brief: Function to complete initializing the class after the struct has
+   *         been created. Computes the torque allocation matrix.
+   */
+  pub fn init(&mut self)
+  {
+    if self.wheels.len() > SC_RWA_MAX_NUM_WHEELS
+    {
+      panic!("! [ERROR] ! > More than {} reaction wheels in reaction_wheels.ini! <", SC_RWA_MAX_NUM_WHEELS);
+    }
+
+    let mut spin_axis_mat: Array2<f64> = Array2::zeros((3, self.wheels.len()));
+    for (i, wheel) in self.wheels.iter().enumerate()
+    {
+      spin_axis_mat.column_mut(i).assign(&wheel.spin_axis_b);
+    }
+
+    let spin_axis_mat_sq_inv: Array2<f64> = calc_inverse_mat33(spin_axis_mat.dot(&spin_axis_mat.t()).view())
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Reaction wheel spin axes do not span all three body axes! <"));
+
+    self.allocation_matrix = spin_axis_mat.t().dot(&spin_axis_mat_sq_inv);
+    self.wheel_torque_nm = Array1::zeros(self.wheels.len());
+  }
+}
+
+impl Default for ReactionWheelAssembly {
+  fn default() -> ReactionWheelAssembly {
+    ReactionWheelAssembly::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl ReactionWheelAssembly {
+  pub fn set_enable_reaction_wheels(&mut self, val_in: &bool) {self.enable_reaction_wheels = *val_in;}
+  pub fn add_wheel(&mut self, wheel_in: ReactionWheel) {self.wheels.push(wheel_in);}
+  pub fn set_dumping_mode(&mut self, val_in: &MomentumDumpingMode) {self.dumping_mode = *val_in;}
+  pub fn set_dumping_gain_1ps(&mut self, val_in: &f64) {self.dumping_gain_1ps = *val_in;}
+  pub fn set_max_dumping_torque_nm(&mut self, val_in: &f64) {self.max_dumping_torque_nm = *val_in;}
+  pub fn set_max_dumping_dipole_amm(&mut self, val_in: &f64) {self.max_dumping_dipole_amm = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl ReactionWheelAssembly {
+  pub fn is_reaction_wheels_enabled(&self) -> &bool {&self.enable_reaction_wheels}
+  pub fn get_wheels(&self) -> &Vec<ReactionWheel> {&self.wheels}
+  pub fn get_num_wheels(&self) -> usize {self.wheels.len()}
+  pub fn get_dumping_mode(&self) -> &MomentumDumpingMode {&self.dumping_mode}
+  pub fn get_wheel_torque_nm(&self) -> &Array1<f64> {&self.wheel_torque_nm}
+  pub fn get_dumping_torque_b_nm(&self) -> &Array1<f64> {&self.dumping_torque_b_nm}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl ReactionWheelAssembly {
+ /*
+  * @brief: Function to compute the total angular momentum of the wheels
+  *         h_w = sum( a_i * J_i * (W_i + a_i . w) )
+  *
+  * @param[in] state_in - Full state vector
+  *
+  * @unit: Nms
+  * @frame: Body
+  */
+  pub fn calc_wheel_ang_mom_b(&self, state_in: ArrayView1<f64>) -> Array1<f64>
+  {
+    let ang_rate_b: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_ATTRATE_X..(STATE_VEC_INDX_ATTRATE_Z+1)]);
+    let mut wheel_ang_mom_b_nms: Array1<f64> = Array1::zeros(3);
+
+    for (i, wheel) in self.wheels.iter().enumerate()
+    {
+      let abs_speed_rads: f64 = state_in[STATE_VEC_INDX_RW_SPEED_1 + i] + wheel.spin_axis_b.dot(&ang_rate_b);
+      wheel_ang_mom_b_nms.scaled_add(wheel.rotor_inertia_kgmm * abs_speed_rads, &wheel.spin_axis_b);
+    }
+
+    wheel_ang_mom_b_nms
+  }
+
+ /*
+  * @brief: Function to compute the derivatives of the wheel speeds relative
+  *         to the body dW_i/dt = T_i / J_i - a_i . dw/dt
+  *
+  * Note: Requires the net wheel torques of this state from get_torque_vec_b()
+  *
+  * @param[in] ang_acc_b_in - Angular acceleration of the body [rad/ss]
+  *
+  * @unit: rad/ss
+  */
+  pub fn calc_wheel_acc_radss(&self, ang_acc_b_in: ArrayView1<f64>) -> Array1<f64>
+  {
+    Array1::from_iter(self.wheels.iter().enumerate().map(|(i, wheel)|
+      self.wheel_torque_nm[i] / wheel.rotor_inertia_kgmm - wheel.spin_axis_b.dot(&ang_acc_b_in)))
+  }
+
+ /*
+  * @brief: Function to copy the start speed of each wheel to the state vector
+  */
+  pub fn write_start_speeds_to_state_vec(&self, state_inout: &mut Array1<f64>)
+  {
+    for (i, wheel) in self.wheels.iter().enumerate()
+    {
+      state_inout[STATE_VEC_INDX_RW_SPEED_1 + i] = wheel.start_speed_rads;
+    }
+  }
+}
+
+/*
+ * @brief: Function to compute the torque acting on the body from the reaction
+ *         wheels realizing the commanded torque and from momentum dumping
+ *
+ * @param[in] state_in           - Full state vector
+ * @param[in] cmd_torque_b_nm_in - Torque commanded by the attitude controller
+ *
+ * @unit: Nm
+ * @frame: Body
+ *
+ */
+pub fn get_torque_vec_b(state_in: &Array1<f64>, cmd_torque_b_nm_in: ArrayView1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let reaction_wheels: &ReactionWheelAssembly = environment.get_spacecraft().get_reaction_wheel_assembly();
+  let wheel_speeds_rads: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_RW_SPEED_1
+                                                            ..(STATE_VEC_INDX_RW_SPEED_1 + reaction_wheels.wheels.len())]);
+
+  /* [MOMENTUM DUMPING] */
+  let mut wheel_ang_mom_b_nms: Array1<f64> = Array1::zeros(3);
+  for (i, wheel) in reaction_wheels.wheels.iter().enumerate()
+  {
+    wheel_ang_mom_b_nms.scaled_add(wheel.rotor_inertia_kgmm * wheel_speeds_rads[i], &wheel.spin_axis_b);
+  }
+
+  let dumping_torque_b_nm: Array1<f64> = match reaction_wheels.dumping_mode
+  {
+    MomentumDumpingMode::Off => Array1::zeros(3),
+    MomentumDumpingMode::Thrusters =>
+    {
+      /* Note: The propellant consumed by the thrusters is not modelled */
+      (-reaction_wheels.dumping_gain_1ps * &wheel_ang_mom_b_nms)
+        .mapv(|torque: f64| torque.clamp(-reaction_wheels.max_dumping_torque_nm,
+                                         reaction_wheels.max_dumping_torque_nm))
+    }
+    MomentumDumpingMode::Magnetorquers =>
+    {
+      let (_, _, mag_field_b_t): (Array1<f64>, Array1<f64>, Array1<f64>) = environment.get_magnetic_field()
+        .calc_field_vecs(state_in.view(), environment.get_planet());
+
+      let mag_dipole_b_amm: Array1<f64> = (reaction_wheels.dumping_gain_1ps / mag_field_b_t.dot(&mag_field_b_t)
+                                          * cross_product_array1(wheel_ang_mom_b_nms.view(), mag_field_b_t.view()))
+        .mapv(|dipole: f64| dipole.clamp(-reaction_wheels.max_dumping_dipole_amm,
+                                         reaction_wheels.max_dumping_dipole_amm));
+      cross_product_array1(mag_dipole_b_amm.view(), mag_field_b_t.view())
+    }
+  };
+
+  /* [TORQUE ALLOCATION]
+   * The reaction torque of the wheels realizes the commanded torque and
+   * compensates the dumping torque */
+  let motor_torque_cmd_nm: Array1<f64> = reaction_wheels.allocation_matrix
+    .dot(&(&dumping_torque_b_nm - &cmd_torque_b_nm_in));
+
+  let mut wheel_torque_nm: Array1<f64> = Array1::zeros(reaction_wheels.wheels.len());
+  let mut torque_b_nm: Array1<f64> = dumping_torque_b_nm.clone();
+  for (i, wheel) in reaction_wheels.wheels.iter().enumerate()
+  {
+    let mut motor_torque_nm: f64 = motor_torque_cmd_nm[i].clamp(-wheel.max_torque_nm, wheel.max_torque_nm);
+    /* Do not accelerate the wheel beyond its maximum speed */
+    if wheel_speeds_rads[i].abs() >= wheel.max_speed_rads
+      && motor_torque_nm * wheel_speeds_rads[i] > 0.0
+    {
+      motor_torque_nm = 0.0;
+    }
+
+    wheel_torque_nm[i] = motor_torque_nm - wheel.calc_friction_torque_nm(wheel_speeds_rads[i]);
+    torque_b_nm.scaled_add(-wheel_torque_nm[i], &wheel.spin_axis_b);
+  }
+
+  let reaction_wheels: &mut ReactionWheelAssembly = environment.get_mut_spacecraft().get_mut_reaction_wheel_assembly();
+  reaction_wheels.wheel_torque_nm = wheel_torque_nm;
+  reaction_wheels.dumping_torque_b_nm = dumping_torque_b_nm;
+
+  torque_b_nm
+}
@@ -1,13 +1,16 @@
 
 /* Include external crates */
-/* None */
+use ndarray::Array2;
 
 /* Import (local) structs */
-/* None */
+use crate::environment::spacecraft::reaction_wheels::ReactionWheelAssembly;
+use crate::environment::spacecraft::magnetorquers::Magnetorquers;
+use crate::environment::spacecraft::propulsion::Propulsion;
 
 /* Include local crates */
 use crate::io::read_csv::*;
 use crate::math::lin_math::*;
+use crate::math::attitude_math::calc_inverse_mat33;
 
 /* Import constants */
 use crate::constants::spacecraft::*;
@@ -46,12 +49,81 @@ pub struct Spacecraft {
   aero_force_pci_n_y: f64,
   aero_force_pci_n_z: f64,
  /*
-  * @brief: Total mass of the spacecraft.
+  * @brief: Third body force of the Sun acting on the spacecraft in x direction 
+  *         in inertial (PCI) frame
   * 
-  * @unit: kg
-  * @frame: N/A
+  * @unit: Newton
+  * @frame: PCI
+  */
+  sun_force_pci_n_x: f64,
+  sun_force_pci_n_y: f64,
+  sun_force_pci_n_z: f64,
+ /*
+  * @brief: Third body force of the Moon acting on the spacecraft in x direction 
+  *         in inertial (PCI) frame
+  * 
+  * @unit: Newton
+  * @frame: PCI
+  */
+  moon_force_pci_n_x: f64,
+  moon_force_pci_n_y: f64,
+  moon_force_pci_n_z: f64,
+ /*
+  * @brief: Solar radiation pressure force acting on the spacecraft in x 
+  *         direction in inertial (PCI) frame
+  * 
+  * @unit: Newton
+  * @frame: PCI
+  */
+  srp_force_pci_n_x: f64,
+  srp_force_pci_n_y: f64,
+  srp_force_pci_n_z: f64,
+ /*
+  * @brief: Gravity gradient torque acting on the spacecraft in body frame
+  * 
+  * @unit: Nm
+  * @frame: Body
+  */
+  grav_grad_torque_b_nm_x: f64,
+  grav_grad_torque_b_nm_y: f64,
+  grav_grad_torque_b_nm_z: f64,
+ /*
+  * @brief: Aerodynamic torque acting on the spacecraft in body frame
+  * 
+  * @unit: Nm
+  * @frame: Body
+  */
+  aero_torque_b_nm_x: f64,
+  aero_torque_b_nm_y: f64,
+  aero_torque_b_nm_z: f64,
+ /*
+  * @brief: Solar radiation pressure torque acting on the spacecraft in body frame
+  * 
+  * @unit: Nm
+  * @frame: Body
+  */
+  srp_torque_b_nm_x: f64,
+  srp_torque_b_nm_y: f64,
+  srp_torque_b_nm_z: f64,
+ /*
+  * @brief: Offset of the aerodynamic centre of pressure from the centre of mass
+  * 
+  * @unit: m
+  * @frame: Body
+  */
+  sc_aero_cop_offset_b_m_x: f64,
+  sc_aero_cop_offset_b_m_y: f64,
+  sc_aero_cop_offset_b_m_z: f64,
+ /*
+  * @brief: Offset of the solar radiation pressure centre of pressure from the 
+  *         centre of mass
+  * 
+  * @unit: m
+  * @frame: Body
   */
-  sc_mass_kg: f64,
+  sc_srp_cop_offset_b_m_x: f64,
+  sc_srp_cop_offset_b_m_y: f64,
+  sc_srp_cop_offset_b_m_z: f64,
  /*
   * @brief: Spacecraft altitude above sea level (wrt the central body)
   * 
@@ -76,6 +148,22 @@ pub struct Spacecraft {
   * @frame: N/A
   */
   sc_charact_length_m: f64,
+ /*
+  * @brief: Effective surface area exposed to the solar radiation 
+  *         (cannonball model)
+  * 
+  * @unit: m * m 
+  * @frame: N/A
+  */
+  sc_srp_area_mm: f64,
+ /*
+  * @brief: Reflectivity coefficient Cr of the spacecraft surface 
+  *         (1 - perfect absorption, 2 - perfect specular reflection)
+  * 
+  * @unit: N/A
+  * @frame: N/A
+  */
+  sc_reflectivity_coeff: f64,
  /*
   * @brief: Drag coefficient of the spacecraft.
   * 
@@ -93,6 +181,36 @@ pub struct Spacecraft {
   * @frame: N/A
   */
   sc_mach_number: f64,
+ /*
+  * @brief: Inertia tensor of the spacecraft about its centre of mass and its 
+  *         inverse
+  * 
+  * @unit: kg * m * m
+  * @frame: Body
+  */
+  sc_inertia_tensor_kgmm: Array2<f64>,
+  sc_inertia_tensor_inv: Array2<f64>,
+ /*
+  * @brief: Reaction wheel assembly of the spacecraft
+  * 
+  * @unit: N/A
+  * @frame: N/A
+  */
+  reaction_wheel_assembly: ReactionWheelAssembly,
+ /*
+  * @brief: Magnetorquers and B-dot detumbling controller of the spacecraft
+  * 
+  * @unit: N/A
+  * @frame: N/A
+  */
+  magnetorquers: Magnetorquers,
+ /*
+  * @brief: Propulsion subsystem of the spacecraft
+  * 
+  * @unit: N/A
+  * @frame: N/A
+  */
+  propulsion: Propulsion,
   /*
    * @brief: Tuple vector to store LUT for Mach - Cd 
    */
@@ -118,12 +236,42 @@ impl Spacecraft {
       aero_force_pci_n_x: 0.0,
       aero_force_pci_n_y: 0.0,
       aero_force_pci_n_z: 0.0,
-      sc_mass_kg: 0.0,
+      sun_force_pci_n_x: 0.0,
+      sun_force_pci_n_y: 0.0,
+      sun_force_pci_n_z: 0.0,
+      moon_force_pci_n_x: 0.0,
+      moon_force_pci_n_y: 0.0,
+      moon_force_pci_n_z: 0.0,
+      srp_force_pci_n_x: 0.0,
+      srp_force_pci_n_y: 0.0,
+      srp_force_pci_n_z: 0.0,
+      grav_grad_torque_b_nm_x: 0.0,
+      grav_grad_torque_b_nm_y: 0.0,
+      grav_grad_torque_b_nm_z: 0.0,
+      aero_torque_b_nm_x: 0.0,
+      aero_torque_b_nm_y: 0.0,
+      aero_torque_b_nm_z: 0.0,
+      srp_torque_b_nm_x: 0.0,
+      srp_torque_b_nm_y: 0.0,
+      srp_torque_b_nm_z: 0.0,
+      sc_aero_cop_offset_b_m_x: 0.0,
+      sc_aero_cop_offset_b_m_y: 0.0,
+      sc_aero_cop_offset_b_m_z: 0.0,
+      sc_srp_cop_offset_b_m_x: 0.0,
+      sc_srp_cop_offset_b_m_y: 0.0,
+      sc_srp_cop_offset_b_m_z: 0.0,
       sc_altitude_m: 0.0,
       sc_aero_eff_area_mm: 0.0,
       sc_charact_length_m: 0.0,
+      sc_srp_area_mm: 0.0,
+      sc_reflectivity_coeff: 0.0,
       sc_drag_contin_coefficient: 0.0,
       sc_mach_number: 0.0,
+      sc_inertia_tensor_kgmm: Array2::eye(3),
+      sc_inertia_tensor_inv: Array2::eye(3),
+      reaction_wheel_assembly: ReactionWheelAssembly::new(),
+      magnetorquers: Magnetorquers::new(),
+      propulsion: Propulsion::new(),
       drag_coeff_lut_vec: Vec::new()
 
     }
@@ -153,12 +301,46 @@ impl Spacecraft {
   pub fn set_aero_force_pci_n_x(&mut self, val_in: &f64) {self.aero_force_pci_n_x = *val_in;}
   pub fn set_aero_force_pci_n_y(&mut self, val_in: &f64) {self.aero_force_pci_n_y = *val_in;}
   pub fn set_aero_force_pci_n_z(&mut self, val_in: &f64) {self.aero_force_pci_n_z = *val_in;}
-  pub fn set_sc_mass_kg(&mut self, val_in: &f64) {self.sc_mass_kg = *val_in;}
+  pub fn set_sun_force_pci_n_x(&mut self, val_in: &f64) {self.sun_force_pci_n_x = *val_in;}
+  pub fn set_sun_force_pci_n_y(&mut self, val_in: &f64) {self.sun_force_pci_n_y = *val_in;}
+  pub fn set_sun_force_pci_n_z(&mut self, val_in: &f64) {self.sun_force_pci_n_z = *val_in;}
+  pub fn set_moon_force_pci_n_x(&mut self, val_in: &f64) {self.moon_force_pci_n_x = *val_in;}
+  pub fn set_moon_force_pci_n_y(&mut self, val_in: &f64) {self.moon_force_pci_n_y = *val_in;}
+  pub fn set_moon_force_pci_n_z(&mut self, val_in: &f64) {self.moon_force_pci_n_z = *val_in;}
+  pub fn set_srp_force_pci_n_x(&mut self, val_in: &f64) {self.srp_force_pci_n_x = *val_in;}
+  pub fn set_srp_force_pci_n_y(&mut self, val_in: &f64) {self.srp_force_pci_n_y = *val_in;}
+  pub fn set_srp_force_pci_n_z(&mut self, val_in: &f64) {self.srp_force_pci_n_z = *val_in;}
+  pub fn set_grav_grad_torque_b_nm_x(&mut self, val_in: &f64) {self.grav_grad_torque_b_nm_x = *val_in;}
+  pub fn set_grav_grad_torque_b_nm_y(&mut self, val_in: &f64) {self.grav_grad_torque_b_nm_y = *val_in;}
+  pub fn set_grav_grad_torque_b_nm_z(&mut self, val_in: &f64) {self.grav_grad_torque_b_nm_z = *val_in;}
+  pub fn set_aero_torque_b_nm_x(&mut self, val_in: &f64) {self.aero_torque_b_nm_x = *val_in;}
+  pub fn set_aero_torque_b_nm_y(&mut self, val_in: &f64) {self.aero_torque_b_nm_y = *val_in;}
+  pub fn set_aero_torque_b_nm_z(&mut self, val_in: &f64) {self.aero_torque_b_nm_z = *val_in;}
+  pub fn set_srp_torque_b_nm_x(&mut self, val_in: &f64) {self.srp_torque_b_nm_x = *val_in;}
+  pub fn set_srp_torque_b_nm_y(&mut self, val_in: &f64) {self.srp_torque_b_nm_y = *val_in;}
+  pub fn set_srp_torque_b_nm_z(&mut self, val_in: &f64) {self.srp_torque_b_nm_z = *val_in;}
+  pub fn set_sc_aero_cop_offset_b_m_x(&mut self, val_in: &f64) {self.sc_aero_cop_offset_b_m_x = *val_in;}
+  pub fn set_sc_aero_cop_offset_b_m_y(&mut self, val_in: &f64) {self.sc_aero_cop_offset_b_m_y = *val_in;}
+  pub fn set_sc_aero_cop_offset_b_m_z(&mut self, val_in: &f64) {self.sc_aero_cop_offset_b_m_z = *val_in;}
+  pub fn set_sc_srp_cop_offset_b_m_x(&mut self, val_in: &f64) {self.sc_srp_cop_offset_b_m_x = *val_in;}
+  pub fn set_sc_srp_cop_offset_b_m_y(&mut self, val_in: &f64) {self.sc_srp_cop_offset_b_m_y = *val_in;}
+  pub fn set_sc_srp_cop_offset_b_m_z(&mut self, val_in: &f64) {self.sc_srp_cop_offset_b_m_z = *val_in;}
   pub fn set_sc_altitude_m(&mut self, val_in: &f64) {self.sc_altitude_m = *val_in;}
   pub fn set_sc_aero_eff_area_mm(&mut self, val_in: &f64) {self.sc_aero_eff_area_mm = *val_in;}
   pub fn set_sc_charact_length_m(&mut self, val_in: &f64) {self.sc_charact_length_m = *val_in;}
+  pub fn set_sc_srp_area_mm(&mut self, val_in: &f64) {self.sc_srp_area_mm = *val_in;}
+  pub fn set_sc_reflectivity_coeff(&mut self, val_in: &f64) {self.sc_reflectivity_coeff = *val_in;}
   pub fn set_sc_drag_contin_coefficient(&mut self, val_in: &f64) {self.sc_drag_contin_coefficient = *val_in;}
   pub fn set_sc_mach_number(&mut self, val_in: &f64) {self.sc_mach_number = *val_in;}
+  pub fn set_sc_inertia_tensor_kgmm(&mut self, val_in: &Array2<f64>) 
+  {
+    self.sc_inertia_tensor_inv = calc_inverse_mat33(val_in.view())
+      .unwrap_or_else(|| panic!("! [ERROR] ! > Spacecraft inertia tensor is singular! <"));
+    self.sc_inertia_tensor_kgmm = val_in.clone();
+  }
+  pub fn get_mut_reaction_wheel_assembly(&mut self) -> &mut ReactionWheelAssembly {&mut self.reaction_wheel_assembly}
+  pub fn get_mut_magnetorquers(&mut self) -> &mut Magnetorquers {&mut self.magnetorquers}
+  pub fn get_mut_propulsion(&mut self) -> &mut Propulsion {&mut self.propulsion}
 }
 /*
  * ----------------------------------------------------------------------
@@ -176,12 +358,42 @@ impl Spacecraft {
   pub fn get_aero_force_pci_n_x(&self) -> &f64 {&self.aero_force_pci_n_x}
   pub fn get_aero_force_pci_n_y(&self) -> &f64 {&self.aero_force_pci_n_y}
   pub fn get_aero_force_pci_n_z(&self) -> &f64 {&self.aero_force_pci_n_z}
-  pub fn get_sc_mass_kg(&self) -> &f64 {&self.sc_mass_kg}
+  pub fn get_sun_force_pci_n_x(&self) -> &f64 {&self.sun_force_pci_n_x}
+  pub fn get_sun_force_pci_n_y(&self) -> &f64 {&self.sun_force_pci_n_y}
+  pub fn get_sun_force_pci_n_z(&self) -> &f64 {&self.sun_force_pci_n_z}
+  pub fn get_moon_force_pci_n_x(&self) -> &f64 {&self.moon_force_pci_n_x}
+  pub fn get_moon_force_pci_n_y(&self) -> &f64 {&self.moon_force_pci_n_y}
+  pub fn get_moon_force_pci_n_z(&self) -> &f64 {&self.moon_force_pci_n_z}
+  pub fn get_srp_force_pci_n_x(&self) -> &f64 {&self.srp_force_pci_n_x}
+  pub fn get_srp_force_pci_n_y(&self) -> &f64 {&self.srp_force_pci_n_y}
+  pub fn get_srp_force_pci_n_z(&self) -> &f64 {&self.srp_force_pci_n_z}
+  pub fn get_grav_grad_torque_b_nm_x(&self) -> &f64 {&self.grav_grad_torque_b_nm_x}
+  pub fn get_grav_grad_torque_b_nm_y(&self) -> &f64 {&self.grav_grad_torque_b_nm_y}
+  pub fn get_grav_grad_torque_b_nm_z(&self) -> &f64 {&self.grav_grad_torque_b_nm_z}
+  pub fn get_aero_torque_b_nm_x(&self) -> &f64 {&self.aero_torque_b_nm_x}
+  pub fn get_aero_torque_b_nm_y(&self) -> &f64 {&self.aero_torque_b_nm_y}
+  pub fn get_aero_torque_b_nm_z(&self) -> &f64 {&self.aero_torque_b_nm_z}
+  pub fn get_srp_torque_b_nm_x(&self) -> &f64 {&self.srp_torque_b_nm_x}
+  pub fn get_srp_torque_b_nm_y(&self) -> &f64 {&self.srp_torque_b_nm_y}
+  pub fn get_srp_torque_b_nm_z(&self) -> &f64 {&self.srp_torque_b_nm_z}
+  pub fn get_sc_aero_cop_offset_b_m_x(&self) -> &f64 {&self.sc_aero_cop_offset_b_m_x}
+  pub fn get_sc_aero_cop_offset_b_m_y(&self) -> &f64 {&self.sc_aero_cop_offset_b_m_y}
+  pub fn get_sc_aero_cop_offset_b_m_z(&self) -> &f64 {&self.sc_aero_cop_offset_b_m_z}
+  pub fn get_sc_srp_cop_offset_b_m_x(&self) -> &f64 {&self.sc_srp_cop_offset_b_m_x}
+  pub fn get_sc_srp_cop_offset_b_m_y(&self) -> &f64 {&self.sc_srp_cop_offset_b_m_y}
+  pub fn get_sc_srp_cop_offset_b_m_z(&self) -> &f64 {&self.sc_srp_cop_offset_b_m_z}
   pub fn get_sc_altitude_m(&self) -> &f64 {&self.sc_altitude_m}
   pub fn get_sc_aero_eff_area_mm(&self) -> &f64 {&self.sc_aero_eff_area_mm}
   pub fn get_sc_charact_length_m(&self) -> &f64 {&self.sc_charact_length_m}
+  pub fn get_sc_srp_area_mm(&self) -> &f64 {&self.sc_srp_area_mm}
+  pub fn get_sc_reflectivity_coeff(&self) -> &f64 {&self.sc_reflectivity_coeff}
   pub fn get_sc_drag_contin_coefficient(&self) -> &f64 {&self.sc_drag_contin_coefficient}
   pub fn get_sc_mach_number(&self) -> &f64 {&self.sc_mach_number}
+  pub fn get_sc_inertia_tensor_kgmm(&self) -> &Array2<f64> {&self.sc_inertia_tensor_kgmm}
+  pub fn get_sc_inertia_tensor_inv(&self) -> &Array2<f64> {&self.sc_inertia_tensor_inv}
+  pub fn get_reaction_wheel_assembly(&self) -> &ReactionWheelAssembly {&self.reaction_wheel_assembly}
+  pub fn get_magnetorquers(&self) -> &Magnetorquers {&self.magnetorquers}
+  pub fn get_propulsion(&self) -> &Propulsion {&self.propulsion}
 }
 
 /*
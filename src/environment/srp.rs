@@ -0,0 +1 @@
+pub mod srp_model;
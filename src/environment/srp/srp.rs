@@ -1,19 +0,0 @@
-
-
-#[derive(Clone)]
-
-pub struct SRP {
-
-}
-/*
- * ----------------------------------------------------------------------
- *                    [constructor]
- * ----------------------------------------------------------------------
- */
-impl SRP {
-  pub fn new() -> SRP {
-    SRP {
-
-    }
-  }
-}
\ No newline at end of file
@@ -0,0 +1,249 @@
+/*
+ * @brief: Solar radiation pressure (SRP)
+ *
+ * @description: The spacecraft is modelled as a sphere (cannonball model) with 
+ *               a constant SRP area and reflectivity coefficient Cr. The solar 
+ *               flux is scaled with the inverse square of the current 
+ *               spacecraft-Sun distance:
+ *
+ *               F = - nu * P_1AU * (AU / |r_sun - r|)^2 * Cr * A * e_sun
+ *
+ *               with the illumination fraction nu (0 - umbra, 1 - full sun) 
+ *               computed from a conical shadow model of the central body.
+ *
+ *               Ref: Montenbruck, Gill - Satellite Orbits, Section 3.4
+ */
+
+/* Include external crates */
+use ndarray::{Array1, Array2, ArrayView1, s};
+use std::f64::consts::PI;
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::epoch::Epoch;
+use crate::math::vec_math::{l2_norm_array1, cross_product_array1};
+use crate::math::attitude_math::convert_quat_to_dcm_pci_to_b;
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::general::*;
+use crate::constants::celestial::*;
+
+#[derive(Clone)]
+
+pub struct SRP {
+  /* 
+   * @description : Flag, if true solar radiation pressure is modelled
+   * @unit        : N/A
+   * 
+   * */
+  enable_srp_modelling: bool,
+  /* 
+   * @description : Solar flux at a distance of 1 AU from the Sun
+   * @unit        : W/m2
+   * 
+   * */
+  solar_flux_1au_wmm: f64,
+  /* 
+   * @description : Illumination fraction of the solar disc as seen from the 
+   *                spacecraft (0 - umbra, 0 < x < 1 - penumbra, 1 - sunlight)
+   *                Updated with every call of get_force_vec_pci.
+   * @unit        : N/A
+   * 
+   * */
+  illumination_fraction: f64
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl SRP {
+  pub fn new() -> SRP {
+    SRP {
+      enable_srp_modelling: false,
+      solar_flux_1au_wmm: 0.0,
+      illumination_fraction: 1.0
+    }
+  }
+}
+
+impl Default for SRP {
+  fn default() -> SRP {
+    SRP::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl SRP {
+  pub fn set_enable_srp_modelling(&mut self, val_in: &bool) {self.enable_srp_modelling = *val_in;}
+  pub fn set_solar_flux_1au_wmm(&mut self, val_in: &f64) {self.solar_flux_1au_wmm = *val_in;}
+  pub fn set_illumination_fraction(&mut self, val_in: &f64) {self.illumination_fraction = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl SRP {
+  pub fn is_srp_modelled(&self) -> &bool {&self.enable_srp_modelling}
+  pub fn get_solar_flux_1au_wmm(&self) -> &f64 {&self.solar_flux_1au_wmm}
+  pub fn get_illumination_fraction(&self) -> &f64 {&self.illumination_fraction}
+}
+
+/*
+ * @brief: Function to compute the solar radiation pressure force acting on the 
+ *         spacecraft.
+ * 
+ * @unit: Newton
+ * @frame: PCI
+ * 
+ */
+pub fn get_force_vec_pci(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let pos_sc_pci_m: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+  let pos_sun_pci_m: Array1<f64> = environment.get_ephemeris()
+    .calc_sun_pos_pci_m(&Epoch::from_state_vec(state_in.view()));
+
+  /* Position of the Sun relative to the spacecraft */
+  let pos_sun_rel_m: Array1<f64> = &pos_sun_pci_m - &pos_sc_pci_m;
+  let dist_sun_m: f64 = l2_norm_array1(pos_sun_rel_m.view());
+
+  let illumination_fraction: f64 = calc_illumination_fraction(pos_sc_pci_m, 
+                                                              pos_sun_rel_m.view(),
+                                                              *environment.get_planet().get_semi_major_axis());
+
+  /* Solar radiation pressure at the current distance to the Sun 
+   * @unit: N/m2
+   */
+  let srp_pressure_nmm: f64 = environment.get_srp().get_solar_flux_1au_wmm() / SPEED_OF_LIGHT_MS
+                              * (ASTRONOMICAL_UNIT_M / dist_sun_m).powi(2);
+
+  let srp_force_magn_n: f64 = illumination_fraction 
+                              * srp_pressure_nmm
+                              * environment.get_spacecraft().get_sc_reflectivity_coeff()
+                              * environment.get_spacecraft().get_sc_srp_area_mm();
+
+  /* Force is pointing away from the Sun */
+  let srp_force_pci_n: Array1<f64> = -srp_force_magn_n * pos_sun_rel_m / dist_sun_m;
+
+  environment.get_mut_srp().set_illumination_fraction(&illumination_fraction);
+  environment.get_mut_spacecraft().set_srp_force_pci_n_x(&srp_force_pci_n[VEC_X]);
+  environment.get_mut_spacecraft().set_srp_force_pci_n_y(&srp_force_pci_n[VEC_Y]);
+  environment.get_mut_spacecraft().set_srp_force_pci_n_z(&srp_force_pci_n[VEC_Z]);
+
+  srp_force_pci_n
+}
+
+/*
+ * @brief: Function to compute the solar radiation pressure torque acting on 
+ *         the spacecraft from the SRP force applied at the SRP centre of 
+ *         pressure
+ * 
+ *         T = r_cp x F_srp_b
+ * 
+ * Note: Uses the SRP force of the last call of get_force_vec_pci(), which 
+ *       needs to be called with the same state before.
+ * 
+ * @unit: Nm
+ * @frame: Body
+ * 
+ */
+pub fn get_torque_vec_b(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let spacecraft = environment.get_spacecraft();
+  let srp_force_pci_n: Array1<f64> = Array1::from(vec![*spacecraft.get_srp_force_pci_n_x(),
+                                                       *spacecraft.get_srp_force_pci_n_y(),
+                                                       *spacecraft.get_srp_force_pci_n_z()]);
+  let cop_offset_b_m: Array1<f64> = Array1::from(vec![*spacecraft.get_sc_srp_cop_offset_b_m_x(),
+                                                      *spacecraft.get_sc_srp_cop_offset_b_m_y(),
+                                                      *spacecraft.get_sc_srp_cop_offset_b_m_z()]);
+
+  let dcm_pci_to_b: Array2<f64> = convert_quat_to_dcm_pci_to_b(state_in.slice(s![STATE_VEC_INDX_ATTQ_X..(STATE_VEC_INDX_ATTQ_W+1)]));
+  let srp_force_b_n: Array1<f64> = dcm_pci_to_b.dot(&srp_force_pci_n);
+  let srp_torque_b_nm: Array1<f64> = cross_product_array1(cop_offset_b_m.view(), srp_force_b_n.view());
+
+  environment.get_mut_spacecraft().set_srp_torque_b_nm_x(&srp_torque_b_nm[VEC_X]);
+  environment.get_mut_spacecraft().set_srp_torque_b_nm_y(&srp_torque_b_nm[VEC_Y]);
+  environment.get_mut_spacecraft().set_srp_torque_b_nm_z(&srp_torque_b_nm[VEC_Z]);
+
+  srp_torque_b_nm
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ * 
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the visible fraction of the solar disc from a 
+ *         conical shadow model.
+ * 
+ * @description: The apparent radii of the Sun (a) and the central body (b) and 
+ *               their apparent separation (c) as seen from the spacecraft are 
+ *               compared to identify umbra, penumbra and annular eclipse. 
+ *               In penumbra the occulted area of the solar disc is computed 
+ *               from the overlap of two circles. 
+ * 
+ * @param[in] pos_sc_pci_m      - Spacecraft position in PCI
+ * @param[in] pos_sun_rel_pci_m - Sun position relative to the spacecraft in PCI
+ * @param[in] body_radius_m     - Radius of the occulting central body
+ * 
+ * @returns: Illumination fraction (0 - umbra, 1 - full sunlight)
+ * @unit: N/A
+ * 
+ */
+fn calc_illumination_fraction(pos_sc_pci_m: ArrayView1<f64>,
+                              pos_sun_rel_pci_m: ArrayView1<f64>,
+                              body_radius_m: f64)
+-> f64
+{
+  let dist_body_m: f64 = l2_norm_array1(pos_sc_pci_m);
+  let dist_sun_m: f64 = l2_norm_array1(pos_sun_rel_pci_m);
+
+  /* Apparent radius of the Sun */
+  let a: f64 = (SUN_RADIUS_M / dist_sun_m).asin();
+  /* Apparent radius of the central body */
+  let b: f64 = (body_radius_m / dist_body_m).min(1.0).asin();
+  /* Apparent separation of the centres of both bodies */
+  let c: f64 = (-pos_sc_pci_m.dot(&pos_sun_rel_pci_m) / (dist_body_m * dist_sun_m))
+                .clamp(-1.0, 1.0)
+                .acos();
+
+  if c >= a + b
+  {
+    /* [SUNLIGHT] */
+    1.0
+  }
+  else if c <= b - a
+  {
+    /* [UMBRA] */
+    0.0
+  }
+  else if c <= a - b
+  {
+    /* [ANNULAR ECLIPSE] Central body fully inside the solar disc */
+    1.0 - (b * b) / (a * a)
+  }
+  else
+  {
+    /* [PENUMBRA] Occulted area from the overlap of both discs */
+    let x: f64 = (c * c + a * a - b * b) / (2.0 * c);
+    let y: f64 = (a * a - x * x).max(0.0).sqrt();
+    let occulted_area: f64 = a * a * (x / a).clamp(-1.0, 1.0).acos()
+                             + b * b * ((c - x) / b).clamp(-1.0, 1.0).acos()
+                             - c * y;
+
+    1.0 - occulted_area / (PI * a * a)
+  }
+}
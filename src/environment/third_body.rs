@@ -0,0 +1 @@
+pub mod third_body_model;
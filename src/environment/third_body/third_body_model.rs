@@ -0,0 +1,170 @@
+/*
+ * @brief: Third body perturbations of the Sun and the Moon
+ *
+ * @description: The Sun and the Moon are modelled as point masses. Since the 
+ *               PCI frame is centred in the (accelerated) central body, the 
+ *               perturbing acceleration is the difference between the direct 
+ *               attraction of the third body on the spacecraft and the 
+ *               attraction of the third body on the central body (indirect 
+ *               term):
+ *
+ *               a = GM_b * ( (r_b - r) / |r_b - r|^3 - r_b / |r_b|^3 )
+ *
+ *               Sun and Moon positions are taken from the ephemeris model of 
+ *               the environment.
+ *
+ *               Ref: Montenbruck, Gill - Satellite Orbits, Section 3.2
+ */
+
+/* Include external crates */
+use ndarray::{Array1, ArrayView1, s};
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::math::epoch::Epoch;
+use crate::math::vec_math::l2_norm_array1;
+
+/* Include constants */
+use crate::constants::state::*;
+use crate::constants::general::*;
+use crate::constants::celestial::*;
+
+#[derive(Clone)]
+
+pub struct ThirdBody {
+  /* 
+   * @description : Flag, if true the perturbation by the Sun is modelled
+   * @unit        : N/A
+   * 
+   * */
+  enable_sun_perturbation: bool,
+  /* 
+   * @description : Flag, if true the perturbation by the Moon is modelled
+   * @unit        : N/A
+   * 
+   * */
+  enable_moon_perturbation: bool
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl ThirdBody {
+  pub fn new() -> ThirdBody {
+    ThirdBody {
+      enable_sun_perturbation: false,
+      enable_moon_perturbation: false
+    }
+  }
+}
+
+impl Default for ThirdBody {
+  fn default() -> ThirdBody {
+    ThirdBody::new()
+  }
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [setters]
+ * ----------------------------------------------------------------------
+ */
+impl ThirdBody {
+  pub fn set_enable_sun_perturbation(&mut self, val_in: &bool) {self.enable_sun_perturbation = *val_in;}
+  pub fn set_enable_moon_perturbation(&mut self, val_in: &bool) {self.enable_moon_perturbation = *val_in;}
+}
+
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl ThirdBody {
+  pub fn is_sun_perturbation_modelled(&self) -> &bool {&self.enable_sun_perturbation}
+  pub fn is_moon_perturbation_modelled(&self) -> &bool {&self.enable_moon_perturbation}
+  pub fn is_any_perturbation_modelled(&self) -> bool 
+  {
+    self.enable_sun_perturbation || self.enable_moon_perturbation
+  }
+}
+
+/*
+ * @brief: Function to compute the sum of all third body forces acting on the 
+ *         spacecraft.
+ * 
+ * @unit: Newton
+ * @frame: PCI
+ * 
+ */
+pub fn get_force_vec_pci(state_in: &Array1<f64>, environment: &mut Environment)
+-> Array1<f64>
+{
+  let pos_sc_pci_m: ArrayView1<f64> = state_in.slice(s![STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_POS_Z+1)]);
+  let sc_mass_kg: f64 = state_in[STATE_VEC_INDX_MASS];
+  let epoch: Epoch = Epoch::from_state_vec(state_in.view());
+
+  let mut sun_force_pci_n: Array1<f64> = Array1::zeros(3);
+  let mut moon_force_pci_n: Array1<f64> = Array1::zeros(3);
+
+  /* [SUN] */
+  if *environment.get_third_body().is_sun_perturbation_modelled()
+  {
+    let pos_sun_pci_m: Array1<f64> = environment.get_ephemeris().calc_sun_pos_pci_m(&epoch);
+    sun_force_pci_n = sc_mass_kg * get_point_mass_acc_vec_pci(pos_sc_pci_m, 
+                                                              pos_sun_pci_m.view(),
+                                                              SUN_GRAVITATIONAL_CONSTANT);
+  }
+
+  /* [MOON] */
+  if *environment.get_third_body().is_moon_perturbation_modelled()
+  {
+    let pos_moon_pci_m: Array1<f64> = environment.get_ephemeris().calc_moon_pos_pci_m(&epoch);
+    moon_force_pci_n = sc_mass_kg * get_point_mass_acc_vec_pci(pos_sc_pci_m, 
+                                                               pos_moon_pci_m.view(),
+                                                               MOON_GRAVITATIONAL_CONSTANT);
+  }
+
+  environment.get_mut_spacecraft().set_sun_force_pci_n_x(&sun_force_pci_n[VEC_X]);
+  environment.get_mut_spacecraft().set_sun_force_pci_n_y(&sun_force_pci_n[VEC_Y]);
+  environment.get_mut_spacecraft().set_sun_force_pci_n_z(&sun_force_pci_n[VEC_Z]);
+  environment.get_mut_spacecraft().set_moon_force_pci_n_x(&moon_force_pci_n[VEC_X]);
+  environment.get_mut_spacecraft().set_moon_force_pci_n_y(&moon_force_pci_n[VEC_Y]);
+  environment.get_mut_spacecraft().set_moon_force_pci_n_z(&moon_force_pci_n[VEC_Z]);
+
+  sun_force_pci_n + moon_force_pci_n
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ * 
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the perturbing acceleration of a point mass third 
+ *         body including the indirect term.
+ * 
+ * @param[in] pos_sc_pci_m   - Spacecraft position in PCI
+ * @param[in] pos_body_pci_m - Third body position in PCI
+ * @param[in] gm_body        - Gravitational constant of the third body [m3/s2]
+ * 
+ * @returns: Perturbing acceleration vector
+ * @unit: m/ss
+ * @frame: PCI
+ * 
+ */
+fn get_point_mass_acc_vec_pci(pos_sc_pci_m: ArrayView1<f64>, 
+                              pos_body_pci_m: ArrayView1<f64>,
+                              gm_body: f64)
+-> Array1<f64>
+{
+  /* Position of the third body relative to the spacecraft */
+  let pos_rel_m: Array1<f64> = &pos_body_pci_m - &pos_sc_pci_m;
+  let dist_rel_m: f64 = l2_norm_array1(pos_rel_m.view());
+  let dist_body_m: f64 = l2_norm_array1(pos_body_pci_m);
+
+  gm_body * (pos_rel_m / dist_rel_m.powi(3) - &pos_body_pci_m / dist_body_m.powi(3))
+}
@@ -12,14 +12,43 @@ use std::path::Path;
 use std::fs;
 use std::fs::File;
 
+/* Import (local) structs */
+use crate::dke_core::maneuvers::ExecutedManeuver;
+
 /* Include constants */
 use crate::constants::state::*;
 
+/*
+ * @brief: Function to create the output column mask of the full state vector.
+ *         All columns are written by default, switchable column groups are 
+ *         removed with disable_csv_columns().
+ */
+pub fn create_csv_column_mask()
+-> Vec<bool>
+{
+  vec![true; STATE_VEC_NUM_ELEMENTS]
+}
+
+/*
+ * @brief: Function to remove the columns first_indx..=last_indx of the full 
+ *         state vector from the csv output.
+ */
+pub fn disable_csv_columns(column_mask_inout: &mut [bool], first_indx: usize, last_indx: usize)
+{
+  for column in column_mask_inout.iter_mut().take(last_indx + 1).skip(first_indx)
+  {
+    *column = false;
+  }
+}
+
 /*
  * @brief: Function to Create a csv file writer and add the file header 
  *         description.
+ * 
+ * @param[in] column_mask_in - Flags for each element of the full state vector,
+ *                             only elements flagged true are written.
  */
-pub fn create_csv(file_path_in: String) 
+pub fn create_csv(file_path_in: String, column_mask_in: &[bool]) 
 -> csv::Writer<File>
 {
   /* Check if output file already exists -> if so remove it */
@@ -34,7 +63,7 @@ pub fn create_csv(file_path_in: String)
   let mut writer_out = csv::Writer::from_writer(file);
 
   /* Write csv header */
-  write_header_to_csv(&mut writer_out).unwrap();
+  write_header_to_csv(&mut writer_out, column_mask_in).unwrap();
 
   writer_out
 }
@@ -43,7 +72,8 @@ pub fn create_csv(file_path_in: String)
  * @brief: Function to append a row to csv file writer (writer_in)
  */
 pub fn append_to_csv(writer_in: &mut csv::Writer<File>,
-                    data_row_in: &Array1<f64>) 
+                    data_row_in: &Array1<f64>,
+                    column_mask_in: &[bool]) 
 -> Result<(), Box<dyn Error>>
 {
   let mut data_out: Vec<String> = vec!["".to_string()];
@@ -53,7 +83,10 @@ pub fn append_to_csv(writer_in: &mut csv::Writer<File>,
 
   for n in 1..STATE_VEC_NUM_ELEMENTS
   {
-    data_out.push(data_row_in[n].to_string())
+    if column_mask_in[n]
+    {
+      data_out.push(data_row_in[n].to_string())
+    }
   }
 
   writer_in.write_record(data_out)?;
@@ -65,11 +98,10 @@ pub fn append_to_csv(writer_in: &mut csv::Writer<File>,
 /*
  * @brief: Function to write csv header  
  */
-pub fn write_header_to_csv(writer_in: &mut csv::Writer<File>)
+pub fn write_header_to_csv(writer_in: &mut csv::Writer<File>, column_mask_in: &[bool])
 -> Result<(), Box<dyn Error>>
 {
-  /* Write csv header */
-  writer_in.write_record(&["sim_time_s", 
+  let header: [&str; STATE_VEC_NUM_ELEMENTS] = ["sim_time_s", 
                             "pos_x_pci",
                             "pos_y_pci", 
                             "pos_z_pci", 
@@ -104,12 +136,235 @@ pub fn write_header_to_csv(writer_in: &mut csv::Writer<File>)
                             "aero_drag_coeff",
                             "ballistic_coeff_kgmm",
                             "mach_number",
-                            "knudsen_number"
-                            ])?;
+                            "knudsen_number",
+                            "sun_force_pci_n_x",
+                            "sun_force_pci_n_y",
+                            "sun_force_pci_n_z",
+                            "moon_force_pci_n_x",
+                            "moon_force_pci_n_y",
+                            "moon_force_pci_n_z",
+                            "illumination_fraction",
+                            "srp_force_pci_n_x",
+                            "srp_force_pci_n_y",
+                            "srp_force_pci_n_z",
+                            "airspeed_ms",
+                            "orbit_sma_m",
+                            "orbit_ecc",
+                            "orbit_inc_deg",
+                            "orbit_raan_deg",
+                            "orbit_argp_deg",
+                            "orbit_true_anomaly_deg",
+                            "orbit_energy_jkg",
+                            "orbit_ang_mom_x_pci_mms",
+                            "orbit_ang_mom_y_pci_mms",
+                            "orbit_ang_mom_z_pci_mms",
+                            "orbit_ang_mom_magn_mms",
+                            "orbit_periapsis_alt_m",
+                            "orbit_apoapsis_alt_m",
+                            "orbit_period_s",
+                            "pos_x_pcpf",
+                            "pos_y_pcpf",
+                            "pos_z_pcpf",
+                            "vel_x_pcpf",
+                            "vel_y_pcpf",
+                            "vel_z_pcpf",
+                            "epoch_days",
+                            "epoch_seconds",
+                            "sun_dir_x_pci",
+                            "sun_dir_y_pci",
+                            "sun_dir_z_pci",
+                            "moon_dir_x_pci",
+                            "moon_dir_y_pci",
+                            "moon_dir_z_pci",
+                            "grav_grad_torque_sbf_nm_x",
+                            "grav_grad_torque_sbf_nm_y",
+                            "grav_grad_torque_sbf_nm_z",
+                            "aero_torque_sbf_nm_x",
+                            "aero_torque_sbf_nm_y",
+                            "aero_torque_sbf_nm_z",
+                            "srp_torque_sbf_nm_x",
+                            "srp_torque_sbf_nm_y",
+                            "srp_torque_sbf_nm_z",
+                            "att_error_angle_deg",
+                            "cmd_torque_sbf_nm_x",
+                            "cmd_torque_sbf_nm_y",
+                            "cmd_torque_sbf_nm_z",
+                            "rw_speed_rads_1",
+                            "rw_speed_rads_2",
+                            "rw_speed_rads_3",
+                            "rw_speed_rads_4",
+                            "rw_speed_rads_5",
+                            "rw_speed_rads_6",
+                            "rw_torque_nm_1",
+                            "rw_torque_nm_2",
+                            "rw_torque_nm_3",
+                            "rw_torque_nm_4",
+                            "rw_torque_nm_5",
+                            "rw_torque_nm_6",
+                            "rw_ang_mom_sbf_nms_x",
+                            "rw_ang_mom_sbf_nms_y",
+                            "rw_ang_mom_sbf_nms_z",
+                            "dump_torque_sbf_nm_x",
+                            "dump_torque_sbf_nm_y",
+                            "dump_torque_sbf_nm_z",
+                            "sys_ang_mom_pci_nms_x",
+                            "sys_ang_mom_pci_nms_y",
+                            "sys_ang_mom_pci_nms_z",
+                            "mag_field_pcpf_t_x",
+                            "mag_field_pcpf_t_y",
+                            "mag_field_pcpf_t_z",
+                            "mag_field_ned_t_n",
+                            "mag_field_ned_t_e",
+                            "mag_field_ned_t_d",
+                            "mag_field_sbf_t_x",
+                            "mag_field_sbf_t_y",
+                            "mag_field_sbf_t_z",
+                            "mtq_dipole_sbf_amm_x",
+                            "mtq_dipole_sbf_amm_y",
+                            "mtq_dipole_sbf_amm_z",
+                            "mtq_torque_sbf_nm_x",
+                            "mtq_torque_sbf_nm_y",
+                            "mtq_torque_sbf_nm_z",
+                            "magnetometer_sbf_t_x",
+                            "magnetometer_sbf_t_y",
+                            "magnetometer_sbf_t_z",
+                            "ang_rate_magn_degs",
+                            "propellant_mass_kg",
+                            "thrust_delta_v_ms",
+                            "thrust_force_pci_n_x",
+                            "thrust_force_pci_n_y",
+                            "thrust_force_pci_n_z",
+                            "mass_flow_kgps"
+                            ];
+
+  /* Write csv header */
+  writer_in.write_record(header.iter()
+                               .zip(column_mask_in.iter())
+                               .filter(|(_, is_written)| **is_written)
+                               .map(|(name, _)| *name))?;
 
  Ok(())
 }
 
+/*
+ * @brief: Function to create a csv file writer for detected events and add the 
+ *         file header description.
+ */
+pub fn create_event_csv(file_path_in: String) 
+-> csv::Writer<File>
+{
+  /* Check if output file already exists -> if so remove it */
+  delete_file_if_exists(&file_path_in).unwrap();
+
+  let file = OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(file_path_in)
+    .unwrap();
+  let mut writer_out = csv::Writer::from_writer(file);
+
+  /* Write csv header */
+  writer_out.write_record(["event",
+                           "sim_time_s",
+                           "j2000_times_s",
+                           "pos_x_pci",
+                           "pos_y_pci",
+                           "pos_z_pci",
+                           "vel_x_pci",
+                           "vel_y_pci",
+                           "vel_z_pci"]).unwrap();
+
+  writer_out
+}
+
+/*
+ * @brief: Function to append a detected event to the event csv file writer
+ */
+pub fn append_event_to_csv(writer_in: &mut csv::Writer<File>,
+                           event_name_in: &str,
+                           sim_time_s_in: f64,
+                           state_in: &Array1<f64>) 
+-> Result<(), Box<dyn Error>>
+{
+  let mut data_out: Vec<String> = vec![event_name_in.to_string(),
+                                       sim_time_s_in.to_string(),
+                                       state_in[STATE_VEC_INDX_J2000_S].to_string()];
+
+  for n in STATE_VEC_INDX_POS_X..(STATE_VEC_INDX_VEL_Z+1)
+  {
+    data_out.push(state_in[n].to_string())
+  }
+
+  writer_in.write_record(data_out)?;
+
+  Ok(())
+}
+
+/*
+ * @brief: Function to create a csv file writer for executed maneuvers and add 
+ *         the file header description.
+ */
+pub fn create_maneuver_csv(file_path_in: String) 
+-> csv::Writer<File>
+{
+  /* Check if output file already exists -> if so remove it */
+  delete_file_if_exists(&file_path_in).unwrap();
+
+  let file = OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(file_path_in)
+    .unwrap();
+  let mut writer_out = csv::Writer::from_writer(file);
+
+  /* Write csv header */
+  writer_out.write_record(["maneuver",
+                           "sim_time_s",
+                           "j2000_times_s",
+                           "frame",
+                           "dv_1_ms",
+                           "dv_2_ms",
+                           "dv_3_ms",
+                           "dv_x_pci_ms",
+                           "dv_y_pci_ms",
+                           "dv_z_pci_ms",
+                           "dv_magn_ms",
+                           "mass_before_kg",
+                           "mass_after_kg",
+                           "propellant_used_kg"]).unwrap();
+
+  writer_out
+}
+
+/*
+ * @brief: Function to append an executed maneuver to the maneuver csv file 
+ *         writer
+ */
+pub fn append_maneuver_to_csv(writer_in: &mut csv::Writer<File>,
+                              maneuver_num_in: usize,
+                              maneuver_in: &ExecutedManeuver) 
+-> Result<(), Box<dyn Error>>
+{
+  let mut data_out: Vec<String> = vec![maneuver_num_in.to_string(),
+                                       maneuver_in.get_maneuver().get_sim_time_s().to_string(),
+                                       maneuver_in.get_state()[STATE_VEC_INDX_J2000_S].to_string(),
+                                       maneuver_in.get_maneuver().get_frame().get_name().to_string()];
+
+  for dv in maneuver_in.get_maneuver().get_delta_v_ms().iter()
+                                                       .chain(maneuver_in.get_delta_v_pci_ms().iter())
+  {
+    data_out.push(dv.to_string())
+  }
+  data_out.push(maneuver_in.get_delta_v_magn_ms().to_string());
+  data_out.push(maneuver_in.get_mass_before_kg().to_string());
+  data_out.push(maneuver_in.get_mass_after_kg().to_string());
+  data_out.push(maneuver_in.get_propellant_used_kg().to_string());
+
+  writer_in.write_record(data_out)?;
+
+  Ok(())
+}
+
 /*
  * @brief: Function to flush file writer buffer. 
  *         Note: This should be called at SIMULATION_WRITE_FLUSH_INTERVAL_S 
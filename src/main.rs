@@ -9,16 +9,19 @@ pub mod util;
 
 /* Import external crates */
 use ini::Ini;
+use ndarray::Array1;
 
 /* Import local crates */
 use crate::dke_core::state::State;
 use crate::dke_core::dke_core::DKE;
 
 use crate::math::time_math::*;
+use crate::math::orbit_math::{AnomalyType, convert_anomaly_to_true_anomaly, convert_kepler_to_cartesian};
 use chrono::*;
 
 /* Import Constants */
 use crate::constants::filepaths::*;
+use crate::constants::orbit::*;
 
 fn main() {
     /* Create parameter file instance > sim.ini < */
@@ -32,31 +35,107 @@ fn main() {
         .get("t_start_s").unwrap())
         .parse::<f64>().unwrap()));
 
-    start_state.set_pos_x(&((sim_conf
+    /* Initial position and velocity either as PCI Cartesian state vector or
+     * as classical Keplerian elements */
+    let start_state_format: &str = sim_conf
         .section(Some("start_state")).unwrap()
-        .get("pos_pci_x_m").unwrap())
-        .parse::<f64>().unwrap()));
-    start_state.set_pos_y(&((sim_conf
-        .section(Some("start_state")).unwrap()
-        .get("pos_pci_y_m").unwrap())
-        .parse::<f64>().unwrap()));
-    start_state.set_pos_z(&((sim_conf
-        .section(Some("start_state")).unwrap()
-        .get("pos_pci_z_m").unwrap())
-        .parse::<f64>().unwrap()));
+        .get("start_state_format").unwrap()
+        .trim().trim_matches('"');
 
-    start_state.set_vel_x(&((sim_conf
-        .section(Some("start_state")).unwrap()
-        .get("vel_pci_x_ms").unwrap())
-        .parse::<f64>().unwrap()));
-    start_state.set_vel_y(&((sim_conf
-        .section(Some("start_state")).unwrap()
-        .get("vel_pci_y_ms").unwrap())
-        .parse::<f64>().unwrap()));
-    start_state.set_vel_z(&((sim_conf
-        .section(Some("start_state")).unwrap()
-        .get("vel_pci_z_ms").unwrap())
-        .parse::<f64>().unwrap()));
+    match start_state_format
+    {
+        "cartesian" =>
+        {
+            start_state.set_pos_x(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("pos_pci_x_m").unwrap())
+                .parse::<f64>().unwrap()));
+            start_state.set_pos_y(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("pos_pci_y_m").unwrap())
+                .parse::<f64>().unwrap()));
+            start_state.set_pos_z(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("pos_pci_z_m").unwrap())
+                .parse::<f64>().unwrap()));
+
+            start_state.set_vel_x(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("vel_pci_x_ms").unwrap())
+                .parse::<f64>().unwrap()));
+            start_state.set_vel_y(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("vel_pci_y_ms").unwrap())
+                .parse::<f64>().unwrap()));
+            start_state.set_vel_z(&((sim_conf
+                .section(Some("start_state")).unwrap()
+                .get("vel_pci_z_ms").unwrap())
+                .parse::<f64>().unwrap()));
+        }
+        "keplerian" =>
+        {
+            /* Gravitational constant of the central body > planet.ini < */
+            let planet_conf = Ini::load_from_file(PLANET_PARAMETER_FILE_PATH).unwrap();
+            let gravitational_constant: f64 = planet_conf
+                .section(Some("general")).unwrap()
+                .get("planet_gravitational_constant").unwrap()
+                .parse::<f64>().unwrap();
+
+            let kepler_conf = sim_conf.section(Some("start_state")).unwrap();
+            let read_kepler_param = |key: &str| -> f64 {
+                kepler_conf.get(key).unwrap().parse::<f64>().unwrap()
+            };
+
+            let mut elements: Array1<f64> = Array1::zeros(KEPLER_NUM_ELEMENTS);
+            elements[KEPLER_INDX_SMA] = read_kepler_param("kepler_sma_m");
+            elements[KEPLER_INDX_ECC] = read_kepler_param("kepler_ecc");
+            elements[KEPLER_INDX_INC] = read_kepler_param("kepler_inc_deg").to_radians();
+            elements[KEPLER_INDX_RAAN] = read_kepler_param("kepler_raan_deg").to_radians();
+            elements[KEPLER_INDX_ARGP] = read_kepler_param("kepler_argp_deg").to_radians();
+
+            let anomaly_type: AnomalyType = AnomalyType::from_param_str(
+                kepler_conf.get("kepler_anomaly_type").unwrap());
+            elements[KEPLER_INDX_TRUE_ANOMALY] = convert_anomaly_to_true_anomaly(
+                read_kepler_param("kepler_anomaly_deg").to_radians(),
+                anomaly_type,
+                elements[KEPLER_INDX_ECC]);
+
+            let (pos_pci_m, vel_pci_ms) = convert_kepler_to_cartesian(elements.view(),
+                                                                      gravitational_constant);
+            start_state.set_pos_x(&pos_pci_m[0]);
+            start_state.set_pos_y(&pos_pci_m[1]);
+            start_state.set_pos_z(&pos_pci_m[2]);
+
+            start_state.set_vel_x(&vel_pci_ms[0]);
+            start_state.set_vel_y(&vel_pci_ms[1]);
+            start_state.set_vel_z(&vel_pci_ms[2]);
+        }
+        _ => panic!("! [ERROR] ! > Unknown start_state_format {} in sim.ini! <", start_state_format)
+    }
+
+    /* Initial attitude quaternion (PCI to body) and angular rate (body) */
+    let attitude_conf = sim_conf.section(Some("start_state")).unwrap();
+    let read_attitude_param = |key: &str| -> f64 {
+        attitude_conf.get(key).unwrap().parse::<f64>().unwrap()
+    };
+    let quat_pci_to_b: [f64; 4] = [read_attitude_param("quat_pci_to_b_x"),
+                                   read_attitude_param("quat_pci_to_b_y"),
+                                   read_attitude_param("quat_pci_to_b_z"),
+                                   read_attitude_param("quat_pci_to_b_w")];
+    let quat_norm: f64 = quat_pci_to_b.iter().map(|q: &f64| q * q).sum::<f64>().sqrt();
+    if quat_norm == 0.0 || !quat_norm.is_finite()
+    {
+        panic!("! [ERROR] ! > quat_pci_to_b in sim.ini must have a finite, non-zero norm (norm: {})! <",
+               quat_norm);
+    }
+    start_state.set_att(&quat_pci_to_b[0],
+                        &quat_pci_to_b[1],
+                        &quat_pci_to_b[2],
+                        &quat_pci_to_b[3]);
+
+    start_state.set_ang_rate_x(&read_attitude_param("ang_rate_b_x_rads"));
+    start_state.set_ang_rate_y(&read_attitude_param("ang_rate_b_y_rads"));
+    start_state.set_ang_rate_z(&read_attitude_param("ang_rate_b_z_rads"));
 
     start_state.set_mass_kg(&((sim_conf
         .section(Some("start_state")).unwrap()
@@ -14,4 +14,18 @@ pub mod time_math;
 
 pub mod rotation_math;
 
-pub mod lin_math;
\ No newline at end of file
+pub mod lin_math;
+
+pub mod ephemeris_math;
+
+pub mod root_math;
+
+pub mod orbit_math;
+
+pub mod earth_orientation_math;
+
+pub mod time_scale_math;
+
+pub mod epoch;
+
+pub mod attitude_math;
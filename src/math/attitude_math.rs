@@ -0,0 +1,273 @@
+/*
+ * @brief: This file contains the attitude kinematics and rigid body dynamics
+ *
+ * @description: The attitude is described by the quaternion q = [x, y, z, w]
+ *               (scalar part last) from the inertial (PCI) frame to the body
+ *               frame, following the Hamilton convention:
+ *
+ *               v_pci = q * v_b * q^-1 , v_b = q^-1 * v_pci * q
+ *
+ *               The angular rate w_b is the rate of the body frame with
+ *               respect to PCI expressed in the body frame.
+ *
+ *               Ref: Markley, Crassidis - Fundamentals of Spacecraft Attitude
+ *                    Determination and Control, Chapters 2.9 and 3.3
+ *
+ */
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+
+use crate::math::vec_math::{cross_product_array1, normalize_array1, l2_norm_array1};
+
+/* Minimum sine of the angle between the two vectors of a TRIAD basis, below
+ * this value the vectors are treated as parallel */
+const TRIAD_MIN_SIN_ANGLE: f64 = 1.0e-3;
+
+/*
+ * @brief: Function to compute the time derivative of the attitude quaternion
+ *         (quaternion kinematics) q_dot = 0.5 * q * [w_b, 0]
+ *
+ * @param[in] quat_in       - Attitude quaternion PCI to body [x, y, z, w]
+ * @param[in] ang_rate_b_in - Angular rate of the body [rad/s]
+ *
+ * @returns: Quaternion derivative [x, y, z, w] [1/s]
+ */
+pub fn calc_quat_derivative(quat_in: ArrayView1<f64>, ang_rate_b_in: ArrayView1<f64>)
+-> Array1<f64>
+{
+  let (qx, qy, qz, qw): (f64, f64, f64, f64) = (quat_in[0], quat_in[1], quat_in[2], quat_in[3]);
+  let (wx, wy, wz): (f64, f64, f64) = (ang_rate_b_in[0], ang_rate_b_in[1], ang_rate_b_in[2]);
+
+  Array1::from(vec![0.5 * ( qw * wx + qy * wz - qz * wy),
+                    0.5 * ( qw * wy + qz * wx - qx * wz),
+                    0.5 * ( qw * wz + qx * wy - qy * wx),
+                    -0.5 * (qx * wx + qy * wy + qz * wz)])
+}
+
+/*
+ * @brief: Function to compute the angular acceleration of a rigid body from
+ *         Euler's equations I * w_dot = T - w x (I * w)
+ *
+ * @param[in] inertia_in     - Inertia tensor about the centre of mass [kg m2]
+ * @param[in] inertia_inv_in - Inverse of the inertia tensor [1/(kg m2)]
+ * @param[in] ang_rate_b_in  - Angular rate of the body [rad/s]
+ * @param[in] torque_b_in    - Sum of external torques [Nm]
+ *
+ * @returns: Angular acceleration [rad/s2]
+ * @frame: Body
+ */
+pub fn calc_ang_acc_b(inertia_in: ArrayView2<f64>,
+                      inertia_inv_in: ArrayView2<f64>,
+                      ang_rate_b_in: ArrayView1<f64>,
+                      torque_b_in: ArrayView1<f64>)
+-> Array1<f64>
+{
+  let ang_mom_b: Array1<f64> = inertia_in.dot(&ang_rate_b_in);
+  let gyroscopic_torque_b: Array1<f64> = cross_product_array1(ang_rate_b_in, ang_mom_b.view());
+
+  inertia_inv_in.dot(&(&torque_b_in - &gyroscopic_torque_b))
+}
+
+/*
+ * @brief: Function to compute the direction cosine matrix from the attitude
+ *         quaternion. The matrix transforms vectors from PCI to body
+ *         coordinates, v_b = C * v_pci.
+ *
+ * @param[in] quat_in - Attitude quaternion PCI to body [x, y, z, w]
+ *
+ * @returns: Direction cosine matrix PCI to body
+ */
+pub fn convert_quat_to_dcm_pci_to_b(quat_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let (qx, qy, qz, qw): (f64, f64, f64, f64) = (quat_in[0], quat_in[1], quat_in[2], quat_in[3]);
+
+  Array2::from_shape_vec((3, 3), vec![
+    1.0 - 2.0 * (qy * qy + qz * qz), 2.0 * (qx * qy + qz * qw),       2.0 * (qx * qz - qy * qw),
+    2.0 * (qx * qy - qz * qw),       1.0 - 2.0 * (qx * qx + qz * qz), 2.0 * (qy * qz + qx * qw),
+    2.0 * (qx * qz + qy * qw),       2.0 * (qy * qz - qx * qw),       1.0 - 2.0 * (qx * qx + qy * qy)
+  ]).unwrap()
+}
+
+/*
+ * @brief: Function to compute the inverse of a 3x3 matrix from its adjugate
+ *
+ * @returns: Inverse matrix, None if the matrix is singular
+ */
+pub fn calc_inverse_mat33(mat_in: ArrayView2<f64>)
+-> Option<Array2<f64>>
+{
+  let m = |row: usize, col: usize| -> f64 {mat_in[[row, col]]};
+
+  let cofactor_00: f64 = m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1);
+  let cofactor_01: f64 = m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2);
+  let cofactor_02: f64 = m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0);
+
+  let determinant: f64 = m(0, 0) * cofactor_00 + m(0, 1) * cofactor_01 + m(0, 2) * cofactor_02;
+  if determinant == 0.0 || !determinant.is_finite()
+  {
+    return None;
+  }
+
+  let adjugate: Array2<f64> = Array2::from_shape_vec((3, 3), vec![
+    cofactor_00, m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2), m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1),
+    cofactor_01, m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0), m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2),
+    cofactor_02, m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1), m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0)
+  ]).unwrap();
+
+  Some(adjugate / determinant)
+}
+
+/*
+ * @brief: Function to compute the attitude quaternion from the direction 
+ *         cosine matrix PCI to body (Shepperd's method)
+ *
+ * @param[in] dcm_pci_to_b_in - Direction cosine matrix PCI to body
+ *
+ * @returns: Attitude quaternion PCI to body [x, y, z, w] with w >= 0
+ */
+pub fn convert_dcm_to_quat_pci_to_b(dcm_pci_to_b_in: ArrayView2<f64>)
+-> Array1<f64>
+{
+  /* Rotation matrix of the quaternion, v_pci = R * v_b */
+  let r = |row: usize, col: usize| -> f64 {dcm_pci_to_b_in[[col, row]]};
+  let trace: f64 = r(0, 0) + r(1, 1) + r(2, 2);
+
+  let quat: [f64; 4] = if trace > 0.0
+  {
+    let s: f64 = 2.0 * (1.0 + trace).sqrt();
+    [(r(2, 1) - r(1, 2)) / s, (r(0, 2) - r(2, 0)) / s, (r(1, 0) - r(0, 1)) / s, 0.25 * s]
+  }
+  else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2)
+  {
+    let s: f64 = 2.0 * (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt();
+    [0.25 * s, (r(0, 1) + r(1, 0)) / s, (r(0, 2) + r(2, 0)) / s, (r(2, 1) - r(1, 2)) / s]
+  }
+  else if r(1, 1) > r(2, 2)
+  {
+    let s: f64 = 2.0 * (1.0 + r(1, 1) - r(0, 0) - r(2, 2)).sqrt();
+    [(r(0, 1) + r(1, 0)) / s, 0.25 * s, (r(1, 2) + r(2, 1)) / s, (r(0, 2) - r(2, 0)) / s]
+  }
+  else
+  {
+    let s: f64 = 2.0 * (1.0 + r(2, 2) - r(0, 0) - r(1, 1)).sqrt();
+    [(r(0, 2) + r(2, 0)) / s, (r(1, 2) + r(2, 1)) / s, 0.25 * s, (r(1, 0) - r(0, 1)) / s]
+  };
+
+  let sign: f64 = if quat[3] < 0.0 {-1.0} else {1.0};
+  Array1::from_iter(quat.iter().map(|q| sign * q))
+}
+
+/*
+ * @brief: Function to compute the Hamilton product of two quaternions p * q
+ *
+ * @returns: Quaternion product [x, y, z, w]
+ */
+pub fn calc_quat_product(quat_p_in: ArrayView1<f64>, quat_q_in: ArrayView1<f64>)
+-> Array1<f64>
+{
+  let (px, py, pz, pw): (f64, f64, f64, f64) = (quat_p_in[0], quat_p_in[1], quat_p_in[2], quat_p_in[3]);
+  let (qx, qy, qz, qw): (f64, f64, f64, f64) = (quat_q_in[0], quat_q_in[1], quat_q_in[2], quat_q_in[3]);
+
+  Array1::from(vec![pw * qx + qw * px + py * qz - pz * qy,
+                    pw * qy + qw * py + pz * qx - px * qz,
+                    pw * qz + qw * pz + px * qy - py * qx,
+                    pw * qw - px * qx - py * qy - pz * qz])
+}
+
+/*
+ * @brief: Function to compute the attitude error quaternion, i.e. the rotation
+ *         from the reference attitude to the current attitude 
+ *         q_err = q_ref^-1 * q
+ *
+ * @param[in] quat_in     - Current attitude quaternion PCI to body
+ * @param[in] quat_ref_in - Reference attitude quaternion PCI to body
+ *
+ * @returns: Error quaternion [x, y, z, w], the vector part is given in the 
+ *           body frame
+ */
+pub fn calc_quat_error(quat_in: ArrayView1<f64>, quat_ref_in: ArrayView1<f64>)
+-> Array1<f64>
+{
+  let quat_ref_conj: Array1<f64> = Array1::from(vec![-quat_ref_in[0], 
+                                                     -quat_ref_in[1], 
+                                                     -quat_ref_in[2], 
+                                                     quat_ref_in[3]]);
+  calc_quat_product(quat_ref_conj.view(), quat_in)
+}
+
+/*
+ * @brief: Function to compute the direction cosine matrix PCI to body that 
+ *         aligns two body axes with two directions (TRIAD). The primary body
+ *         axis is aligned exactly with the primary direction, the secondary 
+ *         body axis as close as possible with the secondary direction.
+ *
+ * @param[in] primary_axis_b_in     - Primary body axis
+ * @param[in] primary_dir_pci_in    - Primary direction
+ * @param[in] secondary_axis_b_in   - Secondary body axis (not parallel to the
+ *                                    primary axis)
+ * @param[in] secondary_dir_pci_in  - Secondary direction
+ *
+ * Note: If the secondary direction is (nearly) parallel to the primary 
+ *       direction, the rotation about the primary axis is undefined. The 
+ *       secondary direction is then replaced by the PCI axis least aligned 
+ *       with the primary direction, see is_triad_degenerate().
+ *
+ * @returns: Direction cosine matrix PCI to body
+ */
+pub fn calc_triad_dcm_pci_to_b(primary_axis_b_in: ArrayView1<f64>,
+                               primary_dir_pci_in: ArrayView1<f64>,
+                               secondary_axis_b_in: ArrayView1<f64>,
+                               secondary_dir_pci_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let basis_b: Array2<f64> = calc_triad_basis(primary_axis_b_in, secondary_axis_b_in);
+  let basis_pci: Array2<f64> = calc_triad_basis(primary_dir_pci_in, secondary_dir_pci_in);
+
+  basis_b.dot(&basis_pci.t())
+}
+
+/*
+ * @brief: Function to check if two vectors are (nearly) parallel, so that
+ *         they do not define a TRIAD basis
+ *
+ * @returns: true if the sine of the angle between the vectors is below 
+ *           TRIAD_MIN_SIN_ANGLE or one of the vectors is zero
+ */
+pub fn is_triad_degenerate(vec_a_in: ArrayView1<f64>, vec_b_in: ArrayView1<f64>)
+-> bool
+{
+  let cross_norm: f64 = l2_norm_array1(cross_product_array1(vec_a_in, vec_b_in).view());
+  cross_norm <= TRIAD_MIN_SIN_ANGLE * l2_norm_array1(vec_a_in) * l2_norm_array1(vec_b_in)
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the orthonormal basis [a, a x b, a x (a x b)] 
+ *         (normalized) from two vectors as columns of a matrix. If a and b are
+ *         parallel, b is replaced by the coordinate axis least aligned with a.
+ */
+fn calc_triad_basis(vec_a_in: ArrayView1<f64>, vec_b_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let axis_1: Array1<f64> = normalize_array1(vec_a_in.to_owned());
+  let mut vec_b: Array1<f64> = vec_b_in.to_owned();
+  if is_triad_degenerate(axis_1.view(), vec_b_in)
+  {
+    let indx_min: usize = (0..3).fold(0, |indx, n| if axis_1[n].abs() < axis_1[indx].abs() {n} else {indx});
+    vec_b = Array1::zeros(3);
+    vec_b[indx_min] = 1.0;
+  }
+  let axis_2: Array1<f64> = normalize_array1(cross_product_array1(axis_1.view(), vec_b.view()));
+  let axis_3: Array1<f64> = cross_product_array1(axis_1.view(), axis_2.view());
+
+  let mut basis: Array2<f64> = Array2::zeros((3, 3));
+  basis.column_mut(0).assign(&axis_1);
+  basis.column_mut(1).assign(&axis_2);
+  basis.column_mut(2).assign(&axis_3);
+  basis
+}
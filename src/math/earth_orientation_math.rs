@@ -0,0 +1,291 @@
+/*
+ * @brief: This file contains the building blocks of the IAU 2006/2000A
+ *         transformation between the celestial (GCRS) and the terrestrial
+ *         (ITRS) reference system following the CIO based procedure of the
+ *         IERS Conventions (2010), Chapter 5:
+ *
+ *         [ITRS] = W(t) * R3(ERA) * Q(t) * [GCRS]
+ *
+ *         * Q(t)   - Precession-nutation (celestial motion of the CIP, given
+ *                    by the CIP coordinates X, Y and the CIO locator s)
+ *         * R3(ERA)- Earth rotation angle about the CIP
+ *         * W(t)   - Polar motion (xp, yp and the TIO locator s')
+ *
+ *         All rotation matrices are frame rotations:
+ *         R1(a) = [[1, 0, 0], [0, cos a, sin a], [0, -sin a, cos a]] (x-axis)
+ *
+ */
+
+/* Include external crates */
+use ndarray::{arr2, Array1, Array2};
+
+/* Import constants */
+use crate::constants::earth_orientation::*;
+
+use std::f64::consts::PI;
+
+/*
+ * @brief: Function to evaluate a polynomial with the coefficients of t^0 ..
+ *         t^n (Horner scheme)
+ */
+pub fn eval_polynomial(coefficients: &[f64], t: f64)
+-> f64
+{
+  coefficients.iter().rev().fold(0.0, |sum, coefficient| sum * t + coefficient)
+}
+
+/*
+ * @brief: Function to compute the fundamental arguments of the nutation
+ *         theory (IERS Conventions 2003/2010)
+ *
+ * @param[in] t - Julian centuries TT since J2000.0
+ *
+ * @returns: [l, l', F, D, Om, L_Me, L_Ve, L_E, L_Ma, L_J, L_Sa, L_U, L_Ne, p_A]
+ * @unit: rad
+ */
+pub fn calc_fundamental_arguments(t: f64)
+-> [f64; NUM_FUNDAMENTAL_ARGUMENTS]
+{
+  let mut arguments_out: [f64; NUM_FUNDAMENTAL_ARGUMENTS] = [0.0; NUM_FUNDAMENTAL_ARGUMENTS];
+
+  /* Delaunay arguments, wrapped to one revolution (1296000 arcsec) */
+  for (argument, coefficients) in arguments_out.iter_mut().zip(DELAUNAY_ARGUMENTS_ARCSEC.iter())
+  {
+    *argument = eval_polynomial(coefficients, t).rem_euclid(1296000.0) * ARCSEC_TO_RAD;
+  }
+  /* Mean longitudes of the planets */
+  for (argument, coefficients) in arguments_out.iter_mut().skip(5).zip(PLANETARY_ARGUMENTS_RAD.iter())
+  {
+    *argument = eval_polynomial(coefficients, t).rem_euclid(2.0 * PI);
+  }
+  /* General accumulated precession in longitude */
+  arguments_out[NUM_FUNDAMENTAL_ARGUMENTS - 1] = (GENERAL_PRECESSION_RAD[0]
+                                                  + GENERAL_PRECESSION_RAD[1] * t) * t;
+  arguments_out
+}
+
+/*
+ * @brief: Function to compute the nutation in longitude and obliquity with
+ *         the IAU 2000B model (77 luni-solar terms and fixed planetary
+ *         offsets, accuracy ~1 mas)
+ *
+ * @param[in] t                    - Julian centuries TT since J2000.0
+ * @param[in] fundamental_arguments - See calc_fundamental_arguments()
+ *
+ * @returns: (nutation in longitude dpsi, nutation in obliquity deps)
+ * @unit: rad
+ */
+pub fn calc_nutation_iau2000b(t: f64, fundamental_arguments: &[f64; NUM_FUNDAMENTAL_ARGUMENTS])
+-> (f64, f64)
+{
+  let mut dpsi: f64 = 0.0;
+  let mut deps: f64 = 0.0;
+
+  /* Sum from the smallest terms to preserve precision */
+  for term in NUTATION_IAU2000B_TERMS.iter().rev()
+  {
+    let argument: f64 = term[0] * fundamental_arguments[0]
+                      + term[1] * fundamental_arguments[1]
+                      + term[2] * fundamental_arguments[2]
+                      + term[3] * fundamental_arguments[3]
+                      + term[4] * fundamental_arguments[4];
+    let (sin_arg, cos_arg): (f64, f64) = argument.sin_cos();
+    dpsi += (term[5] + term[6] * t) * sin_arg + term[7] * cos_arg;
+    deps += (term[8] + term[9] * t) * cos_arg + term[10] * sin_arg;
+  }
+
+  /* Coefficients are given in 0.1 microarcseconds */
+  (dpsi * 0.1 * MICROARCSEC_TO_RAD + NUTATION_IAU2000B_PLANETARY_PSI_MAS * MILLIARCSEC_TO_RAD,
+   deps * 0.1 * MICROARCSEC_TO_RAD + NUTATION_IAU2000B_PLANETARY_EPS_MAS * MILLIARCSEC_TO_RAD)
+}
+
+/*
+ * @brief: Function to compute the CIP coordinates X, Y from the IAU 2006
+ *         precession (Fukushima-Williams angles) and a given nutation
+ *
+ * @description: X and Y are the first two components of the third row of the
+ *               precession-nutation-bias matrix
+ *               R1(-(eps_A + deps)) R3(-(psi_bar + dpsi)) R1(phi_bar) R3(gamma_bar)
+ *
+ *               Ref: IERS Conventions (2010), Eq. (5.38)
+ *
+ * @param[in] t    - Julian centuries TT since J2000.0
+ * @param[in] dpsi - Nutation in longitude [rad]
+ * @param[in] deps - Nutation in obliquity [rad]
+ *
+ * @returns: (X, Y)
+ * @unit: rad
+ */
+pub fn calc_cip_xy_from_nutation(t: f64, dpsi: f64, deps: f64)
+-> (f64, f64)
+{
+  let gamma_bar: f64 = eval_polynomial(&PRECESSION_FW_GAMMA_ARCSEC, t) * ARCSEC_TO_RAD;
+  let phi_bar: f64 = eval_polynomial(&PRECESSION_FW_PHI_ARCSEC, t) * ARCSEC_TO_RAD;
+  let psi_bar: f64 = eval_polynomial(&PRECESSION_FW_PSI_ARCSEC, t) * ARCSEC_TO_RAD;
+  let eps_a: f64 = eval_polynomial(&MEAN_OBLIQUITY_ARCSEC, t) * ARCSEC_TO_RAD;
+
+  let dcm_npb: Array2<f64> = calc_frame_rot_x(-(eps_a + deps))
+                               .dot(&calc_frame_rot_z(-(psi_bar + dpsi)))
+                               .dot(&calc_frame_rot_x(phi_bar))
+                               .dot(&calc_frame_rot_z(gamma_bar));
+  (dcm_npb[[2, 0]], dcm_npb[[2, 1]])
+}
+
+/*
+ * @brief: Function to compute the pole of the ecliptic of date from the IAU
+ *         2006 precession (Fukushima-Williams angles)
+ *
+ * @description: The ecliptic pole is the z-axis of the frame
+ *               R1(phi_bar) R3(gamma_bar), the true equinox lies along the
+ *               cross product of the CIP and the ecliptic pole.
+ *
+ * @param[in] t - Julian centuries TT since J2000.0
+ *
+ * @returns: Unit vector of the ecliptic pole
+ * @unit: N/A
+ * @frame: GCRS
+ */
+pub fn calc_ecliptic_pole(t: f64)
+-> Array1<f64>
+{
+  let gamma_bar: f64 = eval_polynomial(&PRECESSION_FW_GAMMA_ARCSEC, t) * ARCSEC_TO_RAD;
+  let phi_bar: f64 = eval_polynomial(&PRECESSION_FW_PHI_ARCSEC, t) * ARCSEC_TO_RAD;
+
+  calc_frame_rot_x(phi_bar).dot(&calc_frame_rot_z(gamma_bar)).row(2).to_owned()
+}
+
+/*
+ * @brief: Function to compute the largest periodic terms of the CIO locator
+ *         series s + XY/2 (used if the full series is not loaded)
+ *
+ * @unit: microarcseconds
+ */
+pub fn calc_cio_locator_leading_terms_uas(fundamental_arguments: &[f64; NUM_FUNDAMENTAL_ARGUMENTS])
+-> f64
+{
+  CIO_S_LEADING_TERMS.iter()
+                     .map(|term| term[5] * (term[0] * fundamental_arguments[0]
+                                          + term[1] * fundamental_arguments[1]
+                                          + term[2] * fundamental_arguments[2]
+                                          + term[3] * fundamental_arguments[3]
+                                          + term[4] * fundamental_arguments[4]).sin())
+                     .sum()
+}
+
+/*
+ * @brief: Function to compute the TIO locator s'
+ *
+ * @param[in] t - Julian centuries TT since J2000.0
+ *
+ * @unit: rad
+ */
+pub fn calc_tio_locator(t: f64)
+-> f64
+{
+  TIO_LOCATOR_RATE_UAS * t * MICROARCSEC_TO_RAD
+}
+
+/*
+ * @brief: Function to compute the Earth rotation angle (ERA)
+ *
+ * @param[in] days_since_j2000 - Days UT1 since J2000.0 (JD 2451545.0 UT1). 
+ *                              Passed relative to J2000 instead of as Julian 
+ *                              date to retain sub-millisecond resolution.
+ * @param[in] day_fraction     - Additional fraction of a day UT1, the epoch is
+ *                              the sum of both arguments (two-part epoch)
+ *
+ * @returns: ERA in [0, 2pi)
+ * @unit: rad
+ */
+pub fn calc_earth_rotation_angle(days_since_j2000: f64, day_fraction: f64)
+-> f64
+{
+  /* The integer number of days is removed first to retain precision */
+  let era_rev: f64 = days_since_j2000.fract() + day_fraction.fract() + ERA_AT_J2000_REV
+                     + (ERA_RATE_REV_PER_DAY - 1.0) * (days_since_j2000 + day_fraction);
+  (2.0 * PI * era_rev).rem_euclid(2.0 * PI)
+}
+
+/*
+ * @brief: Function to compute the rotation matrix from GCRS to CIRS
+ *         (celestial intermediate reference system) from the CIP coordinates
+ *         and the CIO locator
+ *
+ * @description: Q^T = R3(-(E + s)) R2(d) R3(E) with X = sin d cos E,
+ *               Y = sin d sin E
+ *
+ *               Ref: IERS Conventions (2010), Eq. (5.6) and (5.10)
+ *
+ * @unit: N/A
+ */
+pub fn calc_dcm_gcrs_to_cirs(cip_x: f64, cip_y: f64, cio_s: f64)
+-> Array2<f64>
+{
+  let r_sq: f64 = cip_x * cip_x + cip_y * cip_y;
+  let e: f64 = if r_sq > 0.0 {cip_y.atan2(cip_x)} else {0.0};
+  let d: f64 = (r_sq / (1.0 - r_sq)).sqrt().atan();
+
+  calc_frame_rot_z(-(e + cio_s)).dot(&calc_frame_rot_y(d)).dot(&calc_frame_rot_z(e))
+}
+
+/*
+ * @brief: Function to compute the rotation matrix from TIRS (terrestrial
+ *         intermediate reference system) to ITRS (polar motion)
+ *
+ * @description: W^T = R1(-yp) R2(-xp) R3(s')
+ *
+ *               Ref: IERS Conventions (2010), Eq. (5.3)
+ *
+ * @param[in] xp_rad, yp_rad - Polar motion coordinates
+ * @param[in] s_prime_rad    - TIO locator
+ *
+ * @unit: N/A
+ */
+pub fn calc_dcm_tirs_to_itrs(xp_rad: f64, yp_rad: f64, s_prime_rad: f64)
+-> Array2<f64>
+{
+  calc_frame_rot_x(-yp_rad).dot(&calc_frame_rot_y(-xp_rad)).dot(&calc_frame_rot_z(s_prime_rad))
+}
+
+/*
+ * @brief: Function to compute the frame rotation matrix about the z-axis
+ *         (R3)
+ */
+pub fn calc_frame_rot_z(angle_rad: f64)
+-> Array2<f64>
+{
+  let (sin_a, cos_a): (f64, f64) = angle_rad.sin_cos();
+  arr2(&[[ cos_a, sin_a, 0.0],
+         [-sin_a, cos_a, 0.0],
+         [   0.0,   0.0, 1.0]])
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to compute the frame rotation matrix about the x-axis (R1)
+ */
+fn calc_frame_rot_x(angle_rad: f64)
+-> Array2<f64>
+{
+  let (sin_a, cos_a): (f64, f64) = angle_rad.sin_cos();
+  arr2(&[[1.0,    0.0,   0.0],
+         [0.0,  cos_a, sin_a],
+         [0.0, -sin_a, cos_a]])
+}
+
+/*
+ * @brief: Function to compute the frame rotation matrix about the y-axis (R2)
+ */
+fn calc_frame_rot_y(angle_rad: f64)
+-> Array2<f64>
+{
+  let (sin_a, cos_a): (f64, f64) = angle_rad.sin_cos();
+  arr2(&[[cos_a, 0.0, -sin_a],
+         [  0.0, 1.0,    0.0],
+         [sin_a, 0.0,  cos_a]])
+}
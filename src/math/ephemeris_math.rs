@@ -0,0 +1,301 @@
+use ndarray::Array1;
+
+use crate::math::frame_math::convert_ecliptic_to_equatorial;
+use crate::math::earth_orientation_math::{calc_cip_xy_from_nutation, calc_ecliptic_pole, eval_polynomial};
+use crate::math::orbit_math::{AnomalyType, convert_anomaly_to_true_anomaly, convert_kepler_to_cartesian};
+use crate::math::vec_math::{cross_product_array1, normalize_array1};
+
+use crate::constants::celestial::*;
+use crate::constants::ephemeris::*;
+use crate::constants::orbit::*;
+use crate::constants::time::*;
+
+/*
+ * @brief: Calculate the position of the Sun with respect to the Earth from a
+ *         low precision analytic series.
+ *
+ * @description: The series assumes an unperturbed elliptic motion of the
+ *               Earth around the Sun and is accurate to about 0.1-1% in
+ *               distance and about 1 arcmin in direction.
+ *
+ *               The position is referred to the mean equator and equinox
+ *               of J2000 (EME2000) which is used as PCI frame here.
+ *
+ *               Ref: Montenbruck, Gill - Satellite Orbits, Section 3.3.2
+ *
+ * Note: This function is only valid if Earth is the central body!
+ *
+ * @param[in] j2000_s_tt seconds since J2000 epoch (TT)
+ *
+ * @returns: Sun position vector
+ * @unit: m
+ * @frame: PCI
+ *
+ */
+pub fn calc_sun_pos_pci_m(j2000_s_tt: f64)
+-> Array1<f64>
+{
+  /* Julian centuries TT since J2000 */
+  let t: f64 = j2000_s_tt / SECONDS_OF_EARTH_DAY / DAYS_OF_JULIAN_CENTURY;
+
+  /* Mean anomaly [rad] */
+  let mean_anomaly: f64 = (357.5256 + 35999.049 * t).to_radians();
+  /* Ecliptic longitude [rad] */
+  let longitude: f64 = (282.9400 + mean_anomaly.to_degrees()
+                        + (6892.0 * mean_anomaly.sin()
+                           + 72.0 * (2.0 * mean_anomaly).sin()) / 3600.0).to_radians();
+  /* Distance [m] */
+  let distance_m: f64 = (149.619 - 2.499 * mean_anomaly.cos()
+                         - 0.021 * (2.0 * mean_anomaly).cos()) * 1.0e9;
+
+  let pos_ecliptic_m: Array1<f64> = Array1::from(vec![distance_m * longitude.cos(),
+                                                      distance_m * longitude.sin(),
+                                                      0.0]);
+
+  convert_ecliptic_to_equatorial(&pos_ecliptic_m, OBLIQUITY_J2000_DEG)
+}
+
+/*
+ * @brief: Calculate the position of the Moon with respect to the Earth from a
+ *         low precision analytic series.
+ *
+ * @description: The series contains the main perturbations of the lunar
+ *               motion and is accurate to about several arcmin in direction
+ *               and about 500 km in distance.
+ *
+ *               The position is referred to the mean equator and equinox
+ *               of J2000 (EME2000) which is used as PCI frame here.
+ *
+ *               Ref: Montenbruck, Gill - Satellite Orbits, Section 3.3.2
+ *
+ * Note: This function is only valid if Earth is the central body!
+ *
+ * @param[in] j2000_s_tt seconds since J2000 epoch (TT)
+ *
+ * @returns: Moon position vector
+ * @unit: m
+ * @frame: PCI
+ *
+ */
+pub fn calc_moon_pos_pci_m(j2000_s_tt: f64)
+-> Array1<f64>
+{
+  /* Julian centuries TT since J2000 */
+  let t: f64 = j2000_s_tt / SECONDS_OF_EARTH_DAY / DAYS_OF_JULIAN_CENTURY;
+
+  /* Mean longitude of the Moon (referred to the equinox J2000) [deg] */
+  let l_0: f64 = 218.31617 + 481267.88088 * t - 1.3972 * t;
+  /* Mean anomaly of the Moon [rad] */
+  let l: f64 = (134.96292 + 477198.86753 * t).to_radians();
+  /* Mean anomaly of the Sun [rad] */
+  let lp: f64 = (357.52543 + 35999.04944 * t).to_radians();
+  /* Mean argument of latitude of the Moon [rad] */
+  let f: f64 = (93.27283 + 483202.01873 * t).to_radians();
+  /* Mean elongation of the Moon from the Sun [rad] */
+  let d: f64 = (297.85027 + 445267.11135 * t).to_radians();
+
+  /* Ecliptic longitude [deg] */
+  let longitude_deg: f64 = l_0 + (22640.0 * l.sin()
+                                  + 769.0 * (2.0 * l).sin()
+                                  - 4586.0 * (l - 2.0 * d).sin()
+                                  + 2370.0 * (2.0 * d).sin()
+                                  - 668.0 * lp.sin()
+                                  - 412.0 * (2.0 * f).sin()
+                                  - 212.0 * (2.0 * l - 2.0 * d).sin()
+                                  - 206.0 * (l + lp - 2.0 * d).sin()
+                                  + 192.0 * (l + 2.0 * d).sin()
+                                  - 165.0 * (lp - 2.0 * d).sin()
+                                  + 148.0 * (l - lp).sin()
+                                  - 125.0 * d.sin()
+                                  - 110.0 * (l + lp).sin()
+                                  - 55.0 * (2.0 * f - 2.0 * d).sin()) / 3600.0;
+
+  /* Ecliptic latitude [deg] */
+  let latitude_deg: f64 = (18520.0 * (f + (longitude_deg - l_0).to_radians()
+                                      + ((412.0 * (2.0 * f).sin()
+                                          + 541.0 * lp.sin()) / 3600.0).to_radians()).sin()
+                           - 526.0 * (f - 2.0 * d).sin()
+                           + 44.0 * (l + f - 2.0 * d).sin()
+                           - 31.0 * (-l + f - 2.0 * d).sin()
+                           - 25.0 * (-2.0 * l + f).sin()
+                           - 23.0 * (lp + f - 2.0 * d).sin()
+                           + 21.0 * (-l + f).sin()
+                           + 11.0 * (-lp + f - 2.0 * d).sin()) / 3600.0;
+
+  /* Distance [m] */
+  let distance_m: f64 = (385000.0 - 20905.0 * l.cos()
+                         - 3699.0 * (2.0 * d - l).cos()
+                         - 2956.0 * (2.0 * d).cos()
+                         - 570.0 * (2.0 * l).cos()
+                         + 246.0 * (2.0 * l - 2.0 * d).cos()
+                         - 205.0 * (lp - 2.0 * d).cos()
+                         - 171.0 * (l + 2.0 * d).cos()
+                         - 152.0 * (l + lp - 2.0 * d).cos()) * 1000.0;
+
+  let longitude: f64 = longitude_deg.to_radians();
+  let latitude: f64 = latitude_deg.to_radians();
+
+  let pos_ecliptic_m: Array1<f64> = Array1::from(vec![distance_m * latitude.cos() * longitude.cos(),
+                                                      distance_m * latitude.cos() * longitude.sin(),
+                                                      distance_m * latitude.sin()]);
+
+  convert_ecliptic_to_equatorial(&pos_ecliptic_m, OBLIQUITY_J2000_DEG)
+}
+
+/*
+ * @brief: Calculate the position of the Moon with respect to the Earth from a
+ *         higher precision analytic series.
+ *
+ * @description: The 60 largest periodic terms of each coordinate of the
+ *               lunar theory ELP-2000/82 are evaluated. The series is accurate
+ *               to about 10 arcsec in longitude, 4 arcsec in latitude and a
+ *               few km in distance.
+ *
+ *               The series refers to the mean ecliptic and equinox of date
+ *               which is rotated to PCI with the IAU 2006 precession.
+ *
+ *               Ref: Meeus - Astronomical Algorithms, 2nd edition, Chapter 47
+ *
+ * Note: This function is only valid if Earth is the central body!
+ *
+ * @param[in] j2000_s_tt seconds since J2000 epoch (TT)
+ *
+ * @returns: Moon position vector
+ * @unit: m
+ * @frame: PCI
+ *
+ */
+pub fn calc_moon_pos_pci_m_high_precision(j2000_s_tt: f64)
+-> Array1<f64>
+{
+  /* Julian centuries TT since J2000 */
+  let t: f64 = j2000_s_tt / SECONDS_OF_EARTH_DAY / DAYS_OF_JULIAN_CENTURY;
+
+  /* Fundamental arguments [rad] */
+  let l_0: f64 = eval_polynomial(&MOON_MEAN_LONGITUDE_DEG, t).to_radians();
+  let d: f64 = eval_polynomial(&MOON_MEAN_ELONGATION_DEG, t).to_radians();
+  let m: f64 = eval_polynomial(&SUN_MEAN_ANOMALY_DEG, t).to_radians();
+  let mp: f64 = eval_polynomial(&MOON_MEAN_ANOMALY_DEG, t).to_radians();
+  let f: f64 = eval_polynomial(&MOON_ARGUMENT_OF_LATITUDE_DEG, t).to_radians();
+  let a_1: f64 = eval_polynomial(&MOON_ARGUMENT_A1_DEG, t).to_radians();
+  let a_2: f64 = eval_polynomial(&MOON_ARGUMENT_A2_DEG, t).to_radians();
+  let a_3: f64 = eval_polynomial(&MOON_ARGUMENT_A3_DEG, t).to_radians();
+  /* Decreasing eccentricity of the Earth's orbit */
+  let ecc_factor: f64 = eval_polynomial(&MOON_ECCENTRICITY_FACTOR, t);
+
+  /* Sums of the periodic terms [1e-6 deg] and [m] */
+  let mut sum_longitude: f64 = 3958.0 * a_1.sin() + 1962.0 * (l_0 - f).sin() + 318.0 * a_2.sin();
+  let mut sum_distance_m: f64 = 0.0;
+  for term in MOON_LONGITUDE_DISTANCE_TERMS.iter()
+  {
+    let argument: f64 = term[0] * d + term[1] * m + term[2] * mp + term[3] * f;
+    let scale: f64 = ecc_factor.powi(term[1].abs() as i32);
+    sum_longitude += scale * term[4] * argument.sin();
+    sum_distance_m += scale * term[5] * argument.cos();
+  }
+
+  let mut sum_latitude: f64 = - 2235.0 * l_0.sin()
+                              + 382.0 * a_3.sin()
+                              + 175.0 * (a_1 - f).sin()
+                              + 175.0 * (a_1 + f).sin()
+                              + 127.0 * (l_0 - mp).sin()
+                              - 115.0 * (l_0 + mp).sin();
+  for term in MOON_LATITUDE_TERMS.iter()
+  {
+    let argument: f64 = term[0] * d + term[1] * m + term[2] * mp + term[3] * f;
+    sum_latitude += ecc_factor.powi(term[1].abs() as i32) * term[4] * argument.sin();
+  }
+
+  let longitude: f64 = l_0 + (sum_longitude * 1.0e-6).to_radians();
+  let latitude: f64 = (sum_latitude * 1.0e-6).to_radians();
+  let distance_m: f64 = MOON_MEAN_DISTANCE_M + sum_distance_m;
+
+  let pos_ecliptic_m: Array1<f64> = Array1::from(vec![distance_m * latitude.cos() * longitude.cos(),
+                                                      distance_m * latitude.cos() * longitude.sin(),
+                                                      distance_m * latitude.sin()]);
+
+  convert_ecliptic_of_date_to_pci(&pos_ecliptic_m, t)
+}
+
+/*
+ * @brief: Calculate the position of the Sun with respect to the Earth from a
+ *         higher precision analytic series.
+ *
+ * @description: The heliocentric position of the Earth-Moon barycentre is
+ *               computed from mean Keplerian elements with secular rates
+ *               (valid 1800 - 2050, about 20 arcsec). The Earth is offset
+ *               from the barycentre by the lunar position from
+ *               calc_moon_pos_pci_m_high_precision(), which removes the
+ *               monthly oscillation of up to 6 arcsec:
+ *
+ *               r_sun = - r_emb + r_moon / (1 + M_earth / M_moon)
+ *
+ *               Ref: Standish - Keplerian Elements for Approximate Positions
+ *                    of the Major Planets (JPL)
+ *
+ * Note: This function is only valid if Earth is the central body!
+ *
+ * @param[in] j2000_s_tt seconds since J2000 epoch (TT)
+ *
+ * @returns: Sun position vector
+ * @unit: m
+ * @frame: PCI
+ *
+ */
+pub fn calc_sun_pos_pci_m_high_precision(j2000_s_tt: f64)
+-> Array1<f64>
+{
+  /* Julian centuries TT since J2000 */
+  let t: f64 = j2000_s_tt / SECONDS_OF_EARTH_DAY / DAYS_OF_JULIAN_CENTURY;
+
+  let longitude_perihelion_deg: f64 = eval_polynomial(&EMB_LONGITUDE_PERIHELION_DEG, t);
+  let longitude_node_deg: f64 = eval_polynomial(&EMB_LONGITUDE_NODE_DEG, t);
+  let ecc: f64 = eval_polynomial(&EMB_ECC, t);
+  let mean_anomaly: f64 = (eval_polynomial(&EMB_MEAN_LONGITUDE_DEG, t)
+                           - longitude_perihelion_deg).to_radians();
+
+  let mut elements: Array1<f64> = Array1::zeros(KEPLER_NUM_ELEMENTS);
+  elements[KEPLER_INDX_SMA] = eval_polynomial(&EMB_SMA_AU, t) * ASTRONOMICAL_UNIT_M;
+  elements[KEPLER_INDX_ECC] = ecc;
+  elements[KEPLER_INDX_INC] = eval_polynomial(&EMB_INC_DEG, t).to_radians();
+  elements[KEPLER_INDX_RAAN] = longitude_node_deg.to_radians();
+  elements[KEPLER_INDX_ARGP] = (longitude_perihelion_deg - longitude_node_deg).to_radians();
+  elements[KEPLER_INDX_TRUE_ANOMALY] = convert_anomaly_to_true_anomaly(mean_anomaly, AnomalyType::Mean, ecc);
+
+  /* Only the position is used, the velocity does not depend on the value of 
+   * the gravitational constant */
+  let (pos_emb_ecliptic_m, _) = convert_kepler_to_cartesian(elements.view(), SUN_GRAVITATIONAL_CONSTANT);
+
+  - convert_ecliptic_to_equatorial(&pos_emb_ecliptic_m, OBLIQUITY_J2000_DEG)
+    + calc_moon_pos_pci_m_high_precision(j2000_s_tt) / (1.0 + EARTH_MOON_MASS_RATIO)
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to rotate a vector from the mean ecliptic and equinox of
+ *         date to PCI
+ *
+ * @description: The axes of the ecliptic frame of date are constructed in PCI
+ *               from the IAU 2006 ecliptic pole and the mean pole of date
+ *               (precession only). The mean equinox is the node of the
+ *               ecliptic on the mean equator.
+ *
+ * @param[in] t - Julian centuries TT since J2000.0
+ */
+fn convert_ecliptic_of_date_to_pci(vec_ecliptic_in: &Array1<f64>, t: f64)
+-> Array1<f64>
+{
+  let (pole_x, pole_y): (f64, f64) = calc_cip_xy_from_nutation(t, 0.0, 0.0);
+  let mean_pole: Array1<f64> = Array1::from(vec![pole_x, pole_y, (1.0 - pole_x * pole_x - pole_y * pole_y).sqrt()]);
+  let ecliptic_pole: Array1<f64> = calc_ecliptic_pole(t);
+
+  let equinox: Array1<f64> = normalize_array1(cross_product_array1(mean_pole.view(), ecliptic_pole.view()));
+  let ecliptic_y_axis: Array1<f64> = cross_product_array1(ecliptic_pole.view(), equinox.view());
+
+  vec_ecliptic_in[0] * &equinox + vec_ecliptic_in[1] * &ecliptic_y_axis + vec_ecliptic_in[2] * &ecliptic_pole
+}
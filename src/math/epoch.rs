@@ -0,0 +1,230 @@
+/*
+ * @brief: This file contains the two-part representation of an epoch.
+ *
+ * @description: The epoch is stored as whole days and seconds since J2000
+ *               (2000-01-01 12:00:00) separately. A single f64 of seconds
+ *               since J2000 resolves ~0.1 microseconds today and loses
+ *               precision with every increment, the seconds of the day keep
+ *               a resolution of ~1e-11 s.
+ *
+ *               In the state vector the whole days (STATE_VEC_INDX_EPOCH_DAYS)
+ *               are kept constant during a simulation run and the seconds
+ *               (STATE_VEC_INDX_EPOCH_SECONDS) count from the start of that
+ *               day. The seconds may therefore exceed one day, which keeps the
+ *               state continuous for the integration and interpolation.
+ *
+ */
+
+/* Include external crates */
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, ArrayView1};
+
+/* Include local crates */
+use crate::math::time_scale_math::{convert_time_scale, TimeScale};
+
+/* Import constants */
+use crate::constants::state::{STATE_VEC_INDX_EPOCH_DAYS, STATE_VEC_INDX_EPOCH_SECONDS, STATE_VEC_INDX_J2000_S};
+use crate::constants::time::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub struct Epoch {
+  /* [days]
+   * @description : Whole days since J2000
+   * @unit        : days
+   * */
+  days: i64,
+  /* [seconds]
+   * @description : Seconds since the start of the day, within [0, 86400)
+   * @unit        : seconds
+   * */
+  seconds: f64,
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [constructor]
+ * ----------------------------------------------------------------------
+ */
+impl Epoch {
+ /*
+  * @brief: Create an epoch from days and seconds since J2000. The seconds may
+  *         be outside of one day, the epoch is normalized.
+  */
+  pub fn new(days: i64, seconds: f64) -> Epoch
+  {
+    let whole_days: f64 = seconds.div_euclid(SECONDS_OF_EARTH_DAY);
+    Epoch {
+      days: days + whole_days as i64,
+      seconds: seconds - whole_days * SECONDS_OF_EARTH_DAY,
+    }
+  }
+
+ /*
+  * @brief: Create an epoch from seconds since J2000
+  */
+  pub fn from_j2000_s(j2000_s: f64) -> Epoch
+  {
+    Epoch::new(0, j2000_s)
+  }
+
+ /*
+  * @brief: Create an epoch from a (UTC) DateTime without loss of precision
+  */
+  pub fn from_datetime(datetime: &DateTime<Utc>) -> Epoch
+  {
+    let j2000_whole_s: i64 = datetime.timestamp() - UNIX_SECONDS_AT_J2000_EPOCH;
+    let seconds_of_day_s: i64 = j2000_whole_s.rem_euclid(SECONDS_OF_EARTH_DAY as i64);
+
+    Epoch {
+      days: j2000_whole_s.div_euclid(SECONDS_OF_EARTH_DAY as i64),
+      seconds: seconds_of_day_s as f64 + datetime.timestamp_subsec_nanos() as f64 * 1.0e-9,
+    }
+  }
+
+ /*
+  * @brief: Create an epoch from the epoch elements of the state vector
+  */
+  pub fn from_state_vec(state_vec_in: ArrayView1<f64>) -> Epoch
+  {
+    Epoch::new(state_vec_in[STATE_VEC_INDX_EPOCH_DAYS] as i64,
+               state_vec_in[STATE_VEC_INDX_EPOCH_SECONDS])
+  }
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [getters]
+ * ----------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl Epoch {
+  pub fn get_days(&self) -> &i64 {&self.days}
+  pub fn get_seconds(&self) -> &f64 {&self.seconds}
+}
+/*
+ * ----------------------------------------------------------------------
+ *                    [public functions]
+ * ----------------------------------------------------------------------
+ */
+impl Epoch {
+ /*
+  * @brief: Function to write the epoch to the state vector.
+  *
+  * @description: The whole days of the state vector are kept and the seconds
+  *               are counted from the start of that day. The seconds since
+  *               J2000 are updated as well.
+  */
+  pub fn write_to_state_vec(&self, state_vec_inout: &mut Array1<f64>)
+  {
+    let reference_day: Epoch = Epoch::new(state_vec_inout[STATE_VEC_INDX_EPOCH_DAYS] as i64, 0.0);
+    state_vec_inout[STATE_VEC_INDX_EPOCH_SECONDS] = self.diff_s(&reference_day);
+    state_vec_inout[STATE_VEC_INDX_J2000_S] = self.to_j2000_s();
+  }
+
+ /*
+  * @brief: Function to get the epoch shifted by a number of seconds
+  */
+  pub fn add_seconds(&self, seconds_in: f64) -> Epoch
+  {
+    Epoch::new(self.days, self.seconds + seconds_in)
+  }
+
+ /*
+  * @brief: Function to compute the seconds from another epoch to this epoch
+  *
+  * @unit: seconds
+  */
+  pub fn diff_s(&self, epoch_in: &Epoch) -> f64
+  {
+    (self.days - epoch_in.days) as f64 * SECONDS_OF_EARTH_DAY + (self.seconds - epoch_in.seconds)
+  }
+
+ /*
+  * @brief: Function to convert the epoch to seconds since J2000 (reduced
+  *         precision)
+  *
+  * @unit: seconds
+  */
+  pub fn to_j2000_s(&self) -> f64
+  {
+    self.days as f64 * SECONDS_OF_EARTH_DAY + self.seconds
+  }
+
+ /*
+  * @brief: Function to compute the fraction of the day
+  *
+  * @unit: days
+  */
+  pub fn calc_day_fraction(&self) -> f64
+  {
+    self.seconds / SECONDS_OF_EARTH_DAY
+  }
+
+ /*
+  * @brief: Function to compute the Modified Julian Date
+  *
+  * @unit: days
+  */
+  pub fn calc_mjd(&self) -> f64
+  {
+    (JULIAN_DAYS_AT_J2000_EPOCH - DIFF_MJD_TO_JD) + self.days as f64 + self.calc_day_fraction()
+  }
+
+ /*
+  * @brief: Function to compute Julian centuries since J2000
+  *
+  * @unit: Julian centuries
+  */
+  pub fn calc_julian_centuries(&self) -> f64
+  {
+    (self.days as f64 + self.calc_day_fraction()) / DAYS_OF_JULIAN_CENTURY
+  }
+
+ /*
+  * @brief: Function to compute the decimal year, e.g. 2020.5
+  *
+  * Note: Years of 365.25 days starting at 2000-01-01 00:00, the result 
+  *       differs from the calendar decimal year by less than one day
+  *
+  * @unit: years
+  */
+  pub fn calc_decimal_year(&self) -> f64
+  {
+    2000.0 + (self.days as f64 + self.calc_day_fraction() + 0.5) / MEAN_DAYS_IN_EARTH_YEAR
+  }
+
+ /*
+  * @brief: Function to convert the epoch to a (UTC) DateTime with nanosecond
+  *         precision
+  */
+  pub fn to_datetime(&self) -> DateTime<Utc>
+  {
+    let whole_seconds: f64 = self.seconds.floor();
+    let unix_time_s: i64 = UNIX_SECONDS_AT_J2000_EPOCH
+                           + self.days * SECONDS_OF_EARTH_DAY as i64
+                           + whole_seconds as i64;
+    let nanoseconds: u32 = (((self.seconds - whole_seconds) * 1.0e9).round() as u32).min(999_999_999);
+    DateTime::from_timestamp(unix_time_s, nanoseconds).unwrap()
+  }
+
+ /*
+  * @brief: Function to convert the epoch between two time scales
+  *
+  * @param[in] ut1_minus_utc_s - UT1-UTC, only used for conversions from or
+  *                              to UT1
+  */
+  pub fn convert_time_scale(&self, scale_in: TimeScale, scale_out: TimeScale, ut1_minus_utc_s: f64)
+  -> Epoch
+  {
+    /* The offset between the scales is small and computed at reduced precision */
+    let j2000_s: f64 = self.to_j2000_s();
+    self.add_seconds(convert_time_scale(j2000_s, scale_in, scale_out, ut1_minus_utc_s) - j2000_s)
+  }
+
+ /*
+  * @brief: Function to convert a UTC epoch to TT
+  */
+  pub fn convert_utc_to_tt(&self) -> Epoch
+  {
+    self.convert_time_scale(TimeScale::Utc, TimeScale::Tt, 0.0)
+  }
+}
@@ -4,7 +4,7 @@ use libm::*;
 
 
 use crate::math::rotation_math::dcm_from_zrot;
-use crate::math::vec_math::l2_norm_array1;
+use crate::math::vec_math::{l2_norm_array1, normalize_array1, cross_product_array1};
 
 /*
  * @brief: Function to convert position coordinates from ECEF 
@@ -41,26 +41,364 @@ pub fn convert_ecef_to_llr(pos_PCI_m_in: ArrayView1<f64>)
   vec_out_llr
 }
 
-pub fn convert_eci_to_ecef(pos_eci_in: &Array1<f64>, gast_deg: f64)
+/* Convergence tolerance and iteration limit of the geodetic latitude */
+const GEODETIC_LAT_TOLERANCE_RAD: f64 = 1.0e-13;
+const GEODETIC_MAX_ITERATIONS: usize = 10;
+
+/*
+ * @brief: Function to convert position coordinates from ECEF (planet centered 
+ *         planet fixed) to geodetic latitude, longitude and height above the 
+ *         reference ellipsoid
+ * 
+ * @description: Iterative solution of Bowring. The parametric latitude is 
+ *               updated from the geodetic latitude until the latter converges,
+ *               a single iteration is already accurate to < 1 mm for Earth 
+ *               orbits. The height is computed with a formulation that is 
+ *               well conditioned at the poles.
+ * 
+ *               Ref: Bowring - Transformation from spatial to geographical 
+ *                    coordinates, Survey Review 23 (181), 1976
+ * 
+ * @param[in] pos_ecef_m_in     - Position vector in ECEF frame [m]
+ * @param[in] semi_major_axis_m - Equatorial radius of the reference ellipsoid
+ * @param[in] flattening_factor - Flattening of the reference ellipsoid
+ * 
+ * @returns Position vector in geodetic latitude [rad], longitude [rad], 
+ *          height [m] format
+ * 
+ */
+pub fn convert_ecef_to_geodetic(pos_ecef_m_in: ArrayView1<f64>, 
+                                semi_major_axis_m: f64, 
+                                flattening_factor: f64)
 -> Array1<f64>
 {
+  let mut vec_out_lla: Array1<f64> = Array1::zeros(3);
+  let x: f64 = pos_ecef_m_in[0];
+  let y: f64 = pos_ecef_m_in[1];
+  let z: f64 = pos_ecef_m_in[2];
 
-  // TODO: Remove this ugliness
-  let mut pos_vec_in: Array2<f64> = Array2::zeros((3,1));
-  pos_vec_in[[0,0]] = pos_eci_in[0];
-  pos_vec_in[[1,0]] = pos_eci_in[1];
-  pos_vec_in[[2,0]] = pos_eci_in[2];
+  let semi_minor_axis_m: f64 = semi_major_axis_m * (1.0 - flattening_factor);
+  /* First and second eccentricity squared */
+  let ecc_sq: f64 = flattening_factor * (2.0 - flattening_factor);
+  let ecc_prime_sq: f64 = ecc_sq / (1.0 - ecc_sq);
+  /* Distance from the rotation axis */
+  let p: f64 = x.hypot(y);
 
-  /* Create direction cosine matrix for the z rotation from ECI to ECEF */
-  let dcm_eci_2_ecef: Array2<f64> = dcm_from_zrot(gast_deg);
+  if p == 0.0 && z == 0.0
+  {
+    println!{"[WRN] Conversion from Cartesian to geodetic coordinates failed. Position found to be at the center!"};
+    vec_out_lla[2] = -semi_major_axis_m;
+    return vec_out_lla;
+  }
 
-  /* Create vector rotation from ECI to ECEF */
-  let vec_out_ecef_2: Array2<f64> = dcm_eci_2_ecef.dot(&pos_vec_in);
-  
-  /* Convert Array2 back to Array1 */
+  /* Longitude [rad] */
+  vec_out_lla[1] = atan2(y, x);
+
+  /* Latitude [rad], starting from the parametric latitude of the point */
+  let mut param_lat: f64 = atan2(z, (1.0 - flattening_factor) * p);
+  let mut lat: f64 = 0.0;
+  for _ in 0..GEODETIC_MAX_ITERATIONS
+  {
+    let (sin_beta, cos_beta): (f64, f64) = param_lat.sin_cos();
+    let lat_new: f64 = atan2(z + ecc_prime_sq * semi_minor_axis_m * sin_beta.powi(3),
+                             p - ecc_sq * semi_major_axis_m * cos_beta.powi(3));
+    let is_converged: bool = (lat_new - lat).abs() < GEODETIC_LAT_TOLERANCE_RAD;
+    lat = lat_new;
+    if is_converged
+    {
+      break;
+    }
+    param_lat = atan2((1.0 - flattening_factor) * lat.sin(), lat.cos());
+  }
+  vec_out_lla[0] = lat;
+
+  /* Height above the ellipsoid [m] with the prime vertical radius N */
+  let (sin_lat, cos_lat): (f64, f64) = lat.sin_cos();
+  let prime_vertical_radius_m: f64 = semi_major_axis_m / (1.0 - ecc_sq * sin_lat * sin_lat).sqrt();
+  vec_out_lla[2] = p * cos_lat + (z + ecc_sq * prime_vertical_radius_m * sin_lat) * sin_lat
+                   - prime_vertical_radius_m;
+
+  vec_out_lla
+}
+
+/*
+ * @brief: Function to convert geodetic latitude, longitude and height above 
+ *         the reference ellipsoid to position coordinates in ECEF (planet 
+ *         centered planet fixed)
+ * 
+ * @param[in] pos_lla_in        - Geodetic latitude [rad], longitude [rad], 
+ *                                height [m]
+ * @param[in] semi_major_axis_m - Equatorial radius of the reference ellipsoid
+ * @param[in] flattening_factor - Flattening of the reference ellipsoid
+ * 
+ * @returns Position vector in ECEF frame [m]
+ * 
+ */
+pub fn convert_geodetic_to_ecef(pos_lla_in: ArrayView1<f64>, 
+                                semi_major_axis_m: f64, 
+                                flattening_factor: f64)
+-> Array1<f64>
+{
   let mut vec_out_ecef: Array1<f64> = Array1::zeros(3);
-  vec_out_ecef[0] = vec_out_ecef_2[[0,0]];
-  vec_out_ecef[1] = vec_out_ecef_2[[1,0]];
-  vec_out_ecef[2] = vec_out_ecef_2[[2,0]];
+  let (sin_lat, cos_lat): (f64, f64) = pos_lla_in[0].sin_cos();
+  let (sin_lon, cos_lon): (f64, f64) = pos_lla_in[1].sin_cos();
+  let height_m: f64 = pos_lla_in[2];
+
+  let ecc_sq: f64 = flattening_factor * (2.0 - flattening_factor);
+  let prime_vertical_radius_m: f64 = semi_major_axis_m / (1.0 - ecc_sq * sin_lat * sin_lat).sqrt();
+
+  vec_out_ecef[0] = (prime_vertical_radius_m + height_m) * cos_lat * cos_lon;
+  vec_out_ecef[1] = (prime_vertical_radius_m + height_m) * cos_lat * sin_lon;
+  vec_out_ecef[2] = (prime_vertical_radius_m * (1.0 - ecc_sq) + height_m) * sin_lat;
   vec_out_ecef
-}
\ No newline at end of file
+}
+
+/*
+ * @brief: Function to convert a vector from ECI (planet centered inertial) to 
+ *         ECEF (planet centered planet fixed) frame
+ * 
+ * @param[in] pos_eci_in - Vector in ECI frame
+ * @param[in] gast_deg   - Greenwich apparent sidereal time in degree
+ * 
+ * @returns Vector in ECEF frame
+ * 
+ */
+pub fn convert_eci_to_ecef(pos_eci_in: &Array1<f64>, gast_deg: f64)
+-> Array1<f64>
+{
+  /* Create direction cosine matrix for the z rotation from ECI to ECEF. The
+   * frame is rotated by +GAST, hence the vector by -GAST */
+  let dcm_eci_2_ecef: Array2<f64> = dcm_from_zrot(-gast_deg);
+
+  /* Create vector rotation from ECI to ECEF */
+  dcm_eci_2_ecef.dot(pos_eci_in)
+}
+
+/*
+ * @brief: Function to convert a vector from ECEF (planet centered planet fixed)
+ *         to ECI (planet centered inertial) frame
+ * 
+ * @param[in] pos_ecef_in - Vector in ECEF frame
+ * @param[in] gast_deg    - Greenwich apparent sidereal time in degree
+ * 
+ * @returns Vector in ECI frame
+ * 
+ */
+pub fn convert_ecef_to_eci(pos_ecef_in: &Array1<f64>, gast_deg: f64)
+-> Array1<f64>
+{
+  /* Create direction cosine matrix for the z rotation from ECEF to ECI */
+  let dcm_ecef_2_eci: Array2<f64> = dcm_from_zrot(gast_deg);
+
+  dcm_ecef_2_eci.dot(pos_ecef_in)
+}
+
+/*
+ * @brief: Function to convert a vector from ecliptic to equatorial coordinates
+ * 
+ * @description: Rotation about the x-axis (vernal equinox) by the negative 
+ *               obliquity of the ecliptic
+ * 
+ * @param[in] vec_ecliptic_in - Vector in ecliptic frame
+ * @param[in] obliquity_deg   - Obliquity of the ecliptic in degree
+ * 
+ * @returns Vector in equatorial frame
+ * 
+ */
+pub fn convert_ecliptic_to_equatorial(vec_ecliptic_in: &Array1<f64>, obliquity_deg: f64)
+-> Array1<f64>
+{
+  let cos_eps: f64 = obliquity_deg.to_radians().cos();
+  let sin_eps: f64 = obliquity_deg.to_radians().sin();
+
+  let mut vec_out_equatorial: Array1<f64> = Array1::zeros(3);
+  vec_out_equatorial[0] = vec_ecliptic_in[0];
+  vec_out_equatorial[1] = cos_eps * vec_ecliptic_in[1] - sin_eps * vec_ecliptic_in[2];
+  vec_out_equatorial[2] = sin_eps * vec_ecliptic_in[1] + cos_eps * vec_ecliptic_in[2];
+  vec_out_equatorial
+}
+
+/*
+ * @brief: Function to convert a vector from ECEF (planet centered planet fixed)
+ *         to the local north-east-down (NED) frame at a geodetic position
+ * 
+ * @param[in] vec_ecef_in - Vector in ECEF frame
+ * @param[in] lat_rad     - Geodetic latitude in radians
+ * @param[in] lon_rad     - Longitude in radians
+ * 
+ * @returns Vector in NED frame
+ * 
+ */
+pub fn convert_ecef_to_ned(vec_ecef_in: ArrayView1<f64>, lat_rad: f64, lon_rad: f64)
+-> Array1<f64>
+{
+  let (sin_lat, cos_lat): (f64, f64) = lat_rad.sin_cos();
+  let (sin_lon, cos_lon): (f64, f64) = lon_rad.sin_cos();
+
+  let mut vec_out_ned: Array1<f64> = Array1::zeros(3);
+  vec_out_ned[0] = - sin_lat * cos_lon * vec_ecef_in[0] - sin_lat * sin_lon * vec_ecef_in[1] + cos_lat * vec_ecef_in[2];
+  vec_out_ned[1] = - sin_lon * vec_ecef_in[0] + cos_lon * vec_ecef_in[1];
+  vec_out_ned[2] = - cos_lat * cos_lon * vec_ecef_in[0] - cos_lat * sin_lon * vec_ecef_in[1] - sin_lat * vec_ecef_in[2];
+  vec_out_ned
+}
+
+/*
+ * @brief: Function to compute the direction cosine matrix from the orbital
+ *         RTN frame to PCI. The columns are the RTN axes in PCI:
+ *         R - radial (along the position vector)
+ *         T - transverse (N x R, along the velocity for circular orbits)
+ *         N - orbit normal (along r x v)
+ * 
+ * @param[in] pos_pci_m_in  - Position vector in PCI frame
+ * @param[in] vel_pci_ms_in - Velocity vector in PCI frame
+ * 
+ * @returns DCM RTN to PCI
+ * 
+ */
+pub fn calc_dcm_rtn_to_pci(pos_pci_m_in: ArrayView1<f64>, vel_pci_ms_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let r_axis: Array1<f64> = normalize_array1(pos_pci_m_in.to_owned());
+  let n_axis: Array1<f64> = normalize_array1(cross_product_array1(pos_pci_m_in, vel_pci_ms_in));
+  let t_axis: Array1<f64> = cross_product_array1(n_axis.view(), r_axis.view());
+
+  let mut dcm_rtn_to_pci: Array2<f64> = Array2::zeros((3, 3));
+  dcm_rtn_to_pci.column_mut(0).assign(&r_axis);
+  dcm_rtn_to_pci.column_mut(1).assign(&t_axis);
+  dcm_rtn_to_pci.column_mut(2).assign(&n_axis);
+  dcm_rtn_to_pci
+}
+
+/*
+ * @brief: Function to compute the direction cosine matrix from the local
+ *         vertical local horizontal (LVLH) frame to PCI. The columns are the 
+ *         LVLH axes in PCI (same convention as the nadir guidance):
+ *         x - completes the triad (T of RTN)
+ *         y - against the orbit normal (-N of RTN)
+ *         z - towards nadir (-R of RTN)
+ * 
+ * @param[in] pos_pci_m_in  - Position vector in PCI frame
+ * @param[in] vel_pci_ms_in - Velocity vector in PCI frame
+ * 
+ * @returns DCM LVLH to PCI
+ * 
+ */
+pub fn calc_dcm_lvlh_to_pci(pos_pci_m_in: ArrayView1<f64>, vel_pci_ms_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let dcm_rtn_to_pci: Array2<f64> = calc_dcm_rtn_to_pci(pos_pci_m_in, vel_pci_ms_in);
+
+  let mut dcm_lvlh_to_pci: Array2<f64> = Array2::zeros((3, 3));
+  dcm_lvlh_to_pci.column_mut(0).assign(&dcm_rtn_to_pci.column(1));
+  dcm_lvlh_to_pci.column_mut(1).assign(&(-&dcm_rtn_to_pci.column(2)));
+  dcm_lvlh_to_pci.column_mut(2).assign(&(-&dcm_rtn_to_pci.column(0)));
+  dcm_lvlh_to_pci
+}
+
+/*
+ * @brief: Function to compute the direction cosine matrix from the VNB frame
+ *         to PCI. The columns are the VNB axes in PCI:
+ *         V - along the velocity vector
+ *         N - orbit normal (along r x v)
+ *         B - binormal (V x N)
+ * 
+ * @param[in] pos_pci_m_in  - Position vector in PCI frame
+ * @param[in] vel_pci_ms_in - Velocity vector in PCI frame
+ * 
+ * @returns DCM VNB to PCI
+ * 
+ */
+pub fn calc_dcm_vnb_to_pci(pos_pci_m_in: ArrayView1<f64>, vel_pci_ms_in: ArrayView1<f64>)
+-> Array2<f64>
+{
+  let v_axis: Array1<f64> = normalize_array1(vel_pci_ms_in.to_owned());
+  let n_axis: Array1<f64> = normalize_array1(cross_product_array1(pos_pci_m_in, vel_pci_ms_in));
+  let b_axis: Array1<f64> = cross_product_array1(v_axis.view(), n_axis.view());
+
+  let mut dcm_vnb_to_pci: Array2<f64> = Array2::zeros((3, 3));
+  dcm_vnb_to_pci.column_mut(0).assign(&v_axis);
+  dcm_vnb_to_pci.column_mut(1).assign(&n_axis);
+  dcm_vnb_to_pci.column_mut(2).assign(&b_axis);
+  dcm_vnb_to_pci
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ndarray::arr2;
+
+  const TOLERANCE: f64 = 1.0e-12;
+
+  /*
+   * @brief: Check that the DCM is orthonormal and right-handed
+   */
+  fn assert_orthonormal(dcm: &Array2<f64>)
+  {
+    let identity_deviation: Array2<f64> = dcm.t().dot(dcm) - Array2::<f64>::eye(3);
+    assert!(identity_deviation.iter().all(|value| value.abs() < TOLERANCE));
+    let det: f64 = cross_product_array1(dcm.column(0), dcm.column(1)).dot(&dcm.column(2));
+    assert!((det - 1.0).abs() < TOLERANCE);
+  }
+
+  fn assert_close(dcm: &Array2<f64>, dcm_expected: &Array2<f64>)
+  {
+    assert!((dcm - dcm_expected).iter().all(|value| value.abs() < TOLERANCE), "{} != {}", dcm, dcm_expected);
+  }
+
+  #[test]
+  fn orbital_frames_circular_equatorial_orbit()
+  {
+    let pos_pci_m: Array1<f64> = Array1::from(vec![7.0e6, 0.0, 0.0]);
+    let vel_pci_ms: Array1<f64> = Array1::from(vec![0.0, 7.5e3, 0.0]);
+
+    /* R = x, T = y, N = z */
+    assert_close(&calc_dcm_rtn_to_pci(pos_pci_m.view(), vel_pci_ms.view()),
+                 &arr2(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]));
+    /* x = T = y, y = -N = -z, z = -R = -x */
+    assert_close(&calc_dcm_lvlh_to_pci(pos_pci_m.view(), vel_pci_ms.view()),
+                 &arr2(&[[0.0, 0.0, -1.0], [1.0, 0.0, 0.0], [0.0, -1.0, 0.0]]));
+    /* V = y, N = z, B = x */
+    assert_close(&calc_dcm_vnb_to_pci(pos_pci_m.view(), vel_pci_ms.view()),
+                 &arr2(&[[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]));
+  }
+
+  #[test]
+  fn orbital_frames_eccentric_inclined_orbit()
+  {
+    /* Ascending pass after perigee: radial velocity is positive */
+    let pos_pci_m: Array1<f64> = Array1::from(vec![5.0e6, 3.0e6, 2.0e6]);
+    let vel_pci_ms: Array1<f64> = Array1::from(vec![-3.0e3, 6.0e3, 4.0e3]);
+    let pos_unit: Array1<f64> = normalize_array1(pos_pci_m.clone());
+    let vel_unit: Array1<f64> = normalize_array1(vel_pci_ms.clone());
+    let normal_unit: Array1<f64> = normalize_array1(cross_product_array1(pos_pci_m.view(), vel_pci_ms.view()));
+    assert!(pos_unit.dot(&vel_unit) > 0.0);
+
+    let dcm_rtn_to_pci: Array2<f64> = calc_dcm_rtn_to_pci(pos_pci_m.view(), vel_pci_ms.view());
+    let dcm_lvlh_to_pci: Array2<f64> = calc_dcm_lvlh_to_pci(pos_pci_m.view(), vel_pci_ms.view());
+    let dcm_vnb_to_pci: Array2<f64> = calc_dcm_vnb_to_pci(pos_pci_m.view(), vel_pci_ms.view());
+    assert_orthonormal(&dcm_rtn_to_pci);
+    assert_orthonormal(&dcm_lvlh_to_pci);
+    assert_orthonormal(&dcm_vnb_to_pci);
+
+    /* RTN: R along r, N along h, T has a positive velocity component */
+    assert!((dcm_rtn_to_pci.column(0).dot(&pos_unit) - 1.0).abs() < TOLERANCE);
+    assert!((dcm_rtn_to_pci.column(2).dot(&normal_unit) - 1.0).abs() < TOLERANCE);
+    assert!(dcm_rtn_to_pci.column(1).dot(&vel_unit) > 0.0);
+    assert!(dcm_rtn_to_pci.column(1).dot(&vel_unit) < 1.0 - TOLERANCE);
+
+    /* LVLH: x = T, y = -N, z = -R (nadir) */
+    assert!((dcm_lvlh_to_pci.column(0).dot(&dcm_rtn_to_pci.column(1)) - 1.0).abs() < TOLERANCE);
+    assert!((dcm_lvlh_to_pci.column(1).dot(&normal_unit) + 1.0).abs() < TOLERANCE);
+    assert!((dcm_lvlh_to_pci.column(2).dot(&pos_unit) + 1.0).abs() < TOLERANCE);
+
+    /* VNB: V along v, N along h, B = V x N has a positive radial component */
+    assert!((dcm_vnb_to_pci.column(0).dot(&vel_unit) - 1.0).abs() < TOLERANCE);
+    assert!((dcm_vnb_to_pci.column(1).dot(&normal_unit) - 1.0).abs() < TOLERANCE);
+    assert!(dcm_vnb_to_pci.column(2).dot(&pos_unit) > 0.0);
+
+    /* The frames share the orbit normal and differ by the flight path angle
+     * in the orbit plane */
+    let cos_flight_path_angle: f64 = dcm_rtn_to_pci.column(1).dot(&vel_unit);
+    assert!((dcm_vnb_to_pci.column(2).dot(&pos_unit) - cos_flight_path_angle).abs() < TOLERANCE);
+  }
+}
@@ -0,0 +1,411 @@
+use std::f64::consts::PI;
+
+use ndarray::{Array1, ArrayView1};
+
+use crate::math::vec_math::{l2_norm_array1, cross_product_array1};
+
+use crate::constants::orbit::*;
+
+/*
+ * @brief: Function to convert classical Keplerian elements to a Cartesian
+ *         position and velocity vector.
+ *
+ * @description: The state is computed in the perifocal frame and rotated into
+ *               the inertial frame with R3(-RAAN) * R1(-i) * R3(-argp).
+ *
+ *               For the singular cases the conventions of
+ *               convert_cartesian_to_kepler() apply:
+ *               * circular inclined    : argp = 0, true anomaly = argument of
+ *                                        latitude
+ *               * elliptic equatorial  : RAAN = 0, argp = longitude of
+ *                                        periapsis
+ *               * circular equatorial  : RAAN = 0, argp = 0, true anomaly =
+ *                                        true longitude
+ *
+ *               Elliptic (a > 0, e < 1) and hyperbolic (a < 0, e > 1) orbits
+ *               are supported. Parabolic orbits are not supported.
+ *
+ *               Ref: Vallado - Fundamentals of Astrodynamics and Applications,
+ *                    4th edition, Algorithm 10 (COE2RV)
+ *
+ * @param[in] elements_in            - Keplerian elements (see constants/orbit.rs)
+ * @param[in] gravitational_constant - Gravitational constant mu of the central
+ *                                     body [m3/s2]
+ *
+ * @returns: (position vector [m], velocity vector [m/s])
+ * @frame: PCI
+ */
+pub fn convert_kepler_to_cartesian(elements_in: ArrayView1<f64>, gravitational_constant: f64)
+-> (Array1<f64>, Array1<f64>)
+{
+  let sma_m: f64 = elements_in[KEPLER_INDX_SMA];
+  let ecc: f64 = elements_in[KEPLER_INDX_ECC];
+  let (sin_inc, cos_inc): (f64, f64) = elements_in[KEPLER_INDX_INC].sin_cos();
+  let (sin_raan, cos_raan): (f64, f64) = elements_in[KEPLER_INDX_RAAN].sin_cos();
+  let (sin_argp, cos_argp): (f64, f64) = elements_in[KEPLER_INDX_ARGP].sin_cos();
+  let (sin_nu, cos_nu): (f64, f64) = elements_in[KEPLER_INDX_TRUE_ANOMALY].sin_cos();
+
+  /* Semi-latus rectum */
+  let p_m: f64 = sma_m * (1.0 - ecc * ecc);
+  let radius_m: f64 = p_m / (1.0 + ecc * cos_nu);
+  let vel_scale: f64 = (gravitational_constant / p_m).sqrt();
+
+  /* Position and velocity in the perifocal frame */
+  let pos_pqw: [f64; 2] = [radius_m * cos_nu, radius_m * sin_nu];
+  let vel_pqw: [f64; 2] = [-vel_scale * sin_nu, vel_scale * (ecc + cos_nu)];
+
+  /* First two columns of the rotation matrix from perifocal to inertial */
+  let p_vec: [f64; 3] = [cos_raan * cos_argp - sin_raan * sin_argp * cos_inc,
+                         sin_raan * cos_argp + cos_raan * sin_argp * cos_inc,
+                         sin_argp * sin_inc];
+  let q_vec: [f64; 3] = [-cos_raan * sin_argp - sin_raan * cos_argp * cos_inc,
+                         -sin_raan * sin_argp + cos_raan * cos_argp * cos_inc,
+                         cos_argp * sin_inc];
+
+  let mut pos_m: Array1<f64> = Array1::zeros(3);
+  let mut vel_ms: Array1<f64> = Array1::zeros(3);
+  for axis in 0..3
+  {
+    pos_m[axis] = p_vec[axis] * pos_pqw[0] + q_vec[axis] * pos_pqw[1];
+    vel_ms[axis] = p_vec[axis] * vel_pqw[0] + q_vec[axis] * vel_pqw[1];
+  }
+  (pos_m, vel_ms)
+}
+
+/*
+ * @brief: Function to convert a Cartesian position and velocity vector to
+ *         classical (osculating) Keplerian elements.
+ *
+ * @description: Singular cases (tolerance KEPLER_SINGULARITY_TOLERANCE):
+ *               * circular inclined   : argp is undefined and set to 0. The
+ *                                       true anomaly is replaced by the
+ *                                       argument of latitude u.
+ *               * elliptic equatorial : RAAN is undefined and set to 0. The
+ *                                       argp is replaced by the (true)
+ *                                       longitude of periapsis.
+ *               * circular equatorial : RAAN and argp are set to 0. The true
+ *                                       anomaly is replaced by the true
+ *                                       longitude.
+ *
+ *               All angles are in [0, 2pi), the inclination is in [0, pi].
+ *
+ *               Ref: Vallado - Fundamentals of Astrodynamics and Applications,
+ *                    4th edition, Algorithm 9 (RV2COE)
+ *
+ * @param[in] pos_m_in               - Position vector [m]
+ * @param[in] vel_ms_in              - Velocity vector [m/s]
+ * @param[in] gravitational_constant - Gravitational constant mu of the central
+ *                                     body [m3/s2]
+ *
+ * @returns: Keplerian elements (see constants/orbit.rs). The semi-major axis
+ *           is infinite for parabolic orbits.
+ */
+pub fn convert_cartesian_to_kepler(pos_m_in: ArrayView1<f64>,
+                                   vel_ms_in: ArrayView1<f64>,
+                                   gravitational_constant: f64)
+-> Array1<f64>
+{
+  let mut elements_out: Array1<f64> = Array1::zeros(KEPLER_NUM_ELEMENTS);
+
+  let radius_m: f64 = l2_norm_array1(pos_m_in);
+  let speed_ms: f64 = l2_norm_array1(vel_ms_in);
+  let r_dot_v: f64 = pos_m_in.dot(&vel_ms_in);
+
+  /* Specific angular momentum */
+  let ang_mom_vec: Array1<f64> = cross_product_array1(pos_m_in, vel_ms_in);
+  let ang_mom: f64 = l2_norm_array1(ang_mom_vec.view());
+
+  /* Node vector (z x h) */
+  let node_vec: Array1<f64> = Array1::from(vec![-ang_mom_vec[1], ang_mom_vec[0], 0.0]);
+  let node: f64 = l2_norm_array1(node_vec.view());
+
+  /* Eccentricity vector */
+  let ecc_vec: Array1<f64> = ((speed_ms * speed_ms - gravitational_constant / radius_m) * &pos_m_in
+                              - r_dot_v * &vel_ms_in) / gravitational_constant;
+  let ecc: f64 = l2_norm_array1(ecc_vec.view());
+
+  /* Semi-major axis from the specific orbital energy */
+  let energy: f64 = 0.5 * speed_ms * speed_ms - gravitational_constant / radius_m;
+  elements_out[KEPLER_INDX_SMA] = if energy != 0.0 {-gravitational_constant / (2.0 * energy)}
+                                  else {f64::INFINITY};
+  elements_out[KEPLER_INDX_ECC] = ecc;
+  elements_out[KEPLER_INDX_INC] = (ang_mom_vec[2] / ang_mom).clamp(-1.0, 1.0).acos();
+
+  let is_circular: bool = ecc < KEPLER_SINGULARITY_TOLERANCE;
+  let is_equatorial: bool = node / ang_mom < KEPLER_SINGULARITY_TOLERANCE;
+  let is_retrograde: bool = ang_mom_vec[2] < 0.0;
+
+  /* [RIGHT ASCENSION OF ASCENDING NODE] */
+  if !is_equatorial
+  {
+    elements_out[KEPLER_INDX_RAAN] = calc_angle_in_range(node_vec[1].atan2(node_vec[0]));
+  }
+
+  /* [ARGUMENT OF PERIAPSIS] */
+  if !is_circular && !is_equatorial
+  {
+    elements_out[KEPLER_INDX_ARGP] = calc_angle_between(node_vec.view(), ecc_vec.view(), ecc_vec[2] < 0.0);
+  }
+  else if !is_circular
+  {
+    /* Longitude of periapsis measured from the x-axis */
+    let mut lon_periapsis: f64 = calc_angle_in_range(ecc_vec[1].atan2(ecc_vec[0]));
+    if is_retrograde
+    {
+      lon_periapsis = calc_angle_in_range(2.0 * PI - lon_periapsis);
+    }
+    elements_out[KEPLER_INDX_ARGP] = lon_periapsis;
+  }
+
+  /* [TRUE ANOMALY] */
+  if !is_circular
+  {
+    elements_out[KEPLER_INDX_TRUE_ANOMALY] = calc_angle_between(ecc_vec.view(), pos_m_in, r_dot_v < 0.0);
+  }
+  else if !is_equatorial
+  {
+    /* Argument of latitude */
+    elements_out[KEPLER_INDX_TRUE_ANOMALY] = calc_angle_between(node_vec.view(), pos_m_in, pos_m_in[2] < 0.0);
+  }
+  else
+  {
+    /* True longitude */
+    let mut true_longitude: f64 = calc_angle_in_range(pos_m_in[1].atan2(pos_m_in[0]));
+    if is_retrograde
+    {
+      true_longitude = calc_angle_in_range(2.0 * PI - true_longitude);
+    }
+    elements_out[KEPLER_INDX_TRUE_ANOMALY] = true_longitude;
+  }
+
+  elements_out
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum AnomalyType {
+  True,
+  Mean,
+  Eccentric
+}
+
+impl AnomalyType {
+ /*
+  * @brief: Function to map the anomaly type parameter string to the anomaly
+  *         type.
+  */
+  pub fn from_param_str(anomaly_str: &str) -> AnomalyType
+  {
+    match anomaly_str.trim().trim_matches('"')
+    {
+      "true" => AnomalyType::True,
+      "mean" => AnomalyType::Mean,
+      "eccentric" => AnomalyType::Eccentric,
+      _ => panic!("! [ERROR] ! > Unknown anomaly type {} in sim.ini! <", anomaly_str)
+    }
+  }
+}
+
+/*
+ * @brief: Function to convert a true, mean or eccentric anomaly to the true
+ *         anomaly.
+ *
+ * Note: Mean and eccentric anomalies are only supported for elliptic orbits.
+ *
+ * @unit: rad
+ */
+pub fn convert_anomaly_to_true_anomaly(anomaly_rad: f64, anomaly_type: AnomalyType, ecc: f64)
+-> f64
+{
+  if anomaly_type != AnomalyType::True && ecc >= 1.0
+  {
+    panic!("! [ERROR] ! > Mean and eccentric anomaly require an elliptic orbit (e < 1)! <");
+  }
+  match anomaly_type
+  {
+    AnomalyType::True => calc_angle_in_range(anomaly_rad),
+    AnomalyType::Eccentric => convert_eccentric_to_true_anomaly(anomaly_rad, ecc),
+    AnomalyType::Mean => convert_eccentric_to_true_anomaly(
+                           convert_mean_to_eccentric_anomaly(anomaly_rad, ecc), ecc)
+  }
+}
+
+/*
+ * @brief: Function to solve Kepler's equation M = E - e sin(E) for the
+ *         eccentric anomaly E of an elliptic orbit with Newton's method.
+ *
+ * @param[in] mean_anomaly_rad - Mean anomaly M
+ * @param[in] ecc              - Eccentricity (0 <= e < 1)
+ *
+ * @returns: Eccentric anomaly E in [0, 2pi)
+ * @unit: rad
+ */
+pub fn convert_mean_to_eccentric_anomaly(mean_anomaly_rad: f64, ecc: f64)
+-> f64
+{
+  let mean_anomaly: f64 = calc_angle_in_range(mean_anomaly_rad);
+
+  /* Starting value (Vallado) */
+  let mut ecc_anomaly: f64 = if ecc < 0.8 {mean_anomaly} else {PI};
+
+  for _ in 0..KEPLER_EQUATION_MAX_ITERATIONS
+  {
+    let delta: f64 = (ecc_anomaly - ecc * ecc_anomaly.sin() - mean_anomaly)
+                     / (1.0 - ecc * ecc_anomaly.cos());
+    ecc_anomaly -= delta;
+    if delta.abs() < KEPLER_EQUATION_TOLERANCE_RAD
+    {
+      break;
+    }
+  }
+  calc_angle_in_range(ecc_anomaly)
+}
+
+/*
+ * @brief: Function to compute the mean anomaly from the eccentric anomaly of
+ *         an elliptic orbit (Kepler's equation).
+ *
+ * @unit: rad
+ */
+pub fn convert_eccentric_to_mean_anomaly(ecc_anomaly_rad: f64, ecc: f64)
+-> f64
+{
+  calc_angle_in_range(ecc_anomaly_rad - ecc * ecc_anomaly_rad.sin())
+}
+
+/*
+ * @brief: Function to compute the true anomaly from the eccentric anomaly of
+ *         an elliptic orbit.
+ *
+ * @unit: rad
+ */
+pub fn convert_eccentric_to_true_anomaly(ecc_anomaly_rad: f64, ecc: f64)
+-> f64
+{
+  let (sin_ecc_anomaly, cos_ecc_anomaly): (f64, f64) = ecc_anomaly_rad.sin_cos();
+  calc_angle_in_range(((1.0 - ecc * ecc).sqrt() * sin_ecc_anomaly).atan2(cos_ecc_anomaly - ecc))
+}
+
+/*
+ * @brief: Function to compute the eccentric anomaly from the true anomaly of
+ *         an elliptic orbit.
+ *
+ * @unit: rad
+ */
+pub fn convert_true_to_eccentric_anomaly(true_anomaly_rad: f64, ecc: f64)
+-> f64
+{
+  let (sin_nu, cos_nu): (f64, f64) = true_anomaly_rad.sin_cos();
+  calc_angle_in_range(((1.0 - ecc * ecc).sqrt() * sin_nu).atan2(ecc + cos_nu))
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to wrap an angle into [0, 2pi)
+ */
+fn calc_angle_in_range(angle_rad: f64)
+-> f64
+{
+  let angle_out: f64 = angle_rad.rem_euclid(2.0 * PI);
+  /* rem_euclid may return 2pi for tiny negative angles due to round off */
+  if angle_out >= 2.0 * PI {0.0} else {angle_out}
+}
+
+/*
+ * @brief: Function to compute the angle between two vectors in [0, 2pi). The
+ *         angle is mirrored (2pi - angle) if is_mirrored is true.
+ */
+fn calc_angle_between(vec_a: ArrayView1<f64>, vec_b: ArrayView1<f64>, is_mirrored: bool)
+-> f64
+{
+  let cross_norm: f64 = l2_norm_array1(cross_product_array1(vec_a, vec_b).view());
+  let angle: f64 = cross_norm.atan2(vec_a.dot(&vec_b));
+
+  if is_mirrored {calc_angle_in_range(2.0 * PI - angle)} else {angle}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /* Gravitational constant of the Earth [m3/s2] */
+  const MU_EARTH: f64 = 3.986004418e14;
+
+  /*
+   * @brief: Check that the elements survive the conversion to a Cartesian
+   *         state and back
+   */
+  fn assert_kepler_round_trip(elements: [f64; KEPLER_NUM_ELEMENTS])
+  {
+    let elements_in: Array1<f64> = Array1::from(elements.to_vec());
+    let (pos_m, vel_ms): (Array1<f64>, Array1<f64>) = convert_kepler_to_cartesian(elements_in.view(), MU_EARTH);
+    let elements_out: Array1<f64> = convert_cartesian_to_kepler(pos_m.view(), vel_ms.view(), MU_EARTH);
+
+    assert!((elements_out[KEPLER_INDX_SMA] - elements[KEPLER_INDX_SMA]).abs()
+            < 1.0e-9 * elements[KEPLER_INDX_SMA].abs(), "{} != {:?}", elements_out, elements);
+    assert!((elements_out[KEPLER_INDX_ECC] - elements[KEPLER_INDX_ECC]).abs() < 1.0e-10,
+            "{} != {:?}", elements_out, elements);
+    for indx in [KEPLER_INDX_INC, KEPLER_INDX_RAAN, KEPLER_INDX_ARGP, KEPLER_INDX_TRUE_ANOMALY]
+    {
+      /* Angles are compared modulo 2pi */
+      let angle_diff: f64 = calc_angle_in_range(elements_out[indx] - elements[indx] + PI) - PI;
+      assert!(angle_diff.abs() < 1.0e-9, "{} != {:?}", elements_out, elements);
+    }
+  }
+
+  #[test]
+  fn kepler_cartesian_round_trip()
+  {
+    /* Elliptic inclined, retrograde and hyperbolic orbits */
+    assert_kepler_round_trip([7.0e6, 0.01, 0.9, 1.2, 0.5, 2.0]);
+    assert_kepler_round_trip([2.6e7, 0.7, 1.1, 4.0, 5.5, 3.5]);
+    assert_kepler_round_trip([8.0e6, 0.2, 2.5, 0.3, 1.0, 6.0]);
+    assert_kepler_round_trip([-2.0e7, 1.5, 0.5, 2.0, 3.0, 0.5]);
+
+    /* Circular inclined: argp = 0, true anomaly = argument of latitude */
+    assert_kepler_round_trip([7.0e6, 0.0, 0.9, 1.2, 0.0, 2.0]);
+    /* Elliptic equatorial: RAAN = 0, argp = longitude of periapsis */
+    assert_kepler_round_trip([7.5e6, 0.1, 0.0, 0.0, 1.0, 2.0]);
+    assert_kepler_round_trip([7.5e6, 0.1, PI, 0.0, 1.0, 2.0]);
+    /* Circular equatorial: RAAN = 0, argp = 0, true anomaly = true longitude */
+    assert_kepler_round_trip([7.0e6, 0.0, 0.0, 0.0, 0.0, 4.0]);
+  }
+
+  #[test]
+  fn cartesian_kepler_round_trip()
+  {
+    let states: [([f64; 3], [f64; 3]); 3] = [([6.8e6, 1.0e6, -5.0e5], [-1.0e3, 7.2e3, 1.5e3]),
+                                             ([-4.0e6, 3.0e6, 4.0e6], [-3.0e3, -5.0e3, 2.0e3]),
+                                             ([7.0e6, 0.0, 0.0], [0.0, 1.0e4, 3.0e3])];
+    for (pos, vel) in states
+    {
+      let pos_m: Array1<f64> = Array1::from(pos.to_vec());
+      let vel_ms: Array1<f64> = Array1::from(vel.to_vec());
+      let elements: Array1<f64> = convert_cartesian_to_kepler(pos_m.view(), vel_ms.view(), MU_EARTH);
+      let (pos_out_m, vel_out_ms): (Array1<f64>, Array1<f64>) = convert_kepler_to_cartesian(elements.view(), MU_EARTH);
+
+      assert!((&pos_out_m - &pos_m).iter().all(|diff| diff.abs() < 1.0e-6), "{} != {}", pos_out_m, pos_m);
+      assert!((&vel_out_ms - &vel_ms).iter().all(|diff| diff.abs() < 1.0e-9), "{} != {}", vel_out_ms, vel_ms);
+    }
+  }
+
+  #[test]
+  fn anomaly_conversions()
+  {
+    for ecc in [0.0, 0.1, 0.7, 0.95]
+    {
+      for true_anomaly in [0.0, 0.5, 2.0, 3.5, 6.0]
+      {
+        let ecc_anomaly: f64 = convert_true_to_eccentric_anomaly(true_anomaly, ecc);
+        let mean_anomaly: f64 = convert_eccentric_to_mean_anomaly(ecc_anomaly, ecc);
+        assert!((convert_anomaly_to_true_anomaly(ecc_anomaly, AnomalyType::Eccentric, ecc) - true_anomaly).abs() < 1.0e-10);
+        assert!((convert_anomaly_to_true_anomaly(mean_anomaly, AnomalyType::Mean, ecc) - true_anomaly).abs() < 1.0e-10);
+      }
+    }
+  }
+}
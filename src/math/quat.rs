@@ -117,11 +117,15 @@ impl Quat {
     let ww: f64 = self.w * self.w;
 
     let  dd: f64 = (xx + yy + zz + ww).sqrt();
+    if dd == 0.0 || !dd.is_finite()
+    {
+      panic!("! [ERROR] ! > Cannot normalize quaternion with norm {}! <", dd);
+    }
     /* assign new component values */
-    self.x = xx / dd;
-    self.y = yy / dd;
-    self.z = zz / dd;
-    self.w = ww / dd;
+    self.x /= dd;
+    self.y /= dd;
+    self.z /= dd;
+    self.w /= dd;
   }
 }
 /*
@@ -0,0 +1,117 @@
+/*
+ * @brief: Function to find the root of a scalar function within a bracketing
+ *         interval with Brent's method.
+ *
+ * @description: The method combines inverse quadratic interpolation, the
+ *               secant method and bisection. It keeps the root bracketed at
+ *               all times and falls back to bisection whenever the
+ *               interpolation does not converge fast enough, so it converges
+ *               at least as fast as bisection.
+ *
+ *               Ref: Brent - Algorithms for Minimization without Derivatives,
+ *                    Chapter 4
+ *                    Press et al. - Numerical Recipes, Section 9.3
+ *
+ * @param[in] func     - Scalar function f(x)
+ * @param[in] x_a      - Lower bound of the bracketing interval
+ * @param[in] x_b      - Upper bound of the bracketing interval
+ * @param[in] f_a      - Function value at x_a
+ * @param[in] f_b      - Function value at x_b (f_a and f_b need to have
+ *                       opposite signs or one of them is zero)
+ * @param[in] x_tol    - Absolute tolerance of the root
+ * @param[in] max_iter - Maximum number of iterations
+ *
+ * @returns: x with f(x) ~ 0
+ *
+ */
+pub fn find_root_brent(func: &dyn Fn(f64) -> f64,
+                       x_a: f64,
+                       x_b: f64,
+                       f_a: f64,
+                       f_b: f64,
+                       x_tol: f64,
+                       max_iter: usize)
+-> f64
+{
+  if f_a == 0.0 {return x_a;}
+  if f_b == 0.0 {return x_b;}
+
+  let (mut a, mut b, mut fa, mut fb): (f64, f64, f64, f64) = (x_a, x_b, f_a, f_b);
+  let (mut c, mut fc): (f64, f64) = (a, fa);
+  let mut d: f64 = b - a;
+  let mut e: f64 = d;
+
+  for _ in 0..max_iter
+  {
+    /* Keep the root bracketed between b and c */
+    if (fb > 0.0) == (fc > 0.0)
+    {
+      c = a;
+      fc = fa;
+      d = b - a;
+      e = d;
+    }
+    /* b is the best estimate of the root */
+    if fc.abs() < fb.abs()
+    {
+      a = b;
+      b = c;
+      c = a;
+      fa = fb;
+      fb = fc;
+      fc = fa;
+    }
+
+    let tol: f64 = 2.0 * f64::EPSILON * b.abs() + 0.5 * x_tol;
+    let m: f64 = 0.5 * (c - b);
+
+    if m.abs() <= tol || fb == 0.0
+    {
+      return b;
+    }
+
+    if e.abs() >= tol && fa.abs() > fb.abs()
+    {
+      /* Attempt inverse quadratic interpolation (secant if a == c) */
+      let s: f64 = fb / fa;
+      let (mut p, mut q): (f64, f64);
+      if a == c
+      {
+        p = 2.0 * m * s;
+        q = 1.0 - s;
+      }
+      else
+      {
+        let q_ac: f64 = fa / fc;
+        let r_bc: f64 = fb / fc;
+        p = s * (2.0 * m * q_ac * (q_ac - r_bc) - (b - a) * (r_bc - 1.0));
+        q = (q_ac - 1.0) * (r_bc - 1.0) * (s - 1.0);
+      }
+      if p > 0.0 {q = -q;} else {p = -p;}
+
+      /* Accept interpolation only if it stays well within the bracket */
+      if 2.0 * p < (3.0 * m * q - (tol * q).abs()).min((e * q).abs())
+      {
+        e = d;
+        d = p / q;
+      }
+      else
+      {
+        d = m;
+        e = d;
+      }
+    }
+    else
+    {
+      /* Bisection */
+      d = m;
+      e = d;
+    }
+
+    a = b;
+    fa = fb;
+    b += if d.abs() > tol {d} else {tol.copysign(m)};
+    fb = func(b);
+  }
+  b
+}
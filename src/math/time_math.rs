@@ -78,7 +78,7 @@ pub fn calc_julian_day(datetime: DateTime<Utc>)
 -> f64
 {
   /* datetime as julian day */
-  let julian_day: f64 = datetime.timestamp() as f64 / SECONDS_OF_EARTH_DAY 
+  let julian_day: f64 = datetime.timestamp_micros() as f64 * 1.0e-6 / SECONDS_OF_EARTH_DAY 
   + JULIAN_DAYS_AT_UNIX_EPOCH;
   julian_day
 }
@@ -134,4 +134,4 @@ pub fn calc_equ_equinoxes_hours(datetime: DateTime<Utc>)
                       - 0.000024 * (2.0 * mean_long_sun_deg.to_radians()).sin()
                       * (obliquity_deg.to_radians()).cos();
   equat_of_equinoxes
-}
\ No newline at end of file
+}
@@ -0,0 +1,163 @@
+/*
+ * @brief: This file contains the conversions between the time scales UTC,
+ *         TAI, TT, TDB, GPS and UT1.
+ *
+ * @description: Epochs are given as seconds since J2000 in the respective
+ *               time scale, i.e. since 2000-01-01 12:00:00 read on a clock of
+ *               that scale (JD 2451545.0 of the scale). UTC seconds do not
+ *               count leap seconds (same convention as Unix time), hence
+ *               TAI = UTC + (TAI-UTC) with the leap second table.
+ *
+ *               * TAI - International Atomic Time
+ *               * TT  - Terrestrial Time, TT = TAI + 32.184 s (ephemerides,
+ *                       precession-nutation)
+ *               * TDB - Barycentric Dynamical Time, periodic difference to TT
+ *                       below 2 ms (planetary ephemerides)
+ *               * GPS - GPS time, GPS = TAI - 19 s
+ *               * UT1 - Universal Time, UT1 = UTC + (UT1-UTC) from the Earth
+ *                       Orientation Parameters (Earth rotation)
+ *
+ */
+
+/* Import constants */
+use crate::constants::time::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum TimeScale {
+  Utc,
+  Tai,
+  Tt,
+  Tdb,
+  Gps,
+  Ut1
+}
+
+impl TimeScale {
+ /*
+  * @brief: Function to map a time scale parameter string to the time scale.
+  */
+  pub fn from_param_str(scale_str: &str) -> TimeScale
+  {
+    match scale_str.trim().trim_matches('"')
+    {
+      "utc" => TimeScale::Utc,
+      "tai" => TimeScale::Tai,
+      "tt" => TimeScale::Tt,
+      "tdb" => TimeScale::Tdb,
+      "gps" => TimeScale::Gps,
+      "ut1" => TimeScale::Ut1,
+      _ => panic!("! [ERROR] ! > Unknown time scale {} in sim.ini! <", scale_str)
+    }
+  }
+}
+
+/*
+ * @brief: Function to convert an epoch between two time scales
+ *
+ * @param[in] j2000_s_in      - Seconds since J2000 in the time scale scale_in
+ * @param[in] scale_in        - Time scale of the input epoch
+ * @param[in] scale_out       - Time scale of the output epoch
+ * @param[in] ut1_minus_utc_s - UT1-UTC from the Earth Orientation Parameters,
+ *                              only used for conversions from or to UT1
+ *
+ * @returns: Seconds since J2000 in the time scale scale_out
+ * @unit: seconds
+ */
+pub fn convert_time_scale(j2000_s_in: f64,
+                          scale_in: TimeScale,
+                          scale_out: TimeScale,
+                          ut1_minus_utc_s: f64)
+-> f64
+{
+  if scale_in == scale_out
+  {
+    return j2000_s_in;
+  }
+  let j2000_s_tai: f64 = convert_to_tai(j2000_s_in, scale_in, ut1_minus_utc_s);
+  convert_from_tai(j2000_s_tai, scale_out, ut1_minus_utc_s)
+}
+
+/*
+ * @brief: Function to get the difference TAI-UTC (accumulated leap seconds)
+ *         from the leap second table
+ *
+ * @param[in] j2000_s_utc - Seconds since J2000 UTC
+ *
+ * @unit: seconds
+ */
+pub fn calc_tai_minus_utc_s(j2000_s_utc: f64)
+-> f64
+{
+  let mjd_utc: f64 = (JULIAN_DAYS_AT_J2000_EPOCH - DIFF_MJD_TO_JD) + j2000_s_utc / SECONDS_OF_EARTH_DAY;
+  let indx: usize = LEAP_SECONDS_TAI_MINUS_UTC.partition_point(|entry| entry[0] <= mjd_utc);
+  LEAP_SECONDS_TAI_MINUS_UTC[indx.max(1) - 1][1]
+}
+
+/*
+ * @brief: Function to compute the periodic difference TDB-TT
+ *
+ * @param[in] j2000_s_tt - Seconds since J2000 TT
+ *
+ * @unit: seconds
+ */
+pub fn calc_tdb_minus_tt_s(j2000_s_tt: f64)
+-> f64
+{
+  let mean_anomaly: f64 = (TDB_MEAN_ANOMALY_EARTH_DEG[0]
+                           + TDB_MEAN_ANOMALY_EARTH_DEG[1] * j2000_s_tt / SECONDS_OF_EARTH_DAY).to_radians();
+  TDB_MINUS_TT_AMPLITUDES_S[0] * mean_anomaly.sin()
+    + TDB_MINUS_TT_AMPLITUDES_S[1] * (2.0 * mean_anomaly).sin()
+}
+
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+/*
+ * @brief: Function to convert an epoch to seconds since J2000 TAI
+ */
+fn convert_to_tai(j2000_s_in: f64, scale_in: TimeScale, ut1_minus_utc_s: f64)
+-> f64
+{
+  match scale_in
+  {
+    TimeScale::Utc => j2000_s_in + calc_tai_minus_utc_s(j2000_s_in),
+    TimeScale::Tai => j2000_s_in,
+    TimeScale::Tt => j2000_s_in - TT_MINUS_TAI_S,
+    /* The periodic term is evaluated at TDB instead of TT (error < 1 ns) */
+    TimeScale::Tdb => j2000_s_in - calc_tdb_minus_tt_s(j2000_s_in) - TT_MINUS_TAI_S,
+    TimeScale::Gps => j2000_s_in + TAI_MINUS_GPS_S,
+    TimeScale::Ut1 => convert_to_tai(j2000_s_in - ut1_minus_utc_s, TimeScale::Utc, ut1_minus_utc_s)
+  }
+}
+
+/*
+ * @brief: Function to convert seconds since J2000 TAI to an epoch in the
+ *         given time scale
+ */
+fn convert_from_tai(j2000_s_tai: f64, scale_out: TimeScale, ut1_minus_utc_s: f64)
+-> f64
+{
+  match scale_out
+  {
+    TimeScale::Utc =>
+    {
+      /* The leap second table is given in UTC. The first guess is only off
+       * within TAI-UTC after a leap second */
+      let j2000_s_utc_guess: f64 = j2000_s_tai - calc_tai_minus_utc_s(j2000_s_tai);
+      j2000_s_tai - calc_tai_minus_utc_s(j2000_s_utc_guess)
+    }
+    TimeScale::Tai => j2000_s_tai,
+    TimeScale::Tt => j2000_s_tai + TT_MINUS_TAI_S,
+    TimeScale::Tdb =>
+    {
+      let j2000_s_tt: f64 = j2000_s_tai + TT_MINUS_TAI_S;
+      j2000_s_tt + calc_tdb_minus_tt_s(j2000_s_tt)
+    }
+    TimeScale::Gps => j2000_s_tai - TAI_MINUS_GPS_S,
+    TimeScale::Ut1 => convert_from_tai(j2000_s_tai, TimeScale::Utc, ut1_minus_utc_s) + ut1_minus_utc_s
+  }
+}
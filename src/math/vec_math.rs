@@ -59,4 +59,13 @@ pub fn pointwise_square(arr: ArrayView1<f64>) -> Array1<f64> {
 
   arr_out = arr.mapv(|x| x * x);
   arr_out
-}
\ No newline at end of file
+}
+/*
+ * @brief: Calculate the cross product of two 3 element Array1 vectors
+ * 
+ */
+pub fn cross_product_array1(vec_a: ArrayView1<f64>, vec_b: ArrayView1<f64>) -> Array1<f64> {
+  Array1::from(vec![vec_a[1] * vec_b[2] - vec_a[2] * vec_b[1],
+                    vec_a[2] * vec_b[0] - vec_a[0] * vec_b[2],
+                    vec_a[0] * vec_b[1] - vec_a[1] * vec_b[0]])
+}
@@ -1,3 +1,7 @@
 
 
+pub mod integrator;
 pub mod rk4;
+pub mod rk45;
+pub mod rk87;
+pub mod abm;
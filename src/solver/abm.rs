@@ -0,0 +1,116 @@
+/*
+ * @brief: 4th order Adams-Bashforth-Moulton predictor-corrector ODE solver
+ *         with constant step size
+ *
+ * @description: The multistep method reuses the derivatives of the previous
+ *               three steps and only evaluates the equations of motion twice
+ *               per step (PECE):
+ *
+ *               P: x_p     = x_n + dt/24 * (55 f_n - 59 f_n-1 + 37 f_n-2 - 9 f_n-3)
+ *               E: f_p     = f(x_p)
+ *               C: x_n+1   = x_n + dt/24 * (9 f_p + 19 f_n - 5 f_n-1 + f_n-2)
+ *               E: f_n+1   = f(x_n+1)
+ *
+ *               The derivative history is started (and restarted if a step
+ *               deviating from the constant step size is required) with the
+ *               4th order Runge-Kutta method.
+ *
+ *               Ref: Montenbruck, Gill - Satellite Orbits, Section 4.2
+ *
+ */
+
+ /* Import external crates */
+use ndarray::Array1;
+
+ /* Import local crates */
+use crate::environment::environment::Environment;
+use crate::solver::integrator::{Integrator, Dxdt};
+use crate::solver::rk4;
+
+ /* Import constants */
+/* None */
+
+/* Number of derivatives kept in the history */
+const ABM_ORDER: usize = 4;
+
+#[derive(Clone)]
+
+pub struct ABM {
+  /* [step size]
+   * @description : Fixed step size
+   * @unit        : seconds
+   * */
+  dt_s: f64,
+  /* [derivative history]
+   * @description : Derivatives of the last steps, newest first (f_n, f_n-1,
+   *                f_n-2, f_n-3)
+   * @unit        : N/A
+   * */
+  dxdt_history: Vec<Array1<f64>>,
+}
+
+impl ABM {
+  pub fn new(dt_s_in: f64) -> ABM
+  {
+    ABM {
+      dt_s: dt_s_in,
+      dxdt_history: Vec::with_capacity(ABM_ORDER),
+    }
+  }
+}
+
+impl Integrator for ABM {
+  fn step(&mut self,
+          x_in: &Array1<f64>,
+          dxdt: &Dxdt,
+          dt_max_s: f64,
+          environment: &mut Environment)
+  -> (Array1<f64>, f64)
+  {
+    /* Steps deviating from the constant step size invalidate the history */
+    if dt_max_s < self.dt_s
+    {
+      self.dxdt_history.clear();
+      return (rk4::step(x_in, dxdt, dt_max_s, environment), dt_max_s);
+    }
+
+    let dt: f64 = self.dt_s;
+
+    /* Start up: fill the derivative history with Runge-Kutta steps */
+    if self.dxdt_history.len() < ABM_ORDER
+    {
+      if self.dxdt_history.is_empty()
+      {
+        self.dxdt_history.push(dxdt(x_in, environment));
+      }
+      let x_out: Array1<f64> = rk4::step(x_in, dxdt, dt, environment);
+      self.dxdt_history.insert(0, dxdt(&x_out, environment));
+      return (x_out, dt);
+    }
+
+    let f_n: &Array1<f64> = &self.dxdt_history[0];
+    let f_n1: &Array1<f64> = &self.dxdt_history[1];
+    let f_n2: &Array1<f64> = &self.dxdt_history[2];
+    let f_n3: &Array1<f64> = &self.dxdt_history[3];
+
+    /* [PREDICT] Adams-Bashforth */
+    let x_pred: Array1<f64> = x_in + &((55.0 * f_n - 59.0 * f_n1 + 37.0 * f_n2 - 9.0 * f_n3)
+                                       * (dt / 24.0));
+    /* [EVALUATE] */
+    let f_pred: Array1<f64> = dxdt(&x_pred, environment);
+    /* [CORRECT] Adams-Moulton */
+    let x_out: Array1<f64> = x_in + &((9.0 * &f_pred + 19.0 * f_n - 5.0 * f_n1 + f_n2)
+                                      * (dt / 24.0));
+    /* [EVALUATE] */
+    let f_out: Array1<f64> = dxdt(&x_out, environment);
+
+    self.dxdt_history.pop();
+    self.dxdt_history.insert(0, f_out);
+
+    (x_out, dt)
+  }
+
+  fn is_adaptive(&self) -> bool {false}
+
+  fn reset(&mut self) {self.dxdt_history.clear();}
+}
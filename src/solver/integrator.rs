@@ -0,0 +1,319 @@
+/*
+ * @brief: Common interface of all ODE solvers used to integrate the equations
+ *         of motion.
+ *
+ * @description: Each solver implements the Integrator trait and keeps its own
+ *               step size state (fixed step size, proposed next step size of
+ *               adaptive solvers, derivative history of multistep solvers).
+ *               The simulation loop only requests a step that shall not
+ *               exceed a given maximum step size and receives the propagated
+ *               state together with the step size actually taken.
+ *
+ *               The solver is selected with the solver parameter in the
+ *               [solver] section of sim.ini. Available solvers are:
+ *
+ *               * rk4  - Classic 4th order Runge-Kutta, fixed step size
+ *               * rk45 - Dormand-Prince 5(4) Runge-Kutta, adaptive step size
+ *               * rk87 - Prince-Dormand 8(7) Runge-Kutta, adaptive step size
+ *               * abm  - 4th order Adams-Bashforth-Moulton predictor-corrector,
+ *                        fixed step size
+ *
+ */
+
+/* Include external crates */
+use ndarray::Array1;
+
+/* Include local crates */
+use crate::environment::environment::Environment;
+use crate::solver::rk4::RK4;
+use crate::solver::rk45::RK45;
+use crate::solver::rk87::RK87;
+use crate::solver::abm::ABM;
+
+/* Import constants */
+/* None */
+
+/* Step size controller: safety factor and bounds of the step size change */
+const STEP_SAFETY_FACTOR: f64 = 0.9;
+const STEP_MIN_SCALE_FACTOR: f64 = 0.2;
+const STEP_MAX_SCALE_FACTOR: f64 = 5.0;
+
+/*
+ * @brief: Signature of the equations of motion: dx/dt = f(x, environment)
+ */
+pub type Dxdt = dyn Fn(&Array1<f64>, &mut Environment) -> Array1<f64>;
+
+/*
+ * @brief: Signature of a single step of an embedded Runge-Kutta pair returning
+ *         the propagated state and the local truncation error estimate
+ */
+pub type EmbeddedStep = fn(&Array1<f64>, &Dxdt, f64, &mut Environment) -> (Array1<f64>, Array1<f64>);
+
+pub trait Integrator {
+ /*
+  * @brief: Function to propagate the state by a single step.
+  *
+  * @param[in] : x_in        - Full state vector of the previous step
+  * @param[in] : dxdt        - Function containing the equations of motion
+  * @param[in] : dt_max_s    - Maximum size of this step, e.g. to not step
+  *                            over the simulation end time
+  * @param[in] : environment - Environment used to evaluate the equations of
+  *                            motion
+  *
+  * @returns: (Propagated full state vector, step size taken [s])
+  */
+  fn step(&mut self,
+          x_in: &Array1<f64>,
+          dxdt: &Dxdt,
+          dt_max_s: f64,
+          environment: &mut Environment)
+  -> (Array1<f64>, f64);
+
+ /*
+  * @brief: True if the solver adapts its step size. Adaptive solvers are
+  *         limited by the simulation loop to hit write epochs exactly.
+  */
+  fn is_adaptive(&self) -> bool;
+
+ /*
+  * @brief: Number of steps rejected by the error control since the start of
+  *         the simulation.
+  */
+  fn get_num_rejected_steps(&self) -> i64 {0}
+
+ /*
+  * @brief: Function to discard the information of previous steps (e.g. the
+  *         derivative history of multistep solvers), if the equations of
+  *         motion change discontinuously at the start of the next step.
+  */
+  fn reset(&mut self) {}
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+
+pub enum SolverType {
+  Rk4,
+  Rk45,
+  Rk87,
+  Abm
+}
+
+impl SolverType {
+ /*
+  * @brief: Function to map the solver parameter string to the solver type.
+  */
+  pub fn from_param_str(solver_str: &str) -> SolverType
+  {
+    match solver_str.trim().trim_matches('"')
+    {
+      "rk4" => SolverType::Rk4,
+      "rk45" => SolverType::Rk45,
+      "rk87" => SolverType::Rk87,
+      "abm" => SolverType::Abm,
+      _ => panic!("! [ERROR] ! > Unknown solver {} in sim.ini! <", solver_str)
+    }
+  }
+}
+
+/*
+ * @brief: Function to create the solver selected in sim.ini
+ *
+ * @param[in] : solver_type  - Selected solver
+ * @param[in] : dt_s         - Fixed step size or initial step size of
+ *                             adaptive solvers
+ * @param[in] : step_control - Error and step size control of adaptive solvers
+ *
+ */
+pub fn create_integrator(solver_type: SolverType,
+                         dt_s: f64,
+                         step_control: &StepSizeControl)
+-> Box<dyn Integrator>
+{
+  match solver_type
+  {
+    SolverType::Rk4 => Box::new(RK4::new(dt_s)),
+    SolverType::Rk45 => Box::new(RK45::new(dt_s, step_control)),
+    SolverType::Rk87 => Box::new(RK87::new(dt_s, step_control)),
+    SolverType::Abm => Box::new(ABM::new(dt_s))
+  }
+}
+
+#[derive(Clone)]
+
+pub struct StepSizeControl {
+  /* [relative tolerance]
+   * @description : Relative tolerance of the local truncation error
+   * @unit        : N/A
+   * */
+  rel_tol: f64,
+  /* [absolute tolerance]
+   * @description : Absolute tolerance of the local truncation error
+   * @unit        : N/A
+   * */
+  abs_tol: f64,
+  /* [minimum step size]
+   * @description : Lower bound of the step size. Steps of this size are
+   *                accepted even if the error tolerances are not met.
+   * @unit        : seconds
+   * */
+  dt_min_s: f64,
+  /* [maximum step size]
+   * @description : Upper bound of the step size
+   * @unit        : seconds
+   * */
+  dt_max_s: f64,
+  /* [next step size]
+   * @description : Step size proposed for the next step
+   * @unit        : seconds
+   * */
+  dt_next_s: f64,
+  /* [rejected steps]
+   * @description : Number of rejected steps
+   * @unit        : N/A
+   * */
+  num_rejected_steps: i64,
+}
+/*
+ * -----------------------------------------------------------------------------
+ *                    [constructor]
+ * -----------------------------------------------------------------------------
+ */
+impl StepSizeControl {
+  pub fn new(rel_tol_in: f64, abs_tol_in: f64, dt_min_s_in: f64, dt_max_s_in: f64)
+  -> StepSizeControl
+  {
+    StepSizeControl {
+      rel_tol: rel_tol_in,
+      abs_tol: abs_tol_in,
+      dt_min_s: dt_min_s_in,
+      dt_max_s: dt_max_s_in,
+      dt_next_s: dt_max_s_in,
+      num_rejected_steps: 0,
+    }
+  }
+}
+/*
+ * -----------------------------------------------------------------------------
+ *                    [setters]
+ * -----------------------------------------------------------------------------
+ */
+impl StepSizeControl {
+  pub fn set_dt_next_s(&mut self, dt_next_s_in: &f64)
+  {
+    self.dt_next_s = dt_next_s_in.clamp(self.dt_min_s, self.dt_max_s);
+  }
+}
+/*
+ * -----------------------------------------------------------------------------
+ *                    [getters]
+ * -----------------------------------------------------------------------------
+ * Note: All getters here allow immutable access only by design!
+ */
+impl StepSizeControl {
+  pub fn get_num_rejected_steps(&self) -> &i64 {&self.num_rejected_steps}
+}
+/*
+ * -----------------------------------------------------------------------------
+ *                    [public functions]
+ * -----------------------------------------------------------------------------
+ */
+impl StepSizeControl {
+ /*
+  * @brief: Function to perform an error controlled step with an embedded
+  *         Runge-Kutta pair.
+  *
+  * @description: The step is repeated with a reduced step size until the
+  *               scaled error norm is <= 1.0 or the minimum step size is
+  *               reached. The size of the next step is proposed from the
+  *               error of the accepted step.
+  *
+  * @param[in] : embedded_step - Function returning the propagated state and
+  *                              the local truncation error estimate
+  * @param[in] : error_order   - Order of the error estimate (lower order of
+  *                              the embedded pair + 1)
+  *
+  * @returns: (Propagated full state vector, step size taken [s])
+  */
+  pub fn step(&mut self,
+              x_in: &Array1<f64>,
+              dxdt: &Dxdt,
+              dt_max_s: f64,
+              environment: &mut Environment,
+              embedded_step: EmbeddedStep,
+              error_order: f64)
+  -> (Array1<f64>, f64)
+  {
+    /* Step is shortened to hit the next write epoch or the end time */
+    let is_step_limited: bool = dt_max_s < self.dt_next_s;
+    let mut dt_step_s: f64 = self.dt_next_s.min(dt_max_s);
+
+    loop
+    {
+      let (x_out, err_vec) = embedded_step(x_in, dxdt, dt_step_s, environment);
+      let err_norm: f64 = self.calc_error_norm(x_in, &x_out, &err_vec);
+      let dt_proposed_s: f64 = calc_next_step_size(dt_step_s, err_norm, error_order)
+                                 .clamp(self.dt_min_s, self.dt_max_s);
+
+      if err_norm <= 1.0 || dt_step_s <= self.dt_min_s
+      {
+        /* Keep the proposed step size of the unlimited step if a shortened
+         * step was accepted */
+        if !is_step_limited || dt_proposed_s < self.dt_next_s
+        {
+          self.dt_next_s = dt_proposed_s;
+        }
+        return (x_out, dt_step_s);
+      }
+      self.num_rejected_steps += 1;
+      dt_step_s = dt_proposed_s;
+    }
+  }
+}
+/* - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - - -
+ *
+ *                                    [PRIVATE FUNCTIONS]
+ *
+ *  - - - - - - - - - - - - - - - - - - - - - - - - - -- - - - - -- - -- - - -- - - - - -
+ */
+impl StepSizeControl {
+ /*
+  * @brief: Function to compute the scaled norm of the local truncation error
+  *
+  * @description: Each error element is scaled with
+  *               abs_tol + rel_tol * max(|x_in|, |x_out|). The maximum norm is
+  *               used so that state elements which are not solved by the
+  *               integrator (zero derivative) do not dilute the error estimate.
+  *               A value <= 1.0 means the step satisfies the tolerances.
+  */
+  fn calc_error_norm(&self, x_in: &Array1<f64>, x_out: &Array1<f64>, err_in: &Array1<f64>)
+  -> f64
+  {
+    let mut err_norm: f64 = 0.0;
+
+    for ((x_0, x_1), err) in x_in.iter().zip(x_out.iter()).zip(err_in.iter())
+    {
+      let scale: f64 = self.abs_tol + self.rel_tol * x_0.abs().max(x_1.abs());
+      err_norm = err_norm.max(err.abs() / scale);
+    }
+    err_norm
+  }
+}
+
+/*
+ * @brief: Function to propose the size of the next step from the scaled error
+ *         norm of the current step
+ */
+fn calc_next_step_size(dt: f64, err_norm: f64, error_order: f64)
+-> f64
+{
+  let scale_factor: f64 = if err_norm == 0.0
+  {
+    STEP_MAX_SCALE_FACTOR
+  }
+  else
+  {
+    (STEP_SAFETY_FACTOR * err_norm.powf(-1.0 / error_order)).clamp(STEP_MIN_SCALE_FACTOR,
+                                                                  STEP_MAX_SCALE_FACTOR)
+  };
+  dt * scale_factor
+}
@@ -10,6 +10,7 @@
 
  /* Import local crates */
 use crate::environment::environment::Environment;
+use crate::solver::integrator::{Integrator, Dxdt};
 
  /* Import constants */
 /* None */
@@ -19,6 +20,39 @@ use crate::environment::environment::Environment;
  *                    [struct definition]
  * -----------------------------------------------------------------------------
  */
+#[derive(Clone)]
+
+pub struct RK4 {
+  /* [step size]
+   * @description : Fixed step size
+   * @unit        : seconds
+   * */
+  dt_s: f64,
+}
+
+impl RK4 {
+  pub fn new(dt_s_in: f64) -> RK4
+  {
+    RK4 {
+      dt_s: dt_s_in,
+    }
+  }
+}
+
+impl Integrator for RK4 {
+  fn step(&mut self,
+          x_in: &Array1<f64>,
+          dxdt: &Dxdt,
+          dt_max_s: f64,
+          environment: &mut Environment)
+  -> (Array1<f64>, f64)
+  {
+    let dt_step_s: f64 = self.dt_s.min(dt_max_s);
+    (step(x_in, dxdt, dt_step_s, environment), dt_step_s)
+  }
+
+  fn is_adaptive(&self) -> bool {false}
+}
 
  /*
   * @brief: This function implements a single integration step using the forth
@@ -33,7 +67,7 @@ use crate::environment::environment::Environment;
   *
   */
 pub fn step(x_in: &Array1<f64>,
-            dxdt: &dyn Fn(&Array1<f64>, &mut Environment) -> Array1<f64>,
+            dxdt: &Dxdt,
             dt: f64,
             environment: &mut Environment) 
 -> Array1<f64>
@@ -0,0 +1,152 @@
+/*
+ * @brief: Embedded 5(4) order Runge-Kutta ODE solver (Dormand-Prince) with
+ *         adaptive step size control
+ *
+ * @description: Each step computes a 5th order solution which is used to
+ *               propagate the state and an embedded 4th order solution. The
+ *               difference between both solutions is an estimate of the local
+ *               truncation error which is used to accept or reject the step
+ *               and to propose the size of the next step.
+ *
+ *               Ref: Dormand, Prince - A family of embedded Runge-Kutta
+ *                    formulae, Journal of Computational and Applied
+ *                    Mathematics, 1980
+ *                    Hairer, Norsett, Wanner - Solving Ordinary Differential
+ *                    Equations I, Section II.4
+ *
+ */
+
+ /* Import external crates */
+use ndarray::Array1;
+
+ /* Import local crates */
+use crate::environment::environment::Environment;
+use crate::solver::integrator::{Integrator, Dxdt, StepSizeControl};
+
+ /* Import constants */
+/* None */
+
+/* Dormand-Prince Butcher tableau */
+const A21: f64 = 1.0 / 5.0;
+const A31: f64 = 3.0 / 40.0;
+const A32: f64 = 9.0 / 40.0;
+const A41: f64 = 44.0 / 45.0;
+const A42: f64 = -56.0 / 15.0;
+const A43: f64 = 32.0 / 9.0;
+const A51: f64 = 19372.0 / 6561.0;
+const A52: f64 = -25360.0 / 2187.0;
+const A53: f64 = 64448.0 / 6561.0;
+const A54: f64 = -212.0 / 729.0;
+const A61: f64 = 9017.0 / 3168.0;
+const A62: f64 = -355.0 / 33.0;
+const A63: f64 = 46732.0 / 5247.0;
+const A64: f64 = 49.0 / 176.0;
+const A65: f64 = -5103.0 / 18656.0;
+
+/* 5th order weights (identical to the last row of the tableau) */
+const B1: f64 = 35.0 / 384.0;
+const B3: f64 = 500.0 / 1113.0;
+const B4: f64 = 125.0 / 192.0;
+const B5: f64 = -2187.0 / 6784.0;
+const B6: f64 = 11.0 / 84.0;
+
+/* Difference between 5th and embedded 4th order weights */
+const E1: f64 = 71.0 / 57600.0;
+const E3: f64 = -71.0 / 16695.0;
+const E4: f64 = 71.0 / 1920.0;
+const E5: f64 = -17253.0 / 339200.0;
+const E6: f64 = 22.0 / 525.0;
+const E7: f64 = -1.0 / 40.0;
+
+#[derive(Clone)]
+
+pub struct RK45 {
+  /* [step size control]
+   * @description : Error control and step size state
+   * @unit        : N/A
+   * */
+  step_control: StepSizeControl,
+}
+
+impl RK45 {
+  pub fn new(dt_s_in: f64, step_control_in: &StepSizeControl) -> RK45
+  {
+    let mut step_control: StepSizeControl = step_control_in.clone();
+    step_control.set_dt_next_s(&dt_s_in);
+    RK45 {
+      step_control,
+    }
+  }
+}
+
+impl Integrator for RK45 {
+  fn step(&mut self,
+          x_in: &Array1<f64>,
+          dxdt: &Dxdt,
+          dt_max_s: f64,
+          environment: &mut Environment)
+  -> (Array1<f64>, f64)
+  {
+    /* Error estimate is of 4th order -> step size exponent 1/5 */
+    self.step_control.step(x_in, dxdt, dt_max_s, environment, step, 5.0)
+  }
+
+  fn is_adaptive(&self) -> bool {true}
+
+  fn get_num_rejected_steps(&self) -> i64 {*self.step_control.get_num_rejected_steps()}
+}
+
+ /*
+  * @brief: This function implements a single integration step using the
+  *         Dormand-Prince 5(4) method
+  *
+  * @param[in] : x_in - Vector containing the full state vector of the previous
+  *                     step
+  *
+  * @param[in] : dxdt - Function containing the  equations of motion
+  *
+  * @param[in] : dt   - Step size
+  *
+  * @returns: (State propagated with the 5th order solution,
+  *            local truncation error estimate for each state element)
+  *
+  */
+pub fn step(x_in: &Array1<f64>,
+            dxdt: &Dxdt,
+            dt: f64,
+            environment: &mut Environment)
+-> (Array1<f64>, Array1<f64>)
+{
+  let k1: Array1<f64> = dxdt(x_in, environment);
+
+  let x_2: Array1<f64> = x_in + &(&k1 * (A21 * dt));
+  let k2: Array1<f64> = dxdt(&x_2, environment);
+
+  let x_3: Array1<f64> = x_in + &((A31 * &k1 + A32 * &k2) * dt);
+  let k3: Array1<f64> = dxdt(&x_3, environment);
+
+  let x_4: Array1<f64> = x_in + &((A41 * &k1 + A42 * &k2 + A43 * &k3) * dt);
+  let k4: Array1<f64> = dxdt(&x_4, environment);
+
+  let x_5: Array1<f64> = x_in + &((A51 * &k1 + A52 * &k2 + A53 * &k3 + A54 * &k4) * dt);
+  let k5: Array1<f64> = dxdt(&x_5, environment);
+
+  let x_6: Array1<f64> = x_in + &((A61 * &k1 + A62 * &k2 + A63 * &k3 + A64 * &k4
+                                    + A65 * &k5) * dt);
+  let k6: Array1<f64> = dxdt(&x_6, environment);
+
+  /* Propagate state at t + dt with the 5th order solution */
+  let x_out: Array1<f64> = x_in + &((B1 * &k1 + B3 * &k3 + B4 * &k4 + B5 * &k5
+                                     + B6 * &k6) * dt);
+  /* Last stage is evaluated at the new state for the error estimate. It is
+   * not reused as the first stage of the next step, since the simulation
+   * loop modifies the state between steps (quaternion normalization, epoch,
+   * state augmentation) */
+  let k7: Array1<f64> = dxdt(&x_out, environment);
+
+  /* Local truncation error estimate (5th order minus 4th order solution) */
+  let err_out: Array1<f64> = (E1 * &k1 + E3 * &k3 + E4 * &k4 + E5 * &k5 + E6 * &k6
+                              + E7 * &k7) * dt;
+
+  (x_out, err_out)
+}
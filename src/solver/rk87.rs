@@ -0,0 +1,174 @@
+/*
+ * @brief: Embedded 8(7) order Runge-Kutta ODE solver (Prince-Dormand
+ *         RK8(7)13M) with adaptive step size control
+ *
+ * @description: Each step evaluates the equations of motion 13 times and
+ *               computes an 8th order solution which is used to propagate the
+ *               state and an embedded 7th order solution for the estimate of
+ *               the local truncation error. For tight tolerances this allows
+ *               considerably larger steps than the 5(4) pair.
+ *
+ *               Ref: Prince, Dormand - High order embedded Runge-Kutta
+ *                    formulae, Journal of Computational and Applied
+ *                    Mathematics, 1981
+ *
+ */
+
+ /* Import external crates */
+use ndarray::Array1;
+
+ /* Import local crates */
+use crate::environment::environment::Environment;
+use crate::solver::integrator::{Integrator, Dxdt, StepSizeControl};
+
+ /* Import constants */
+/* None */
+
+const NUM_STAGES: usize = 13;
+
+/* RK8(7)13M Butcher tableau (lower triangle, row i holds a_i1 ... a_i(i-1)) */
+const A: [[f64; NUM_STAGES - 1]; NUM_STAGES] = [
+  [0.0; NUM_STAGES - 1],
+  [1.0 / 18.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [1.0 / 48.0, 1.0 / 16.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [1.0 / 32.0, 0.0, 3.0 / 32.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [5.0 / 16.0, 0.0, -75.0 / 64.0, 75.0 / 64.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [3.0 / 80.0, 0.0, 0.0, 3.0 / 16.0, 3.0 / 20.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [29443841.0 / 614563906.0, 0.0, 0.0, 77736538.0 / 692538347.0,
+   -28693883.0 / 1125000000.0, 23124283.0 / 1800000000.0,
+   0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+  [16016141.0 / 946692911.0, 0.0, 0.0, 61564180.0 / 158732637.0,
+   22789713.0 / 633445777.0, 545815736.0 / 2771057229.0,
+   -180193667.0 / 1043307555.0,
+   0.0, 0.0, 0.0, 0.0, 0.0],
+  [39632708.0 / 573591083.0, 0.0, 0.0, -433636366.0 / 683701615.0,
+   -421739975.0 / 2616292301.0, 100302831.0 / 723423059.0,
+   790204164.0 / 839813087.0, 800635310.0 / 3783071287.0,
+   0.0, 0.0, 0.0, 0.0],
+  [246121993.0 / 1340847787.0, 0.0, 0.0, -37695042795.0 / 15268766246.0,
+   -309121744.0 / 1061227803.0, -12992083.0 / 490766935.0,
+   6005943493.0 / 2108947869.0, 393006217.0 / 1396673457.0,
+   123872331.0 / 1001029789.0,
+   0.0, 0.0, 0.0],
+  [-1028468189.0 / 846180014.0, 0.0, 0.0, 8478235783.0 / 508512852.0,
+   1311729495.0 / 1432422823.0, -10304129995.0 / 1701304382.0,
+   -48777925059.0 / 3047939560.0, 15336726248.0 / 1032824649.0,
+   -45442868181.0 / 3398467696.0, 3065993473.0 / 597172653.0,
+   0.0, 0.0],
+  [185892177.0 / 718116043.0, 0.0, 0.0, -3185094517.0 / 667107341.0,
+   -477755414.0 / 1098053517.0, -703635378.0 / 230739211.0,
+   5731566787.0 / 1027545527.0, 5232866602.0 / 850066563.0,
+   -4093664535.0 / 808688257.0, 3962137247.0 / 1805957418.0,
+   65686358.0 / 487910083.0,
+   0.0],
+  [403863854.0 / 491063109.0, 0.0, 0.0, -5068492393.0 / 434740067.0,
+   -411421997.0 / 543043805.0, 652783627.0 / 914296604.0,
+   11173962825.0 / 925320556.0, -13158990841.0 / 6184727034.0,
+   3936647629.0 / 1978049680.0, -160528059.0 / 685178525.0,
+   248638103.0 / 1413531060.0, 0.0],
+];
+
+/* 8th order weights */
+const B8: [f64; NUM_STAGES] = [
+  14005451.0 / 335480064.0, 0.0, 0.0, 0.0, 0.0,
+  -59238493.0 / 1068277825.0, 181606767.0 / 758867731.0,
+  561292985.0 / 797845732.0, -1041891430.0 / 1371343529.0,
+  760417239.0 / 1151165299.0, 118820643.0 / 751138087.0,
+  -528747749.0 / 2220607170.0, 1.0 / 4.0
+];
+
+/* Embedded 7th order weights */
+const B7: [f64; NUM_STAGES] = [
+  13451932.0 / 455176623.0, 0.0, 0.0, 0.0, 0.0,
+  -808719846.0 / 976000145.0, 1757004468.0 / 5645159321.0,
+  656045339.0 / 265891186.0, -3867574721.0 / 1518517206.0,
+  465885868.0 / 322736535.0, 53011238.0 / 667516719.0,
+  2.0 / 45.0, 0.0
+];
+
+#[derive(Clone)]
+
+pub struct RK87 {
+  /* [step size control]
+   * @description : Error control and step size state
+   * @unit        : N/A
+   * */
+  step_control: StepSizeControl,
+}
+
+impl RK87 {
+  pub fn new(dt_s_in: f64, step_control_in: &StepSizeControl) -> RK87
+  {
+    let mut step_control: StepSizeControl = step_control_in.clone();
+    step_control.set_dt_next_s(&dt_s_in);
+    RK87 {
+      step_control,
+    }
+  }
+}
+
+impl Integrator for RK87 {
+  fn step(&mut self,
+          x_in: &Array1<f64>,
+          dxdt: &Dxdt,
+          dt_max_s: f64,
+          environment: &mut Environment)
+  -> (Array1<f64>, f64)
+  {
+    /* Error estimate is of 7th order -> step size exponent 1/8 */
+    self.step_control.step(x_in, dxdt, dt_max_s, environment, step, 8.0)
+  }
+
+  fn is_adaptive(&self) -> bool {true}
+
+  fn get_num_rejected_steps(&self) -> i64 {*self.step_control.get_num_rejected_steps()}
+}
+
+ /*
+  * @brief: This function implements a single integration step using the
+  *         Prince-Dormand 8(7) method
+  *
+  * @param[in] : x_in - Vector containing the full state vector of the previous
+  *                     step
+  *
+  * @param[in] : dxdt - Function containing the  equations of motion
+  *
+  * @param[in] : dt   - Step size
+  *
+  * @returns: (State propagated with the 8th order solution,
+  *            local truncation error estimate for each state element)
+  *
+  */
+pub fn step(x_in: &Array1<f64>,
+            dxdt: &Dxdt,
+            dt: f64,
+            environment: &mut Environment)
+-> (Array1<f64>, Array1<f64>)
+{
+  let mut k: Vec<Array1<f64>> = Vec::with_capacity(NUM_STAGES);
+
+  for a_row in A.iter()
+  {
+    let mut x_stage: Array1<f64> = x_in.clone();
+    for (a_ij, k_j) in a_row.iter().zip(k.iter())
+    {
+      if *a_ij != 0.0
+      {
+        x_stage.scaled_add(a_ij * dt, k_j);
+      }
+    }
+    k.push(dxdt(&x_stage, environment));
+  }
+
+  /* Propagate state at t + dt with the 8th order solution and estimate the
+   * local truncation error (8th order minus 7th order solution) */
+  let mut x_out: Array1<f64> = x_in.clone();
+  let mut err_out: Array1<f64> = Array1::zeros(x_in.len());
+  for ((b8_i, b7_i), k_i) in B8.iter().zip(B7.iter()).zip(k.iter())
+  {
+    x_out.scaled_add(b8_i * dt, k_i);
+    err_out.scaled_add((b8_i - b7_i) * dt, k_i);
+  }
+
+  (x_out, err_out)
+}